@@ -5,9 +5,10 @@
 //! - Auto-syncing configuration from GitHub
 //! - Auto-installing Main-Profile if not present
 
+use crate::ui::Ui;
 use anyhow::Result;
 use colored::Colorize;
-use rhinolabs_core::{Deploy, ProfileType, Profiles};
+use rhinolabs_core::{Deploy, InstallOptions, ProfileType, Profiles};
 use std::fs;
 use std::io::{self, Write};
 use std::path::PathBuf;
@@ -62,8 +63,13 @@ fn is_main_profile_installed() -> bool {
     false
 }
 
-/// Prompt user for yes/no confirmation
+/// Prompt user for yes/no confirmation; always yes with `--yes`
 fn prompt_yes_no(prompt: &str, default_yes: bool) -> bool {
+    // `--yes` proceeds even where the default is No (e.g. uninstall)
+    if Ui::assume_yes() {
+        return true;
+    }
+
     let suffix = if default_yes { "[Y/n]" } else { "[y/N]" };
     print!("{} {}: ", prompt, suffix);
     io::stdout().flush().unwrap();
@@ -84,6 +90,10 @@ fn prompt_yes_no(prompt: &str, default_yes: bool) -> bool {
 /// Run auto-sync check and sync if needed
 /// Returns true if sync was performed, false otherwise
 pub async fn run_auto_sync() -> Result<bool> {
+    if rhinolabs_core::network::is_offline() {
+        return Ok(false);
+    }
+
     // Check if GitHub is configured
     let project_config = match rhinolabs_core::Project::get_config() {
         Ok(config) => config,
@@ -198,7 +208,7 @@ async fn check_and_install_main_profile() -> Result<()> {
         println!();
         println!("Installing Main-Profile...");
 
-        match Profiles::install("main", None, None) {
+        match Profiles::install("main", None, None, InstallOptions::default()) {
             Ok(result) => {
                 println!();
                 println!("{} Main-Profile installed to ~/.claude/", "✓".green());
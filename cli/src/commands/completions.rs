@@ -0,0 +1,47 @@
+use anyhow::Result;
+use clap_complete::Shell;
+use std::io::Write;
+use std::path::Path;
+
+/// Name the CLI was invoked as (`rhinolabs-ai` or the `rlai` alias), so the script
+/// completes the command the user actually types
+fn bin_name() -> String {
+    std::env::args()
+        .next()
+        .as_deref()
+        .and_then(|arg| Path::new(arg).file_stem())
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "rhinolabs-ai".to_string())
+}
+
+/// The completion script for `shell`
+fn script(shell: Shell) -> Vec<u8> {
+    let mut command = crate::cli_command();
+    let mut out = Vec::new();
+    clap_complete::generate(shell, &mut command, bin_name(), &mut out);
+    out
+}
+
+/// Print the completion script for `shell`
+pub fn run(shell: Shell) -> Result<()> {
+    std::io::stdout().write_all(&script(shell))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_produces_a_script_for_each_shell() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+            let script = String::from_utf8(script(shell)).unwrap();
+            assert!(!script.is_empty(), "{} script is empty", shell);
+            assert!(
+                script.contains("profile"),
+                "{} script lacks subcommands",
+                shell
+            );
+        }
+    }
+}
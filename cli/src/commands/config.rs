@@ -0,0 +1,61 @@
+use crate::ui::Ui;
+use anyhow::Result;
+use colored::Colorize;
+use rhinolabs_core::Deploy;
+use std::path::Path;
+
+/// Export a snapshot of this machine's configuration to `out`
+pub fn export(out: &Path, json: bool) -> Result<()> {
+    let manifest = Deploy::export_snapshot(out)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&manifest)?);
+        return Ok(());
+    }
+
+    Ui::success(&format!(
+        "Exported {} file(s) to {}",
+        manifest.files_count,
+        out.display()
+    ));
+    if !manifest.redacted.is_empty() {
+        Ui::warning(&format!(
+            "{} secret value(s) were left out and will need to be set again:",
+            manifest.redacted.len()
+        ));
+        for secret in &manifest.redacted {
+            println!("    {} {}", secret.file, secret.pointer.dimmed());
+        }
+    }
+
+    Ok(())
+}
+
+/// Restore a snapshot written by `config export`
+pub fn import(file: &Path, json: bool) -> Result<()> {
+    let result = Deploy::import_snapshot(file)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    Ui::success(&format!(
+        "Restored {} file(s) from a snapshot taken {} (rhinolabs-ai {})",
+        result.files_restored, result.manifest.created_at, result.manifest.version
+    ));
+    if result.secrets_kept > 0 {
+        Ui::info(&format!(
+            "Kept {} secret value(s) already set on this machine",
+            result.secrets_kept
+        ));
+    }
+    if !result.secrets_missing.is_empty() {
+        Ui::warning("These secret values were not in the snapshot; set them again:");
+        for secret in &result.secrets_missing {
+            println!("    {} {}", secret.file, secret.pointer.dimmed());
+        }
+    }
+
+    Ok(())
+}
@@ -1,14 +1,28 @@
 use crate::ui::Ui;
 use anyhow::Result;
 use colored::Colorize;
-use rhinolabs_core::{diagnostics::CheckStatus, Doctor};
+use rhinolabs_core::{
+    diagnostics::{CheckStatus, DiagnosticReport},
+    Doctor,
+};
 
-pub async fn run(json: bool) -> Result<()> {
-    let report = Doctor::run().await?;
+pub async fn run(json: bool, fix: bool, offline: bool) -> Result<()> {
+    let repairs = if fix { Doctor::fix()? } else { Vec::new() };
+    let report = Doctor::run(offline).await?;
 
     if json {
         println!("{}", serde_json::to_string_pretty(&report)?);
-        return Ok(());
+        return exit_status(&report);
+    }
+
+    if fix {
+        Ui::header("🔧 Applying Repairs");
+        if repairs.is_empty() {
+            Ui::info("Nothing to repair");
+        }
+        for repair in &repairs {
+            Ui::success(repair);
+        }
     }
 
     Ui::header("🔍 Running Diagnostics");
@@ -43,5 +57,13 @@ pub async fn run(json: bool) -> Result<()> {
         Ui::success("All checks passed!");
     }
 
+    exit_status(&report)
+}
+
+/// Fail the command when any check failed, so `doctor` can gate a CI pipeline
+fn exit_status(report: &DiagnosticReport) -> Result<()> {
+    if !report.success {
+        anyhow::bail!("{} diagnostic check(s) failed", report.failed);
+    }
     Ok(())
 }
@@ -2,31 +2,55 @@ use crate::ui::Ui;
 use anyhow::Result;
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
-use rhinolabs_core::{DeployTarget, Installer, Paths, Profiles};
+use rhinolabs_core::{DeployTarget, InstallOptions, Installer, Paths, Profiles, TargetPaths};
 
-/// Parse target strings into DeployTarget vec.
+/// Parse target strings into DeployTarget vec, defaulting to Claude Code.
 fn parse_targets(strs: &[String]) -> Result<Vec<DeployTarget>> {
     if strs.iter().any(|s| s == "all") {
-        return Ok(DeployTarget::all().to_vec());
+        return Ok(DeployTarget::all_with_custom()?);
     }
-    strs.iter()
-        .map(|s| s.parse::<DeployTarget>().map_err(|e| anyhow::anyhow!(e)))
-        .collect()
+    if strs.is_empty() {
+        return Ok(vec![DeployTarget::ClaudeCode]);
+    }
+
+    let mut targets: Vec<DeployTarget> = Vec::new();
+    for s in strs {
+        let target = s.parse::<DeployTarget>().map_err(|e| anyhow::anyhow!(e))?;
+        if !targets.contains(&target) {
+            targets.push(target);
+        }
+    }
+    Ok(targets)
 }
 
-pub async fn run(target_strs: Vec<String>, skip_profile: bool, dry_run: bool) -> Result<()> {
+pub async fn run(
+    target_strs: Vec<String>,
+    skip_profile: bool,
+    dry_run: bool,
+    verify: bool,
+    version: Option<String>,
+) -> Result<()> {
     Ui::header("Installing Rhinolabs AI");
 
-    let installer = Installer::new().dry_run(dry_run);
+    // Validate targets before downloading anything
+    let targets = parse_targets(&target_strs)?;
 
-    // Pre-flight checks
-    Ui::step("Checking Claude Code installation...");
-    if !Paths::is_claude_code_installed() {
-        Ui::error("Claude Code not found");
-        Ui::info("Please install Claude Code from: https://code.claude.com");
-        return Ok(());
+    let installer = Installer::new()
+        .dry_run(dry_run)
+        .verify(verify)
+        .version(version.clone())
+        .targets(targets.clone());
+
+    // Pre-flight checks (Claude Code is only required when it's a target)
+    if targets.contains(&DeployTarget::ClaudeCode) {
+        Ui::step("Checking Claude Code installation...");
+        if !Paths::is_claude_code_installed() {
+            Ui::error("Claude Code not found");
+            Ui::info("Please install Claude Code from: https://code.claude.com");
+            return Ok(());
+        }
+        Ui::success("Claude Code detected");
     }
-    Ui::success("Claude Code detected");
 
     // Check if already installed
     if Paths::is_plugin_installed() && !dry_run {
@@ -38,6 +62,9 @@ pub async fn run(target_strs: Vec<String>, skip_profile: bool, dry_run: bool) ->
 
     // Step 1: Install plugin from GitHub releases
     Ui::step("Installing plugin...");
+    if !verify {
+        Ui::warning("Skipping checksum verification (--no-verify)");
+    }
 
     let pb = ProgressBar::new_spinner();
     pb.set_style(
@@ -45,7 +72,10 @@ pub async fn run(target_strs: Vec<String>, skip_profile: bool, dry_run: bool) ->
             .template("{spinner:.cyan} {msg}")
             .unwrap(),
     );
-    pb.set_message("Downloading from GitHub releases...");
+    pb.set_message(match &version {
+        Some(version) => format!("Downloading {} from GitHub releases...", version),
+        None => "Downloading from GitHub releases...".to_string(),
+    });
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
 
     installer.install().await?;
@@ -60,6 +90,12 @@ pub async fn run(target_strs: Vec<String>, skip_profile: bool, dry_run: bool) ->
     }
 
     if dry_run || skip_profile {
+        if dry_run && !skip_profile {
+            Ui::info(&format!(
+                "[DRY RUN] Would install main profile skills to: {}",
+                format_targets(&targets)
+            ));
+        }
         if skip_profile {
             Ui::info("Skipped profile installation (--skip-profile)");
             Ui::info("Run 'rhinolabs-ai profile install main' to install skills later.");
@@ -72,14 +108,7 @@ pub async fn run(target_strs: Vec<String>, skip_profile: bool, dry_run: bool) ->
     println!();
     Ui::step("Installing main profile skills...");
 
-    let targets = parse_targets(&target_strs)?;
-    let targets_ref = if targets.is_empty() {
-        None
-    } else {
-        Some(targets.as_slice())
-    };
-
-    let result = Profiles::install("main", None, targets_ref)?;
+    let result = Profiles::install("main", None, Some(&targets), InstallOptions::default())?;
 
     if !result.skills_installed.is_empty() {
         Ui::success(&format!(
@@ -102,11 +131,84 @@ pub async fn run(target_strs: Vec<String>, skip_profile: bool, dry_run: bool) ->
         Ui::warning("No skills found in plugin. The plugin may be incomplete.");
     }
 
+    // Per-target results
+    Ui::section("Targets");
+    for target in &result.targets_installed {
+        match TargetPaths::user_skills_dir(*target) {
+            Ok(dir) => println!(
+                "  {} {} → {}",
+                "✓".green(),
+                target.display_name(),
+                dir.display()
+            ),
+            Err(e) => println!("  {} {} - {}", "✗".red(), target.display_name(), e),
+        }
+    }
+    for failure in &result.targets_failed {
+        println!(
+            "  {} {} - {}",
+            "✗".red(),
+            failure.target.display_name(),
+            failure.error
+        );
+    }
+
     // Summary
     println!();
-    Ui::success("Installation complete!");
+    if result.targets_failed.is_empty() {
+        Ui::success("Installation complete!");
+    } else {
+        Ui::warning(&format!(
+            "Installation completed with {} failed target(s)",
+            result.targets_failed.len()
+        ));
+    }
     println!();
-    Ui::info("Restart Claude Code to activate the plugin.");
+    Ui::info(&format!(
+        "Restart {} to activate the plugin.",
+        format_targets(&result.targets_installed)
+    ));
 
     Ok(())
 }
+
+fn format_targets(targets: &[DeployTarget]) -> String {
+    targets
+        .iter()
+        .map(|t| t.display_name())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_targets_defaults_to_claude_code() {
+        assert_eq!(parse_targets(&[]).unwrap(), vec![DeployTarget::ClaudeCode]);
+    }
+
+    #[test]
+    fn test_parse_targets_all_keyword() {
+        let targets = parse_targets(&["amp".to_string(), "all".to_string()]).unwrap();
+        // "all" includes any custom targets configured on this machine
+        assert_eq!(targets, DeployTarget::all_with_custom().unwrap());
+    }
+
+    #[test]
+    fn test_parse_targets_dedups_and_keeps_order() {
+        let targets = parse_targets(&[
+            "amp".to_string(),
+            "claude-code".to_string(),
+            "amp".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(targets, vec![DeployTarget::Amp, DeployTarget::ClaudeCode]);
+    }
+
+    #[test]
+    fn test_parse_targets_rejects_unknown() {
+        assert!(parse_targets(&["vim".to_string()]).is_err());
+    }
+}
@@ -23,14 +23,19 @@ pub async fn run() -> Result<()> {
             .interact()?;
 
         match selection {
-            0 => super::install::run(vec![], false, false).await?,
-            1 => super::update::run(false).await?,
+            0 => super::install::run(vec![], false, false, true, None).await?,
+            1 => super::update::run(false, true, None, false, false).await?,
             2 => {
                 Ui::info("Please use: rhinolabs sync-mcp --url <URL>");
                 Ui::info("Or: rhinolabs sync-mcp --file <PATH>");
             }
             3 => super::status::run(false)?,
-            4 => super::doctor::run(false).await?,
+            4 => {
+                // Failed checks are already listed; keep the menu open instead of exiting
+                if let Err(e) = super::doctor::run(false, false, false).await {
+                    Ui::error(&e.to_string());
+                }
+            }
             5 => super::uninstall::run(false)?,
             6 => {
                 println!("Goodbye!");
@@ -0,0 +1,188 @@
+use crate::ui::Ui;
+use anyhow::Result;
+use colored::Colorize;
+use rhinolabs_core::{McpConfig, McpConfigManager, McpMergeStrategy, McpServer};
+use std::path::Path;
+
+/// Servers to display, sorted by name. Secret env/header values are masked
+/// unless `reveal` is set.
+fn display_servers(config: &McpConfig, reveal: bool) -> Vec<(String, McpServer)> {
+    let mut servers: Vec<(String, McpServer)> = config
+        .mcp_servers
+        .iter()
+        .map(|(name, server)| {
+            let server = if reveal {
+                server.clone()
+            } else {
+                server.redacted(&config.settings.secret_keys)
+            };
+            (name.clone(), server)
+        })
+        .collect();
+    servers.sort_by(|a, b| a.0.cmp(&b.0));
+    servers
+}
+
+/// List configured MCP servers
+pub fn list(json: bool, reveal: bool) -> Result<()> {
+    let config = McpConfigManager::get()?;
+    let servers = display_servers(&config, reveal);
+
+    if json {
+        let map: serde_json::Map<String, serde_json::Value> = servers
+            .into_iter()
+            .map(|(name, server)| Ok((name, serde_json::to_value(server)?)))
+            .collect::<Result<_>>()?;
+        println!("{}", serde_json::to_string_pretty(&map)?);
+        return Ok(());
+    }
+
+    Ui::header("MCP Servers");
+
+    if servers.is_empty() {
+        Ui::info("No MCP servers configured.");
+        return Ok(());
+    }
+
+    println!();
+    for (name, server) in &servers {
+        let target = match (&server.command, &server.url) {
+            (Some(command), _) => format!("{} {}", command, server.args.join(" ")),
+            (None, Some(url)) => url.clone(),
+            (None, None) => String::new(),
+        };
+        let disabled = if server.enabled {
+            "".normal()
+        } else {
+            " [disabled]".yellow()
+        };
+        println!(
+            "  {}{} {}",
+            name.bold(),
+            disabled,
+            target.trim_end().dimmed()
+        );
+
+        for (label, values) in [("env", &server.env), ("header", &server.headers)] {
+            let Some(values) = values else { continue };
+            let mut entries: Vec<_> = values.iter().collect();
+            entries.sort();
+            for (key, value) in entries {
+                println!("      {} {}={}", label.dimmed(), key, value);
+            }
+        }
+    }
+    println!();
+
+    if !reveal {
+        Ui::info("Secret values are masked. Use --reveal to show them.");
+    }
+
+    Ok(())
+}
+
+/// Enable or disable a server; disabled servers stay in the config but aren't deployed
+pub fn set_enabled(name: &str, enabled: bool, json: bool) -> Result<()> {
+    McpConfigManager::set_enabled(name, enabled)?;
+
+    if json {
+        let result = serde_json::json!({ "name": name, "enabled": enabled });
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else if enabled {
+        Ui::success(&format!("MCP server '{}' enabled", name));
+    } else {
+        Ui::success(&format!("MCP server '{}' disabled", name));
+        Ui::info("Its definition is kept; re-enable it with 'rhinolabs-ai mcp enable'.");
+    }
+
+    Ok(())
+}
+
+/// Import servers from a project's .mcp.json into the managed config
+pub fn import(project: &Path, overwrite: bool, json: bool) -> Result<()> {
+    let strategy = if overwrite {
+        McpMergeStrategy::Overwrite
+    } else {
+        McpMergeStrategy::KeepExisting
+    };
+    let result = McpConfigManager::import_project_mcp(project, strategy)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    Ui::header("MCP Import");
+
+    if result.imported.is_empty() {
+        Ui::info("No servers imported.");
+    } else {
+        Ui::success(&format!("Imported {} server(s)", result.imported.len()));
+        for name in &result.imported {
+            println!("  {} {}", "✓".green(), name);
+        }
+    }
+
+    if !result.unchanged.is_empty() {
+        println!();
+        println!("  Already managed: {}", result.unchanged.join(", "));
+    }
+
+    if !result.conflicts.is_empty() {
+        Ui::section("Conflicts");
+        for name in &result.conflicts {
+            println!("  {} {}", "⚠".yellow(), name);
+        }
+        if !overwrite {
+            Ui::info("Kept the managed definitions. Use --overwrite to replace them.");
+        }
+    }
+
+    if !result.invalid.is_empty() {
+        Ui::section("Skipped (invalid)");
+        for entry in &result.invalid {
+            println!("  {} {}", "✗".red(), entry);
+        }
+    }
+
+    println!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rhinolabs_core::REDACTED_VALUE;
+    use std::collections::HashMap;
+
+    fn config_with_github_token() -> McpConfig {
+        let mut server = McpServer::stdio("npx".into(), vec!["github-mcp".into()]);
+        server.env = Some(HashMap::from([
+            ("GITHUB_TOKEN".to_string(), "ghp_secret".to_string()),
+            ("LOG_LEVEL".to_string(), "debug".to_string()),
+        ]));
+
+        let mut config = McpConfig::default();
+        config.mcp_servers.insert("github".into(), server);
+        config
+    }
+
+    fn env_of(servers: &[(String, McpServer)], key: &str) -> String {
+        servers[0].1.env.as_ref().unwrap()[key].clone()
+    }
+
+    #[test]
+    fn test_github_token_masked_by_default() {
+        let servers = display_servers(&config_with_github_token(), false);
+
+        assert_eq!(env_of(&servers, "GITHUB_TOKEN"), REDACTED_VALUE);
+        assert_eq!(env_of(&servers, "LOG_LEVEL"), "debug");
+    }
+
+    #[test]
+    fn test_github_token_shown_with_reveal() {
+        let servers = display_servers(&config_with_github_token(), true);
+
+        assert_eq!(env_of(&servers, "GITHUB_TOKEN"), "ghp_secret");
+    }
+}
@@ -1,13 +1,19 @@
 pub mod auto_sync;
+pub mod completions;
+pub mod config;
 pub mod deploy;
 pub mod doctor;
 pub mod install;
 pub mod interactive;
+pub mod mcp;
+pub mod output_style;
 pub mod profile;
 pub mod rag;
 pub mod skill;
+pub mod source;
 pub mod status;
 pub mod sync_mcp;
 pub mod uninstall;
 pub mod update;
 pub mod version;
+pub mod watch;
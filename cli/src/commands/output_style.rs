@@ -0,0 +1,78 @@
+use crate::ui::Ui;
+use anyhow::Result;
+use colored::Colorize;
+use rhinolabs_core::OutputStyles;
+
+/// List output styles, marking the active one
+pub fn list(json: bool) -> Result<()> {
+    let styles = OutputStyles::list()?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&styles)?);
+        return Ok(());
+    }
+
+    Ui::header("Output Styles");
+
+    if styles.is_empty() {
+        Ui::info("No output styles found.");
+        return Ok(());
+    }
+
+    let active = OutputStyles::get_active()?.map(|style| style.id);
+    println!();
+    for style in &styles {
+        let marker = if active.as_deref() == Some(style.id.as_str()) {
+            " [active]".green()
+        } else {
+            "".normal()
+        };
+        println!(
+            "  {}{} {}",
+            style.id.bold(),
+            marker,
+            style.description.dimmed()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+/// Show an output style, or with `rendered` the exact file a profile install deploys
+pub fn show(id: &str, rendered: bool, json: bool) -> Result<()> {
+    if rendered {
+        let content = OutputStyles::render(id)?;
+        if json {
+            let value = serde_json::json!({ "id": id, "rendered": content });
+            println!("{}", serde_json::to_string_pretty(&value)?);
+        } else {
+            println!("{}", content);
+        }
+        return Ok(());
+    }
+
+    let Some(style) = OutputStyles::get(id)? else {
+        anyhow::bail!("Output style '{}' not found", id);
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&style)?);
+        return Ok(());
+    }
+
+    Ui::header(&format!("Output Style: {}", style.name));
+    println!("  {}: {}", "ID".bold(), style.id);
+    println!("  {}: {}", "Description".bold(), style.description);
+    println!(
+        "  {}: {}",
+        "Keep coding instructions".bold(),
+        if style.keep_coding_instructions {
+            "yes"
+        } else {
+            "no"
+        }
+    );
+    Ui::section("Content");
+    println!("{}", style.content);
+    Ok(())
+}
@@ -1,11 +1,14 @@
 use crate::ui::Ui;
 use anyhow::Result;
 use colored::Colorize;
-use rhinolabs_core::{DeployTarget, ProfileType, Profiles};
+use rhinolabs_core::{
+    DeployTarget, GenericDeployer, InstallManifest, InstallOptions, Profile, ProfileInstallResult,
+    ProfileType, Profiles, TargetDetector, TargetInstallError, TargetPaths, Version,
+};
 use serde::Serialize;
 use std::fs;
 use std::io::{self, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -16,24 +19,63 @@ pub(crate) struct ProfileUninstallResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub profile_name: Option<String>,
     pub target_path: String,
+    /// Instruction files were left in place (`--keep-instructions`)
+    pub instructions_kept: bool,
+}
+
+/// Machine-readable record of a profile command, written by `--report <file>`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommandReport<'a, T: Serialize> {
+    timestamp: chrono::DateTime<chrono::Utc>,
+    tool_version: String,
+    command: &'a str,
+    result: &'a T,
+}
+
+/// Fail early if a report can't be written to `path` (its parent directory must exist)
+fn validate_report_path(path: &Path) -> Result<()> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => return Ok(()),
+    };
+    if !parent.is_dir() {
+        anyhow::bail!("Report directory does not exist: {}", parent.display());
+    }
+    Ok(())
+}
+
+/// Write a command result as a JSON report file
+fn write_report<T: Serialize>(path: &Path, command: &str, result: &T) -> Result<()> {
+    let report = CommandReport {
+        timestamp: chrono::Utc::now(),
+        tool_version: Version::current(),
+        command,
+        result,
+    };
+    fs::write(path, serde_json::to_string_pretty(&report)?)?;
+    Ok(())
 }
 
 /// Parse target strings into DeployTarget vec.
 /// Handles "all" keyword and individual target names.
-fn parse_targets(strs: &[String]) -> Result<Vec<DeployTarget>> {
+pub(crate) fn parse_targets(strs: &[String]) -> Result<Vec<DeployTarget>> {
     if strs.iter().any(|s| s == "all") {
-        return Ok(DeployTarget::all().to_vec());
+        return Ok(DeployTarget::all_with_custom()?);
     }
     strs.iter()
         .map(|s| s.parse::<DeployTarget>().map_err(|e| anyhow::anyhow!(e)))
         .collect()
 }
 
-/// Detect installed profile from .claude-plugin/plugin.json
-fn detect_installed_profile(path: &Path) -> Option<(String, String)> {
+/// Detect installed profile from .claude-plugin/plugin.json, or from the install
+/// manifest for projects installed with `--no-plugin`
+pub(crate) fn detect_installed_profile(path: &Path) -> Option<(String, String)> {
     let plugin_json = path.join(".claude-plugin").join("plugin.json");
     if !plugin_json.exists() {
-        return None;
+        let manifest = InstallManifest::load(path).ok()??;
+        let profile = Profiles::get(&manifest.profile_id).ok()??;
+        return Some((profile.id, profile.name));
     }
 
     let content = fs::read_to_string(&plugin_json).ok()?;
@@ -46,8 +88,13 @@ fn detect_installed_profile(path: &Path) -> Option<(String, String)> {
     Some((profile_id, profile_name))
 }
 
-/// Prompt user for yes/no confirmation
+/// Prompt user for yes/no confirmation; always yes with `--yes`
 fn prompt_yes_no(prompt: &str, default_yes: bool) -> bool {
+    // `--yes` proceeds even where the default is No (e.g. uninstall)
+    if Ui::assume_yes() {
+        return true;
+    }
+
     let suffix = if default_yes { "[Y/n]" } else { "[y/N]" };
     print!("{} {}: ", prompt, suffix);
     io::stdout().flush().unwrap();
@@ -65,6 +112,24 @@ fn prompt_yes_no(prompt: &str, default_yes: bool) -> bool {
     matches!(input.as_str(), "y" | "yes" | "si" | "sí")
 }
 
+/// Warn about targets whose tool does not appear to be installed; files deployed
+/// for them would not be read by anything. The deploy itself still goes ahead.
+pub(crate) fn warn_undetected_targets(target_strs: &[String]) -> Result<()> {
+    let mut targets = parse_targets(target_strs)?;
+    if targets.is_empty() {
+        targets.push(DeployTarget::ClaudeCode);
+    }
+    for target in targets {
+        if !GenericDeployer::new(target).is_installed() {
+            Ui::warning(&format!(
+                "{} does not appear to be installed; deploying anyway (--skip-detection to silence)",
+                target.display_name()
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Format target list for display
 fn format_targets(targets: &[DeployTarget]) -> String {
     targets
@@ -74,9 +139,39 @@ fn format_targets(targets: &[DeployTarget]) -> String {
         .join(", ")
 }
 
+/// Print skills left out of an `--only-enabled` install
+fn print_skipped_disabled(skipped: &[String]) {
+    if skipped.is_empty() {
+        return;
+    }
+    Ui::section("Skipped (disabled)");
+    for skill in skipped {
+        println!("  {} {}", "○".dimmed(), skill);
+    }
+}
+
+/// Print targets that failed to install, with the reason for each
+fn print_failed_targets(failures: &[TargetInstallError]) {
+    if failures.is_empty() {
+        return;
+    }
+    Ui::section("Failed Targets");
+    for failure in failures {
+        println!(
+            "  {} {} - {}",
+            "✗".red(),
+            failure.target.display_name(),
+            failure.error
+        );
+    }
+}
+
 /// List all profiles
-pub fn list(json: bool) -> Result<()> {
-    let profiles = Profiles::list()?;
+pub fn list(tag: Option<&str>, json: bool) -> Result<()> {
+    let profiles = match tag {
+        Some(tag) => Profiles::list_by_tag(tag)?,
+        None => Profiles::list()?,
+    };
 
     if json {
         println!("{}", serde_json::to_string_pretty(&profiles)?);
@@ -86,6 +181,10 @@ pub fn list(json: bool) -> Result<()> {
     Ui::header("Profiles");
 
     if profiles.is_empty() {
+        if let Some(tag) = tag {
+            Ui::info(&format!("No profiles tagged '{}'.", tag));
+            return Ok(());
+        }
         Ui::info("No profiles configured yet.");
         Ui::info("Create profiles in the GUI to organize your skills.");
         return Ok(());
@@ -117,6 +216,9 @@ pub fn list(json: bool) -> Result<()> {
         );
         println!("    ID: {}", profile.id);
         println!("    Skills: {}", skill_count);
+        if !profile.tags.is_empty() {
+            println!("    Tags: {}", profile.tags.join(", "));
+        }
         if !profile.description.is_empty() {
             println!("    {}", profile.description.dimmed());
         }
@@ -149,6 +251,12 @@ pub fn show(profile_id: &str, json: bool) -> Result<()> {
             println!("  Type:        {}", type_str);
             println!("  Description: {}", profile.description);
             println!("  Targets:     {}", format_targets(DeployTarget::all()));
+            if !profile.tags.is_empty() {
+                println!("  Tags:        {}", profile.tags.join(", "));
+            }
+            if let Some(style) = &profile.output_style {
+                println!("  Style:       {} (output style)", style);
+            }
             println!("  Created:     {}", profile.created_at);
             println!("  Updated:     {}", profile.updated_at);
             println!();
@@ -172,15 +280,147 @@ pub fn show(profile_id: &str, json: bool) -> Result<()> {
     Ok(())
 }
 
+/// Clone a profile under a new id
+pub fn clone(source_id: &str, new_id: &str, name: &str, json: bool) -> Result<()> {
+    let profile = Profiles::clone(source_id, new_id, name)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&profile)?);
+    } else {
+        Ui::success(&format!(
+            "Cloned '{}' as '{}' ({} skills)",
+            source_id,
+            profile.id,
+            profile.skills.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Export a profile to a JSON file
+pub fn export(profile_id: &str, out: Option<PathBuf>, json: bool) -> Result<()> {
+    let out = out.unwrap_or_else(|| PathBuf::from(format!("{}.json", profile_id)));
+    Profiles::export(profile_id, &out)?;
+
+    if json {
+        let result = serde_json::json!({ "profileId": profile_id, "file": out });
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        Ui::success(&format!("Exported '{}' to {}", profile_id, out.display()));
+    }
+    Ok(())
+}
+
+/// Import a profile from a JSON file
+pub fn import(file: &Path, json: bool) -> Result<()> {
+    let profile = Profiles::import(file)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&profile)?);
+    } else {
+        Ui::success(&format!(
+            "Imported profile '{}' ({} skills)",
+            profile.id,
+            profile.skills.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Add an installed skill to a profile
+pub fn add_skill(profile_id: &str, skill_id: &str, allow_main: bool, json: bool) -> Result<()> {
+    let profile = Profiles::add_skill(profile_id, skill_id, allow_main)?;
+    print_skills(&profile, json)
+}
+
+/// Remove a skill from a profile
+pub fn remove_skill(profile_id: &str, skill_id: &str, json: bool) -> Result<()> {
+    let profile = Profiles::remove_skill(profile_id, skill_id)?;
+    print_skills(&profile, json)
+}
+
+fn print_skills(profile: &Profile, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(profile)?);
+        return Ok(());
+    }
+
+    if profile.skills.is_empty() {
+        Ui::success(&format!("Profile '{}' has no skills", profile.id));
+    } else {
+        Ui::success(&format!(
+            "Profile '{}' skills: {}",
+            profile.id,
+            profile.skills.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Set or clear the output style a user profile installs
+pub fn set_output_style(profile_id: &str, style_id: Option<&str>, json: bool) -> Result<()> {
+    let profile = Profiles::set_output_style(profile_id, style_id)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&profile)?);
+        return Ok(());
+    }
+
+    match &profile.output_style {
+        Some(style) => Ui::success(&format!(
+            "Profile '{}' will install output style '{}'",
+            profile.id, style
+        )),
+        None => Ui::success(&format!(
+            "Profile '{}' will install the active output style",
+            profile.id
+        )),
+    }
+    Ui::info("Reinstall the profile to deploy it.");
+    Ok(())
+}
+
+/// Add a tag to a profile
+pub fn add_tag(profile_id: &str, tag: &str, json: bool) -> Result<()> {
+    let profile = Profiles::add_tag(profile_id, tag)?;
+    print_tags(&profile, json)
+}
+
+/// Remove a tag from a profile
+pub fn remove_tag(profile_id: &str, tag: &str, json: bool) -> Result<()> {
+    let profile = Profiles::remove_tag(profile_id, tag)?;
+    print_tags(&profile, json)
+}
+
+fn print_tags(profile: &Profile, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(profile)?);
+        return Ok(());
+    }
+
+    if profile.tags.is_empty() {
+        Ui::success(&format!("Profile '{}' has no tags", profile.id));
+    } else {
+        Ui::success(&format!(
+            "Profile '{}' tags: {}",
+            profile.id,
+            profile.tags.join(", ")
+        ));
+    }
+    Ok(())
+}
+
 /// Install a profile to a target path
 pub fn install(
     profile_id: &str,
     target_path: Option<String>,
     target_strs: Vec<String>,
-    json: bool,
+    no_plugin: bool,
+    only_enabled: bool,
+    report: Option<PathBuf>,
 ) -> Result<()> {
-    if json {
-        return install_json(profile_id, target_path, target_strs);
+    if let Some(report) = &report {
+        validate_report_path(report)?;
     }
 
     Ui::header("Installing Profile");
@@ -232,7 +472,7 @@ pub fn install(
                     println!("    {}:", target.display_name().bold());
                     println!("      • {}/  ({} skills)", prefix, profile.skills.len());
                     println!("      • {}", target.instructions_filename());
-                    if *target == DeployTarget::ClaudeCode {
+                    if *target == DeployTarget::ClaudeCode && !no_plugin {
                         println!("      • .claude-plugin/plugin.json");
                     }
                 }
@@ -269,10 +509,23 @@ pub fn install(
             Ui::step(&format!("Installing {} skills...", profile.skills.len()));
 
             let path = effective_path.as_deref();
-            let result = Profiles::install(profile_id, path, targets_ref)?;
+            let result = Profiles::install(
+                profile_id,
+                path,
+                targets_ref,
+                InstallOptions {
+                    create_plugin_manifest: !no_plugin,
+                    only_enabled,
+                    ..Default::default()
+                },
+            )?;
 
             println!();
             Ui::success(&format!("Installed to: {}", result.target_path));
+            if let Some(report) = &report {
+                write_report(report, "profile install", &result)?;
+                Ui::info(&format!("Report written to {}", report.display()));
+            }
 
             // Show what was created per target
             if profile.profile_type == ProfileType::Project {
@@ -281,7 +534,9 @@ pub fn install(
                     println!("  {}:", target.display_name().bold());
                     println!("    {} {}/", "✓".green(), target.project_skills_prefix());
                     println!("    {} {}", "✓".green(), target.instructions_filename());
-                    if *target == DeployTarget::ClaudeCode {
+                    if *target == DeployTarget::ClaudeCode
+                        && result.plugin_manifest_installed == Some(true)
+                    {
                         println!("    {} .claude-plugin/plugin.json", "✓".green());
                     }
                 }
@@ -301,6 +556,9 @@ pub fn install(
                 }
             }
 
+            print_skipped_disabled(&result.skills_skipped_disabled);
+            print_failed_targets(&result.targets_failed);
+
             println!();
             if profile.profile_type == ProfileType::Project {
                 let target_names = format_targets(&result.targets_installed);
@@ -308,6 +566,7 @@ pub fn install(
             } else {
                 Ui::info("Skills installed to user config directories.");
             }
+            Ui::success(&install_summary("Installed", &result));
         }
         None => {
             Ui::error(&format!("Profile '{}' not found", profile_id));
@@ -318,12 +577,315 @@ pub fn install(
     Ok(())
 }
 
+/// Outcome of installing into one of several paths; failures carry the error
+/// instead of aborting the remaining paths
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum PathInstallOutcome {
+    Installed(Box<ProfileInstallResult>),
+    #[serde(rename_all = "camelCase")]
+    Failed {
+        target_path: String,
+        error: String,
+    },
+}
+
+/// Install a profile into each of `target_paths` (the current directory if there are
+/// none), continuing past failures. With `json` the outcomes are printed as an array.
+pub fn install_many(
+    profile_id: &str,
+    target_paths: &[String],
+    target_strs: Vec<String>,
+    no_plugin: bool,
+    only_enabled: bool,
+    json: bool,
+    report: Option<PathBuf>,
+) -> Result<()> {
+    if let Some(report) = &report {
+        validate_report_path(report)?;
+    }
+
+    let targets = parse_targets(&target_strs)?;
+    let targets_ref = if targets.is_empty() {
+        None
+    } else {
+        Some(targets.as_slice())
+    };
+
+    let Some(profile) = Profiles::get(profile_id)? else {
+        anyhow::bail!("Profile '{}' not found", profile_id);
+    };
+    let effective_paths: Vec<Option<PathBuf>> = match profile.profile_type {
+        ProfileType::Project if !target_paths.is_empty() => target_paths
+            .iter()
+            .map(|p| Some(PathBuf::from(p)))
+            .collect(),
+        ProfileType::Project => vec![Some(std::env::current_dir().unwrap_or_default())],
+        ProfileType::User if target_paths.len() > 1 => anyhow::bail!(
+            "Profile '{}' is a user profile and installs to user config directories; pass at most one --path",
+            profile_id
+        ),
+        ProfileType::User => vec![None],
+    };
+
+    if !json {
+        Ui::header("Installing Profile");
+        Ui::step(&format!("Profile: {} ({})", profile.name, profile.id));
+        let effective_targets = targets_ref.unwrap_or(&[DeployTarget::ClaudeCode]);
+        println!(
+            "  {} Targets: {}",
+            "→".cyan(),
+            format_targets(effective_targets).bold()
+        );
+        println!();
+        println!(
+            "  {} Profile '{}' will be installed in {} projects:",
+            "→".cyan(),
+            profile.name,
+            effective_paths.len()
+        );
+        for path in effective_paths.iter().flatten() {
+            println!("    {}", path.display().to_string().bold());
+        }
+        println!();
+
+        if !prompt_yes_no("Continue?", true) {
+            Ui::info("Installation cancelled.");
+            return Ok(());
+        }
+        println!();
+    }
+
+    let options = InstallOptions {
+        create_plugin_manifest: !no_plugin,
+        only_enabled,
+        ..Default::default()
+    };
+    let outcomes = install_each(profile_id, &effective_paths, targets_ref, options, json);
+
+    if let Some(report) = &report {
+        write_report(report, "profile install", &outcomes)?;
+    }
+
+    let failed = outcomes
+        .iter()
+        .filter(|outcome| matches!(outcome, PathInstallOutcome::Failed { .. }))
+        .count();
+    if json {
+        println!("{}", serde_json::to_string_pretty(&outcomes)?);
+    } else {
+        if let Some(report) = &report {
+            Ui::info(&format!("Report written to {}", report.display()));
+        }
+        println!();
+        let skills: usize = outcomes
+            .iter()
+            .map(|outcome| match outcome {
+                PathInstallOutcome::Installed(result) => result.skills_installed.len(),
+                PathInstallOutcome::Failed { .. } => 0,
+            })
+            .sum();
+        let summary = format!(
+            "Installed to {} of {} projects ({} skills in total)",
+            outcomes.len() - failed,
+            outcomes.len(),
+            skills
+        );
+        if failed == 0 {
+            Ui::success(&summary);
+        } else {
+            Ui::warning(&summary);
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("Install failed for {} of {} paths", failed, outcomes.len());
+    }
+    Ok(())
+}
+
+/// Install into each path in turn; a failing path is recorded and the rest still run.
+/// `None` installs a user profile to the user config directories.
+fn install_each(
+    profile_id: &str,
+    paths: &[Option<PathBuf>],
+    targets: Option<&[DeployTarget]>,
+    options: InstallOptions,
+    json: bool,
+) -> Vec<PathInstallOutcome> {
+    let mut outcomes = Vec::new();
+    for path in paths {
+        let outcome = match Profiles::install(profile_id, path.as_deref(), targets, options) {
+            Ok(result) => PathInstallOutcome::Installed(Box::new(result)),
+            Err(e) => PathInstallOutcome::Failed {
+                target_path: path
+                    .as_deref()
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_else(|| "~".to_string()),
+                error: e.to_string(),
+            },
+        };
+        if !json {
+            match &outcome {
+                PathInstallOutcome::Installed(result) => {
+                    println!(
+                        "  {} {} - {}",
+                        "✓".green(),
+                        result.target_path,
+                        install_summary("installed", result)
+                    );
+                    for error in &result.skills_failed {
+                        println!("      {} {} - {}", "✗".red(), error.skill_id, error.error);
+                    }
+                    for failure in &result.targets_failed {
+                        println!(
+                            "      {} {} - {}",
+                            "✗".red(),
+                            failure.target.display_name(),
+                            failure.error
+                        );
+                    }
+                }
+                PathInstallOutcome::Failed { target_path, error } => {
+                    println!("  {} {} - {}", "✗".red(), target_path, error);
+                }
+            }
+        }
+        outcomes.push(outcome);
+    }
+    outcomes
+}
+
+/// Add what a project profile install generated to the project's `.gitignore`
+pub fn add_to_gitignore(profile_id: &str, target_path: Option<String>, json: bool) -> Result<()> {
+    let Some(profile) = Profiles::get(profile_id)? else {
+        return Ok(());
+    };
+    if profile.profile_type != ProfileType::Project {
+        if !json {
+            Ui::warning("--gitignore only applies to project profiles; nothing was added.");
+        }
+        return Ok(());
+    }
+
+    let path = target_path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+    // Nothing to list when the install was cancelled
+    if InstallManifest::load(&path)?.is_none() {
+        return Ok(());
+    }
+    let entries = Profiles::add_to_gitignore(&path)?;
+
+    if !json {
+        Ui::success(&format!(
+            "Listed {} generated path(s) in .gitignore",
+            entries.len()
+        ));
+        for entry in &entries {
+            println!("    {}", entry.dimmed());
+        }
+    }
+    Ok(())
+}
+
+/// Print every path `profile install` would create, without touching the filesystem
+pub fn install_dry_run(
+    profile_id: &str,
+    target_paths: &[String],
+    target_strs: Vec<String>,
+    no_plugin: bool,
+    only_enabled: bool,
+    json: bool,
+) -> Result<()> {
+    let targets = parse_targets(&target_strs)?;
+    let targets_ref = if targets.is_empty() {
+        None
+    } else {
+        Some(targets.as_slice())
+    };
+
+    let Some(profile) = Profiles::get(profile_id)? else {
+        anyhow::bail!("Profile '{}' not found", profile_id);
+    };
+    let effective_paths: Vec<Option<PathBuf>> = match profile.profile_type {
+        ProfileType::Project if !target_paths.is_empty() => target_paths
+            .iter()
+            .map(|p| Some(PathBuf::from(p)))
+            .collect(),
+        ProfileType::Project => vec![Some(std::env::current_dir().unwrap_or_default())],
+        ProfileType::User => vec![None],
+    };
+
+    let mut plan = Vec::new();
+    for path in &effective_paths {
+        plan.extend(Profiles::install_plan(
+            profile_id,
+            path.as_deref(),
+            targets_ref,
+            InstallOptions {
+                create_plugin_manifest: !no_plugin,
+                only_enabled,
+                ..Default::default()
+            },
+        )?);
+    }
+
+    if json {
+        let paths: Vec<String> = plan.iter().map(|p| p.path.display().to_string()).collect();
+        println!("{}", serde_json::to_string_pretty(&paths)?);
+        return Ok(());
+    }
+
+    Ui::header("Profile Install (dry run)");
+    Ui::step(&format!("Profile: {} ({})", profile.name, profile.id));
+
+    let mut targets: Vec<DeployTarget> = Vec::new();
+    for planned in &plan {
+        if !targets.contains(&planned.target) {
+            targets.push(planned.target);
+        }
+    }
+    for target in targets {
+        println!();
+        println!("  {}:", target.display_name().bold());
+        for planned in plan.iter().filter(|p| p.target == target) {
+            println!("    • {}", planned.path.display());
+        }
+    }
+
+    println!();
+    Ui::info(&format!(
+        "Dry run: {} path(s) would be written. Nothing was changed.",
+        plan.len()
+    ));
+    Ok(())
+}
+
+/// One-line wrap-up, e.g. "Installed 12 skills to 2 targets in 3.4s"
+fn install_summary(verb: &str, result: &ProfileInstallResult) -> String {
+    let plural = |n: usize, word: &str| format!("{} {}{}", n, word, if n == 1 { "" } else { "s" });
+    format!(
+        "{} {} to {} in {:.1}s",
+        verb,
+        plural(result.skills_installed.len(), "skill"),
+        plural(result.targets_installed.len(), "target"),
+        result.duration_ms as f64 / 1000.0
+    )
+}
+
 /// Update installed profile (re-install with latest skill versions)
 pub fn update(
     profile_id: Option<String>,
     target_path: Option<String>,
     target_strs: Vec<String>,
+    options: InstallOptions,
+    report: Option<PathBuf>,
 ) -> Result<()> {
+    if let Some(report) = &report {
+        validate_report_path(report)?;
+    }
+
     Ui::header("Updating Profile");
 
     let targets = parse_targets(&target_strs)?;
@@ -380,18 +942,46 @@ pub fn update(
 
             Ui::step("Updating skills to latest versions...");
 
-            let result =
-                Profiles::update_installed(&effective_profile_id, Some(&target), targets_ref)?;
+            let result = Profiles::update_installed(
+                &effective_profile_id,
+                Some(&target),
+                targets_ref,
+                options,
+            )?;
 
             println!();
             Ui::success("Profile updated!");
 
-            println!("  Updated: {} skills", result.skills_installed.len());
+            for refreshed in &result.skills_refreshed {
+                if result.skills_refreshed.len() > 1 {
+                    println!(
+                        "  Refreshed: {} skills ({})",
+                        refreshed.skills.len(),
+                        refreshed.target.display_name()
+                    );
+                } else {
+                    println!("  Refreshed: {} skills", refreshed.skills.len());
+                }
+            }
+            if !result.skills_unchanged.is_empty() {
+                println!(
+                    "  Unchanged: {} skills {}",
+                    result.skills_unchanged.len(),
+                    "(use --force to redeploy)".dimmed()
+                );
+            }
             if !result.skills_failed.is_empty() {
                 println!("  Failed: {} skills", result.skills_failed.len());
             }
+            print_skipped_disabled(&result.skills_skipped_disabled);
+            print_failed_targets(&result.targets_failed);
+            if let Some(report) = &report {
+                write_report(report, "profile update", &result)?;
+                println!("  Report: {}", report.display());
+            }
 
             println!();
+            Ui::success(&install_summary("Updated", &result));
         }
         None => {
             Ui::error(&format!(
@@ -408,7 +998,17 @@ pub fn update(
 }
 
 /// Uninstall profile from a target path
-pub fn uninstall(target_path: Option<String>, target_strs: Vec<String>, json: bool) -> Result<()> {
+pub fn uninstall(
+    target_path: Option<String>,
+    target_strs: Vec<String>,
+    keep_instructions: bool,
+    json: bool,
+    report: Option<PathBuf>,
+) -> Result<()> {
+    if let Some(report) = &report {
+        validate_report_path(report)?;
+    }
+
     let targets = parse_targets(&target_strs)?;
     let targets_ref = if targets.is_empty() {
         None
@@ -421,7 +1021,7 @@ pub fn uninstall(target_path: Option<String>, target_strs: Vec<String>, json: bo
         .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
 
     if json {
-        return uninstall_json(&path, targets_ref);
+        return uninstall_json(&path, targets_ref, keep_instructions, report.as_deref());
     }
 
     Ui::header("Uninstalling Profile");
@@ -459,12 +1059,7 @@ pub fn uninstall(target_path: Option<String>, target_strs: Vec<String>, json: bo
 
     println!("  This will remove:");
     for target in effective_targets {
-        let config_dir = path.join(match target {
-            DeployTarget::ClaudeCode => ".claude",
-            DeployTarget::Amp => ".agents",
-            DeployTarget::Antigravity => ".agent",
-            DeployTarget::OpenCode => ".opencode",
-        });
+        let config_dir = TargetPaths::project_config_dir(*target, &path);
         if config_dir.exists() {
             println!(
                 "    • {}/ (skills)",
@@ -473,7 +1068,7 @@ pub fn uninstall(target_path: Option<String>, target_strs: Vec<String>, json: bo
             has_anything = true;
         }
         let instructions_file = path.join(target.instructions_filename());
-        if instructions_file.exists() {
+        if !keep_instructions && instructions_file.exists() {
             println!(
                 "    • {} (if generated by rhinolabs-ai)",
                 target.instructions_filename()
@@ -493,6 +1088,9 @@ pub fn uninstall(target_path: Option<String>, target_strs: Vec<String>, json: bo
         Ui::warning("No profile installation found at this location.");
         return Ok(());
     }
+    if keep_instructions {
+        println!("  Instruction files will be kept.");
+    }
     println!();
 
     if !prompt_yes_no("Continue?", false) {
@@ -501,67 +1099,48 @@ pub fn uninstall(target_path: Option<String>, target_strs: Vec<String>, json: bo
     }
     println!();
 
-    Profiles::uninstall(&path, targets_ref)?;
+    Profiles::uninstall(&path, targets_ref, keep_instructions)?;
 
     Ui::success("Profile uninstalled!");
+    if let Some(report) = &report {
+        let result = ProfileUninstallResult {
+            success: true,
+            profile_id: profile_info.as_ref().map(|(id, _)| id.clone()),
+            profile_name: profile_info.map(|(_, name)| name),
+            target_path: path.display().to_string(),
+            instructions_kept: keep_instructions,
+        };
+        write_report(report, "profile uninstall", &result)?;
+        Ui::info(&format!("Report written to {}", report.display()));
+    }
 
     Ok(())
 }
 
 /// Uninstall a profile in JSON mode (non-interactive, no prompts)
-fn uninstall_json(path: &std::path::Path, targets: Option<&[DeployTarget]>) -> Result<()> {
+fn uninstall_json(
+    path: &std::path::Path,
+    targets: Option<&[DeployTarget]>,
+    keep_instructions: bool,
+    report: Option<&Path>,
+) -> Result<()> {
     let profile_info = detect_installed_profile(path);
     let profile_id = profile_info.as_ref().map(|(id, _)| id.clone());
     let profile_name = profile_info.map(|(_, name)| name);
 
-    Profiles::uninstall(path, targets)?;
+    Profiles::uninstall(path, targets, keep_instructions)?;
 
     let result = ProfileUninstallResult {
         success: true,
         profile_id,
         profile_name,
         target_path: path.display().to_string(),
+        instructions_kept: keep_instructions,
     };
-    println!("{}", serde_json::to_string_pretty(&result)?);
-    Ok(())
-}
-
-/// Install a profile in JSON mode (non-interactive, no prompts)
-fn install_json(
-    profile_id: &str,
-    target_path: Option<String>,
-    target_strs: Vec<String>,
-) -> Result<()> {
-    let targets = parse_targets(&target_strs)?;
-    let targets_ref = if targets.is_empty() {
-        None
-    } else {
-        Some(targets.as_slice())
-    };
-
-    let profile = Profiles::get(profile_id)?;
-    match profile {
-        Some(profile) => {
-            let effective_path = if profile.profile_type == ProfileType::Project {
-                Some(
-                    target_path
-                        .map(std::path::PathBuf::from)
-                        .unwrap_or_else(|| std::env::current_dir().unwrap_or_default()),
-                )
-            } else {
-                None
-            };
-
-            let path = effective_path.as_deref();
-            let result = Profiles::install(profile_id, path, targets_ref)?;
-            println!("{}", serde_json::to_string_pretty(&result)?);
-        }
-        None => {
-            let err = serde_json::json!({ "error": format!("Profile '{}' not found", profile_id) });
-            println!("{}", serde_json::to_string_pretty(&err)?);
-        }
+    if let Some(report) = report {
+        write_report(report, "profile uninstall", &result)?;
     }
-
+    println!("{}", serde_json::to_string_pretty(&result)?);
     Ok(())
 }
 
@@ -619,6 +1198,63 @@ pub fn sync(target_path: Option<String>, json: bool) -> Result<()> {
         }
     }
 
+    Ui::warnings(&result.warnings);
+
+    println!();
+    Ok(())
+}
+
+/// Show what `sync` would change: declared vs installed skills, read-only
+pub fn diff(target_path: Option<String>, json: bool) -> Result<()> {
+    let path = target_path
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+
+    let result = Profiles::diff_project(&path)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    Ui::header("Profile Diff");
+
+    match result.status.as_str() {
+        "no_profile" => {
+            Ui::warning("No profile installed in this directory.");
+            Ui::info("Use 'rhinolabs-ai profile install <profile>' to install one first.");
+        }
+        "synced" => {
+            Ui::success("Profile in sync — sync would change nothing.");
+            println!("  Profile: {}", result.profile_id.as_deref().unwrap_or("—"));
+            println!("  Skills:  {}", result.unchanged.len());
+        }
+        _ => {
+            println!("  Profile: {}", result.profile_id.as_deref().unwrap_or("—"));
+
+            if !result.added.is_empty() {
+                Ui::section("Would Add");
+                for skill in &result.added {
+                    println!("  {} {}", "+".green(), skill);
+                }
+            }
+
+            if !result.removed.is_empty() {
+                Ui::section("Would Remove");
+                for skill in &result.removed {
+                    println!("  {} {}", "-".red(), skill);
+                }
+            }
+
+            if !result.unchanged.is_empty() {
+                println!();
+                println!("  Unchanged: {} skills", result.unchanged.len());
+            }
+            println!();
+            Ui::info("Run 'rhinolabs-ai profile sync' to apply these changes.");
+        }
+    }
+
     println!();
     Ok(())
 }
@@ -628,6 +1264,62 @@ mod tests {
     use super::*;
     use rhinolabs_core::DeployTarget;
 
+    #[test]
+    fn test_path_install_failure_serializes_with_its_path() {
+        let outcome = PathInstallOutcome::Failed {
+            target_path: "/repo/apps/web".into(),
+            error: "Not a directory".into(),
+        };
+        assert_eq!(
+            serde_json::to_value(&outcome).unwrap(),
+            serde_json::json!({ "targetPath": "/repo/apps/web", "error": "Not a directory" })
+        );
+    }
+
+    #[test]
+    fn test_install_each_continues_past_a_failing_path() {
+        use crate::test_utils::{TestEnv, ENV_MUTEX};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+        Profiles::create(rhinolabs_core::CreateProfileInput {
+            id: "web".into(),
+            name: "Web".into(),
+            description: "Web apps".into(),
+            profile_type: ProfileType::Project,
+            skills: Vec::new(),
+            instructions: None,
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+        })
+        .unwrap();
+
+        let projects = tempfile::tempdir().unwrap();
+        let not_a_dir = projects.path().join("README.md");
+        std::fs::write(&not_a_dir, "# Not a project").unwrap();
+        let web = projects.path().join("web");
+        std::fs::create_dir(&web).unwrap();
+
+        let outcomes = install_each(
+            "web",
+            &[Some(not_a_dir.clone()), Some(web.clone())],
+            None,
+            InstallOptions::default(),
+            true,
+        );
+
+        assert_eq!(outcomes.len(), 2);
+        assert!(matches!(
+            &outcomes[0],
+            PathInstallOutcome::Failed { target_path, .. } if *target_path == not_a_dir.display().to_string()
+        ));
+        assert!(matches!(&outcomes[1], PathInstallOutcome::Installed(_)));
+        assert!(web.join("CLAUDE.md").exists());
+    }
+
     #[test]
     fn test_parse_targets_empty_returns_empty() {
         let input: Vec<String> = vec![];
@@ -742,6 +1434,7 @@ mod tests {
             profile_id: Some("react-stack".to_string()),
             profile_name: Some("React Stack".to_string()),
             target_path: "/home/user/project".to_string(),
+            instructions_kept: false,
         };
         let json: serde_json::Value = serde_json::to_value(&result).unwrap();
 
@@ -758,6 +1451,7 @@ mod tests {
             profile_id: None,
             profile_name: None,
             target_path: "/tmp/test".to_string(),
+            instructions_kept: false,
         };
         let json: serde_json::Value = serde_json::to_value(&result).unwrap();
 
@@ -774,6 +1468,7 @@ mod tests {
             profile_id: Some("test".to_string()),
             profile_name: Some("Test".to_string()),
             target_path: "/tmp".to_string(),
+            instructions_kept: true,
         };
         let json_str = serde_json::to_string(&result).unwrap();
 
@@ -781,8 +1476,86 @@ mod tests {
         assert!(json_str.contains("profileId"));
         assert!(json_str.contains("profileName"));
         assert!(json_str.contains("targetPath"));
+        assert!(json_str.contains("instructionsKept"));
         assert!(!json_str.contains("profile_id"));
         assert!(!json_str.contains("profile_name"));
         assert!(!json_str.contains("target_path"));
+        assert!(!json_str.contains("instructions_kept"));
+    }
+
+    #[test]
+    fn test_install_summary_line() {
+        let result = ProfileInstallResult {
+            profile_id: "frontend".into(),
+            profile_name: "Frontend".into(),
+            target_path: "/tmp/project".into(),
+            skills_installed: vec!["react-patterns".into()],
+            skills_unchanged: Vec::new(),
+            skills_refreshed: Vec::new(),
+            skills_failed: vec![],
+            instructions_installed: None,
+            settings_installed: None,
+            output_style_installed: None,
+            plugin_manifest_installed: None,
+            skills_skipped_disabled: vec![],
+            targets_installed: vec![DeployTarget::ClaudeCode, DeployTarget::Amp],
+            targets_failed: vec![],
+            duration_ms: 3412,
+        };
+
+        assert_eq!(
+            install_summary("Installed", &result),
+            "Installed 1 skill to 2 targets in 3.4s"
+        );
+    }
+
+    #[test]
+    fn test_validate_report_path_requires_existing_parent() {
+        let dir = tempfile::tempdir().unwrap();
+
+        assert!(validate_report_path(&dir.path().join("report.json")).is_ok());
+        assert!(validate_report_path(Path::new("report.json")).is_ok());
+
+        let err = validate_report_path(&dir.path().join("missing").join("report.json"));
+        assert!(err.unwrap_err().to_string().contains("does not exist"));
+    }
+
+    #[test]
+    fn test_write_report_contains_skills_and_targets() {
+        let dir = tempfile::tempdir().unwrap();
+        let report_path = dir.path().join("install-report.json");
+        let result = ProfileInstallResult {
+            profile_id: "frontend".into(),
+            profile_name: "Frontend".into(),
+            target_path: "/tmp/project".into(),
+            skills_installed: vec!["react-patterns".into(), "typescript".into()],
+            skills_unchanged: Vec::new(),
+            skills_refreshed: Vec::new(),
+            skills_failed: vec![],
+            instructions_installed: None,
+            settings_installed: None,
+            output_style_installed: None,
+            plugin_manifest_installed: None,
+            skills_skipped_disabled: vec![],
+            targets_installed: vec![DeployTarget::ClaudeCode, DeployTarget::Amp],
+            targets_failed: vec![],
+            duration_ms: 0,
+        };
+
+        write_report(&report_path, "profile install", &result).unwrap();
+
+        let report: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&report_path).unwrap()).unwrap();
+        assert_eq!(report["command"], "profile install");
+        assert_eq!(report["toolVersion"], Version::current());
+        assert!(report["timestamp"].is_string());
+        assert_eq!(
+            report["result"]["skillsInstalled"],
+            serde_json::json!(["react-patterns", "typescript"])
+        );
+        assert_eq!(
+            report["result"]["targetsInstalled"],
+            serde_json::json!(["claude-code", "amp"])
+        );
     }
 }
@@ -1,13 +1,14 @@
 //! RAG (Retrieval-Augmented Generation) CLI commands
 //!
 //! Manages local RAG configuration. All RAG operations (save, search, etc.)
-//! are performed by the centralized MCP Worker and accessed via Claude Code.
+//! are performed by the centralized MCP Worker and accessed via Claude Code;
+//! `rag index` and `rag query` feed and search the project memory from the terminal.
 
 use crate::ui::Ui;
 use anyhow::Result;
 use colored::Colorize;
-use reqwest::Client;
-use rhinolabs_core::Rag;
+use indicatif::{ProgressBar, ProgressStyle};
+use rhinolabs_core::{network, Rag};
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::path::PathBuf;
@@ -137,6 +138,89 @@ pub fn status() -> Result<()> {
     Ok(())
 }
 
+/// Search the current project's memory and print the matching snippets
+pub async fn query(text: &str, limit: usize, json: bool) -> Result<()> {
+    let results = Rag::query(&get_cwd()?, text, limit).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+        return Ok(());
+    }
+
+    if results.is_empty() {
+        Ui::info(&format!("No results for \"{}\"", text));
+        return Ok(());
+    }
+
+    for (i, result) in results.iter().enumerate() {
+        let mut details = Vec::new();
+        if let Some(source) = &result.source {
+            details.push(source.clone());
+        }
+        if let Some(score) = result.score {
+            details.push(format!("score {:.2}", score));
+        }
+        println!(
+            "{} {}",
+            format!("{}.", i + 1).cyan().bold(),
+            details.join(", ").dimmed()
+        );
+        for line in result.content.lines() {
+            println!("   {}", line);
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+/// Index the current project's files (or just `paths`) into its memory
+pub async fn index(paths: &[PathBuf], json: bool) -> Result<()> {
+    let pb = if json {
+        ProgressBar::hidden()
+    } else {
+        Ui::step("Indexing project files...");
+        let pb = ProgressBar::new(0);
+        pb.set_style(
+            ProgressStyle::default_bar()
+                .template("  {bar:30.cyan/dim} {pos}/{len} documents")
+                .unwrap(),
+        );
+        pb
+    };
+
+    let report = Rag::index(&get_cwd()?, paths, |done, total| {
+        pb.set_length(total as u64);
+        pb.set_position(done as u64);
+    })
+    .await;
+    pb.finish_and_clear();
+    let report = report?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    if report.indexed == 0 {
+        Ui::warning("No files to index. Check the paths and 'rag settings' index-extensions.");
+    } else {
+        Ui::success(&format!("Indexed {} document(s)", report.indexed));
+    }
+    if !report.skipped.is_empty() {
+        Ui::info(&format!("Skipped {} file(s):", report.skipped.len()));
+        for file in &report.skipped {
+            println!(
+                "    {} {}",
+                file.path,
+                format!("({})", file.reason).dimmed()
+            );
+        }
+    }
+
+    Ok(())
+}
+
 /// Create a new API key (requires admin key)
 pub async fn create_key(name: String, projects: Option<Vec<String>>) -> Result<()> {
     Ui::header("Create API Key");
@@ -160,7 +244,7 @@ pub async fn create_key(name: String, projects: Option<Vec<String>>) -> Result<(
 
     Ui::step(&format!("Creating API key '{}'...", name));
 
-    let client = Client::new();
+    let client = network::client()?;
     let mcp_url = env::var("RHINOLABS_RAG_MCP_URL").unwrap_or_else(|_| DEFAULT_MCP_URL.to_string());
 
     #[derive(Serialize)]
@@ -179,7 +263,8 @@ pub async fn create_key(name: String, projects: Option<Vec<String>>) -> Result<(
         .header("Authorization", format!("Bearer {}", admin_key))
         .json(&request_body)
         .send()
-        .await?;
+        .await
+        .map_err(network::request_error)?;
 
     if !response.status().is_success() {
         let status = response.status();
@@ -227,14 +312,15 @@ pub async fn list_keys() -> Result<()> {
 
     Ui::step("Fetching API keys...");
 
-    let client = Client::new();
+    let client = network::client()?;
     let mcp_url = env::var("RHINOLABS_RAG_MCP_URL").unwrap_or_else(|_| DEFAULT_MCP_URL.to_string());
 
     let response = client
         .get(format!("{}/admin/keys", mcp_url))
         .header("Authorization", format!("Bearer {}", admin_key))
         .send()
-        .await?;
+        .await
+        .map_err(network::request_error)?;
 
     if !response.status().is_success() {
         let status = response.status();
@@ -285,6 +371,50 @@ pub fn set_admin_key(key: String) -> Result<()> {
     Ok(())
 }
 
+/// Show the current project's indexing settings
+pub fn settings_show() -> Result<()> {
+    Ui::header("RAG Settings");
+
+    let Some(config) = Rag::load_config(&get_cwd()?)? else {
+        anyhow::bail!(
+            "RAG is not initialized for this project. Run 'rhinolabs-ai rag init' first."
+        );
+    };
+    let settings = config.settings;
+
+    println!();
+    println!("  {}:       {}", "chunk-size".bold(), settings.chunk_size);
+    println!(
+        "  {}:    {}",
+        "chunk-overlap".bold(),
+        settings.chunk_overlap
+    );
+    println!(
+        "  {}:  {}",
+        "embedding-model".bold(),
+        settings.embedding_model
+    );
+    println!(
+        "  {}: {}",
+        "index-extensions".bold(),
+        settings.index_extensions.join(",")
+    );
+    println!();
+    Ui::info("Change with: rhinolabs-ai rag settings set <key> <value>");
+
+    Ok(())
+}
+
+/// Set one of the current project's indexing settings
+pub fn settings_set(key: String, value: String) -> Result<()> {
+    Ui::header("RAG Settings");
+
+    Rag::set_setting(&get_cwd()?, &key, &value)?;
+    Ui::success(&format!("{} set to {}", key, value));
+
+    Ok(())
+}
+
 /// Remove RAG configuration from the current project
 pub fn remove() -> Result<()> {
     Ui::header("Remove RAG");
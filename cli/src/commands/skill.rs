@@ -1,22 +1,16 @@
 use crate::ui::Ui;
 use anyhow::Result;
 use colored::Colorize;
-use rhinolabs_core::{CreateSkillInput, SkillCategory, Skills};
-
-/// Parse category string to SkillCategory enum
-fn parse_category(category: &str) -> SkillCategory {
-    match category.to_lowercase().as_str() {
-        "corporate" => SkillCategory::Corporate,
-        "frontend" => SkillCategory::Frontend,
-        "testing" => SkillCategory::Testing,
-        "ai-sdk" | "aisdk" => SkillCategory::AiSdk,
-        "utilities" => SkillCategory::Utilities,
-        _ => SkillCategory::Custom,
-    }
-}
+use dialoguer::{theme::ColorfulTheme, Confirm, Input, Select};
+use rhinolabs_core::{
+    CreateSkillInput, InstallSkillInput, IssueLevel, RemoteSkillFetch, SkillCategory, SkillDiff,
+    SkillUpdateCheck, Skills, SkillsShQuery, UpdateSkillInput, Warnings,
+};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 
 /// Format category for display
-fn category_display(category: &SkillCategory) -> &'static str {
+fn category_display(category: &SkillCategory) -> &str {
     match category {
         SkillCategory::Corporate => "Corporate",
         SkillCategory::Backend => "Backend",
@@ -25,12 +19,16 @@ fn category_display(category: &SkillCategory) -> &'static str {
         SkillCategory::AiSdk => "AI SDK",
         SkillCategory::Utilities => "Utilities",
         SkillCategory::Custom => "Custom",
+        SkillCategory::Named(label) => label,
     }
 }
 
 /// List all skills
-pub fn list(json: bool) -> Result<()> {
-    let skills = Skills::list()?;
+pub fn list(json: bool, with_profiles: bool) -> Result<()> {
+    let (mut skills, warnings) = Skills::list_with_warnings()?;
+    if with_profiles {
+        Skills::attach_profile_ids(&mut skills)?;
+    }
 
     if json {
         println!("{}", serde_json::to_string_pretty(&skills)?);
@@ -45,6 +43,12 @@ pub fn list(json: bool) -> Result<()> {
         return Ok(());
     }
 
+    let duplicated: HashSet<&str> = warnings
+        .duplicate_names
+        .iter()
+        .map(|d| d.name.as_str())
+        .collect();
+
     // Group by category
     let mut current_category: Option<SkillCategory> = None;
 
@@ -73,46 +77,279 @@ pub fn list(json: bool) -> Result<()> {
             "".normal()
         };
 
+        let duplicate_mark = if duplicated.contains(skill.name.as_str()) {
+            "*".yellow()
+        } else {
+            "".normal()
+        };
+
         println!(
-            "    {} {}{}{}",
-            status, skill.name, custom_badge, source_badge
+            "    {} {}{}{}{}",
+            status, skill.name, duplicate_mark, custom_badge, source_badge
         );
         println!("      ID: {}", skill.id.dimmed());
+        if let Some(profile_ids) = &skill.profile_ids {
+            let profiles = if profile_ids.is_empty() {
+                "none".to_string()
+            } else {
+                profile_ids.join(", ")
+            };
+            println!("      Profiles: {}", profiles.dimmed());
+        }
+    }
+
+    if !warnings.duplicate_names.is_empty() {
+        println!();
+        Ui::warning("* Name shared by multiple skills:");
+        for dup in &warnings.duplicate_names {
+            println!("    {} → {}", dup.name, dup.skill_ids.join(", "));
+        }
     }
 
     println!();
     Ok(())
 }
 
-/// Create a new skill
+/// Show which profiles use a skill and when each one auto-invokes it
+fn print_invocation_contexts(skill_id: &str) -> Result<()> {
+    let profiles = Skills::get_assigned_profiles(skill_id)?;
+    if profiles.is_empty() {
+        return Ok(());
+    }
+
+    let contexts = Skills::get_invocation_contexts(skill_id)?;
+
+    Ui::section("Auto-invoke");
+    for profile in &profiles {
+        let rules: Vec<_> = contexts
+            .iter()
+            .filter(|(profile_id, _)| *profile_id == profile.id)
+            .map(|(_, rule)| rule)
+            .collect();
+
+        if rules.is_empty() {
+            println!(
+                "  {} {}",
+                profile.id.bold(),
+                "(no auto-invoke rule)".dimmed()
+            );
+            continue;
+        }
+
+        for rule in rules {
+            println!("  {} {}", profile.id.bold(), rule.trigger);
+            if !rule.description.is_empty() {
+                println!("      {}", rule.description.dimmed());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Create a new skill, or import one from a local folder
 pub fn create(
     id: String,
-    name: String,
+    name: Option<String>,
     category: String,
     description: Option<String>,
+    from_dir: Option<PathBuf>,
 ) -> Result<()> {
     Ui::header("Create Skill");
 
-    let category_enum = parse_category(&category);
-    let desc = description.unwrap_or_else(|| format!("Custom skill: {}", name));
+    let category_enum = SkillCategory::from_label(&category);
 
     Ui::step(&format!("Creating skill '{}'...", id));
     Ui::step(&format!("Category: {}", category_display(&category_enum)));
 
-    let input = CreateSkillInput {
-        id: id.clone(),
-        name: name.clone(),
-        description: desc,
-        category: category_enum,
-        content: format!("# {}\n\nYour skill instructions here.", name),
+    let input = match from_dir {
+        Some(dir) => {
+            Ui::step(&format!("Importing from: {}", dir.display()));
+            CreateSkillInput {
+                id: id.clone(),
+                name: String::new(),
+                description: String::new(),
+                category: category_enum,
+                content: String::new(),
+                from_dir: Some(dir),
+            }
+        }
+        None => {
+            let name = name.unwrap_or_else(|| id.clone());
+            let desc = description.unwrap_or_else(|| format!("Custom skill: {}", name));
+            CreateSkillInput {
+                id: id.clone(),
+                name: name.clone(),
+                description: desc,
+                category: category_enum,
+                content: format!("# {}\n\nYour skill instructions here.", name),
+                from_dir: None,
+            }
+        }
     };
+    let imported = input.from_dir.is_some();
 
     let skill = Skills::create(input)?;
 
     println!();
     Ui::success(&format!("Skill '{}' created successfully!", skill.id));
     Ui::info(&format!("Path: {}", skill.path));
-    Ui::info("Edit the SKILL.md file to add your instructions.");
+    if !imported {
+        Ui::info("Edit the SKILL.md file to add your instructions.");
+    }
+
+    Ok(())
+}
+
+/// Categories offered by the `skill new` wizard, in display order
+const WIZARD_CATEGORIES: [SkillCategory; 7] = [
+    SkillCategory::Custom,
+    SkillCategory::Corporate,
+    SkillCategory::Backend,
+    SkillCategory::Frontend,
+    SkillCategory::Testing,
+    SkillCategory::AiSdk,
+    SkillCategory::Utilities,
+];
+
+/// Prompts used by the `skill new` wizard.
+/// Abstracted so tests can script the answers.
+trait Prompter {
+    fn input(&mut self, prompt: &str, default: Option<&str>) -> Result<String>;
+    fn select(&mut self, prompt: &str, items: &[&str]) -> Result<usize>;
+    fn confirm(&mut self, prompt: &str, default: bool) -> Result<bool>;
+}
+
+/// Terminal prompts backed by dialoguer
+struct TerminalPrompter;
+
+impl Prompter for TerminalPrompter {
+    fn input(&mut self, prompt: &str, default: Option<&str>) -> Result<String> {
+        let theme = ColorfulTheme::default();
+        let mut input = Input::<String>::with_theme(&theme).with_prompt(prompt);
+        if let Some(default) = default {
+            input = input.default(default.to_string());
+        }
+        Ok(input.interact_text()?)
+    }
+
+    fn select(&mut self, prompt: &str, items: &[&str]) -> Result<usize> {
+        Ok(Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .items(items)
+            .default(0)
+            .interact()?)
+    }
+
+    fn confirm(&mut self, prompt: &str, default: bool) -> Result<bool> {
+        Ok(Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .default(default)
+            .interact()?)
+    }
+}
+
+/// Derive a skill id from a display name ("My React Skill" → "my-react-skill")
+fn slugify(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Check a skill id is a lowercase slug that isn't already taken
+fn validate_skill_id(id: &str, existing: &HashSet<String>) -> std::result::Result<(), String> {
+    if id.is_empty() {
+        return Err("Skill ID cannot be empty".into());
+    }
+    if slugify(id) != id {
+        return Err(format!(
+            "'{}' is not a valid ID. Use lowercase letters, digits and single hyphens (e.g. \"{}\")",
+            id,
+            slugify(id)
+        ));
+    }
+    if existing.contains(id) {
+        return Err(format!("A skill with ID '{}' already exists", id));
+    }
+    Ok(())
+}
+
+/// Ask for the new skill's details, re-prompting until each answer is valid
+fn prompt_skill_input(
+    prompter: &mut dyn Prompter,
+    existing: &HashSet<String>,
+) -> Result<CreateSkillInput> {
+    let name = loop {
+        let name = prompter.input("Skill name", None)?.trim().to_string();
+        if !name.is_empty() {
+            break name;
+        }
+        Ui::error("Skill name cannot be empty");
+    };
+
+    let suggested = slugify(&name);
+    let id = loop {
+        let id = prompter
+            .input("Skill ID", Some(&suggested))?
+            .trim()
+            .to_string();
+        match validate_skill_id(&id, existing) {
+            Ok(()) => break id,
+            Err(e) => Ui::error(&e),
+        }
+    };
+
+    let labels: Vec<&str> = WIZARD_CATEGORIES.iter().map(category_display).collect();
+    let category = WIZARD_CATEGORIES
+        .get(prompter.select("Category", &labels)?)
+        .cloned()
+        .unwrap_or_default();
+
+    let default_description = format!("Custom skill: {}", name);
+    let description = prompter
+        .input("Description", Some(&default_description))?
+        .trim()
+        .to_string();
+
+    Ok(CreateSkillInput {
+        id,
+        content: format!("# {}\n\nYour skill instructions here.", name),
+        from_dir: None,
+        name,
+        description,
+        category,
+    })
+}
+
+/// Create a new skill through an interactive wizard
+pub fn new_interactive() -> Result<()> {
+    Ui::header("New Skill");
+
+    let existing: HashSet<String> = Skills::list()?.into_iter().map(|s| s.id).collect();
+    let mut prompter = TerminalPrompter;
+    let input = prompt_skill_input(&mut prompter, &existing)?;
+
+    let skill = Skills::create(input)?;
+
+    println!();
+    Ui::success(&format!("Skill '{}' created successfully!", skill.id));
+    Ui::info(&format!("Path: {}", skill.path));
+
+    match std::env::var("EDITOR") {
+        Ok(editor) if !editor.trim().is_empty() => {
+            if prompter.confirm("Open SKILL.md in $EDITOR?", true)? {
+                let status = std::process::Command::new(&editor)
+                    .arg(&skill.path)
+                    .status()?;
+                if !status.success() {
+                    Ui::warning(&format!("{} exited with {}", editor, status));
+                }
+            }
+        }
+        _ => Ui::info("Edit the SKILL.md file to add your instructions."),
+    }
 
     Ok(())
 }
@@ -121,7 +358,7 @@ pub fn create(
 pub fn set_category(skill_id: String, category: String) -> Result<()> {
     Ui::header("Set Skill Category");
 
-    let category_enum = parse_category(&category);
+    let category_enum = SkillCategory::from_label(&category);
 
     Ui::step(&format!(
         "Setting category for '{}' to '{}'...",
@@ -141,6 +378,102 @@ pub fn set_category(skill_id: String, category: String) -> Result<()> {
     Ok(())
 }
 
+/// Enable or disable a skill globally, or only for the given targets
+pub fn set_enabled(skill_id: &str, target_strs: &[String], enabled: bool) -> Result<()> {
+    let action = if enabled { "enabled" } else { "disabled" };
+
+    if target_strs.is_empty() {
+        Skills::toggle(skill_id, enabled)?;
+        Ui::success(&format!("Skill '{}' {}", skill_id, action));
+        return Ok(());
+    }
+
+    let targets = super::profile::parse_targets(target_strs)?;
+    for target in &targets {
+        Skills::toggle_for_target(skill_id, *target, enabled)?;
+    }
+    let names: Vec<&str> = targets.iter().map(|t| t.display_name()).collect();
+    Ui::success(&format!(
+        "Skill '{}' {} for {}",
+        skill_id,
+        action,
+        names.join(", ")
+    ));
+
+    if enabled && Skills::disabled_ids()?.iter().any(|id| id == skill_id) {
+        Ui::warning(&format!(
+            "'{}' is disabled globally, which overrides per-target settings. Run: rhinolabs-ai skill enable {}",
+            skill_id, skill_id
+        ));
+    }
+
+    Ok(())
+}
+
+/// Update a skill's name, description and/or content (read from a file)
+pub fn update(
+    skill_id: &str,
+    name: Option<String>,
+    description: Option<String>,
+    content_file: Option<&Path>,
+    json: bool,
+) -> Result<()> {
+    if name.is_none() && description.is_none() && content_file.is_none() {
+        anyhow::bail!("Nothing to update: pass --name, --description or --content-file");
+    }
+
+    let content = content_file
+        .map(|path| {
+            std::fs::read_to_string(path)
+                .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))
+        })
+        .transpose()?;
+
+    Skills::update(
+        skill_id,
+        UpdateSkillInput {
+            name,
+            description,
+            content,
+            enabled: None,
+            category: None,
+        },
+    )?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&Skills::get(skill_id)?)?);
+    } else {
+        Ui::success(&format!("Updated skill '{}'", skill_id));
+    }
+
+    Ok(())
+}
+
+/// Delete a custom or source-installed skill, asking first unless `yes`
+pub fn delete(skill_id: &str, yes: bool, json: bool) -> Result<()> {
+    if !yes {
+        if json {
+            anyhow::bail!("Deleting '{}' needs confirmation. Pass --yes", skill_id);
+        }
+        let prompt = format!("Delete skill '{}' and its files?", skill_id);
+        if !TerminalPrompter.confirm(&prompt, false)? {
+            Ui::info("Delete cancelled");
+            return Ok(());
+        }
+    }
+
+    Skills::delete(skill_id)?;
+
+    if json {
+        let result = serde_json::json!({ "skillId": skill_id, "deleted": true });
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        Ui::success(&format!("Deleted skill '{}'", skill_id));
+    }
+
+    Ok(())
+}
+
 /// Show details of a specific skill
 pub fn show(skill_id: &str, json: bool) -> Result<()> {
     let skill = Skills::get(skill_id)?;
@@ -181,6 +514,8 @@ pub fn show(skill_id: &str, json: bool) -> Result<()> {
                 Ui::warning("This skill has been modified from its original source.");
             }
 
+            print_invocation_contexts(&skill.id)?;
+
             println!();
         }
         None => {
@@ -190,3 +525,507 @@ pub fn show(skill_id: &str, json: bool) -> Result<()> {
 
     Ok(())
 }
+
+/// Validate a skill, optionally against the full Agent Skills spec.
+/// Fails (non-zero exit) when any blocking problem is found, so it can gate CI.
+pub fn validate(skill_id: &str, strict: bool, json: bool) -> Result<()> {
+    let issues = Skills::validate(skill_id, strict)?;
+    let (errors, lints): (Vec<_>, Vec<_>) = issues
+        .iter()
+        .partition(|issue| issue.level == IssueLevel::Error);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&issues)?);
+    } else {
+        Ui::header(&format!("Validate: {}", skill_id));
+
+        if !errors.is_empty() {
+            Ui::section("Errors");
+            for issue in &errors {
+                println!("  {} {}", "✗".red(), issue.message);
+            }
+        }
+
+        if !lints.is_empty() {
+            Ui::section("Lints");
+            for issue in &lints {
+                println!("  {} {}", "⚠".yellow(), issue.message);
+            }
+        }
+
+        println!();
+        if issues.is_empty() {
+            Ui::success("No problems found");
+        } else if errors.is_empty() {
+            Ui::success(&format!("{} lint(s), no blocking problems", lints.len()));
+        }
+    }
+
+    if !errors.is_empty() {
+        anyhow::bail!(
+            "Skill '{}' has {} blocking problem(s)",
+            skill_id,
+            errors.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Search fetchable sources for remote skills; `sort` and `category` pick the
+/// skills.sh leaderboard to search
+pub async fn search(
+    query: &str,
+    source: Option<&str>,
+    sort: Option<&str>,
+    category: Option<String>,
+    refresh: bool,
+    json: bool,
+) -> Result<()> {
+    let listing = SkillsShQuery {
+        sort: sort.map(str::parse).transpose()?.unwrap_or_default(),
+        category,
+    };
+    if refresh {
+        Skills::invalidate_cache(source)?;
+    }
+
+    let mut warnings = Warnings::new();
+    let skills = Skills::search(query, source, &listing, &mut warnings).await?;
+
+    if json {
+        let result = RemoteSkillFetch { skills, warnings };
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    Ui::header(&format!("Search: {}", query));
+
+    if skills.is_empty() {
+        Ui::info("No matching skills found.");
+    } else {
+        for skill in &skills {
+            let installed = if skill.installed {
+                " [installed]".green()
+            } else {
+                "".normal()
+            };
+            println!(
+                "  {} {}{}",
+                skill.id.bold(),
+                format!("[{}]", skill.source_name).dimmed(),
+                installed
+            );
+            if !skill.description.is_empty() {
+                println!("      {}", skill.description.dimmed());
+            }
+        }
+        println!();
+        Ui::info(&format!("{} skill(s) found", skills.len()));
+    }
+
+    Ui::warnings(&warnings);
+    println!();
+    Ok(())
+}
+
+/// Search the content of installed skills
+pub fn grep(pattern: &str, ignore_case: bool, files: bool, json: bool) -> Result<()> {
+    let matches = Skills::search_content(pattern, ignore_case, files)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&matches)?);
+        return Ok(());
+    }
+
+    if matches.is_empty() {
+        Ui::info("No matches found.");
+        return Ok(());
+    }
+
+    for m in &matches {
+        println!(
+            "{}{}{}{}{}{} {}",
+            m.skill_id.magenta(),
+            "/".dimmed(),
+            m.file.cyan(),
+            ":".dimmed(),
+            m.line.to_string().green(),
+            ":".dimmed(),
+            m.text
+        );
+    }
+
+    Ok(())
+}
+
+/// Install one or more skills from a source
+pub async fn install(skill_ids: Vec<String>, source: &str, json: bool) -> Result<()> {
+    let specs = skill_ids
+        .into_iter()
+        .map(|skill_id| InstallSkillInput {
+            source_id: source.to_string(),
+            skill_id,
+        })
+        .collect();
+    let result = Skills::install_many(specs).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        Ui::header("Installing Skills");
+
+        for id in &result.skills_installed {
+            println!("  {} {}", "✓".green(), id);
+        }
+        for id in &result.skills_skipped {
+            println!(
+                "  {} {} {}",
+                "•".dimmed(),
+                id,
+                "(already installed)".dimmed()
+            );
+        }
+        for failed in &result.skills_failed {
+            println!("  {} {}: {}", "✗".red(), failed.skill_id, failed.error);
+        }
+
+        println!();
+        Ui::info(&format!(
+            "{} installed, {} skipped, {} failed",
+            result.skills_installed.len(),
+            result.skills_skipped.len(),
+            result.skills_failed.len()
+        ));
+    }
+
+    if !result.skills_failed.is_empty() {
+        anyhow::bail!(
+            "{} skill(s) could not be installed",
+            result.skills_failed.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Install a skill from a GitHub tree or blob URL
+pub async fn install_url(url: &str, json: bool) -> Result<()> {
+    let result = Skills::install_from_github_url(url).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        Ui::success(&format!("Installed skill '{}'", result.skill.id));
+        if let Some(source) = &result.skill.source_name {
+            println!("  Source: {}", source);
+        }
+        if let Some(branch) = &result.branch {
+            println!("  Branch: {}", branch);
+        }
+    }
+
+    Ok(())
+}
+
+/// Import a skill from a directory or an exported `.zip` archive
+pub fn import(path: &Path, json: bool) -> Result<()> {
+    let skill = Skills::import_from_path(path)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&skill)?);
+    } else {
+        Ui::success(&format!(
+            "Imported skill '{}' from {}",
+            skill.id,
+            path.display()
+        ));
+        println!("  Category: {}", category_display(&skill.category));
+        if let Some(source) = &skill.source_name {
+            println!("  Source: {}", source);
+        }
+    }
+
+    Ok(())
+}
+
+/// Rename a skill and update the profiles that reference it
+pub fn rename(old_id: &str, new_id: &str, json: bool) -> Result<()> {
+    let skill = Skills::rename(old_id, new_id)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&skill)?);
+    } else {
+        Ui::success(&format!("Renamed skill '{}' to '{}'", old_id, new_id));
+    }
+
+    Ok(())
+}
+
+/// Export a skill to `<out>/<skill_id>.zip`
+pub fn export(skill_id: &str, out: Option<PathBuf>, json: bool) -> Result<()> {
+    let out = match out {
+        Some(out) => out,
+        None => std::env::current_dir()?,
+    };
+    let archive = Skills::export(skill_id, &out)?;
+
+    if json {
+        let result = serde_json::json!({ "skillId": skill_id, "archive": archive });
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        Ui::success(&format!("Exported '{}' to {}", skill_id, archive.display()));
+    }
+
+    Ok(())
+}
+
+/// Show source-installed skills whose upstream SKILL.md has changed
+pub async fn outdated(json: bool) -> Result<()> {
+    let mut warnings = Warnings::new();
+    let updates = Skills::check_updates(&mut warnings).await?;
+
+    if json {
+        let result = SkillUpdateCheck { updates, warnings };
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    Ui::header("Outdated Skills");
+
+    let outdated: Vec<_> = updates.iter().filter(|u| u.has_update).collect();
+    if outdated.is_empty() {
+        Ui::success(&format!(
+            "All {} source-installed skill(s) are up to date",
+            updates.len()
+        ));
+    } else {
+        for update in &outdated {
+            let note = if update.is_locally_modified {
+                " (locally modified: updating would overwrite your edits)".yellow()
+            } else {
+                "".normal()
+            };
+            println!("  {} {}{}", "↑".cyan(), update.skill_id.bold(), note);
+        }
+        println!();
+        Ui::info(&format!(
+            "{} of {} source-installed skill(s) have updates",
+            outdated.len(),
+            updates.len()
+        ));
+    }
+
+    Ui::warnings(&warnings);
+    println!();
+    Ok(())
+}
+
+/// Show a unified diff of a skill's SKILL.md against its original
+pub async fn diff(skill_id: &str, json: bool) -> Result<()> {
+    let mut warnings = Warnings::new();
+    let diff = Skills::diff(skill_id, &mut warnings).await?;
+
+    if json {
+        let result = SkillDiff {
+            skill_id: skill_id.to_string(),
+            diff,
+            warnings,
+        };
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    Ui::warnings(&warnings);
+    if diff.is_empty() {
+        Ui::success(&format!("'{}' has no local modifications", skill_id));
+        return Ok(());
+    }
+
+    for line in diff.lines() {
+        if line.starts_with("+++") || line.starts_with("---") {
+            println!("{}", line.bold());
+        } else if line.starts_with("@@") {
+            println!("{}", line.cyan());
+        } else if line.starts_with('+') {
+            println!("{}", line.green());
+        } else if line.starts_with('-') {
+            println!("{}", line.red());
+        } else {
+            println!("{}", line);
+        }
+    }
+
+    Ok(())
+}
+
+/// Re-download a skill from its source, asking first if it has local modifications
+pub async fn reset(skill_id: &str, yes: bool, json: bool) -> Result<()> {
+    let skill =
+        Skills::get(skill_id)?.ok_or_else(|| anyhow::anyhow!("Skill '{}' not found", skill_id))?;
+
+    if skill.is_modified && !yes {
+        if json {
+            anyhow::bail!(
+                "Skill '{}' has local modifications. Pass --yes to discard them",
+                skill_id
+            );
+        }
+        let prompt = format!("Discard local modifications to '{}'?", skill_id);
+        if !TerminalPrompter.confirm(&prompt, false)? {
+            Ui::info("Reset cancelled");
+            return Ok(());
+        }
+    }
+
+    let skill = Skills::reset_from_source(skill_id).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&skill)?);
+    } else {
+        Ui::success(&format!(
+            "Reset '{}' to the latest version from {}",
+            skill_id,
+            skill.source_name.as_deref().unwrap_or("its source")
+        ));
+    }
+
+    Ok(())
+}
+
+/// Show commits that touched a skill
+pub fn history(skill_id: &str, json: bool) -> Result<()> {
+    let commits = Skills::history(skill_id)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&commits)?);
+        return Ok(());
+    }
+
+    Ui::header(&format!("History: {}", skill_id));
+
+    if commits.is_empty() {
+        Ui::info("No history available (skills directory is not a git repository, or the skill is uncommitted).");
+        return Ok(());
+    }
+
+    println!();
+    for commit in &commits {
+        println!(
+            "  {} {}",
+            commit.sha[..7.min(commit.sha.len())].yellow(),
+            commit.subject
+        );
+        println!(
+            "      {} · {}",
+            commit.author.dimmed(),
+            commit.date.dimmed()
+        );
+    }
+    println!();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// Answers prompts from a fixed script, in order
+    #[derive(Default)]
+    struct ScriptedPrompter {
+        inputs: VecDeque<&'static str>,
+        selections: VecDeque<usize>,
+        prompts: Vec<String>,
+    }
+
+    impl Prompter for ScriptedPrompter {
+        fn input(&mut self, prompt: &str, default: Option<&str>) -> Result<String> {
+            self.prompts.push(prompt.to_string());
+            let answer = self.inputs.pop_front().expect("unexpected input prompt");
+            Ok(match (answer, default) {
+                ("", Some(default)) => default.to_string(),
+                _ => answer.to_string(),
+            })
+        }
+
+        fn select(&mut self, prompt: &str, _items: &[&str]) -> Result<usize> {
+            self.prompts.push(prompt.to_string());
+            Ok(self
+                .selections
+                .pop_front()
+                .expect("unexpected select prompt"))
+        }
+
+        fn confirm(&mut self, prompt: &str, default: bool) -> Result<bool> {
+            self.prompts.push(prompt.to_string());
+            Ok(default)
+        }
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("My React Skill"), "my-react-skill");
+        assert_eq!(slugify("  API -- Design!  "), "api-design");
+        assert_eq!(slugify("Next.js 15"), "next-js-15");
+    }
+
+    #[test]
+    fn test_validate_skill_id() {
+        let existing = HashSet::from(["taken".to_string()]);
+
+        assert!(validate_skill_id("my-skill", &existing).is_ok());
+        assert!(validate_skill_id("", &existing).is_err());
+        assert!(validate_skill_id("My Skill", &existing).is_err());
+        assert!(validate_skill_id("-leading", &existing).is_err());
+        assert!(validate_skill_id("double--hyphen", &existing).is_err());
+        assert!(validate_skill_id("taken", &existing)
+            .unwrap_err()
+            .contains("already exists"));
+    }
+
+    #[test]
+    fn test_wizard_accepts_derived_id_and_defaults() {
+        let mut prompter = ScriptedPrompter {
+            inputs: VecDeque::from(["React Patterns", "", ""]),
+            selections: VecDeque::from([3]),
+            ..Default::default()
+        };
+
+        let input = prompt_skill_input(&mut prompter, &HashSet::new()).unwrap();
+
+        assert_eq!(input.id, "react-patterns");
+        assert_eq!(input.name, "React Patterns");
+        assert_eq!(input.category, SkillCategory::Frontend);
+        assert_eq!(input.description, "Custom skill: React Patterns");
+        assert_eq!(
+            prompter.prompts,
+            vec!["Skill name", "Skill ID", "Category", "Description"]
+        );
+    }
+
+    #[test]
+    fn test_wizard_reprompts_for_empty_name_and_invalid_or_taken_ids() {
+        let existing = HashSet::from(["react-patterns".to_string()]);
+        let mut prompter = ScriptedPrompter {
+            inputs: VecDeque::from([
+                "  ",
+                "React Patterns",
+                "",            // derived id is taken
+                "Bad ID",      // not a slug
+                "react-hooks", // accepted
+                "Hooks guidance",
+            ]),
+            selections: VecDeque::from([0]),
+            ..Default::default()
+        };
+
+        let input = prompt_skill_input(&mut prompter, &existing).unwrap();
+
+        assert_eq!(input.id, "react-hooks");
+        assert_eq!(input.category, SkillCategory::Custom);
+        assert_eq!(input.description, "Hooks guidance");
+        assert!(prompter.inputs.is_empty());
+    }
+}
@@ -0,0 +1,255 @@
+use crate::ui::Ui;
+use anyhow::Result;
+use colored::Colorize;
+use rhinolabs_core::{
+    SkillSchema, SkillSource, SkillSourceType, Skills, UpdateSkillSourceInput, DEFAULT_SKILLS_PATH,
+};
+
+/// Parse a schema name as used in the sources config
+fn parse_schema(schema: &str) -> Result<SkillSchema> {
+    match schema.to_lowercase().as_str() {
+        "standard" => Ok(SkillSchema::Standard),
+        "skills-sh" | "skillssh" => Ok(SkillSchema::SkillsSh),
+        "custom" => Ok(SkillSchema::Custom),
+        _ => anyhow::bail!(
+            "Unknown schema '{}'. Valid: standard, skills-sh, custom",
+            schema
+        ),
+    }
+}
+
+/// Parse a source type name
+fn parse_source_type(source_type: &str) -> Result<SkillSourceType> {
+    match source_type.to_lowercase().as_str() {
+        "official" => Ok(SkillSourceType::Official),
+        "marketplace" => Ok(SkillSourceType::Marketplace),
+        "community" => Ok(SkillSourceType::Community),
+        "local" => Ok(SkillSourceType::Local),
+        _ => anyhow::bail!(
+            "Unknown source type '{}'. Valid: official, marketplace, community, local",
+            source_type
+        ),
+    }
+}
+
+/// Build an enabled source with no description, on the default branch and skills path.
+/// Standard and skills.sh sources can be fetched automatically; custom-schema sources
+/// are browse-only.
+pub fn new_source(
+    id: String,
+    name: String,
+    url: String,
+    schema: &str,
+    source_type: &str,
+) -> Result<SkillSource> {
+    let schema = parse_schema(schema)?;
+    Ok(SkillSource {
+        id,
+        name,
+        source_type: parse_source_type(source_type)?,
+        url,
+        description: String::new(),
+        enabled: true,
+        fetchable: schema != SkillSchema::Custom,
+        schema,
+        skill_count: None,
+        branch: None,
+        skills_path: None,
+        auth_token_env: None,
+    })
+}
+
+fn schema_display(schema: &SkillSchema) -> &'static str {
+    match schema {
+        SkillSchema::Standard => "standard",
+        SkillSchema::SkillsSh => "skills-sh",
+        SkillSchema::Custom => "custom",
+    }
+}
+
+/// List configured skill sources
+pub fn list(json: bool) -> Result<()> {
+    let sources = Skills::list_sources()?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&sources)?);
+        return Ok(());
+    }
+
+    Ui::header("Skill Sources");
+
+    for source in &sources {
+        let status = if source.enabled {
+            "●".green()
+        } else {
+            "○".dimmed()
+        };
+        let browse_badge = if source.fetchable {
+            "".normal()
+        } else {
+            " [browse only]".dimmed()
+        };
+        let private_badge = match &source.auth_token_env {
+            Some(var) => format!(" [token: ${}]", var).dimmed(),
+            None => "".normal(),
+        };
+        println!(
+            "  {} {} {}{}{}",
+            status,
+            source.id.bold(),
+            source.name,
+            browse_badge,
+            private_badge
+        );
+        let path = match source.skills_path() {
+            DEFAULT_SKILLS_PATH => String::new(),
+            "" => ", repo root".to_string(),
+            path => format!(", {}/", path),
+        };
+        println!(
+            "      {} ({}{})",
+            source.url.dimmed(),
+            schema_display(&source.schema),
+            path
+        );
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Register a new skill source (see [`new_source`]), probing it first with `validate`
+pub async fn add(source: SkillSource, validate: bool, json: bool) -> Result<()> {
+    Skills::add_source(source.clone(), validate).await?;
+    let source = Skills::list_sources()?
+        .into_iter()
+        .find(|s| s.id == source.id)
+        .unwrap_or(source);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&source)?);
+    } else {
+        Ui::success(&format!("Added source '{}'", source.id));
+        if let Some(count) = source.skill_count {
+            Ui::info(&format!("Found {} skill(s)", count));
+        }
+        if !source.fetchable {
+            Ui::info("Custom-schema sources are browse-only; skills can't be fetched from them.");
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove a user-added source (default sources can only be disabled)
+pub fn remove(source_id: &str, json: bool) -> Result<()> {
+    if !Skills::list_sources()?.iter().any(|s| s.id == source_id) {
+        anyhow::bail!("Source '{}' not found", source_id);
+    }
+    Skills::remove_source(source_id)?;
+
+    if json {
+        let result = serde_json::json!({ "sourceId": source_id, "removed": true });
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        Ui::success(&format!("Removed source '{}'", source_id));
+    }
+
+    Ok(())
+}
+
+/// Enable or disable a source
+pub async fn set_enabled(source_id: &str, enabled: bool) -> Result<()> {
+    Skills::update_source(
+        source_id,
+        UpdateSkillSourceInput {
+            enabled: Some(enabled),
+            name: None,
+            url: None,
+            description: None,
+            fetchable: None,
+            schema: None,
+            branch: None,
+            skills_path: None,
+            auth_token_env: None,
+        },
+        false,
+    )
+    .await?;
+
+    let action = if enabled { "enabled" } else { "disabled" };
+    Ui::success(&format!("Source '{}' {}", source_id, action));
+    Ok(())
+}
+
+/// Check that a source can be fetched from and record its skill count
+pub async fn test(source_id: &str, json: bool) -> Result<()> {
+    let source = Skills::list_sources()?
+        .into_iter()
+        .find(|s| s.id == source_id)
+        .ok_or_else(|| anyhow::anyhow!("Source '{}' not found", source_id))?;
+    if !source.fetchable {
+        anyhow::bail!(
+            "Source '{}' is browse-only; there is nothing to fetch from it",
+            source_id
+        );
+    }
+
+    // Validating through update_source stores the discovered skill count
+    let result = Skills::update_source(source_id, UpdateSkillSourceInput::default(), true).await;
+    let skill_count = Skills::list_sources()?
+        .into_iter()
+        .find(|s| s.id == source_id)
+        .and_then(|s| s.skill_count);
+
+    if json {
+        let report = match &result {
+            Ok(()) => serde_json::json!({
+                "sourceId": source_id,
+                "reachable": true,
+                "skillCount": skill_count,
+            }),
+            Err(e) => serde_json::json!({
+                "sourceId": source_id,
+                "reachable": false,
+                "error": e.to_string(),
+            }),
+        };
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    match result {
+        Ok(()) => {
+            Ui::success(&format!("Source '{}' is reachable", source_id));
+            println!("  Skills found: {}", skill_count.unwrap_or(0));
+            Ok(())
+        }
+        Err(e) => anyhow::bail!("Source '{}' is unreachable: {}", source_id, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_source_defaults_fetchable_by_schema() {
+        let source = |schema| {
+            new_source(
+                "acme".into(),
+                "Acme".into(),
+                "https://github.com/acme/skills".into(),
+                schema,
+                "community",
+            )
+            .unwrap()
+        };
+
+        assert!(source("standard").fetchable);
+        assert!(source("skills-sh").fetchable);
+        assert!(!source("custom").fetchable);
+        assert!(parse_schema("zip").is_err());
+        assert!(parse_source_type("private").is_err());
+    }
+}
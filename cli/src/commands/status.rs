@@ -1,7 +1,7 @@
 use crate::ui::Ui;
 use anyhow::Result;
 use colored::*;
-use rhinolabs_core::{Paths, Version};
+use rhinolabs_core::{DeployTarget, GenericDeployer, Paths, TargetDetector, TargetStatus, Version};
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -15,6 +15,8 @@ struct StatusOutput {
     #[serde(skip_serializing_if = "Option::is_none")]
     plugin_location: Option<String>,
     claude_code_detected: bool,
+    /// Built-in targets whose tool appears to be installed
+    targets_detected: Vec<DeployTarget>,
     mcp_configured: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     mcp_location: Option<String>,
@@ -54,12 +56,15 @@ pub fn run(json: bool) -> Result<()> {
         Ui::info("Run 'rhinolabs-ai install' to install the plugin");
     }
 
-    // Claude Code info
-    Ui::section("Claude Code");
-    if Paths::is_claude_code_installed() {
-        println!("  Status:       {}", "✓ Detected".green());
-    } else {
-        println!("  Status:       {}", "✗ Not found".red());
+    // AI tools the plugin can deploy to
+    Ui::section("AI Tools");
+    for target in DeployTarget::all() {
+        let status = if GenericDeployer::new(*target).is_installed() {
+            "✓ Detected".green()
+        } else {
+            "✗ Not found".dimmed()
+        };
+        println!("  {:<14}{}", format!("{}:", target.display_name()), status);
     }
 
     // MCP config
@@ -102,6 +107,11 @@ fn run_json() -> Result<()> {
     };
 
     let claude_code_detected = Paths::is_claude_code_installed();
+    let targets_detected = DeployTarget::all()
+        .iter()
+        .copied()
+        .filter(|target| GenericDeployer::new(*target).is_installed())
+        .collect();
 
     let (mcp_configured, mcp_location) = match Paths::mcp_config_path() {
         Ok(path) if path.exists() => (true, Some(path.display().to_string())),
@@ -114,6 +124,7 @@ fn run_json() -> Result<()> {
         plugin_installed_at,
         plugin_location,
         claude_code_detected,
+        targets_detected,
         mcp_configured,
         mcp_location,
     };
@@ -121,3 +132,56 @@ fn run_json() -> Result<()> {
     println!("{}", serde_json::to_string_pretty(&output)?);
     Ok(())
 }
+
+/// Show, for each deploy target, whether skills and instructions are deployed
+pub fn per_target(json: bool) -> Result<()> {
+    let statuses = TargetStatus::all_user()?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&statuses)?);
+        return Ok(());
+    }
+
+    Ui::header("📊 Deploy Targets");
+    println!(
+        "  {:<14} {:<12} {:<10} {:>6}  {}",
+        "Target".bold(),
+        "Tool".bold(),
+        "Deployed".bold(),
+        "Skills".bold(),
+        "Instructions".bold()
+    );
+    for status in &statuses {
+        let installed = if status.installed {
+            format!("{:<12}", "✓ detected").green()
+        } else {
+            format!("{:<12}", "✗ not found").dimmed()
+        };
+        let deployed = if status.is_deployed() {
+            format!("{:<10}", "✓ yes").green()
+        } else {
+            format!("{:<10}", "✗ no").dimmed()
+        };
+        let instructions = if status.instructions_exist {
+            status.instructions_path.display().to_string().normal()
+        } else {
+            "-".dimmed()
+        };
+        println!(
+            "  {:<14} {} {} {:>6}  {}",
+            status.target.display_name(),
+            installed,
+            deployed,
+            status.skill_count,
+            instructions
+        );
+    }
+    println!();
+
+    if statuses.iter().all(|s| !s.is_deployed()) {
+        Ui::info("Nothing deployed yet. Run 'rhinolabs-ai install --target <target>'");
+        println!();
+    }
+
+    Ok(())
+}
@@ -1,8 +1,14 @@
+use super::profile::parse_targets;
 use crate::ui::Ui;
 use anyhow::{anyhow, Result};
 use rhinolabs_core::McpSync;
 
-pub async fn run(url: Option<String>, file: Option<String>, dry_run: bool) -> Result<()> {
+pub async fn run(
+    url: Option<String>,
+    file: Option<String>,
+    target: Vec<String>,
+    dry_run: bool,
+) -> Result<()> {
     Ui::header("🔄 Syncing MCP Configuration");
 
     let sync = match (url, file) {
@@ -22,13 +28,36 @@ pub async fn run(url: Option<String>, file: Option<String>, dry_run: bool) -> Re
         }
     };
 
-    let sync = sync.dry_run(dry_run);
-    sync.sync().await?;
+    let mut sync = sync.dry_run(dry_run);
+    if !target.is_empty() {
+        sync = sync.targets(parse_targets(&target)?);
+    }
+    let report = sync.sync().await?;
+
+    println!();
+    let verb = if dry_run { "Would write" } else { "Wrote" };
+    for target in &report.deployed {
+        Ui::step(&format!(
+            "{} MCP servers for {}",
+            verb,
+            target.display_name()
+        ));
+    }
+    for target in &report.skipped {
+        Ui::warning(&format!(
+            "Skipped {}: it has no MCP support",
+            target.display_name()
+        ));
+    }
+    Ui::warnings(&report.warnings);
+    if dry_run {
+        return Ok(());
+    }
 
     println!();
     Ui::success("MCP configuration synced successfully");
     println!();
-    Ui::info("Next step: Restart Claude Code to apply changes");
+    Ui::info("Next step: Restart your agents to apply changes");
 
     Ok(())
 }
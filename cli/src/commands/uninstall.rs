@@ -6,7 +6,7 @@ use rhinolabs_core::Installer;
 pub fn run(dry_run: bool) -> Result<()> {
     Ui::header("🗑️  Uninstalling Rhinolabs Claude Plugin");
 
-    if !dry_run {
+    if !dry_run && !Ui::assume_yes() {
         let confirmed = Confirm::new()
             .with_prompt("Are you sure you want to uninstall?")
             .default(false)
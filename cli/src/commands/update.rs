@@ -2,7 +2,9 @@ use crate::ui::Ui;
 use anyhow::Result;
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
-use rhinolabs_core::{Profiles, Updater, Version};
+use rhinolabs_core::{
+    BinaryReplace, InstallOptions, Profiles, ReleaseInfo, UpdatePlan, Updater, Version,
+};
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -14,8 +16,9 @@ struct UpdateCheckResult {
     update_available: bool,
 }
 
-/// Check if an update is available (no install)
-pub async fn check(json: bool) -> Result<()> {
+/// Check if an update is available (no install). `binary` points the hint at
+/// `self-update` instead of the plugin update.
+pub async fn check(json: bool, binary: bool) -> Result<()> {
     let current_version = Version::current();
 
     let latest = match Version::check_update().await {
@@ -48,7 +51,8 @@ pub async fn check(json: bool) -> Result<()> {
 
     if let Some(ref version) = latest {
         Ui::success(&format!("Update available: v{}", version));
-        Ui::info("Run 'rhinolabs-ai update' to install it.");
+        let command = if binary { "self-update" } else { "update" };
+        Ui::info(&format!("Run 'rhinolabs-ai {}' to install it.", command));
     } else {
         Ui::success("Already on latest version.");
     }
@@ -56,7 +60,112 @@ pub async fn check(json: bool) -> Result<()> {
     Ok(())
 }
 
-pub async fn run(dry_run: bool) -> Result<()> {
+/// Replace the running rhinolabs-ai executable with the latest release build.
+/// `verify` checks the download against the release's checksums.
+pub async fn binary(verify: bool) -> Result<()> {
+    Ui::header("🔄 Updating rhinolabs-ai");
+
+    let exe = std::env::current_exe()?;
+    Ui::step(&format!("Executable: {}", exe.display()));
+
+    match Version::check_update().await? {
+        Some(version) => Ui::step(&format!("Downloading v{}...", version)),
+        None => {
+            Ui::success("Already on latest version.");
+            return Ok(());
+        }
+    }
+
+    match Updater::new().verify(verify).update_binary(&exe).await? {
+        BinaryReplace::Replaced => Ui::success("rhinolabs-ai updated."),
+        BinaryReplace::RestartRequired => {
+            Ui::success("New version downloaded and verified.");
+            Ui::warning("Restart rhinolabs-ai to finish the update.");
+        }
+    }
+
+    Ok(())
+}
+
+/// Preview an update: version delta, release notes and files that would be replaced.
+/// Nothing is downloaded or written.
+async fn preview(updater: Updater, json: bool) -> Result<()> {
+    let plan = match updater.dry_run(true).plan().await {
+        Ok(plan) => plan,
+        Err(e) => {
+            if json {
+                let err = serde_json::json!({ "error": e.to_string() });
+                println!("{}", serde_json::to_string_pretty(&err)?);
+            } else {
+                Ui::header("🔄 Update Preview (dry run)");
+                Ui::info("Check your network connection and try again.");
+            }
+            anyhow::bail!("Could not fetch release information: {}", e);
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&plan)?);
+        return Ok(());
+    }
+
+    print_plan(&plan);
+    Ok(())
+}
+
+fn print_plan(plan: &UpdatePlan) {
+    Ui::header("🔄 Update Preview (dry run)");
+
+    if !plan.update_available {
+        Ui::success(&format!(
+            "Already on latest version (v{})",
+            plan.current_version
+        ));
+        return;
+    }
+
+    println!(
+        "  Version: {} → {}",
+        plan.current_version.yellow(),
+        plan.latest_version.green()
+    );
+
+    if let Some(notes) = &plan.release_notes {
+        Ui::section("Release Notes");
+        for line in notes.lines() {
+            println!("  {}", line);
+        }
+    }
+
+    Ui::section(&format!("Files to Replace ({})", plan.files_replaced.len()));
+    if plan.files_replaced.is_empty() {
+        println!("  (plugin not installed locally)");
+    }
+    for file in &plan.files_replaced {
+        println!("  • {}", file);
+    }
+
+    println!();
+    Ui::info("[DRY RUN] No files were downloaded or modified.");
+    Ui::info("Run 'rhinolabs-ai update' to apply the update.");
+}
+
+/// Update the plugin to the latest release, or to `version` when pinned
+pub async fn run(
+    dry_run: bool,
+    verify: bool,
+    version: Option<String>,
+    allow_downgrade: bool,
+    json: bool,
+) -> Result<()> {
+    let updater = Updater::new()
+        .verify(verify)
+        .version(version.clone())
+        .allow_downgrade(allow_downgrade);
+    if dry_run {
+        return preview(updater, json).await;
+    }
+
     Ui::header("🔄 Updating Rhinolabs Claude Plugin");
 
     // Check for updates
@@ -70,19 +179,26 @@ pub async fn run(dry_run: bool) -> Result<()> {
     );
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
 
-    match Version::check_update().await? {
-        Some(version) => {
-            pb.finish_and_clear();
-            Ui::success(format!("Update available: v{}", version).as_str());
+    let target_version = match &version {
+        // The updater checks that a pinned release exists and is not a downgrade
+        Some(tag) => Some(tag.trim_start_matches('v').to_string()),
+        None => Version::check_update().await?,
+    };
 
-            if dry_run {
-                Ui::info("[DRY RUN] Would update to latest version");
-                return Ok(());
+    match target_version {
+        Some(target_version) => {
+            pb.finish_and_clear();
+            if version.is_some() {
+                Ui::success(&format!("Switching to v{}", target_version));
+                Ui::step("Downloading pinned version...");
+            } else {
+                Ui::success(&format!("Update available: v{}", target_version));
+                Ui::step("Downloading latest version...");
             }
-
-            Ui::step("Downloading latest version...");
-            let updater = Updater::new().dry_run(dry_run);
-            updater.update().await?;
+            if !verify {
+                Ui::warning("Skipping checksum verification (--no-verify)");
+            }
+            let changelog = updater.update().await?;
 
             // Show synced profiles
             Ui::step("Syncing profile configurations...");
@@ -108,7 +224,12 @@ pub async fn run(dry_run: bool) -> Result<()> {
                     if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
                         if let Some(profile_id) = json["profile"]["id"].as_str() {
                             Ui::step(&format!("Updating project profile '{}'...", profile_id));
-                            match Profiles::install(profile_id, Some(&cwd), None) {
+                            match Profiles::install(
+                                profile_id,
+                                Some(&cwd),
+                                None,
+                                InstallOptions::default(),
+                            ) {
                                 Ok(result) => {
                                     println!(
                                         "  {} {} skills updated",
@@ -130,6 +251,7 @@ pub async fn run(dry_run: bool) -> Result<()> {
 
             println!();
             Ui::success("Update complete!");
+            print_changelog(&changelog);
             println!();
             Ui::info("Next steps:");
             println!("  1. Restart Claude Code");
@@ -143,3 +265,89 @@ pub async fn run(dry_run: bool) -> Result<()> {
 
     Ok(())
 }
+
+/// Print the release notes between the installed version and the latest release
+pub async fn changelog_only(json: bool) -> Result<()> {
+    let changelog = Updater::new().changelog().await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&changelog)?);
+        return Ok(());
+    }
+
+    Ui::header("📝 Changelog");
+    if changelog.is_empty() {
+        Ui::success("Already on latest version.");
+        return Ok(());
+    }
+    print_changelog(&changelog);
+    println!();
+    Ui::info("Run 'rhinolabs-ai update' to install it.");
+
+    Ok(())
+}
+
+fn print_changelog(changelog: &[ReleaseInfo]) {
+    for release in changelog {
+        Ui::section(&format!("What's new in v{}", release.version));
+        match &release.body {
+            Some(body) => {
+                for line in body.lines() {
+                    println!("  {}", render_markdown_line(line));
+                }
+            }
+            None => println!("  {}", "(no release notes)".dimmed()),
+        }
+    }
+}
+
+/// Minimal terminal rendering of a markdown line: bold headings, bullet
+/// points, and `**` emphasis markers dropped
+fn render_markdown_line(line: &str) -> String {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+
+    if trimmed.starts_with('#') {
+        let heading = trimmed.trim_start_matches('#').trim().replace("**", "");
+        return heading.bold().to_string();
+    }
+    let text = match trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))
+    {
+        Some(item) => format!("{}• {}", indent, item),
+        None => line.to_string(),
+    };
+    text.replace("**", "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `render_markdown_line` without the ANSI color codes, which depend on the terminal
+    fn render_plain(line: &str) -> String {
+        let rendered = render_markdown_line(line);
+        let mut plain = String::new();
+        let mut chars = rendered.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|&c| c == 'm');
+            } else {
+                plain.push(c);
+            }
+        }
+        plain
+    }
+
+    #[test]
+    fn test_render_markdown_line() {
+        assert_eq!(render_plain("## What's Changed"), "What's Changed");
+        assert_eq!(
+            render_plain("* **sync**: faster MCP sync"),
+            "• sync: faster MCP sync"
+        );
+        assert_eq!(render_plain("  - nested"), "  • nested");
+        assert_eq!(render_plain("Plain text"), "Plain text");
+    }
+}
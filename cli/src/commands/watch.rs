@@ -0,0 +1,300 @@
+//! `profile watch`: redeploy a project's profile whenever its sources change
+//!
+//! Watches the skills directory, the profile's instructions and the profiles
+//! config, and re-runs the incremental install once changes settle.
+
+use crate::commands::profile::{detect_installed_profile, parse_targets};
+use crate::ui::Ui;
+use anyhow::Result;
+use colored::Colorize;
+use notify::{EventKind, RecursiveMode, Watcher};
+use rhinolabs_core::{InstallOptions, ProfileInstallResult, Profiles};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Quiet period after the last change before redeploying
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Whether an event can change what gets deployed; reads and metadata-only
+/// changes (e.g. access times) are not
+fn is_content_change(kind: &EventKind) -> bool {
+    match kind {
+        EventKind::Create(_) | EventKind::Remove(_) | EventKind::Any => true,
+        EventKind::Modify(modify) => !matches!(modify, notify::event::ModifyKind::Metadata(_)),
+        EventKind::Access(_) | EventKind::Other => false,
+    }
+}
+
+/// Whether `changed` is one of the watched paths or inside a watched directory
+fn is_watched(changed: &Path, watched: &[PathBuf]) -> bool {
+    watched.iter().any(|path| changed.starts_with(path))
+}
+
+/// Show `path` relative to the watched path containing it
+fn display_path(path: &Path, watched: &[PathBuf]) -> String {
+    watched
+        .iter()
+        .filter_map(|root| {
+            let relative = path.strip_prefix(root.parent()?).ok()?;
+            Some(relative.display().to_string())
+        })
+        .min_by_key(String::len)
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+/// Watch the profile's sources and redeploy on change until Ctrl-C
+pub async fn run(
+    profile_id: Option<String>,
+    target_path: Option<String>,
+    target_strs: Vec<String>,
+    no_plugin: bool,
+    only_enabled: bool,
+    json: bool,
+) -> Result<()> {
+    let targets = parse_targets(&target_strs)?;
+    let targets_ref = if targets.is_empty() {
+        None
+    } else {
+        Some(targets.as_slice())
+    };
+
+    let target = target_path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+
+    let profile_id = match profile_id {
+        Some(id) => id,
+        None => match detect_installed_profile(&target) {
+            Some((id, _)) => id,
+            None => {
+                anyhow::bail!(
+                    "No profile installed in {}. Pass a profile ID or run 'rhinolabs-ai profile install <profile>' first.",
+                    target.display()
+                );
+            }
+        },
+    };
+
+    let watched = Profiles::watch_paths(&profile_id)?;
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<PathBuf>();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if let Ok(event) = event {
+            if is_content_change(&event.kind) {
+                for path in event.paths {
+                    let _ = tx.send(path);
+                }
+            }
+        }
+    })?;
+    // Files are watched through their parent directory so that editors that
+    // replace the file on save, or files created later, are still picked up
+    let mut roots: Vec<(&Path, RecursiveMode)> = Vec::new();
+    for path in &watched {
+        let root = if path.is_dir() {
+            (path.as_path(), RecursiveMode::Recursive)
+        } else if let Some(parent) = path.parent().filter(|parent| parent.is_dir()) {
+            (parent, RecursiveMode::NonRecursive)
+        } else {
+            continue;
+        };
+        if !roots.iter().any(|(existing, _)| *existing == root.0) {
+            roots.push(root);
+        }
+    }
+    for (root, mode) in roots {
+        watcher.watch(root, mode)?;
+    }
+
+    let redeploy = || {
+        Profiles::update_installed(
+            &profile_id,
+            Some(&target),
+            targets_ref,
+            InstallOptions {
+                create_plugin_manifest: !no_plugin,
+                only_enabled,
+                ..Default::default()
+            },
+        )
+    };
+
+    if !json {
+        Ui::header(&format!("Watching profile '{}'", profile_id));
+        println!("  {} {}", "→".cyan(), target.display().to_string().bold());
+        for path in &watched {
+            println!("    {}", path.display().to_string().dimmed());
+        }
+        println!();
+    }
+    report_cycle(&redeploy()?, &[], &watched, json)?;
+    if !json {
+        Ui::info("Waiting for changes (Ctrl-C to stop)...");
+    }
+
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    loop {
+        let first = tokio::select! {
+            _ = &mut ctrl_c => break,
+            changed = rx.recv() => match changed {
+                Some(path) => path,
+                None => break,
+            },
+        };
+
+        let mut changed = BTreeSet::new();
+        if is_watched(&first, &watched) {
+            changed.insert(first);
+        }
+        loop {
+            tokio::select! {
+                _ = &mut ctrl_c => return stop(json),
+                next = tokio::time::timeout(DEBOUNCE, rx.recv()) => match next {
+                    Ok(Some(path)) => {
+                        if is_watched(&path, &watched) {
+                            changed.insert(path);
+                        }
+                    }
+                    Ok(None) | Err(_) => break,
+                },
+            }
+        }
+        if changed.is_empty() {
+            continue;
+        }
+
+        let changed: Vec<PathBuf> = changed.into_iter().collect();
+        match redeploy() {
+            Ok(result) => report_cycle(&result, &changed, &watched, json)?,
+            // Keep watching: the next save may well fix it
+            Err(e) => Ui::error(&format!("Redeploy failed: {}", e)),
+        }
+    }
+
+    stop(json)
+}
+
+fn stop(json: bool) -> Result<()> {
+    if !json {
+        println!();
+        Ui::info("Stopped watching.");
+    }
+    Ok(())
+}
+
+/// Print what one install cycle changed (one JSON line per cycle with `--json`)
+fn report_cycle(
+    result: &ProfileInstallResult,
+    changed: &[PathBuf],
+    watched: &[PathBuf],
+    json: bool,
+) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string(result)?);
+        return Ok(());
+    }
+
+    let time = chrono::Local::now().format("%H:%M:%S").to_string();
+    if !changed.is_empty() {
+        println!();
+        for path in changed {
+            println!(
+                "  {} {} {}",
+                time.dimmed(),
+                "changed".yellow(),
+                display_path(path, watched)
+            );
+        }
+    }
+
+    let redeployed: Vec<&str> = result
+        .skills_installed
+        .iter()
+        .filter(|skill| !result.skills_unchanged.contains(skill))
+        .map(String::as_str)
+        .collect();
+    if redeployed.is_empty() {
+        println!(
+            "  {} {}",
+            time.dimmed(),
+            "up to date, no skills redeployed".dimmed()
+        );
+    } else {
+        println!(
+            "  {} {} {}",
+            time.dimmed(),
+            "redeployed".green(),
+            redeployed.join(", ")
+        );
+    }
+    for failure in &result.skills_failed {
+        println!(
+            "  {} {} {} - {}",
+            time.dimmed(),
+            "failed".red(),
+            failure.skill_id,
+            failure.error
+        );
+    }
+    for failure in &result.targets_failed {
+        println!(
+            "  {} {} {} - {}",
+            time.dimmed(),
+            "failed".red(),
+            failure.target.display_name(),
+            failure.error
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, CreateKind, DataChange, MetadataKind, ModifyKind};
+
+    #[test]
+    fn test_only_content_changes_trigger_redeploy() {
+        assert!(is_content_change(&EventKind::Create(CreateKind::File)));
+        assert!(is_content_change(&EventKind::Modify(ModifyKind::Data(
+            DataChange::Content
+        ))));
+        assert!(!is_content_change(&EventKind::Modify(
+            ModifyKind::Metadata(MetadataKind::AccessTime)
+        )));
+        assert!(!is_content_change(&EventKind::Access(AccessKind::Read)));
+    }
+
+    #[test]
+    fn test_changed_paths_are_matched_and_shown_relative_to_the_watch_root() {
+        let watched = vec![
+            PathBuf::from("/plugin/skills"),
+            PathBuf::from("/config/profile-instructions/web.md"),
+        ];
+        let skill_file = Path::new("/plugin/skills/react-19/SKILL.md");
+
+        assert!(is_watched(skill_file, &watched));
+        assert!(is_watched(
+            Path::new("/config/profile-instructions/web.md"),
+            &watched
+        ));
+        // A sibling in a watched parent directory is not a source of this profile
+        assert!(!is_watched(
+            Path::new("/config/profile-instructions/api.md"),
+            &watched
+        ));
+
+        assert_eq!(
+            display_path(skill_file, &watched),
+            "skills/react-19/SKILL.md"
+        );
+        assert_eq!(
+            display_path(Path::new("/config/profile-instructions/web.md"), &watched),
+            "web.md"
+        );
+    }
+}
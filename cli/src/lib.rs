@@ -1,8 +1,10 @@
 pub mod commands;
+mod test_utils;
 pub mod ui;
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 use commands::*;
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "rhinolabs-ai")]
@@ -15,6 +17,24 @@ struct Cli {
     /// Output results as JSON (for programmatic consumption)
     #[arg(long, global = true)]
     json: bool,
+
+    /// Make no network requests: skip auto-sync and fail fast on remote fetches
+    /// (also enabled by RHINOLABS_OFFLINE=1)
+    #[arg(long, global = true)]
+    offline: bool,
+
+    /// Log network requests and file writes to stderr (-v info, -vv debug)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Disable colored output (also NO_COLOR=1; off automatically when not a terminal)
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Answer yes to every confirmation, including destructive ones
+    /// (also enabled by RHINOLABS_ASSUME_YES=1)
+    #[arg(short = 'y', long, global = true)]
+    yes: bool,
 }
 
 #[derive(Subcommand)]
@@ -32,17 +52,56 @@ enum Commands {
         /// Dry run - show what would be done without making changes
         #[arg(long)]
         dry_run: bool,
+
+        /// Install without checking the download against the release's SHA256SUMS
+        #[arg(long)]
+        no_verify: bool,
+
+        /// Install this plugin release (e.g. v1.2.0) instead of the latest
+        #[arg(long, value_name = "TAG")]
+        version: Option<String>,
     },
 
     /// Update plugin to latest version
     Update {
-        /// Dry run - show what would be done without making changes
+        /// Dry run - show the version delta, release notes and files that would be replaced
         #[arg(long)]
         dry_run: bool,
 
         /// Only check if an update is available (does not install)
         #[arg(long)]
         check: bool,
+
+        /// Update the rhinolabs-ai executable itself instead of the plugin (same as self-update)
+        #[arg(long, conflicts_with = "dry_run")]
+        binary: bool,
+
+        /// Update without checking the download against the release's SHA256SUMS
+        #[arg(long)]
+        no_verify: bool,
+
+        /// Move to this plugin release (e.g. v1.2.0) instead of the latest
+        #[arg(long, value_name = "TAG", conflicts_with_all = ["check", "binary"])]
+        version: Option<String>,
+
+        /// Allow --version to install a release older than the installed one
+        #[arg(long, requires = "version")]
+        allow_downgrade: bool,
+
+        /// Print the release notes between the installed and latest versions (does not install)
+        #[arg(long, conflicts_with_all = ["dry_run", "check", "binary", "version"])]
+        changelog_only: bool,
+    },
+
+    /// Update the rhinolabs-ai executable to the latest release
+    SelfUpdate {
+        /// Only check if a newer release exists (does not download)
+        #[arg(long)]
+        check: bool,
+
+        /// Update without checking the download against the release's checksums
+        #[arg(long)]
+        no_verify: bool,
     },
 
     /// Uninstall the plugin
@@ -58,20 +117,32 @@ enum Commands {
         #[arg(short, long)]
         url: Option<String>,
 
-        /// Local file path to import configuration from
+        /// Local file path to import configuration from (.json, .yaml/.yml or .toml)
         #[arg(short, long)]
         file: Option<String>,
 
+        /// Deploy targets: claude-code (default), amp, antigravity, open-code, all
+        #[arg(short, long)]
+        target: Vec<String>,
+
         /// Dry run - show what would be done without making changes
         #[arg(long)]
         dry_run: bool,
     },
 
     /// Show plugin status and version info
-    Status,
+    Status {
+        /// Show what is deployed for each target (skills, instructions) instead
+        #[arg(long)]
+        per_target: bool,
+    },
 
     /// Run diagnostic checks
-    Doctor,
+    Doctor {
+        /// Repair problems that can be fixed automatically before running checks
+        #[arg(long)]
+        fix: bool,
+    },
 
     /// Show version information
     Version,
@@ -88,6 +159,12 @@ enum Commands {
         action: SkillAction,
     },
 
+    /// Manage skill sources (repositories skills are searched and installed from)
+    Source {
+        #[command(subcommand)]
+        action: SourceAction,
+    },
+
     /// Sync configuration from GitHub (pull latest deployed config)
     Sync,
 
@@ -96,12 +173,201 @@ enum Commands {
         #[command(subcommand)]
         action: RagAction,
     },
+
+    /// Inspect configured MCP servers
+    Mcp {
+        #[command(subcommand)]
+        action: McpAction,
+    },
+
+    /// Inspect output styles
+    OutputStyle {
+        #[command(subcommand)]
+        action: OutputStyleAction,
+    },
+
+    /// Move this machine's configuration to another one
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
+    /// Print a shell completion script (bash, zsh, fish, powershell)
+    #[command(hide = true)]
+    Completions {
+        /// Shell to generate the script for
+        shell: clap_complete::Shell,
+    },
+}
+
+#[derive(Subcommand)]
+enum SourceAction {
+    /// List configured skill sources
+    List,
+
+    /// Add a skill source
+    Add {
+        /// Unique source identifier
+        #[arg(long)]
+        id: String,
+
+        /// Display name
+        #[arg(long)]
+        name: String,
+
+        /// Repository or site URL
+        #[arg(long)]
+        url: String,
+
+        /// Layout of the source: standard, skills-sh, custom (custom sources are browse-only)
+        #[arg(long, default_value = "standard")]
+        schema: String,
+
+        /// Source type: official, marketplace, community, local
+        #[arg(long = "type", default_value = "community")]
+        source_type: String,
+
+        /// Optional description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Git branch to read skills from (defaults to main)
+        #[arg(long)]
+        branch: Option<String>,
+
+        /// Repo directory holding the skill folders (defaults to `skills`; `.` for the
+        /// repo root). A directory with its own SKILL.md is treated as a single skill
+        #[arg(long)]
+        skills_path: Option<String>,
+
+        /// Environment variable holding a GitHub token for a private repository
+        /// (only the variable name is saved)
+        #[arg(long)]
+        token_env: Option<String>,
+
+        /// Check the source can be fetched from before adding it
+        #[arg(long)]
+        validate: bool,
+    },
+
+    /// Check a source can be fetched from and show how many skills it has
+    Test {
+        /// Source ID
+        source_id: String,
+    },
+
+    /// Remove a user-added source (default sources can only be disabled)
+    Remove {
+        /// Source ID
+        source_id: String,
+    },
+
+    /// Enable a source
+    Enable {
+        /// Source ID
+        source_id: String,
+    },
+
+    /// Disable a source
+    Disable {
+        /// Source ID
+        source_id: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum OutputStyleAction {
+    /// List output styles
+    List,
+
+    /// Show an output style
+    Show {
+        /// Output style ID
+        style_id: String,
+
+        /// Print the exact file a profile install deploys (frontmatter and content)
+        #[arg(long)]
+        rendered: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum McpAction {
+    /// List configured MCP servers (secret values are masked)
+    List {
+        /// Show secret env and header values in plain text
+        #[arg(long)]
+        reveal: bool,
+    },
+
+    /// Import servers from a project's committed .mcp.json
+    Import {
+        /// Project directory containing .mcp.json
+        #[arg(long)]
+        project: PathBuf,
+
+        /// Replace managed servers that have the same name but a different definition
+        #[arg(long)]
+        overwrite: bool,
+    },
+
+    /// Turn a disabled server back on
+    Enable {
+        /// Server name
+        name: String,
+    },
+
+    /// Stop deploying a server to Claude Code without removing it
+    Disable {
+        /// Server name
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Bundle profiles, skills, settings, instructions, sources and MCP config
+    /// into one archive (secret values are left out)
+    Export {
+        /// Archive to write
+        #[arg(long)]
+        out: PathBuf,
+    },
+
+    /// Restore an archive written by `config export`
+    Import {
+        /// Archive to restore
+        file: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProfileTagAction {
+    /// Tag a profile
+    Add {
+        /// Profile ID
+        profile_id: String,
+        /// Tag to add (normalized to lowercase)
+        tag: String,
+    },
+
+    /// Remove a tag from a profile
+    Remove {
+        /// Profile ID
+        profile_id: String,
+        /// Tag to remove
+        tag: String,
+    },
 }
 
 #[derive(Subcommand)]
 enum ProfileAction {
     /// List all profiles
-    List,
+    List {
+        /// Only list profiles carrying this tag
+        #[arg(long)]
+        tag: Option<String>,
+    },
 
     /// Show details of a specific profile
     Show {
@@ -114,13 +380,43 @@ enum ProfileAction {
         /// Profile ID to install
         profile: String,
 
-        /// Target project path (defaults to current directory)
+        /// Target project path (defaults to current directory). Repeat it to install
+        /// into several projects, e.g. a monorepo's packages
         #[arg(short = 'P', long)]
-        path: Option<String>,
+        path: Vec<String>,
+
+        /// Comma-separated list of project paths, added to any --path
+        #[arg(long, value_delimiter = ',')]
+        paths: Vec<String>,
 
         /// Deploy targets: claude-code (default), amp, antigravity, open-code, all
         #[arg(short, long)]
         target: Vec<String>,
+
+        /// Skip the .claude-plugin/ manifest (deploy skills and instructions only)
+        #[arg(long)]
+        no_plugin: bool,
+
+        /// Leave out skills that are globally disabled (default: deploy every profile skill)
+        #[arg(long)]
+        only_enabled: bool,
+
+        /// Write a JSON report of the result to this file
+        #[arg(long)]
+        report: Option<PathBuf>,
+
+        /// Dry run - list every path that would be created without making changes
+        #[arg(long, conflicts_with = "report")]
+        dry_run: bool,
+
+        /// Add the generated files to the project's .gitignore (kept up to date on
+        /// later installs and removed by uninstall)
+        #[arg(long, conflicts_with = "dry_run")]
+        gitignore: bool,
+
+        /// Don't warn about targets whose tool does not appear to be installed
+        #[arg(long)]
+        skip_detection: bool,
     },
 
     /// Update an installed profile with latest skill versions
@@ -135,6 +431,53 @@ enum ProfileAction {
         /// Deploy targets: claude-code (default), amp, antigravity, open-code, all
         #[arg(short, long)]
         target: Vec<String>,
+
+        /// Skip the .claude-plugin/ manifest (deploy skills and instructions only)
+        #[arg(long)]
+        no_plugin: bool,
+
+        /// Leave out skills that are globally disabled (default: deploy every profile skill)
+        #[arg(long)]
+        only_enabled: bool,
+
+        /// Write a JSON report of the result to this file
+        #[arg(long)]
+        report: Option<PathBuf>,
+
+        /// Redeploy every skill, even those unchanged since the last install
+        #[arg(long)]
+        force: bool,
+
+        /// Replace instructions files that have no managed-section markers (e.g. from
+        /// installs that predate them) instead of leaving them alone
+        #[arg(long)]
+        overwrite_instructions: bool,
+
+        /// Don't warn about targets whose tool does not appear to be installed
+        #[arg(long)]
+        skip_detection: bool,
+    },
+
+    /// Redeploy a project's profile whenever its skills, instructions or config change
+    Watch {
+        /// Profile ID (optional - detects from installed plugin if not specified)
+        profile: Option<String>,
+
+        /// Target project path (defaults to current directory)
+        #[arg(short = 'P', long)]
+        path: Option<String>,
+
+        /// Deploy targets: claude-code (default), amp, antigravity, open-code, all
+        #[arg(short, long)]
+        target: Vec<String>,
+
+        /// Skip the .claude-plugin/ manifest (deploy skills and instructions only)
+        #[arg(long)]
+        no_plugin: bool,
+
+        /// Leave out skills that are globally disabled (default: deploy every profile skill)
+        #[arg(long)]
+        only_enabled: bool,
     },
 
     /// Uninstall profile from a project (removes .claude directory)
@@ -146,6 +489,14 @@ enum ProfileAction {
         /// Deploy targets: claude-code (default), amp, antigravity, open-code, all
         #[arg(short, long)]
         target: Vec<String>,
+
+        /// Keep instruction files (CLAUDE.md, AGENTS.md, ...) even if generated
+        #[arg(long)]
+        keep_instructions: bool,
+
+        /// Write a JSON report of the result to this file
+        #[arg(long)]
+        report: Option<PathBuf>,
     },
 
     /// Sync installed profile: reconcile declared vs installed skills
@@ -154,12 +505,86 @@ enum ProfileAction {
         #[arg(short = 'P', long)]
         path: Option<String>,
     },
+
+    /// Preview what `profile sync` would change, without changing anything
+    Diff {
+        /// Target project path (defaults to current directory)
+        #[arg(short = 'P', long)]
+        path: Option<String>,
+    },
+
+    /// Create a new project profile from a copy of an existing one
+    Clone {
+        /// Profile ID to copy
+        source_id: String,
+        /// ID for the new profile
+        new_id: String,
+        /// Display name for the new profile
+        #[arg(long)]
+        name: String,
+    },
+
+    /// Write a profile and its instructions to a shareable JSON file
+    Export {
+        /// Profile ID to export
+        profile_id: String,
+        /// Output file (defaults to ./<profile-id>.json)
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+
+    /// Add a profile from a file written by `profile export`
+    Import {
+        /// Profile JSON file
+        file: PathBuf,
+    },
+
+    /// Add an installed skill to a profile
+    AddSkill {
+        /// Profile ID
+        profile_id: String,
+        /// Skill ID to add
+        skill_id: String,
+        /// Allow adding to the main (user) profile
+        #[arg(long)]
+        allow_main: bool,
+    },
+
+    /// Remove a skill from a profile
+    RemoveSkill {
+        /// Profile ID
+        profile_id: String,
+        /// Skill ID to remove
+        skill_id: String,
+    },
+
+    /// Choose the output style a user profile installs instead of the active one
+    SetOutputStyle {
+        /// Profile ID
+        profile_id: String,
+        /// Output style ID
+        #[arg(required_unless_present = "clear")]
+        style_id: Option<String>,
+        /// Go back to installing the globally active style
+        #[arg(long, conflicts_with = "style_id")]
+        clear: bool,
+    },
+
+    /// Add or remove profile tags
+    Tag {
+        #[command(subcommand)]
+        action: ProfileTagAction,
+    },
 }
 
 #[derive(Subcommand)]
 enum SkillAction {
     /// List all skills
-    List,
+    List {
+        /// Show which profiles include each skill
+        #[arg(long)]
+        with_profiles: bool,
+    },
 
     /// Show details of a specific skill
     Show {
@@ -174,26 +599,196 @@ enum SkillAction {
         id: String,
 
         /// Display name for the skill
-        #[arg(long)]
-        name: String,
+        #[arg(long, required_unless_present = "from_dir")]
+        name: Option<String>,
 
-        /// Skill category: corporate, backend, frontend, testing, ai-sdk, utilities, custom
+        /// Skill category: corporate, backend, frontend, testing, ai-sdk, utilities, custom,
+        /// or any other label to define a new category
         #[arg(long, default_value = "custom")]
         category: String,
 
         /// Optional description
         #[arg(long)]
         description: Option<String>,
+
+        /// Import an existing folder (with SKILL.md) as the skill; honours .skillignore
+        #[arg(long, conflicts_with_all = ["name", "description"])]
+        from_dir: Option<PathBuf>,
     },
 
+    /// Create a new custom skill with an interactive wizard
+    New,
+
     /// Set the category for an existing skill
     SetCategory {
         /// Skill ID to update
         skill_id: String,
 
-        /// New category: corporate, backend, frontend, testing, ai-sdk, utilities, custom
+        /// New category: corporate, backend, frontend, testing, ai-sdk, utilities, custom,
+        /// or any other label to define a new category
         category: String,
     },
+
+    /// Enable or disable a skill (same as `enable` / `disable`)
+    Toggle {
+        /// Skill ID
+        skill_id: String,
+
+        /// Enable the skill
+        #[arg(long, conflicts_with = "disable", required_unless_present = "disable")]
+        enable: bool,
+
+        /// Disable the skill
+        #[arg(long)]
+        disable: bool,
+
+        /// Only change the state for these targets
+        #[arg(short, long)]
+        target: Vec<String>,
+    },
+
+    /// Update a skill's name, description or content
+    Update {
+        /// Skill ID
+        skill_id: String,
+
+        /// New display name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// New description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Markdown file with the new skill content (the SKILL.md body, without frontmatter)
+        #[arg(long)]
+        content_file: Option<PathBuf>,
+    },
+
+    /// Delete a custom or source-installed skill (built-in skills can only be disabled)
+    Delete {
+        /// Skill ID (asks for confirmation unless --yes is given)
+        skill_id: String,
+    },
+
+    /// Enable a skill, globally or only for specific deploy targets
+    Enable {
+        /// Skill ID
+        skill_id: String,
+
+        /// Only enable for these targets (a globally disabled skill stays off everywhere)
+        #[arg(short, long)]
+        target: Vec<String>,
+    },
+
+    /// Disable a skill, globally or only for specific deploy targets
+    Disable {
+        /// Skill ID
+        skill_id: String,
+
+        /// Only disable for these targets, e.g. `-t amp`; profile installs skip it there
+        #[arg(short, long)]
+        target: Vec<String>,
+    },
+
+    /// Show the git history of a skill (when the skills directory is a git repo)
+    History {
+        /// Skill ID
+        skill_id: String,
+    },
+
+    /// Check a skill's SKILL.md and layout for problems (exits non-zero on errors)
+    Validate {
+        /// Skill ID
+        skill_id: String,
+
+        /// Enforce the Agent Skills spec (fails on violations)
+        #[arg(long)]
+        strict: bool,
+    },
+    /// Search fetchable skill sources for remote skills
+    Search {
+        /// Matched case-insensitively against skill id, name and description
+        query: String,
+
+        /// Only search this source ID
+        #[arg(long)]
+        source: Option<String>,
+
+        /// Ignore cached source listings and refetch them
+        #[arg(long)]
+        refresh: bool,
+
+        /// skills.sh leaderboard to search: hot, trending, new, or all-time
+        #[arg(long)]
+        sort: Option<String>,
+
+        /// Narrow the skills.sh leaderboard to one category
+        #[arg(long)]
+        category: Option<String>,
+    },
+    /// Search the content of installed skills
+    Grep {
+        /// Regular expression matched against each line
+        pattern: String,
+
+        /// Match case-insensitively
+        #[arg(short = 'i', long)]
+        ignore_case: bool,
+
+        /// Also search supporting files, not only SKILL.md
+        #[arg(long)]
+        files: bool,
+    },
+    /// List source-installed skills whose upstream SKILL.md has changed
+    Outdated,
+    /// Show local changes to a source-installed skill's SKILL.md
+    Diff {
+        /// Skill ID
+        skill_id: String,
+    },
+    /// Change a custom or source-installed skill's id (profiles are updated too)
+    Rename {
+        /// Current skill ID
+        old_id: String,
+        /// New skill ID
+        new_id: String,
+    },
+    /// Re-download a source-installed skill, discarding local modifications
+    Reset {
+        /// Skill ID (asks before discarding local modifications unless --yes is given)
+        skill_id: String,
+    },
+    /// Install skills from a source by id (already installed skills are skipped)
+    Install {
+        /// Skill IDs to install
+        #[arg(required = true)]
+        skill_ids: Vec<String>,
+
+        /// Source ID to install from
+        #[arg(long)]
+        source: String,
+    },
+    /// Install a skill from a GitHub link to its directory or SKILL.md
+    InstallUrl {
+        /// e.g. https://github.com/owner/repo/tree/main/skills/<id>
+        url: String,
+    },
+    /// Import a skill from a directory with SKILL.md or an exported .zip archive
+    Import {
+        /// Skill directory or .zip archive
+        path: PathBuf,
+    },
+
+    /// Export a skill to a portable <skill-id>.zip archive
+    Export {
+        /// Skill ID to export
+        skill_id: String,
+
+        /// Directory to write the archive to (defaults to current directory)
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -212,6 +807,23 @@ enum RagAction {
     /// Show RAG status for the current project
     Status,
 
+    /// Search the project's indexed memory
+    Query {
+        /// What to search for
+        text: String,
+
+        /// Maximum number of snippets to show
+        #[arg(long, default_value_t = rhinolabs_core::DEFAULT_RAG_QUERY_LIMIT)]
+        limit: usize,
+    },
+
+    /// Send project files to the memory (respects .gitignore)
+    Index {
+        /// File or directory to index, relative to the project (repeatable; default: whole project)
+        #[arg(long)]
+        path: Vec<PathBuf>,
+    },
+
     /// Create a new API key (requires admin key)
     CreateKey {
         /// Name for the API key (e.g., "Backend Team")
@@ -234,23 +846,83 @@ enum RagAction {
 
     /// Remove RAG configuration from the current project
     Remove,
+
+    /// Show or change this project's indexing settings (chunking, embedding model)
+    Settings {
+        #[command(subcommand)]
+        action: Option<RagSettingsAction>,
+    },
+}
+
+#[derive(Subcommand)]
+enum RagSettingsAction {
+    /// Show current RAG settings
+    Show,
+
+    /// Set a RAG setting
+    Set {
+        /// Setting key: chunk-size, chunk-overlap, embedding-model, index-extensions
+        key: String,
+
+        /// New value
+        value: String,
+    },
+}
+
+/// The clap definition of the CLI, for tooling such as completion scripts
+pub fn cli_command() -> clap::Command {
+    Cli::command()
+}
+
+/// Send this crate's and core's log events to stderr; nothing is logged without `-v`
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => return,
+        1 => tracing::Level::INFO,
+        _ => tracing::Level::DEBUG,
+    };
+    let filter = tracing_subscriber::filter::Targets::new()
+        .with_target("rhinolabs_core", level)
+        .with_target("rhinolabs_ai_cli", level);
+
+    use tracing_subscriber::prelude::*;
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(std::io::stderr)
+                .with_ansi(colored::control::SHOULD_COLORIZE.should_colorize()),
+        )
+        .with(filter)
+        .init();
 }
 
 pub async fn run() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    ui::Ui::init_color(cli.no_color, cli.json);
+    ui::Ui::set_assume_yes(cli.yes);
+    init_logging(cli.verbose);
+
+    // Remove the previous executable left behind by a Windows self-update
+    if let Ok(exe) = std::env::current_exe() {
+        rhinolabs_core::updater::cleanup_replaced_binary(&exe);
+    }
 
     // Determine if auto-sync should run for this command
     let should_auto_sync = matches!(
         &cli.command,
         Some(Commands::Profile { .. })
-            | Some(Commands::Status)
-            | Some(Commands::Doctor)
+            | Some(Commands::Status { .. })
+            | Some(Commands::Doctor { .. })
             | Some(Commands::SyncMcp { .. })
             | None // Interactive mode
     );
 
-    // Run auto-sync for applicable commands
-    if should_auto_sync {
+    if cli.offline {
+        rhinolabs_core::network::set_offline(true);
+    }
+
+    // Run auto-sync for applicable commands (never in offline mode)
+    if should_auto_sync && !rhinolabs_core::network::is_offline() {
         // Auto-sync runs silently if not needed, shows UI if syncing
         let _ = auto_sync::run_auto_sync().await;
     }
@@ -262,62 +934,220 @@ pub async fn run() -> anyhow::Result<()> {
             target,
             skip_profile,
             dry_run,
+            no_verify,
+            version,
+        }) => {
+            install::run(target, skip_profile, dry_run, !no_verify, version).await?;
+        }
+        Some(Commands::Update {
+            dry_run,
+            check,
+            binary,
+            no_verify,
+            version,
+            allow_downgrade,
+            changelog_only,
         }) => {
-            install::run(target, skip_profile, dry_run).await?;
+            if changelog_only {
+                update::changelog_only(json).await?;
+            } else if check {
+                update::check(json, binary).await?;
+            } else if binary {
+                update::binary(!no_verify).await?;
+            } else {
+                update::run(dry_run, !no_verify, version, allow_downgrade, json).await?;
+            }
         }
-        Some(Commands::Update { dry_run, check }) => {
+        Some(Commands::SelfUpdate { check, no_verify }) => {
             if check {
-                update::check(json).await?;
+                update::check(json, true).await?;
             } else {
-                update::run(dry_run).await?;
+                update::binary(!no_verify).await?;
             }
         }
         Some(Commands::Uninstall { dry_run }) => {
             uninstall::run(dry_run)?;
         }
-        Some(Commands::SyncMcp { url, file, dry_run }) => {
-            sync_mcp::run(url, file, dry_run).await?;
+        Some(Commands::SyncMcp {
+            url,
+            file,
+            target,
+            dry_run,
+        }) => {
+            sync_mcp::run(url, file, target, dry_run).await?;
         }
-        Some(Commands::Status) => {
-            status::run(json)?;
+        Some(Commands::Status { per_target }) => {
+            if per_target {
+                status::per_target(json)?;
+            } else {
+                status::run(json)?;
+            }
         }
-        Some(Commands::Doctor) => {
-            doctor::run(json).await?;
+        Some(Commands::Doctor { fix }) => {
+            doctor::run(json, fix, cli.offline).await?;
         }
         Some(Commands::Version) => {
             version::run();
         }
         Some(Commands::Profile { action }) => match action {
-            ProfileAction::List => {
-                profile::list(json)?;
+            ProfileAction::List { tag } => {
+                profile::list(tag.as_deref(), json)?;
             }
             ProfileAction::Show { profile_id } => {
                 profile::show(&profile_id, json)?;
             }
             ProfileAction::Install {
                 profile,
-                path,
+                mut path,
+                paths,
                 target,
+                no_plugin,
+                only_enabled,
+                report,
+                dry_run,
+                gitignore,
+                skip_detection,
             } => {
-                profile::install(&profile, path, target, json)?;
+                if !skip_detection && !json {
+                    profile::warn_undetected_targets(&target)?;
+                }
+                path.extend(paths);
+                if dry_run {
+                    profile::install_dry_run(
+                        &profile,
+                        &path,
+                        target,
+                        no_plugin,
+                        only_enabled,
+                        json,
+                    )?;
+                } else if path.len() > 1 || json {
+                    let result = profile::install_many(
+                        &profile,
+                        &path,
+                        target,
+                        no_plugin,
+                        only_enabled,
+                        json,
+                        report,
+                    );
+                    // Ignore what did install even when another path failed
+                    if gitignore {
+                        let projects = if path.is_empty() {
+                            vec![None]
+                        } else {
+                            path.into_iter().map(Some).collect()
+                        };
+                        for project in projects {
+                            profile::add_to_gitignore(&profile, project, json)?;
+                        }
+                    }
+                    result?;
+                } else {
+                    let path = path.into_iter().next();
+                    profile::install(
+                        &profile,
+                        path.clone(),
+                        target,
+                        no_plugin,
+                        only_enabled,
+                        report,
+                    )?;
+                    if gitignore {
+                        profile::add_to_gitignore(&profile, path, json)?;
+                    }
+                }
             }
             ProfileAction::Update {
                 profile,
                 path,
                 target,
+                no_plugin,
+                only_enabled,
+                report,
+                force,
+                overwrite_instructions,
+                skip_detection,
+            } => {
+                if !skip_detection && !json {
+                    profile::warn_undetected_targets(&target)?;
+                }
+                let options = rhinolabs_core::InstallOptions {
+                    create_plugin_manifest: !no_plugin,
+                    only_enabled,
+                    force,
+                    overwrite_instructions,
+                };
+                profile::update(profile, path, target, options, report)?;
+            }
+            ProfileAction::Watch {
+                profile,
+                path,
+                target,
+                no_plugin,
+                only_enabled,
             } => {
-                profile::update(profile, path, target)?;
+                watch::run(profile, path, target, no_plugin, only_enabled, json).await?;
             }
-            ProfileAction::Uninstall { path, target } => {
-                profile::uninstall(path, target, json)?;
+            ProfileAction::Uninstall {
+                path,
+                target,
+                keep_instructions,
+                report,
+            } => {
+                profile::uninstall(path, target, keep_instructions, json, report)?;
             }
             ProfileAction::Sync { path } => {
                 profile::sync(path, json)?;
             }
+            ProfileAction::Diff { path } => {
+                profile::diff(path, json)?;
+            }
+            ProfileAction::Clone {
+                source_id,
+                new_id,
+                name,
+            } => {
+                profile::clone(&source_id, &new_id, &name, json)?;
+            }
+            ProfileAction::Export { profile_id, out } => {
+                profile::export(&profile_id, out, json)?;
+            }
+            ProfileAction::Import { file } => {
+                profile::import(&file, json)?;
+            }
+            ProfileAction::AddSkill {
+                profile_id,
+                skill_id,
+                allow_main,
+            } => {
+                profile::add_skill(&profile_id, &skill_id, allow_main, json)?;
+            }
+            ProfileAction::RemoveSkill {
+                profile_id,
+                skill_id,
+            } => {
+                profile::remove_skill(&profile_id, &skill_id, json)?;
+            }
+            ProfileAction::SetOutputStyle {
+                profile_id,
+                style_id,
+                clear: _,
+            } => {
+                profile::set_output_style(&profile_id, style_id.as_deref(), json)?;
+            }
+            ProfileAction::Tag { action } => match action {
+                ProfileTagAction::Add { profile_id, tag } => {
+                    profile::add_tag(&profile_id, &tag, json)?;
+                }
+                ProfileTagAction::Remove { profile_id, tag } => {
+                    profile::remove_tag(&profile_id, &tag, json)?;
+                }
+            },
         },
         Some(Commands::Skill { action }) => match action {
-            SkillAction::List => {
-                skill::list(json)?;
+            SkillAction::List { with_profiles } => {
+                skill::list(json, with_profiles)?;
             }
             SkillAction::Show { skill_id } => {
                 skill::show(&skill_id, json)?;
@@ -327,17 +1157,171 @@ pub async fn run() -> anyhow::Result<()> {
                 name,
                 category,
                 description,
+                from_dir,
             } => {
-                skill::create(id, name, category, description)?;
+                skill::create(id, name, category, description, from_dir)?;
+            }
+            SkillAction::New => {
+                skill::new_interactive()?;
             }
             SkillAction::SetCategory { skill_id, category } => {
                 skill::set_category(skill_id, category)?;
             }
+            SkillAction::Toggle {
+                skill_id,
+                enable,
+                disable: _,
+                target,
+            } => {
+                skill::set_enabled(&skill_id, &target, enable)?;
+            }
+            SkillAction::Update {
+                skill_id,
+                name,
+                description,
+                content_file,
+            } => {
+                skill::update(&skill_id, name, description, content_file.as_deref(), json)?;
+            }
+            SkillAction::Delete { skill_id } => {
+                skill::delete(&skill_id, ui::Ui::assume_yes(), json)?;
+            }
+            SkillAction::Enable { skill_id, target } => {
+                skill::set_enabled(&skill_id, &target, true)?;
+            }
+            SkillAction::Disable { skill_id, target } => {
+                skill::set_enabled(&skill_id, &target, false)?;
+            }
+            SkillAction::History { skill_id } => {
+                skill::history(&skill_id, json)?;
+            }
+            SkillAction::Validate { skill_id, strict } => {
+                skill::validate(&skill_id, strict, json)?;
+            }
+            SkillAction::Search {
+                query,
+                source,
+                refresh,
+                sort,
+                category,
+            } => {
+                skill::search(
+                    &query,
+                    source.as_deref(),
+                    sort.as_deref(),
+                    category,
+                    refresh,
+                    json,
+                )
+                .await?;
+            }
+            SkillAction::Grep {
+                pattern,
+                ignore_case,
+                files,
+            } => {
+                skill::grep(&pattern, ignore_case, files, json)?;
+            }
+            SkillAction::Outdated => {
+                skill::outdated(json).await?;
+            }
+            SkillAction::Diff { skill_id } => {
+                skill::diff(&skill_id, json).await?;
+            }
+            SkillAction::Rename { old_id, new_id } => {
+                skill::rename(&old_id, &new_id, json)?;
+            }
+            SkillAction::Reset { skill_id } => {
+                skill::reset(&skill_id, ui::Ui::assume_yes(), json).await?;
+            }
+            SkillAction::Install { skill_ids, source } => {
+                skill::install(skill_ids, &source, json).await?;
+            }
+            SkillAction::InstallUrl { url } => {
+                skill::install_url(&url, json).await?;
+            }
+            SkillAction::Import { path } => {
+                skill::import(&path, json)?;
+            }
+            SkillAction::Export { skill_id, out } => {
+                skill::export(&skill_id, out, json)?;
+            }
         },
         Some(Commands::Sync) => {
             // Manual sync - always runs regardless of session marker
             deploy::sync().await?;
         }
+        Some(Commands::Source { action }) => match action {
+            SourceAction::List => {
+                source::list(json)?;
+            }
+            SourceAction::Add {
+                id,
+                name,
+                url,
+                schema,
+                source_type,
+                description,
+                branch,
+                skills_path,
+                token_env,
+                validate,
+            } => {
+                let new = rhinolabs_core::SkillSource {
+                    description: description.unwrap_or_default(),
+                    branch,
+                    skills_path,
+                    auth_token_env: token_env,
+                    ..source::new_source(id, name, url, &schema, &source_type)?
+                };
+                source::add(new, validate, json).await?;
+            }
+            SourceAction::Test { source_id } => {
+                source::test(&source_id, json).await?;
+            }
+            SourceAction::Remove { source_id } => {
+                source::remove(&source_id, json)?;
+            }
+            SourceAction::Enable { source_id } => {
+                source::set_enabled(&source_id, true).await?;
+            }
+            SourceAction::Disable { source_id } => {
+                source::set_enabled(&source_id, false).await?;
+            }
+        },
+        Some(Commands::Mcp { action }) => match action {
+            McpAction::List { reveal } => {
+                mcp::list(json, reveal)?;
+            }
+            McpAction::Import { project, overwrite } => {
+                mcp::import(&project, overwrite, json)?;
+            }
+            McpAction::Enable { name } => {
+                mcp::set_enabled(&name, true, json)?;
+            }
+            McpAction::Disable { name } => {
+                mcp::set_enabled(&name, false, json)?;
+            }
+        },
+        Some(Commands::OutputStyle { action }) => match action {
+            OutputStyleAction::List => {
+                output_style::list(json)?;
+            }
+            OutputStyleAction::Show { style_id, rendered } => {
+                output_style::show(&style_id, rendered, json)?;
+            }
+        },
+        Some(Commands::Completions { shell }) => {
+            completions::run(shell)?;
+        }
+        Some(Commands::Config { action }) => match action {
+            ConfigAction::Export { out } => {
+                config::export(&out, json)?;
+            }
+            ConfigAction::Import { file } => {
+                config::import(&file, json)?;
+            }
+        },
         Some(Commands::Rag { action }) => match action {
             RagAction::Init { project, api_key } => {
                 rag::init(project, api_key)?;
@@ -345,6 +1329,12 @@ pub async fn run() -> anyhow::Result<()> {
             RagAction::Status => {
                 rag::status()?;
             }
+            RagAction::Query { text, limit } => {
+                rag::query(&text, limit, json).await?;
+            }
+            RagAction::Index { path } => {
+                rag::index(&path, json).await?;
+            }
             RagAction::CreateKey { name, projects } => {
                 rag::create_key(name, projects).await?;
             }
@@ -357,6 +1347,14 @@ pub async fn run() -> anyhow::Result<()> {
             RagAction::Remove => {
                 rag::remove()?;
             }
+            RagAction::Settings { action } => match action {
+                None | Some(RagSettingsAction::Show) => {
+                    rag::settings_show()?;
+                }
+                Some(RagSettingsAction::Set { key, value }) => {
+                    rag::settings_set(key, value)?;
+                }
+            },
         },
         None => {
             // Interactive mode
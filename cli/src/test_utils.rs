@@ -0,0 +1,62 @@
+//! Test utilities for managing the test environment
+//!
+//! Commands read their config and plugin locations from the RHINOLABS_CONFIG_PATH
+//! and RHINOLABS_DEV_PATH environment variables, which are process-wide, so tests
+//! that set them must be serialized and must restore them afterwards.
+
+#[cfg(test)]
+use std::sync::Mutex;
+#[cfg(test)]
+use tempfile::TempDir;
+
+/// Global mutex to serialize tests that modify the rhinolabs env vars
+#[cfg(test)]
+pub static ENV_MUTEX: Mutex<()> = Mutex::new(());
+
+/// Points RHINOLABS_CONFIG_PATH and RHINOLABS_DEV_PATH at fresh temp dirs and
+/// restores the original values on drop.
+/// Caller must hold ENV_MUTEX.
+#[cfg(test)]
+pub struct TestEnv {
+    // Held so the dirs live until the env vars are restored
+    _config_dir: TempDir,
+    _plugin_dir: TempDir,
+    original_config: Option<String>,
+    original_dev: Option<String>,
+}
+
+#[cfg(test)]
+impl TestEnv {
+    pub fn new() -> Self {
+        let config_dir = TempDir::new().expect("Failed to create temp config dir");
+        let plugin_dir = TempDir::new().expect("Failed to create temp plugin dir");
+        let original_config = std::env::var("RHINOLABS_CONFIG_PATH").ok();
+        let original_dev = std::env::var("RHINOLABS_DEV_PATH").ok();
+        // RHINOLABS_CONFIG_PATH points to a file; the config dir is its parent
+        std::env::set_var(
+            "RHINOLABS_CONFIG_PATH",
+            config_dir.path().join("profiles.json"),
+        );
+        std::env::set_var("RHINOLABS_DEV_PATH", plugin_dir.path());
+        TestEnv {
+            _config_dir: config_dir,
+            _plugin_dir: plugin_dir,
+            original_config,
+            original_dev,
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for TestEnv {
+    fn drop(&mut self) {
+        match &self.original_config {
+            Some(val) => std::env::set_var("RHINOLABS_CONFIG_PATH", val),
+            None => std::env::remove_var("RHINOLABS_CONFIG_PATH"),
+        }
+        match &self.original_dev {
+            Some(val) => std::env::set_var("RHINOLABS_DEV_PATH", val),
+            None => std::env::remove_var("RHINOLABS_DEV_PATH"),
+        }
+    }
+}
@@ -1,8 +1,55 @@
 use colored::*;
+use rhinolabs_core::Warnings;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Env var that answers yes to every confirmation when set to `1`, `true`, `yes` or `on`
+pub const ASSUME_YES_ENV: &str = "RHINOLABS_ASSUME_YES";
+
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
 
 pub struct Ui;
 
 impl Ui {
+    /// Turn colors off for `--no-color`, `--json`, a non-empty `NO_COLOR`, or when
+    /// stdout isn't a terminal (piped to a file or a CI log). Otherwise the terminal's
+    /// own settings (`CLICOLOR`, ...) apply.
+    pub fn init_color(no_color: bool, json: bool) {
+        let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        if !Self::use_color(
+            no_color,
+            json,
+            no_color_env,
+            std::io::stdout().is_terminal(),
+        ) {
+            colored::control::set_override(false);
+            console::set_colors_enabled(false);
+            console::set_colors_enabled_stderr(false);
+        }
+    }
+
+    fn use_color(no_color: bool, json: bool, no_color_env: bool, is_terminal: bool) -> bool {
+        !(no_color || json || no_color_env) && is_terminal
+    }
+
+    /// Answer yes to every confirmation prompt for the rest of the process (`--yes`)
+    pub fn set_assume_yes(yes: bool) {
+        ASSUME_YES.store(yes, Ordering::Relaxed);
+    }
+
+    /// Whether confirmations are skipped, by `--yes` or `RHINOLABS_ASSUME_YES`
+    pub fn assume_yes() -> bool {
+        ASSUME_YES.load(Ordering::Relaxed)
+            || Self::env_enables(std::env::var(ASSUME_YES_ENV).ok().as_deref())
+    }
+
+    fn env_enables(value: Option<&str>) -> bool {
+        matches!(
+            value.map(|v| v.trim().to_ascii_lowercase()).as_deref(),
+            Some("1" | "true" | "yes" | "on")
+        )
+    }
+
     pub fn header(text: &str) {
         println!();
         println!("{}", "═".repeat(50).bright_blue());
@@ -35,4 +82,40 @@ impl Ui {
         println!();
         println!("{}", title.bold().underline());
     }
+
+    /// Print non-fatal warnings collected by an operation, if any
+    pub fn warnings(warnings: &Warnings) {
+        if warnings.is_empty() {
+            return;
+        }
+
+        println!();
+        for warning in warnings {
+            Self::warning(warning);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_use_color_only_on_a_terminal_without_opt_outs() {
+        assert!(Ui::use_color(false, false, false, true));
+        assert!(!Ui::use_color(true, false, false, true));
+        assert!(!Ui::use_color(false, true, false, true));
+        assert!(!Ui::use_color(false, false, true, true));
+        assert!(!Ui::use_color(false, false, false, false));
+    }
+
+    #[test]
+    fn test_assume_yes_env_values() {
+        for value in ["1", "true", "YES", " on "] {
+            assert!(Ui::env_enables(Some(value)), "{}", value);
+        }
+        for value in [None, Some(""), Some("0"), Some("no")] {
+            assert!(!Ui::env_enables(value), "{:?}", value);
+        }
+    }
 }
@@ -0,0 +1,126 @@
+//! Release notes generated from commit subjects.
+//!
+//! Subjects following the conventional-commit format (`feat: ...`,
+//! `fix(scope): ...`) are grouped into sections; anything else is listed
+//! under "Other changes".
+
+use crate::CommitInfo;
+
+/// Sections in the order they appear, keyed by commit type
+const SECTIONS: &[(&str, &str)] = &[("feat", "Features"), ("fix", "Fixes"), ("chore", "Chores")];
+const OTHER_SECTION: &str = "Other changes";
+
+/// A parsed `type(scope)!: description` subject
+struct Conventional<'a> {
+    kind: &'a str,
+    scope: Option<&'a str>,
+    description: &'a str,
+}
+
+impl<'a> Conventional<'a> {
+    fn parse(subject: &'a str) -> Option<Self> {
+        let (head, description) = subject.split_once(": ")?;
+        let head = head.strip_suffix('!').unwrap_or(head);
+        let (kind, scope) = match head.split_once('(') {
+            Some((kind, rest)) => (kind, Some(rest.strip_suffix(')')?)),
+            None => (head, None),
+        };
+        if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        Some(Self {
+            kind,
+            scope: scope.filter(|s| !s.is_empty()),
+            description: description.trim(),
+        })
+    }
+}
+
+/// Markdown release notes for `commits`, grouped by conventional-commit type.
+///
+/// Subjects of other types, or without a type, go under "Other changes"
+/// unchanged. Returns an empty string when there are no commits.
+pub fn from_commits(commits: &[CommitInfo]) -> String {
+    let mut sections: Vec<(&str, Vec<String>)> = SECTIONS
+        .iter()
+        .map(|(_, title)| (*title, Vec::new()))
+        .chain(std::iter::once((OTHER_SECTION, Vec::new())))
+        .collect();
+
+    for commit in commits {
+        let grouped = Conventional::parse(&commit.subject).and_then(|parsed| {
+            let index = SECTIONS
+                .iter()
+                .position(|(kind, _)| parsed.kind.eq_ignore_ascii_case(kind))?;
+            let entry = match parsed.scope {
+                Some(scope) => format!("**{}:** {}", scope, parsed.description),
+                None => parsed.description.to_string(),
+            };
+            Some((index, entry))
+        });
+        let (index, entry) =
+            grouped.unwrap_or_else(|| (SECTIONS.len(), commit.subject.trim().to_string()));
+        sections[index]
+            .1
+            .push(format!("- {} ({})", entry, short_sha(commit)));
+    }
+
+    let mut notes = String::new();
+    for (title, entries) in sections.iter().filter(|(_, e)| !e.is_empty()) {
+        if notes.is_empty() {
+            notes.push_str("## What's Changed\n");
+        }
+        notes.push_str(&format!("\n### {}\n\n{}\n", title, entries.join("\n")));
+    }
+    notes
+}
+
+fn short_sha(commit: &CommitInfo) -> &str {
+    commit.sha.get(..7).unwrap_or(&commit.sha)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commit(sha: &str, subject: &str) -> CommitInfo {
+        CommitInfo {
+            sha: sha.into(),
+            subject: subject.into(),
+            author: "Dev".into(),
+            date: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_from_commits_groups_by_type() {
+        let commits = vec![
+            commit("aaaaaaa111", "fix(skills): handle missing SKILL.md"),
+            commit("bbbbbbb222", "feat!: drop the legacy config"),
+            commit("ccccccc333", "Merge branch 'main'"),
+            commit("ddddddd444", "chore: bump deps"),
+            commit("eeeeeee555", "feat(cli): add profile diff"),
+            commit("fffffff666", "docs: fix typo"),
+        ];
+
+        assert_eq!(
+            from_commits(&commits),
+            "## What's Changed\n\
+             \n### Features\n\n\
+             - drop the legacy config (bbbbbbb)\n\
+             - **cli:** add profile diff (eeeeeee)\n\
+             \n### Fixes\n\n\
+             - **skills:** handle missing SKILL.md (aaaaaaa)\n\
+             \n### Chores\n\n\
+             - bump deps (ddddddd)\n\
+             \n### Other changes\n\n\
+             - Merge branch 'main' (ccccccc)\n\
+             - docs: fix typo (fffffff)\n"
+        );
+    }
+
+    #[test]
+    fn test_from_commits_without_commits_is_empty() {
+        assert_eq!(from_commits(&[]), "");
+    }
+}
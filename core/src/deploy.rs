@@ -4,7 +4,9 @@
 //! - Exporting current configuration (profiles, skills, settings, etc.)
 //! - Publishing configuration to GitHub releases
 //! - Syncing configuration from GitHub releases
+//! - Snapshotting a machine's configuration to move it to another one
 
+use crate::mcp_config::{is_secret_key, REDACTED_VALUE};
 use crate::{InstructionsManager, Paths, Profiles, Result, RhinolabsError, Settings};
 use serde::{Deserialize, Serialize};
 use std::fs::{self, File};
@@ -48,6 +50,57 @@ pub struct SyncResult {
     pub output_styles_installed: usize,
 }
 
+/// Name of the manifest inside a snapshot archive
+const SNAPSHOT_MANIFEST: &str = "snapshot.json";
+
+/// Plugin directory entries a snapshot carries: the user's content and config,
+/// not the plugin files that `install` puts there
+const SNAPSHOT_PLUGIN_ENTRIES: &[&str] = &[
+    "skills",
+    "output-styles",
+    "CLAUDE.md",
+    "settings.json",
+    ".mcp.json",
+    ".skills-config.json",
+    ".project.json",
+];
+
+/// Manifest of a machine-migration snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotManifest {
+    /// rhinolabs-ai version that wrote the snapshot
+    pub version: String,
+    pub created_at: String,
+    /// Files in the archive, not counting this manifest
+    pub files_count: usize,
+    /// Secret values replaced by a placeholder; they are not in the archive
+    #[serde(default)]
+    pub redacted: Vec<SnapshotSecret>,
+}
+
+/// A secret value left out of a snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotSecret {
+    /// Archive path of the file holding it, e.g. `plugin/.mcp.json`
+    pub file: String,
+    /// JSON pointer to the value in that file
+    pub pointer: String,
+}
+
+/// Outcome of restoring a snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotImport {
+    pub manifest: SnapshotManifest,
+    pub files_restored: usize,
+    /// Redacted secrets this machine already had a value for, which was kept
+    pub secrets_kept: usize,
+    /// Redacted secrets with no value on this machine; they need to be set again
+    pub secrets_missing: Vec<SnapshotSecret>,
+}
+
 pub struct Deploy;
 
 impl Deploy {
@@ -188,6 +241,195 @@ impl Deploy {
         Ok(count)
     }
 
+    /// Bundle this machine's profiles, skills, skills config, settings, instructions,
+    /// output styles and MCP config into a zip archive at `dest`.
+    ///
+    /// Everything in the rhinolabs config directory (profiles, profile instructions,
+    /// custom targets, ...) goes under `config/`; the user content of the plugin
+    /// directory under `plugin/`. Secret values in the top-level JSON files (API keys,
+    /// tokens, auth headers) are replaced by a placeholder and listed in the manifest.
+    pub fn export_snapshot(dest: &Path) -> Result<SnapshotManifest> {
+        let mut files = Vec::new();
+
+        let config_dir = Profiles::config_dir()?;
+        if config_dir.is_dir() {
+            Self::collect_snapshot_files(&config_dir, "config", &mut files)?;
+        }
+
+        let plugin_dir = Paths::plugin_dir()?;
+        for entry in SNAPSHOT_PLUGIN_ENTRIES {
+            let path = plugin_dir.join(entry);
+            let name = format!("plugin/{}", entry);
+            if path.is_dir() {
+                Self::collect_snapshot_files(&path, &name, &mut files)?;
+            } else if path.is_file() {
+                files.push((name, fs::read(&path)?));
+            }
+        }
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut redacted = Vec::new();
+        for (name, content) in files.iter_mut() {
+            if !Self::is_snapshot_config_file(name) || !name.ends_with(".json") {
+                continue;
+            }
+            let Ok(mut value) = serde_json::from_slice::<serde_json::Value>(content) else {
+                continue;
+            };
+            let mut pointers = Vec::new();
+            redact_secrets(&mut value, "", &mut pointers);
+            if !pointers.is_empty() {
+                *content = serde_json::to_vec_pretty(&value)?;
+                redacted.extend(pointers.into_iter().map(|pointer| SnapshotSecret {
+                    file: name.clone(),
+                    pointer,
+                }));
+            }
+        }
+
+        let manifest = SnapshotManifest {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+            files_count: files.len(),
+            redacted,
+        };
+
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut zip = ZipWriter::new(File::create(dest)?);
+        let options = FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        for (name, content) in &files {
+            zip.start_file(name.as_str(), options)?;
+            zip.write_all(content)?;
+        }
+        zip.start_file(SNAPSHOT_MANIFEST, options)?;
+        zip.write_all(serde_json::to_string_pretty(&manifest)?.as_bytes())?;
+        zip.finish()?;
+
+        Ok(manifest)
+    }
+
+    /// Restore a snapshot written by [`Self::export_snapshot`] into this machine's
+    /// config and plugin directories.
+    ///
+    /// Existing top-level config files are backed up before being replaced. A redacted
+    /// secret keeps the value this machine already has for it, if any; the rest are
+    /// returned as missing.
+    pub fn import_snapshot(src: &Path) -> Result<SnapshotImport> {
+        use zip::ZipArchive;
+
+        let mut archive = ZipArchive::new(File::open(src)?)?;
+        let manifest: SnapshotManifest = match archive.by_name(SNAPSHOT_MANIFEST) {
+            Ok(mut file) => {
+                let mut content = Vec::new();
+                file.read_to_end(&mut content)?;
+                serde_json::from_slice(&content).map_err(|e| {
+                    RhinolabsError::ConfigError(format!("Invalid snapshot manifest: {}", e))
+                })?
+            }
+            Err(_) => {
+                return Err(RhinolabsError::ConfigError(format!(
+                    "{} is not a rhinolabs-ai snapshot",
+                    src.display()
+                )))
+            }
+        };
+
+        let config_dir = Profiles::config_dir()?;
+        let plugin_dir = Paths::plugin_dir()?;
+        let mut files_restored = 0;
+        let mut secrets_kept = 0;
+        let mut secrets_missing = Vec::new();
+
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            let name = file.name().to_string();
+            // Entries that would escape the target directories are ignored
+            let Some(relative) = file.enclosed_name().map(Path::to_path_buf) else {
+                continue;
+            };
+            if file.is_dir() || name == SNAPSHOT_MANIFEST {
+                continue;
+            }
+            let target = if let Ok(rest) = relative.strip_prefix("config") {
+                config_dir.join(rest)
+            } else if let Ok(rest) = relative.strip_prefix("plugin") {
+                plugin_dir.join(rest)
+            } else {
+                continue;
+            };
+
+            let mut content = Vec::new();
+            file.read_to_end(&mut content)?;
+
+            let secrets: Vec<&SnapshotSecret> = manifest
+                .redacted
+                .iter()
+                .filter(|s| s.file == name)
+                .collect();
+            if !secrets.is_empty() {
+                let mut value: serde_json::Value = serde_json::from_slice(&content)?;
+                let existing = fs::read(&target)
+                    .ok()
+                    .and_then(|c| serde_json::from_slice::<serde_json::Value>(&c).ok());
+                for secret in secrets {
+                    let current = existing
+                        .as_ref()
+                        .and_then(|e| e.pointer(&secret.pointer))
+                        .and_then(|v| v.as_str())
+                        .filter(|v| *v != REDACTED_VALUE);
+                    match (current, value.pointer_mut(&secret.pointer)) {
+                        (Some(current), Some(slot)) => {
+                            *slot = current.into();
+                            secrets_kept += 1;
+                        }
+                        _ => secrets_missing.push(secret.clone()),
+                    }
+                }
+                content = serde_json::to_vec_pretty(&value)?;
+            }
+
+            crate::paths::atomic_write(&target, &content, Self::is_snapshot_config_file(&name))?;
+            files_restored += 1;
+        }
+
+        Ok(SnapshotImport {
+            manifest,
+            files_restored,
+            secrets_kept,
+            secrets_missing,
+        })
+    }
+
+    /// Add every file under `dir` to `files` as `(archive path, content)`,
+    /// skipping the `.bak` copies config writes leave behind
+    fn collect_snapshot_files(
+        dir: &Path,
+        prefix: &str,
+        files: &mut Vec<(String, Vec<u8>)>,
+    ) -> Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let Some(name) = path.file_name().map(|n| n.to_string_lossy().to_string()) else {
+                continue;
+            };
+            let archive_name = format!("{}/{}", prefix, name);
+            if path.is_dir() {
+                Self::collect_snapshot_files(&path, &archive_name, files)?;
+            } else if path.is_file() && !name.ends_with(".bak") && !name.contains(".backup.") {
+                files.push((archive_name, fs::read(&path)?));
+            }
+        }
+        Ok(())
+    }
+
+    /// Top-level files of the config and plugin directories (`config/profiles.json`,
+    /// `plugin/.mcp.json`, ...), as opposed to skill and output style content
+    fn is_snapshot_config_file(name: &str) -> bool {
+        name.matches('/').count() == 1
+    }
+
     /// Get current plugin version
     fn get_current_version() -> Result<String> {
         let plugin_dir = Paths::plugin_dir()?;
@@ -237,7 +479,7 @@ impl Deploy {
             manifest.output_styles_count
         );
 
-        let client = reqwest::Client::new();
+        let client = crate::network::client()?;
 
         // Create release
         let release_url = format!(
@@ -253,14 +495,15 @@ impl Deploy {
             "draft": false,
         });
 
-        let response = client
-            .post(&release_url)
-            .header("User-Agent", "rhinolabs-cli")
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Accept", "application/vnd.github+json")
-            .json(&release_body_json)
-            .send()
-            .await?;
+        let response = crate::network::send(
+            client
+                .post(&release_url)
+                .header("User-Agent", "rhinolabs-cli")
+                .header("Authorization", format!("Bearer {}", token))
+                .header("Accept", "application/vnd.github+json")
+                .json(&release_body_json),
+        )
+        .await?;
 
         if !response.status().is_success() {
             let error_text = response.text().await.unwrap_or_default();
@@ -286,14 +529,15 @@ impl Deploy {
 
         let zip_content = fs::read(&zip_path)?;
 
-        let upload_response = client
-            .post(&upload_url)
-            .header("User-Agent", "rhinolabs-cli")
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Content-Type", "application/zip")
-            .body(zip_content)
-            .send()
-            .await?;
+        let upload_response = crate::network::send(
+            client
+                .post(&upload_url)
+                .header("User-Agent", "rhinolabs-cli")
+                .header("Authorization", format!("Bearer {}", token))
+                .header("Content-Type", "application/zip")
+                .body(zip_content),
+        )
+        .await?;
 
         if !upload_response.status().is_success() {
             let error_text = upload_response.text().await.unwrap_or_default();
@@ -334,7 +578,7 @@ impl Deploy {
             ));
         }
 
-        let client = reqwest::Client::new();
+        let client = crate::network::client()?;
 
         // 1. Find the latest config release
         let releases_url = format!(
@@ -342,12 +586,13 @@ impl Deploy {
             project_config.github.owner, project_config.github.repo
         );
 
-        let response = client
-            .get(&releases_url)
-            .header("User-Agent", "rhinolabs-cli")
-            .header("Accept", "application/vnd.github+json")
-            .send()
-            .await?;
+        let response = crate::network::send(
+            client
+                .get(&releases_url)
+                .header("User-Agent", "rhinolabs-cli")
+                .header("Accept", "application/vnd.github+json"),
+        )
+        .await?;
 
         if !response.status().is_success() {
             return Err(RhinolabsError::Other(
@@ -398,11 +643,12 @@ impl Deploy {
             .to_string();
 
         // 2. Download the config zip
-        let zip_response = client
-            .get(download_url)
-            .header("User-Agent", "rhinolabs-cli")
-            .send()
-            .await?;
+        let zip_response = crate::network::send(
+            client
+                .get(download_url)
+                .header("User-Agent", "rhinolabs-cli"),
+        )
+        .await?;
 
         if !zip_response.status().is_success() {
             return Err(RhinolabsError::Other(
@@ -458,7 +704,7 @@ impl Deploy {
                 // Install profiles
                 let target = config_dir.join("profiles.json");
                 fs::create_dir_all(&config_dir)?;
-                fs::write(&target, &content)?;
+                crate::fs_utils::write(&target, &content)?;
                 // Count profiles in the file
                 if let Ok(json) = serde_json::from_slice::<serde_json::Value>(&content) {
                     if let Some(profiles) = json["profiles"].as_array() {
@@ -472,19 +718,19 @@ impl Deploy {
                 if let Some(parent) = target.parent() {
                     fs::create_dir_all(parent)?;
                 }
-                fs::write(&target, &content)?;
+                crate::fs_utils::write(&target, &content)?;
                 if name.ends_with("SKILL.md") {
                     skills_installed += 1;
                 }
             } else if name == "CLAUDE.md" {
                 // Install instructions
                 let target = plugin_dir.join("CLAUDE.md");
-                fs::write(&target, &content)?;
+                crate::fs_utils::write(&target, &content)?;
                 instructions_installed = true;
             } else if name == "settings.json" {
                 // Install settings
                 let target = plugin_dir.join("settings.json");
-                fs::write(&target, &content)?;
+                crate::fs_utils::write(&target, &content)?;
                 settings_installed = true;
             } else if name.starts_with("output-styles/") {
                 // Install output style
@@ -493,18 +739,18 @@ impl Deploy {
                 if let Some(parent) = target.parent() {
                     fs::create_dir_all(parent)?;
                 }
-                fs::write(&target, &content)?;
+                crate::fs_utils::write(&target, &content)?;
                 if name.ends_with(".md") {
                     output_styles_installed += 1;
                 }
             } else if name == ".mcp.json" {
                 // Install MCP config
                 let target = plugin_dir.join(".mcp.json");
-                fs::write(&target, &content)?;
+                crate::fs_utils::write(&target, &content)?;
             } else if name == ".skills-config.json" {
                 // Install skills config
                 let target = plugin_dir.join(".skills-config.json");
-                fs::write(&target, &content)?;
+                crate::fs_utils::write(&target, &content)?;
             }
         }
 
@@ -518,6 +764,36 @@ impl Deploy {
     }
 }
 
+/// Replace string values under secret-looking keys with [`REDACTED_VALUE`], collecting
+/// the JSON pointer of each. Keys naming an environment variable (`authTokenEnv`)
+/// hold the variable's name, not its value, and are kept.
+fn redact_secrets(value: &mut serde_json::Value, pointer: &str, redacted: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                let child_pointer =
+                    format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1"));
+                let names_env_var = key.ends_with("Env") || key.ends_with("_env");
+                match child {
+                    serde_json::Value::String(secret)
+                        if is_secret_key(key) && !names_env_var && !secret.is_empty() =>
+                    {
+                        *child = REDACTED_VALUE.into();
+                        redacted.push(child_pointer);
+                    }
+                    _ => redact_secrets(child, &child_pointer, redacted),
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                redact_secrets(item, &format!("{}/{}", pointer, index), redacted);
+            }
+        }
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -538,4 +814,90 @@ mod tests {
         assert!(json.contains("profilesCount"));
         assert!(json.contains("skillsCount"));
     }
+
+    #[test]
+    fn test_snapshot_round_trip_redacts_secrets() {
+        use crate::test_utils::{HomeEnv, TestEnv, ENV_MUTEX};
+        use zip::ZipArchive;
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let _home = HomeEnv::new();
+        let plugin_dir = env.plugin_dir();
+        let config_dir = Profiles::config_dir().unwrap();
+
+        fs::create_dir_all(plugin_dir.join("skills").join("mine")).unwrap();
+        fs::write(plugin_dir.join("skills/mine/SKILL.md"), "# Mine").unwrap();
+        fs::write(plugin_dir.join(".version"), "1.0.0").unwrap();
+        let mcp = serde_json::json!({
+            "mcpServers": {
+                "github": { "command": "npx", "env": { "GITHUB_TOKEN": "ghp_secret" } }
+            }
+        });
+        fs::write(plugin_dir.join(".mcp.json"), mcp.to_string()).unwrap();
+        fs::write(
+            plugin_dir.join(".skills-config.json"),
+            r#"{"sources": [{"id": "internal", "authTokenEnv": "ACME_TOKEN"}]}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(config_dir.join("profiles.json"), r#"{"profiles": []}"#).unwrap();
+
+        let out = tempfile::tempdir().unwrap();
+        let dest = out.path().join("snapshot.zip");
+        let manifest = Deploy::export_snapshot(&dest).unwrap();
+
+        // Only the token value is redacted; the env var *name* in the skills config stays
+        assert_eq!(
+            manifest.redacted,
+            vec![SnapshotSecret {
+                file: "plugin/.mcp.json".to_string(),
+                pointer: "/mcpServers/github/env/GITHUB_TOKEN".to_string(),
+            }]
+        );
+        let mut archive = ZipArchive::new(File::open(&dest).unwrap()).unwrap();
+        let mut archived_mcp = String::new();
+        archive
+            .by_name("plugin/.mcp.json")
+            .unwrap()
+            .read_to_string(&mut archived_mcp)
+            .unwrap();
+        assert!(!archived_mcp.contains("ghp_secret"));
+        assert!(archive.by_name("plugin/.version").is_err());
+        assert!(archive.by_name("config/profiles.json").is_ok());
+
+        // A fresh machine gets everything back, with the token flagged as missing
+        fs::remove_dir_all(plugin_dir.join("skills")).unwrap();
+        fs::remove_file(plugin_dir.join(".mcp.json")).unwrap();
+        fs::remove_file(config_dir.join("profiles.json")).unwrap();
+        let restored = Deploy::import_snapshot(&dest).unwrap();
+        assert_eq!(restored.files_restored, manifest.files_count);
+        assert_eq!(restored.secrets_missing, manifest.redacted);
+        assert!(plugin_dir.join("skills/mine/SKILL.md").exists());
+        assert!(config_dir.join("profiles.json").exists());
+        let skills_config = fs::read_to_string(plugin_dir.join(".skills-config.json")).unwrap();
+        assert!(skills_config.contains("ACME_TOKEN"));
+
+        // A machine that already has the secret keeps it
+        fs::write(plugin_dir.join(".mcp.json"), mcp.to_string()).unwrap();
+        let restored = Deploy::import_snapshot(&dest).unwrap();
+        assert_eq!(restored.secrets_kept, 1);
+        assert!(restored.secrets_missing.is_empty());
+        let mcp_after = fs::read_to_string(plugin_dir.join(".mcp.json")).unwrap();
+        assert!(mcp_after.contains("ghp_secret"));
+    }
+
+    #[test]
+    fn test_import_snapshot_rejects_other_archives() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("other.zip");
+        let mut zip = ZipWriter::new(File::create(&path).unwrap());
+        zip.start_file("readme.txt", FileOptions::default())
+            .unwrap();
+        zip.write_all(b"hello").unwrap();
+        zip.finish().unwrap();
+
+        let err = Deploy::import_snapshot(&path).unwrap_err();
+        assert!(err.to_string().contains("not a rhinolabs-ai snapshot"));
+    }
 }
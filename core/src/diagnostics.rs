@@ -1,5 +1,15 @@
-use crate::{Paths, Result, Version};
+use crate::{
+    Manifest, McpConfigManager, McpServer, OutputStyle, OutputStyles, Paths, Profile, Profiles,
+    Result, Skills, SkillsConfigProblem, Version,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How long to wait for an HTTP/SSE MCP server to answer before calling it unreachable
+const MCP_REACHABILITY_TIMEOUT: Duration = Duration::from_secs(5);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CheckStatus {
@@ -21,13 +31,29 @@ pub struct DiagnosticReport {
     pub passed: usize,
     pub failed: usize,
     pub warnings: usize,
+    /// True when no check failed (warnings don't count as failures)
+    #[serde(default)]
+    pub success: bool,
 }
 
 pub struct Doctor;
 
+/// How the deployed active output style compares to its config
+enum OutputStyleState {
+    NoActiveStyle,
+    InSync(OutputStyle),
+    Missing(OutputStyle, PathBuf),
+    Drifted(OutputStyle, PathBuf),
+}
+
 impl Doctor {
-    /// Run all diagnostic checks
-    pub async fn run() -> Result<DiagnosticReport> {
+    /// Run all diagnostic checks.
+    /// With `offline` (or in global offline mode), the update check and the MCP server
+    /// connectivity checks are skipped.
+    pub async fn run(offline: bool) -> Result<DiagnosticReport> {
+        // Network checks are skipped offline, including in global offline mode
+        let offline = offline || crate::network::is_offline();
+
         let mut checks = Vec::new();
 
         // Check Claude Code installation
@@ -36,6 +62,11 @@ impl Doctor {
         // Check plugin installation
         checks.push(Self::check_plugin());
 
+        // Check the plugin manifest is one Claude Code will accept
+        if Paths::is_plugin_installed() {
+            checks.push(Self::check_plugin_manifest());
+        }
+
         // Check Node.js (for MCP servers)
         checks.push(Self::check_nodejs());
 
@@ -45,8 +76,25 @@ impl Doctor {
         // Check MCP config
         checks.push(Self::check_mcp_config());
 
+        // Check the skills config parses
+        checks.push(Self::check_skills_config());
+
+        // Check skill sources config
+        checks.push(Self::check_skill_sources());
+
+        // Check deployed output style matches config
+        checks.push(Self::check_output_style());
+
+        // Check every skill a profile references is installed
+        checks.push(Self::check_profile_skills());
+
         // Check for updates
-        checks.push(Self::check_updates().await);
+        checks.push(Self::check_updates(offline).await);
+
+        // Check each configured MCP server can actually be started or reached
+        if !offline {
+            checks.extend(Self::check_mcp_servers().await);
+        }
 
         // Calculate summary
         let passed = checks
@@ -67,9 +115,92 @@ impl Doctor {
             passed,
             failed,
             warnings,
+            success: failed == 0,
         })
     }
 
+    /// Apply the automatic repairs for problems `run` can detect.
+    /// Returns a description of each repair that was made.
+    pub fn fix() -> Result<Vec<String>> {
+        let mut repairs = Vec::new();
+
+        // First, since every other skills repair needs a readable config
+        match Skills::config_problem()? {
+            Some(SkillsConfigProblem::Unreadable(_)) => {
+                if let Some(backup) = Skills::repair_config()? {
+                    repairs.push(format!(
+                        "Reset the unreadable skills config (backup at {})",
+                        backup.display()
+                    ));
+                }
+            }
+            Some(SkillsConfigProblem::UnknownCategories(entries)) => {
+                Skills::repair_config()?;
+                repairs.push(format!(
+                    "Dropped {} invalid categoryMap entr{}",
+                    entries.len(),
+                    if entries.len() == 1 { "y" } else { "ies" }
+                ));
+            }
+            None => {}
+        }
+
+        let removed = Skills::repair_sources()?;
+        if removed > 0 {
+            repairs.push(format!(
+                "Removed {} duplicate skill source entr{}",
+                removed,
+                if removed == 1 { "y" } else { "ies" }
+            ));
+        }
+
+        match Self::output_style_state()? {
+            OutputStyleState::Missing(style, path) | OutputStyleState::Drifted(style, path) => {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                crate::fs_utils::write(&path, OutputStyles::generate_style_file(&style)?)?;
+                repairs.push(format!("Redeployed output style '{}'", style.name));
+            }
+            OutputStyleState::NoActiveStyle | OutputStyleState::InSync(_) => {}
+        }
+
+        // With no skills installed yet (e.g. before the plugin's skills are), every
+        // reference looks missing; pruning then would empty every profile
+        let dangling = if Skills::installed_ids()?.is_empty() {
+            Vec::new()
+        } else {
+            Self::dangling_profile_skills()?
+        };
+        for (profile, missing) in dangling {
+            let is_missing = |id: &String| missing.contains(id);
+            if profile.skills.iter().any(is_missing) {
+                let skills = profile.skills.iter().filter(|id| !is_missing(id));
+                Profiles::assign_skills(&profile.id, skills.cloned().collect())?;
+            }
+            if profile
+                .auto_invoke_rules
+                .iter()
+                .any(|r| is_missing(&r.skill_id))
+            {
+                let rules = profile
+                    .auto_invoke_rules
+                    .into_iter()
+                    .filter(|r| !is_missing(&r.skill_id))
+                    .collect();
+                Profiles::update_auto_invoke_rules(&profile.id, rules)?;
+            }
+            repairs.push(format!(
+                "Removed missing skill{} {} from profile '{}'",
+                if missing.len() == 1 { "" } else { "s" },
+                missing.join(", "),
+                profile.name
+            ));
+        }
+
+        Ok(repairs)
+    }
+
     fn check_claude_code() -> DiagnosticCheck {
         if Paths::is_claude_code_installed() {
             DiagnosticCheck {
@@ -109,6 +240,43 @@ impl Doctor {
         }
     }
 
+    fn check_plugin_manifest() -> DiagnosticCheck {
+        let name = "Plugin Manifest".to_string();
+        let path = match Manifest::manifest_path() {
+            Ok(path) => path,
+            Err(e) => {
+                return DiagnosticCheck {
+                    name,
+                    status: CheckStatus::Warning,
+                    message: format!("Could not locate plugin manifest: {}", e),
+                }
+            }
+        };
+        if !path.exists() {
+            return DiagnosticCheck {
+                name,
+                status: CheckStatus::Fail,
+                message: format!(
+                    "Plugin manifest missing at {}. Reinstall the plugin to restore it",
+                    path.display()
+                ),
+            };
+        }
+
+        match Manifest::get().and_then(|manifest| Manifest::validate(&manifest)) {
+            Ok(()) => DiagnosticCheck {
+                name,
+                status: CheckStatus::Pass,
+                message: "Plugin manifest is valid".into(),
+            },
+            Err(e) => DiagnosticCheck {
+                name,
+                status: CheckStatus::Fail,
+                message: format!("{}. Claude Code will not load the plugin", e),
+            },
+        }
+    }
+
     fn check_nodejs() -> DiagnosticCheck {
         if which::which("node").is_ok() {
             DiagnosticCheck {
@@ -156,7 +324,281 @@ impl Doctor {
         }
     }
 
-    async fn check_updates() -> DiagnosticCheck {
+    async fn check_mcp_servers() -> Vec<DiagnosticCheck> {
+        let config = match McpConfigManager::get_resolved() {
+            Ok(config) => config,
+            Err(e) => {
+                return vec![DiagnosticCheck {
+                    name: "MCP Servers".into(),
+                    status: CheckStatus::Warning,
+                    message: format!("Could not read MCP config: {}", e),
+                }]
+            }
+        };
+
+        let client = match crate::network::client_builder().and_then(|builder| {
+            builder
+                .timeout(MCP_REACHABILITY_TIMEOUT)
+                .build()
+                .map_err(Into::into)
+        }) {
+            Ok(client) => client,
+            Err(e) => {
+                return vec![DiagnosticCheck {
+                    name: "MCP Servers".into(),
+                    status: CheckStatus::Warning,
+                    message: format!("Could not create HTTP client: {}", e),
+                }]
+            }
+        };
+
+        // Disabled servers aren't started by Claude Code, so there is nothing to check
+        let mut servers: Vec<_> = config
+            .mcp_servers
+            .iter()
+            .filter(|(_, server)| server.enabled)
+            .collect();
+        servers.sort_by(|a, b| a.0.cmp(b.0));
+
+        futures::future::join_all(
+            servers
+                .into_iter()
+                .map(|(name, server)| Self::check_mcp_server(&client, name, server)),
+        )
+        .await
+    }
+
+    /// Stdio servers pass when their command is on `PATH`; HTTP/SSE servers pass
+    /// when the URL answers at all (any status code means something is listening).
+    async fn check_mcp_server(
+        client: &reqwest::Client,
+        name: &str,
+        server: &McpServer,
+    ) -> DiagnosticCheck {
+        let name = format!("MCP Server '{}'", name);
+
+        if let Some(command) = &server.command {
+            return if which::which(command).is_ok() {
+                DiagnosticCheck {
+                    name,
+                    status: CheckStatus::Pass,
+                    message: format!("OK: command '{}' found", command),
+                }
+            } else {
+                DiagnosticCheck {
+                    name,
+                    status: CheckStatus::Fail,
+                    message: format!("Command missing: '{}' is not on PATH", command),
+                }
+            };
+        }
+
+        let Some(url) = &server.url else {
+            return DiagnosticCheck {
+                name,
+                status: CheckStatus::Warning,
+                message: "Server has neither a command nor a url".into(),
+            };
+        };
+
+        match crate::network::send(client.head(url)).await {
+            Ok(response) => DiagnosticCheck {
+                name,
+                status: CheckStatus::Pass,
+                message: format!("OK: {} responded with {}", url, response.status()),
+            },
+            Err(e) => DiagnosticCheck {
+                name,
+                status: CheckStatus::Warning,
+                message: format!("Unreachable: {} ({})", url, e),
+            },
+        }
+    }
+
+    fn check_skills_config() -> DiagnosticCheck {
+        match Skills::config_problem() {
+            Ok(None) => DiagnosticCheck {
+                name: "Skills Config".into(),
+                status: CheckStatus::Pass,
+                message: "Skills config is valid".into(),
+            },
+            Ok(Some(SkillsConfigProblem::Unreadable(e))) => DiagnosticCheck {
+                name: "Skills Config".into(),
+                status: CheckStatus::Fail,
+                message: format!(
+                    "Skills config is unreadable: {}. Run: rhinolabs doctor --fix to back it up and reset it",
+                    e
+                ),
+            },
+            Ok(Some(SkillsConfigProblem::UnknownCategories(entries))) => DiagnosticCheck {
+                name: "Skills Config".into(),
+                status: CheckStatus::Warning,
+                message: format!(
+                    "Ignoring invalid categories in categoryMap: {}. Run: rhinolabs doctor --fix",
+                    entries
+                        .iter()
+                        .map(|(id, category)| format!("{} ({})", id, category))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
+            Err(e) => DiagnosticCheck {
+                name: "Skills Config".into(),
+                status: CheckStatus::Warning,
+                message: format!("Could not read skills config: {}", e),
+            },
+        }
+    }
+
+    fn check_skill_sources() -> DiagnosticCheck {
+        match Skills::duplicate_source_ids() {
+            Ok(ids) if ids.is_empty() => DiagnosticCheck {
+                name: "Skill Sources".into(),
+                status: CheckStatus::Pass,
+                message: "No duplicate skill sources".into(),
+            },
+            Ok(ids) => DiagnosticCheck {
+                name: "Skill Sources".into(),
+                status: CheckStatus::Warning,
+                message: format!(
+                    "Duplicate skill sources: {}. Run: rhinolabs doctor --fix",
+                    ids.join(", ")
+                ),
+            },
+            Err(e) => DiagnosticCheck {
+                name: "Skill Sources".into(),
+                status: CheckStatus::Warning,
+                message: format!("Could not read skills config: {}", e),
+            },
+        }
+    }
+
+    /// Profiles whose `skills` or auto-invoke rules name skills that are not installed,
+    /// with the missing ids. Installing such a profile silently skips those skills.
+    fn dangling_profile_skills() -> Result<Vec<(Profile, Vec<String>)>> {
+        let installed: HashSet<String> = Skills::installed_ids()?.into_iter().collect();
+
+        let mut dangling = Vec::new();
+        for profile in Profiles::list()? {
+            let referenced = profile
+                .skills
+                .iter()
+                .chain(profile.auto_invoke_rules.iter().map(|r| &r.skill_id));
+            let mut missing: Vec<String> = Vec::new();
+            for id in referenced {
+                if !installed.contains(id) && !missing.contains(id) {
+                    missing.push(id.clone());
+                }
+            }
+            if !missing.is_empty() {
+                dangling.push((profile, missing));
+            }
+        }
+        Ok(dangling)
+    }
+
+    fn check_profile_skills() -> DiagnosticCheck {
+        match Self::dangling_profile_skills() {
+            Ok(dangling) if dangling.is_empty() => DiagnosticCheck {
+                name: "Profile Skills".into(),
+                status: CheckStatus::Pass,
+                message: "All skills referenced by profiles are installed".into(),
+            },
+            Ok(dangling) => DiagnosticCheck {
+                name: "Profile Skills".into(),
+                status: CheckStatus::Warning,
+                message: format!(
+                    "Profiles reference skills that are not installed: {}. Run: rhinolabs doctor --fix",
+                    dangling
+                        .iter()
+                        .map(|(profile, missing)| format!(
+                            "{} ({})",
+                            profile.name,
+                            missing.join(", ")
+                        ))
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ),
+            },
+            Err(e) => DiagnosticCheck {
+                name: "Profile Skills".into(),
+                status: CheckStatus::Warning,
+                message: format!("Could not check profile skills: {}", e),
+            },
+        }
+    }
+
+    fn output_style_state() -> Result<OutputStyleState> {
+        // The Main-Profile may install its own style instead of the active one
+        let style = match Profiles::get("main")? {
+            Some(main) => Profiles::output_style_for(&Profiles::resolve(&main.id)?)?,
+            None => OutputStyles::get_active()?,
+        };
+        let Some(style) = style else {
+            return Ok(OutputStyleState::NoActiveStyle);
+        };
+
+        let path = Profiles::user_output_style_path(&style)?;
+        if !path.exists() {
+            return Ok(OutputStyleState::Missing(style, path));
+        }
+
+        let deployed = fs::read_to_string(&path)?;
+        if deployed == OutputStyles::generate_style_file(&style)? {
+            Ok(OutputStyleState::InSync(style))
+        } else {
+            Ok(OutputStyleState::Drifted(style, path))
+        }
+    }
+
+    fn check_output_style() -> DiagnosticCheck {
+        let name = "Output Style".to_string();
+        match Self::output_style_state() {
+            Ok(OutputStyleState::NoActiveStyle) => DiagnosticCheck {
+                name,
+                status: CheckStatus::Pass,
+                message: "No active output style configured".into(),
+            },
+            Ok(OutputStyleState::InSync(style)) => DiagnosticCheck {
+                name,
+                status: CheckStatus::Pass,
+                message: format!("Output style '{}' matches config", style.name),
+            },
+            Ok(OutputStyleState::Missing(style, path)) => DiagnosticCheck {
+                name,
+                status: CheckStatus::Warning,
+                message: format!(
+                    "Output style '{}' is not deployed at {}. Run: rhinolabs doctor --fix",
+                    style.name,
+                    path.display()
+                ),
+            },
+            Ok(OutputStyleState::Drifted(style, path)) => DiagnosticCheck {
+                name,
+                status: CheckStatus::Warning,
+                message: format!(
+                    "Output style '{}' at {} differs from config. Run: rhinolabs doctor --fix",
+                    style.name,
+                    path.display()
+                ),
+            },
+            Err(e) => DiagnosticCheck {
+                name,
+                status: CheckStatus::Warning,
+                message: format!("Could not check output style: {}", e),
+            },
+        }
+    }
+
+    async fn check_updates(offline: bool) -> DiagnosticCheck {
+        if offline {
+            return DiagnosticCheck {
+                name: "Updates".into(),
+                status: CheckStatus::Warning,
+                message: "Skipped in offline mode".into(),
+            };
+        }
+
         match Version::check_update().await {
             Ok(Some(version)) => DiagnosticCheck {
                 name: "Updates".into(),
@@ -208,7 +650,7 @@ mod tests {
 
     #[test]
     fn test_diagnostic_report_summary_calculation() {
-        let checks = vec![
+        let checks = [
             DiagnosticCheck {
                 name: "Check 1".into(),
                 status: CheckStatus::Pass,
@@ -266,6 +708,7 @@ mod tests {
             passed: 1,
             failed: 0,
             warnings: 0,
+            success: true,
         };
 
         assert_eq!(report.checks.len(), 1);
@@ -342,4 +785,265 @@ mod tests {
         ));
         assert!(!check.message.is_empty());
     }
+
+    #[test]
+    fn test_check_skill_sources_reports_duplicates_and_fix_repairs() {
+        use crate::test_utils::{HomeEnv, TestEnv, ENV_MUTEX};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let _home = HomeEnv::new();
+
+        let source = serde_json::json!({
+            "id": "anthropic-official",
+            "name": "Anthropic Official",
+            "sourceType": "official",
+            "url": "https://github.com/anthropics/skills",
+            "description": "Official skills",
+            "enabled": true
+        });
+        let config = serde_json::json!({
+            "disabled": [],
+            "custom": [],
+            "sources": [source, source]
+        });
+        std::fs::write(
+            env.plugin_dir().join(".skills-config.json"),
+            config.to_string(),
+        )
+        .unwrap();
+
+        let check = Doctor::check_skill_sources();
+        assert!(matches!(check.status, CheckStatus::Warning));
+        assert!(check.message.contains("anthropic-official"));
+
+        let repairs = Doctor::fix().unwrap();
+        assert_eq!(repairs.len(), 1);
+
+        let check = Doctor::check_skill_sources();
+        assert!(matches!(check.status, CheckStatus::Pass));
+    }
+
+    #[test]
+    fn test_check_skills_config_reports_corruption_and_fix_resets() {
+        use crate::test_utils::{HomeEnv, TestEnv, ENV_MUTEX};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let _home = HomeEnv::new();
+        let config_path = env.plugin_dir().join(".skills-config.json");
+        std::fs::write(&config_path, "{ \"disabled\": [] \"custom\": [] }").unwrap();
+
+        let check = Doctor::check_skills_config();
+        assert!(matches!(check.status, CheckStatus::Fail));
+        assert!(check.message.contains("line 1"), "{}", check.message);
+
+        let repairs = Doctor::fix().unwrap();
+        assert!(repairs[0].contains("backup at"), "{:?}", repairs);
+        assert!(matches!(
+            Doctor::check_skills_config().status,
+            CheckStatus::Pass
+        ));
+    }
+
+    #[test]
+    fn test_check_plugin_manifest_reports_invalid_fields() {
+        use crate::test_utils::{TestEnv, ENV_MUTEX};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+        let path = Manifest::manifest_path().unwrap();
+        assert!(!path.exists());
+        let check = Doctor::check_plugin_manifest();
+        assert!(matches!(check.status, CheckStatus::Fail));
+        assert!(check.message.contains("missing"), "{}", check.message);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        std::fs::write(&path, r#"{ "name": "rhinolabs-claude", "version": "1.0" }"#).unwrap();
+        let check = Doctor::check_plugin_manifest();
+        assert!(matches!(check.status, CheckStatus::Fail));
+        assert!(check.message.contains("'version'"), "{}", check.message);
+
+        std::fs::write(
+            &path,
+            serde_json::to_string(&crate::PluginManifest::default()).unwrap(),
+        )
+        .unwrap();
+        assert!(matches!(
+            Doctor::check_plugin_manifest().status,
+            CheckStatus::Pass
+        ));
+    }
+
+    #[test]
+    fn test_check_output_style_reports_drift_and_fix_redeploys() {
+        use crate::test_utils::{HomeEnv, TestEnv, ENV_MUTEX};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+        let home = HomeEnv::new();
+
+        let style = OutputStyles::create("Terse", "Short answers", true, "Be brief.").unwrap();
+        OutputStyles::set_active(&style.id).unwrap();
+
+        let check = Doctor::check_output_style();
+        assert!(matches!(check.status, CheckStatus::Warning));
+        assert!(check.message.contains("not deployed"));
+
+        let deployed = home.dir.path().join(".claude/output-styles/terse.md");
+        fs::create_dir_all(deployed.parent().unwrap()).unwrap();
+        fs::write(&deployed, "---\nname: Terse\n---\n\nHand-edited.").unwrap();
+
+        let check = Doctor::check_output_style();
+        assert!(matches!(check.status, CheckStatus::Warning));
+        assert!(check.message.contains("differs from config"));
+
+        let repairs = Doctor::fix().unwrap();
+        assert!(repairs.iter().any(|r| r.contains("Terse")));
+        assert_eq!(
+            fs::read_to_string(&deployed).unwrap(),
+            OutputStyles::generate_style_file(&style).unwrap()
+        );
+
+        let check = Doctor::check_output_style();
+        assert!(matches!(check.status, CheckStatus::Pass));
+    }
+
+    #[test]
+    fn test_check_profile_skills_reports_missing_and_fix_prunes() {
+        use crate::test_utils::{HomeEnv, TestEnv, ENV_MUTEX};
+        use crate::{AutoInvokeRule, CreateProfileInput, ProfileType};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let _home = HomeEnv::new();
+
+        let skill_dir = env.plugin_dir().join("skills").join("present");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: Present\ndescription: Installed\n---\n\n# Present",
+        )
+        .unwrap();
+
+        Profiles::create(CreateProfileInput {
+            id: "web".into(),
+            name: "Web".into(),
+            description: "Web projects".into(),
+            profile_type: ProfileType::Project,
+            skills: vec!["present".into(), "deleted".into()],
+            instructions: None,
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+        })
+        .unwrap();
+        let rule = |skill_id: &str| AutoInvokeRule {
+            skill_id: skill_id.into(),
+            trigger: "Always".into(),
+            description: "Rule".into(),
+        };
+        Profiles::update_auto_invoke_rules("web", vec![rule("present"), rule("gone")]).unwrap();
+
+        let check = Doctor::check_profile_skills();
+        assert!(matches!(check.status, CheckStatus::Warning));
+        assert!(
+            check.message.contains("Web (deleted, gone)"),
+            "{}",
+            check.message
+        );
+
+        let repairs = Doctor::fix().unwrap();
+        assert!(repairs.iter().any(|r| r.contains("'Web'")));
+
+        let profile = Profiles::get("web").unwrap().unwrap();
+        assert_eq!(profile.skills, vec!["present".to_string()]);
+        assert_eq!(profile.auto_invoke_rules.len(), 1);
+        assert!(matches!(
+            Doctor::check_profile_skills().status,
+            CheckStatus::Pass
+        ));
+    }
+
+    #[test]
+    fn test_fix_keeps_profile_skills_when_no_skills_are_installed() {
+        use crate::test_utils::{HomeEnv, TestEnv, ENV_MUTEX};
+        use crate::{CreateProfileInput, ProfileType};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let _home = HomeEnv::new();
+        fs::create_dir_all(env.plugin_dir().join("skills")).unwrap();
+
+        Profiles::create(CreateProfileInput {
+            id: "web".into(),
+            name: "Web".into(),
+            description: "Web projects".into(),
+            profile_type: ProfileType::Project,
+            skills: vec!["react-patterns".into()],
+            instructions: None,
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+        })
+        .unwrap();
+
+        let repairs = Doctor::fix().unwrap();
+        assert!(
+            !repairs.iter().any(|r| r.contains("'Web'")),
+            "{:?}",
+            repairs
+        );
+        assert_eq!(
+            Profiles::get("web").unwrap().unwrap().skills,
+            vec!["react-patterns".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_mcp_server_stdio_command() {
+        let client = reqwest::Client::new();
+
+        let missing = McpServer::stdio("rhinolabs-no-such-binary".into(), Vec::new());
+        let check = Doctor::check_mcp_server(&client, "ghost", &missing).await;
+        assert_eq!(check.name, "MCP Server 'ghost'");
+        assert!(matches!(check.status, CheckStatus::Fail));
+        assert!(check.message.contains("Command missing"));
+
+        let exe = std::env::current_exe().unwrap();
+        let present = McpServer::stdio(exe.to_string_lossy().into_owned(), Vec::new());
+        let check = Doctor::check_mcp_server(&client, "local", &present).await;
+        assert!(matches!(check.status, CheckStatus::Pass));
+    }
+
+    #[tokio::test]
+    async fn test_check_mcp_server_http_reachability() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let client = reqwest::Client::new();
+
+        let server = MockServer::start().await;
+        Mock::given(path("/mcp"))
+            .respond_with(ResponseTemplate::new(405))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let url = format!("{}/mcp", server.uri());
+        let check = Doctor::check_mcp_server(&client, "up", &McpServer::http(url)).await;
+        assert!(matches!(check.status, CheckStatus::Pass));
+        assert!(check.message.contains("405"));
+
+        // Bind then drop to get a port nothing is listening on
+        let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/mcp", closed.local_addr().unwrap());
+        drop(closed);
+        let check = Doctor::check_mcp_server(&client, "down", &McpServer::http(url)).await;
+        assert!(matches!(check.status, CheckStatus::Warning));
+        assert!(check.message.starts_with("Unreachable"));
+    }
 }
@@ -1,8 +1,15 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::Result;
 
+/// `fs::write`, logged at info level so `-v` shows every file the CLI writes
+pub(crate) fn write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> std::io::Result<()> {
+    let path = path.as_ref();
+    tracing::info!("writing {}", path.display());
+    fs::write(path, contents)
+}
+
 /// Copy a directory recursively, skipping `.git/` directories.
 pub fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     fs::create_dir_all(dst)?;
@@ -26,6 +33,86 @@ pub fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Copy a directory recursively, skipping entries for which `exclude` returns true.
+///
+/// `exclude` receives each entry's path relative to `src` and whether it is a
+/// directory; an excluded directory is skipped with everything under it.
+pub(crate) fn copy_dir_filtered(
+    src: &Path,
+    dst: &Path,
+    exclude: &dyn Fn(&Path, bool) -> bool,
+) -> Result<()> {
+    copy_dir_filtered_at(src, dst, Path::new(""), exclude)
+}
+
+fn copy_dir_filtered_at(
+    src: &Path,
+    dst: &Path,
+    relative: &Path,
+    exclude: &dyn Fn(&Path, bool) -> bool,
+) -> Result<()> {
+    fs::create_dir_all(dst)?;
+
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let is_dir = entry.file_type()?.is_dir();
+        let entry_relative = relative.join(entry.file_name());
+
+        if exclude(&entry_relative, is_dir) {
+            continue;
+        }
+
+        let dst_path = dst.join(entry.file_name());
+        if is_dir {
+            copy_dir_filtered_at(&entry.path(), &dst_path, &entry_relative, exclude)?;
+        } else {
+            fs::copy(entry.path(), &dst_path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// SHA-256 over a directory's relative file paths and contents, skipping `.git/`.
+///
+/// Entries are visited in sorted order, so the hash only changes when content does.
+pub fn hash_dir(dir: &Path) -> Result<String> {
+    use sha2::{Digest, Sha256};
+
+    fn visit(dir: &Path, relative: &Path, hasher: &mut Sha256) -> Result<()> {
+        let mut entries = fs::read_dir(dir)?.collect::<std::io::Result<Vec<_>>>()?;
+        entries.sort_by_key(|e| e.file_name());
+
+        for entry in entries {
+            let name = entry.file_name();
+            let entry_relative = relative.join(&name);
+            let path = entry.path();
+            if path.is_dir() {
+                if name == ".git" {
+                    continue;
+                }
+                visit(&path, &entry_relative, hasher)?;
+            } else {
+                hasher.update(entry_relative.to_string_lossy().as_bytes());
+                hasher.update([0]);
+                hasher.update(fs::read(&path)?);
+                hasher.update([0]);
+            }
+        }
+        Ok(())
+    }
+
+    let mut hasher = Sha256::new();
+    visit(dir, Path::new(""), &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// SHA-256 of `content`, hex encoded
+pub fn hash_bytes(content: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    format!("{:x}", Sha256::digest(content))
+}
+
 /// Deploy a skill by creating a symlink (Unix) or junction (Windows) from `dest` → `source`.
 ///
 /// If the symlink already points to the correct source, this is a no-op.
@@ -72,6 +159,11 @@ pub fn deploy_skill_link(source: &Path, dest: &Path) -> Result<()> {
     }
 
     // Try creating symlink/junction
+    tracing::info!(
+        "linking {} -> {}",
+        dest.display(),
+        canonical_source.display()
+    );
     match create_dir_symlink(&canonical_source, dest) {
         Ok(()) => Ok(()),
         Err(_) => {
@@ -107,6 +199,266 @@ pub fn remove_skill_dir(path: &Path) -> Result<()> {
     }
 }
 
+/// A directory rebuilt in a temporary sibling and swapped into place on `commit`.
+///
+/// The staged copy starts as a copy of the current directory (symlinks preserved), so callers
+/// only write what changes. Dropping a `StagedDir` without committing discards the staged copy
+/// and leaves the original directory untouched. Staging dirs get unique names
+/// (`<name>.XXXXXX.tmp`), so several `StagedDir`s can be open on the same directory at once.
+pub struct StagedDir {
+    target: PathBuf,
+    staging: PathBuf,
+    kept: Vec<PathBuf>,
+    committed: bool,
+}
+
+impl StagedDir {
+    /// Start staging `target`
+    pub fn begin(target: &Path) -> Result<Self> {
+        Self::begin_keeping(target, &[])
+    }
+
+    /// Like `begin`, but the entries at `kept` (relative to `target`) aren't copied into the
+    /// staged dir; `commit` moves them over from the original instead. For large entries the
+    /// caller won't touch, such as skills that didn't change.
+    pub fn begin_keeping(target: &Path, kept: &[PathBuf]) -> Result<Self> {
+        let staging = unique_sibling(target, "tmp")?;
+
+        if fs::symlink_metadata(target).is_ok() {
+            let skip: Vec<PathBuf> = kept.iter().map(|k| target.join(k)).collect();
+            if let Err(e) = copy_dir_preserving_links(target, &staging, &skip) {
+                let _ = remove_skill_dir(&staging);
+                return Err(e);
+            }
+        } else {
+            fs::create_dir_all(&staging)?;
+        }
+
+        Ok(Self {
+            target: target.to_path_buf(),
+            staging,
+            kept: kept.to_vec(),
+            committed: false,
+        })
+    }
+
+    /// Directory to write into while staging
+    pub fn path(&self) -> &Path {
+        &self.staging
+    }
+
+    /// Swap the staged directory into place.
+    ///
+    /// Kept entries are moved into the staged dir, then the original is moved aside and
+    /// restored (kept entries included) if the final rename fails.
+    pub fn commit(mut self) -> Result<()> {
+        let backup_root = unique_sibling(&self.target, "bak")?;
+        let backup = backup_root.join(self.target.file_name().unwrap_or_default());
+
+        let moved = match self.move_kept() {
+            Ok(moved) => moved,
+            Err((moved, e)) => {
+                self.restore_kept(&moved);
+                return Err(e);
+            }
+        };
+
+        let had_original = fs::symlink_metadata(&self.target).is_ok();
+        if had_original {
+            if let Err(e) = fs::rename(&self.target, &backup) {
+                self.restore_kept(&moved);
+                let _ = fs::remove_dir(&backup_root);
+                return Err(e.into());
+            }
+        } else if let Some(parent) = self.target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        if let Err(e) = fs::rename(&self.staging, &self.target) {
+            if had_original {
+                let _ = fs::rename(&backup, &self.target);
+            }
+            self.restore_kept(&moved);
+            let _ = fs::remove_dir(&backup_root);
+            return Err(e.into());
+        }
+
+        self.committed = true;
+        tracing::info!("replaced {}", self.target.display());
+        let _ = remove_skill_dir(&backup_root);
+        Ok(())
+    }
+
+    /// Move the kept entries that exist in the original into the staged dir.
+    /// On failure, returns the ones already moved along with the error.
+    fn move_kept(
+        &self,
+    ) -> std::result::Result<Vec<PathBuf>, (Vec<PathBuf>, crate::RhinolabsError)> {
+        let mut moved = Vec::new();
+        for kept in &self.kept {
+            let src = self.target.join(kept);
+            if fs::symlink_metadata(&src).is_err() {
+                continue;
+            }
+            let dst = self.staging.join(kept);
+            let outcome = match dst.parent() {
+                Some(parent) => fs::create_dir_all(parent).and_then(|_| fs::rename(&src, &dst)),
+                None => fs::rename(&src, &dst),
+            };
+            if let Err(e) = outcome {
+                return Err((moved, e.into()));
+            }
+            moved.push(kept.clone());
+        }
+        Ok(moved)
+    }
+
+    /// Put kept entries moved into the staged dir back into the original
+    fn restore_kept(&self, moved: &[PathBuf]) {
+        for kept in moved {
+            let _ = fs::rename(self.staging.join(kept), self.target.join(kept));
+        }
+    }
+}
+
+impl Drop for StagedDir {
+    fn drop(&mut self) {
+        if !self.committed {
+            let _ = remove_skill_dir(&self.staging);
+        }
+    }
+}
+
+/// Create an empty directory next to `path` with a unique name:
+/// `/a/.claude` + `tmp` → `/a/.claude.Ab12Cd.tmp`
+fn unique_sibling(path: &Path, suffix: &str) -> Result<PathBuf> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    fs::create_dir_all(parent)?;
+    let mut prefix = path.file_name().unwrap_or_default().to_os_string();
+    prefix.push(".");
+    let dir = tempfile::Builder::new()
+        .prefix(&prefix)
+        .suffix(&format!(".{}", suffix))
+        .tempdir_in(parent)?;
+    Ok(dir.keep())
+}
+
+/// Files written to temporary siblings and only renamed into place on `commit`.
+///
+/// Dropping `StagedFiles` without committing deletes the temporary files, and any
+/// parent directories `write` had to create, leaving the originals untouched.
+#[derive(Default)]
+pub struct StagedFiles {
+    files: Vec<(tempfile::TempPath, PathBuf)>,
+    created_dirs: Vec<PathBuf>,
+}
+
+impl StagedFiles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stage `contents` for `path`, replacing anything staged for it before
+    pub fn write(&mut self, path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let missing: Vec<PathBuf> = dir
+            .ancestors()
+            .take_while(|d| !d.as_os_str().is_empty() && !d.exists())
+            .map(Path::to_path_buf)
+            .collect();
+        fs::create_dir_all(dir)?;
+        self.created_dirs.extend(missing);
+
+        let mut temp = tempfile::NamedTempFile::new_in(dir)?;
+        std::io::Write::write_all(&mut temp, contents.as_ref())?;
+        if let Ok(meta) = fs::metadata(path) {
+            fs::set_permissions(temp.path(), meta.permissions())?;
+        }
+        self.files.retain(|(_, staged)| staged != path);
+        self.files.push((temp.into_temp_path(), path.to_path_buf()));
+        Ok(())
+    }
+
+    /// Rename every staged file into place
+    pub fn commit(mut self) -> Result<()> {
+        for (temp, path) in self.files.drain(..) {
+            temp.persist(&path).map_err(|e| e.error)?;
+            tracing::info!("wrote {}", path.display());
+        }
+        self.created_dirs.clear();
+        Ok(())
+    }
+}
+
+impl Drop for StagedFiles {
+    fn drop(&mut self) {
+        // Temp files delete themselves; directories made only for them go too
+        self.files.clear();
+        for dir in &self.created_dirs {
+            let _ = fs::remove_dir(dir);
+        }
+    }
+}
+
+/// `/a/.claude` + `tmp` → `/a/.claude.tmp`
+pub(crate) fn sibling_with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Copy a directory recursively, recreating symlinks instead of following them.
+/// Paths in `skip` are left out.
+fn copy_dir_preserving_links(src: &Path, dst: &Path, skip: &[PathBuf]) -> Result<()> {
+    fs::create_dir_all(dst)?;
+
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+
+        if skip.contains(&src_path) {
+            continue;
+        } else if file_type.is_symlink() {
+            let link_target = fs::read_link(&src_path)?;
+            if src_path.is_dir() {
+                create_dir_symlink(&link_target, &dst_path)?;
+            } else {
+                create_file_symlink(&link_target, &dst_path)?;
+            }
+        } else if file_type.is_dir() {
+            copy_dir_preserving_links(&src_path, &dst_path, skip)?;
+        } else {
+            fs::copy(&src_path, &dst_path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Create a file symlink. Falls back to copying on Windows, where file symlinks need privileges.
+#[cfg(unix)]
+fn create_file_symlink(src: &Path, dst: &Path) -> Result<()> {
+    std::os::unix::fs::symlink(src, dst)?;
+    Ok(())
+}
+
+#[cfg(windows)]
+fn create_file_symlink(src: &Path, dst: &Path) -> Result<()> {
+    if std::os::windows::fs::symlink_file(src, dst).is_err() {
+        fs::copy(src, dst)?;
+    }
+    Ok(())
+}
+
 /// Create a directory symlink (Unix) or junction (Windows).
 #[cfg(unix)]
 fn create_dir_symlink(src: &Path, dst: &Path) -> Result<()> {
@@ -168,6 +520,23 @@ mod tests {
         assert!(!dest.join(".git").exists());
     }
 
+    #[test]
+    fn test_hash_dir_tracks_content_not_git() {
+        let temp = TempDir::new().unwrap();
+        let source = temp.path().join("source");
+        fs::create_dir_all(source.join("sub")).unwrap();
+        fs::write(source.join("SKILL.md"), "# Skill").unwrap();
+        fs::write(source.join("sub").join("file.txt"), "content").unwrap();
+        let original = hash_dir(&source).unwrap();
+
+        fs::create_dir_all(source.join(".git")).unwrap();
+        fs::write(source.join(".git").join("HEAD"), "ref").unwrap();
+        assert_eq!(hash_dir(&source).unwrap(), original);
+
+        fs::write(source.join("sub").join("file.txt"), "changed").unwrap();
+        assert_ne!(hash_dir(&source).unwrap(), original);
+    }
+
     #[test]
     fn test_copy_dir_recursive_deeply_nested() {
         let temp = TempDir::new().unwrap();
@@ -464,4 +833,150 @@ mod tests {
             "console.log('hi')"
         );
     }
+
+    #[test]
+    fn test_staged_dir_commit_swaps_into_place() {
+        let temp = TempDir::new().unwrap();
+        let target = temp.path().join(".claude");
+        fs::create_dir_all(&target).unwrap();
+        fs::write(target.join("keep.json"), "{}").unwrap();
+
+        let staged = StagedDir::begin(&target).unwrap();
+        assert_eq!(staged.path().parent(), Some(temp.path()));
+        // Existing content is carried over into the staged copy
+        assert!(staged.path().join("keep.json").exists());
+        fs::write(staged.path().join("new.md"), "new").unwrap();
+        // Original is untouched until commit
+        assert!(!target.join("new.md").exists());
+
+        staged.commit().unwrap();
+
+        assert!(target.join("keep.json").exists());
+        assert!(target.join("new.md").exists());
+        // Neither the staging dir nor the backup is left behind
+        assert_eq!(fs::read_dir(temp.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_staged_dir_drop_without_commit_leaves_original() {
+        let temp = TempDir::new().unwrap();
+        let target = temp.path().join("skills");
+        fs::create_dir_all(&target).unwrap();
+        fs::write(target.join("original.md"), "original").unwrap();
+
+        {
+            let staged = StagedDir::begin(&target).unwrap();
+            fs::remove_file(staged.path().join("original.md")).unwrap();
+            fs::write(staged.path().join("partial.md"), "partial").unwrap();
+        }
+
+        assert!(target.join("original.md").exists());
+        assert!(!target.join("partial.md").exists());
+        assert_eq!(fs::read_dir(temp.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_staged_dirs_on_the_same_target_do_not_collide() {
+        let temp = TempDir::new().unwrap();
+        let target = temp.path().join(".shared");
+        fs::create_dir_all(&target).unwrap();
+
+        let first = StagedDir::begin(&target).unwrap();
+        fs::write(first.path().join("first.md"), "1").unwrap();
+        let second = StagedDir::begin(&target).unwrap();
+        assert_ne!(first.path(), second.path());
+        assert!(first.path().join("first.md").exists());
+
+        drop(second);
+        first.commit().unwrap();
+        assert!(target.join("first.md").exists());
+    }
+
+    #[test]
+    fn test_staged_files_are_written_only_on_commit() {
+        let temp = TempDir::new().unwrap();
+        let existing = temp.path().join("CLAUDE.md");
+        fs::write(&existing, "old").unwrap();
+        let nested = temp.path().join(".github").join("copilot-instructions.md");
+
+        {
+            let mut staged = StagedFiles::new();
+            staged.write(&existing, "new").unwrap();
+            staged.write(&nested, "copilot").unwrap();
+        }
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "old");
+        // Directories created only for staged files are cleaned up too
+        assert!(!temp.path().join(".github").exists());
+        assert_eq!(fs::read_dir(temp.path()).unwrap().count(), 1);
+
+        let mut staged = StagedFiles::new();
+        staged.write(&existing, "new").unwrap();
+        staged.write(&nested, "copilot").unwrap();
+        staged.commit().unwrap();
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "new");
+        assert_eq!(fs::read_to_string(&nested).unwrap(), "copilot");
+    }
+
+    #[test]
+    fn test_staged_dir_moves_kept_entries_instead_of_copying() {
+        let temp = TempDir::new().unwrap();
+        let target = temp.path().join(".claude");
+        fs::create_dir_all(target.join("skills/kept")).unwrap();
+        fs::create_dir_all(target.join("skills/changed")).unwrap();
+        fs::write(target.join("skills/kept/SKILL.md"), "kept").unwrap();
+        fs::write(target.join("skills/changed/SKILL.md"), "old").unwrap();
+
+        let kept = [PathBuf::from("skills/kept")];
+        let staged = StagedDir::begin_keeping(&target, &kept).unwrap();
+        assert!(!staged.path().join("skills/kept").exists());
+        assert!(staged.path().join("skills/changed/SKILL.md").exists());
+        fs::write(staged.path().join("skills/changed/SKILL.md"), "new").unwrap();
+        staged.commit().unwrap();
+
+        assert_eq!(
+            fs::read_to_string(target.join("skills/kept/SKILL.md")).unwrap(),
+            "kept"
+        );
+        assert_eq!(
+            fs::read_to_string(target.join("skills/changed/SKILL.md")).unwrap(),
+            "new"
+        );
+
+        // Without a commit, kept entries never leave the original
+        let staged = StagedDir::begin_keeping(&target, &kept).unwrap();
+        drop(staged);
+        assert!(target.join("skills/kept/SKILL.md").exists());
+    }
+
+    #[test]
+    fn test_staged_dir_creates_missing_target_on_commit() {
+        let temp = TempDir::new().unwrap();
+        let target = temp.path().join("project").join(".claude");
+
+        let staged = StagedDir::begin(&target).unwrap();
+        fs::write(staged.path().join("file.md"), "x").unwrap();
+        staged.commit().unwrap();
+
+        assert!(target.join("file.md").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_staged_dir_preserves_symlinks() {
+        let temp = TempDir::new().unwrap();
+        let source = temp.path().join("skill-source");
+        fs::create_dir_all(&source).unwrap();
+        fs::write(source.join("SKILL.md"), "# Skill").unwrap();
+
+        let target = temp.path().join("skills");
+        deploy_skill_link(&source, &target.join("my-skill")).unwrap();
+
+        let staged = StagedDir::begin(&target).unwrap();
+        let meta = fs::symlink_metadata(staged.path().join("my-skill")).unwrap();
+        assert!(meta.file_type().is_symlink());
+        staged.commit().unwrap();
+
+        let meta = fs::symlink_metadata(target.join("my-skill")).unwrap();
+        assert!(meta.file_type().is_symlink());
+    }
 }
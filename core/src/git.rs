@@ -1,7 +1,19 @@
 use crate::{Result, RhinolabsError};
 use git2::Repository;
+use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+/// A commit that touched a path
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitInfo {
+    pub sha: String,
+    pub subject: String,
+    pub author: String,
+    /// Commit time (RFC 3339)
+    pub date: String,
+}
+
 pub struct GitOperations;
 
 impl GitOperations {
@@ -59,6 +71,122 @@ impl GitOperations {
     pub fn is_repository(path: &Path) -> bool {
         Repository::open(path).is_ok()
     }
+
+    /// List commits reachable from HEAD that changed `path`, newest first.
+    ///
+    /// Returns an empty list when `path` is not inside a git repository
+    /// or the repository has no commits yet.
+    pub fn history(path: &Path) -> Result<Vec<CommitInfo>> {
+        let repo = match Repository::discover(path) {
+            Ok(repo) => repo,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let workdir = match repo.workdir() {
+            Some(dir) => dir.canonicalize()?,
+            None => return Ok(Vec::new()),
+        };
+        let relative = match path.canonicalize()?.strip_prefix(&workdir) {
+            Ok(rel) => rel.to_path_buf(),
+            Err(_) => return Ok(Vec::new()),
+        };
+
+        let mut revwalk = repo.revwalk()?;
+        if revwalk.push_head().is_err() {
+            return Ok(Vec::new());
+        }
+        revwalk.set_sorting(git2::Sort::TIME)?;
+
+        // Tree/blob id of `relative` in a commit, or None if absent
+        let entry_id = |commit: &git2::Commit| -> Option<git2::Oid> {
+            commit
+                .tree()
+                .ok()
+                .and_then(|tree| tree.get_path(&relative).ok())
+                .map(|entry| entry.id())
+        };
+
+        let mut commits = Vec::new();
+        for oid in revwalk {
+            let commit = repo.find_commit(oid?)?;
+            let current = entry_id(&commit);
+
+            let changed = if commit.parent_count() == 0 {
+                current.is_some()
+            } else {
+                commit.parents().all(|parent| entry_id(&parent) != current)
+            };
+
+            if changed {
+                commits.push(Self::commit_info(&commit));
+            }
+        }
+
+        Ok(commits)
+    }
+
+    /// The tag with the highest semantic version (`v1.2.3` or `1.2.3`), if any
+    pub fn latest_version_tag(repo_path: &Path) -> Result<Option<String>> {
+        let repo = match Repository::discover(repo_path) {
+            Ok(repo) => repo,
+            Err(_) => return Ok(None),
+        };
+
+        let tags = repo.tag_names(None)?;
+        Ok(tags
+            .iter()
+            .flatten()
+            .filter_map(|tag| {
+                semver::Version::parse(tag.trim_start_matches('v'))
+                    .ok()
+                    .map(|version| (version, tag.to_string()))
+            })
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, tag)| tag))
+    }
+
+    /// List commits reachable from HEAD but not from `tag`, newest first.
+    ///
+    /// With no tag (nothing released yet) every commit is listed. Returns an
+    /// empty list outside a git repository or before the first commit.
+    pub fn commits_since_tag(repo_path: &Path, tag: Option<&str>) -> Result<Vec<CommitInfo>> {
+        let repo = match Repository::discover(repo_path) {
+            Ok(repo) => repo,
+            Err(_) => return Ok(Vec::new()),
+        };
+
+        let mut revwalk = repo.revwalk()?;
+        if revwalk.push_head().is_err() {
+            return Ok(Vec::new());
+        }
+        if let Some(tag) = tag {
+            let tagged = repo
+                .revparse_single(&format!("refs/tags/{}", tag))
+                .map_err(|_| RhinolabsError::Other(format!("Tag '{}' not found", tag)))?
+                .peel_to_commit()?;
+            revwalk.hide(tagged.id())?;
+        }
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
+
+        revwalk
+            .map(|oid| {
+                let commit = repo.find_commit(oid?)?;
+                Ok(Self::commit_info(&commit))
+            })
+            .collect()
+    }
+
+    fn commit_info(commit: &git2::Commit) -> CommitInfo {
+        let date = chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
+            .map(|d| d.to_rfc3339())
+            .unwrap_or_default();
+
+        CommitInfo {
+            sha: commit.id().to_string(),
+            subject: commit.summary().unwrap_or_default().to_string(),
+            author: commit.author().name().unwrap_or_default().to_string(),
+            date,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -70,4 +198,59 @@ mod tests {
         let temp_dir = tempfile::tempdir().unwrap();
         assert!(!GitOperations::is_repository(temp_dir.path()));
     }
+
+    #[test]
+    fn test_history_outside_repository_is_empty() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let history = GitOperations::history(temp_dir.path()).unwrap();
+        assert!(history.is_empty());
+    }
+
+    fn commit(repo: &Repository, subject: &str) -> git2::Oid {
+        let signature = git2::Signature::now("Dev", "dev@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            subject,
+            &tree,
+            &parents,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_commits_since_tag() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(temp_dir.path()).unwrap();
+
+        let first = commit(&repo, "feat: first");
+        let object = repo.find_object(first, None).unwrap();
+        repo.tag_lightweight("v1.0.0", &object, false).unwrap();
+        repo.tag_lightweight("v0.9.0", &object, false).unwrap();
+        repo.tag_lightweight("nightly", &object, false).unwrap();
+        commit(&repo, "fix: second");
+        commit(&repo, "chore: third");
+
+        let latest = GitOperations::latest_version_tag(temp_dir.path()).unwrap();
+        assert_eq!(latest.as_deref(), Some("v1.0.0"));
+
+        let since: Vec<String> =
+            GitOperations::commits_since_tag(temp_dir.path(), latest.as_deref())
+                .unwrap()
+                .into_iter()
+                .map(|c| c.subject)
+                .collect();
+        assert_eq!(since, vec!["chore: third", "fix: second"]);
+
+        // Nothing released yet: every commit
+        let all = GitOperations::commits_since_tag(temp_dir.path(), None).unwrap();
+        assert_eq!(all.len(), 3);
+
+        assert!(GitOperations::commits_since_tag(temp_dir.path(), Some("v2.0.0")).is_err());
+    }
 }
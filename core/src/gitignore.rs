@@ -0,0 +1,178 @@
+//! The block of a project's `.gitignore` that lists files rhinolabs-ai generated.
+//!
+//! Entries live between marker comments so they can be rewritten or removed
+//! without touching the user's own patterns. The skills directory's `.gitignore`
+//! uses the same markers (see `Profiles::generate_skills_gitignore`).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+
+/// Every rhinolabs-ai block starts with a line beginning with this
+const BLOCK_START_PREFIX: &str = "# Auto-generated by rhinolabs-ai";
+const BLOCK_START: &str = "# Auto-generated by rhinolabs-ai — files from `rlai profile install`";
+pub(crate) const BLOCK_END: &str = "# End rhinolabs-ai";
+
+/// First line of a block, e.g. `block_start("symlinked skills")`
+pub(crate) fn block_start(description: &str) -> String {
+    format!("{} — {}", BLOCK_START_PREFIX, description)
+}
+
+/// Lines of a `.gitignore` outside its rhinolabs-ai blocks
+pub(crate) fn user_lines(content: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut in_block = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with(BLOCK_START_PREFIX) {
+            in_block = true;
+        } else if in_block && trimmed == BLOCK_END {
+            in_block = false;
+        } else if !in_block {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+fn gitignore_path(project: &Path) -> PathBuf {
+    project.join(".gitignore")
+}
+
+/// Whether the project's `.gitignore` has a rhinolabs-ai block
+pub fn has_block(project: &Path) -> bool {
+    fs::read_to_string(gitignore_path(project))
+        .map(|content| content.lines().any(|line| line.trim() == BLOCK_START))
+        .unwrap_or(false)
+}
+
+/// Replace the rhinolabs-ai block with `entries`, creating `.gitignore` if needed.
+///
+/// Patterns the user already lists outside the block are not repeated. With no
+/// entries left the block is removed, and so is the file if nothing else is in it.
+/// Returns the entries written to the block.
+pub fn write_block(project: &Path, entries: &[String]) -> Result<Vec<String>> {
+    let path = gitignore_path(project);
+    let existing = if path.exists() {
+        fs::read_to_string(&path)?
+    } else {
+        String::new()
+    };
+
+    let mut user_lines = user_lines(&existing);
+
+    let mut written: Vec<String> = Vec::new();
+    for entry in entries {
+        let already_listed = user_lines
+            .iter()
+            .copied()
+            .chain(written.iter().map(String::as_str))
+            .any(|line| normalize(line) == normalize(entry));
+        if !already_listed {
+            written.push(entry.clone());
+        }
+    }
+
+    while user_lines.last().is_some_and(|line| line.trim().is_empty()) {
+        user_lines.pop();
+    }
+    let mut content = user_lines.join("\n");
+    if !written.is_empty() {
+        if !content.is_empty() {
+            content.push_str("\n\n");
+        }
+        content.push_str(BLOCK_START);
+        for entry in &written {
+            content.push('\n');
+            content.push_str(entry);
+        }
+        content.push('\n');
+        content.push_str(BLOCK_END);
+    }
+
+    if content.is_empty() {
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
+    } else {
+        content.push('\n');
+        if content != existing {
+            crate::fs_utils::write(&path, content)?;
+        }
+    }
+    Ok(written)
+}
+
+/// `/.claude/` and `.claude` ignore the same generated directory
+fn normalize(pattern: &str) -> &str {
+    pattern.trim().trim_start_matches('/').trim_end_matches('/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(list: &[&str]) -> Vec<String> {
+        list.iter().map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn test_write_block_keeps_user_lines_and_skips_duplicates() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = temp.path();
+        fs::write(project.join(".gitignore"), "target/\nCLAUDE.md\n").unwrap();
+
+        let written = write_block(project, &entries(&["/CLAUDE.md", "/.claude/"])).unwrap();
+        assert_eq!(written, entries(&["/.claude/"]));
+        assert!(has_block(project));
+        assert_eq!(
+            fs::read_to_string(project.join(".gitignore")).unwrap(),
+            format!(
+                "target/\nCLAUDE.md\n\n{}\n/.claude/\n{}\n",
+                BLOCK_START, BLOCK_END
+            )
+        );
+
+        // Rewriting replaces the block instead of appending another one
+        write_block(project, &entries(&["/.claude/", "/AGENTS.md"])).unwrap();
+        let content = fs::read_to_string(project.join(".gitignore")).unwrap();
+        assert_eq!(content.matches(BLOCK_START).count(), 1);
+        assert!(content.contains("/AGENTS.md"));
+
+        write_block(project, &[]).unwrap();
+        assert_eq!(
+            fs::read_to_string(project.join(".gitignore")).unwrap(),
+            "target/\nCLAUDE.md\n"
+        );
+        assert!(!has_block(project));
+    }
+
+    #[test]
+    fn test_user_lines_skip_every_block() {
+        let input = format!(
+            "# User stuff\n*.log\n{}\n# Re-run `rlai profile install` to regenerate\nskill-a/\n{}\n# More user stuff\n*.tmp\n{}\n/CLAUDE.md\n{}\n",
+            block_start("symlinked skills (machine-specific)"),
+            BLOCK_END,
+            BLOCK_START,
+            BLOCK_END
+        );
+
+        assert_eq!(
+            user_lines(&input),
+            vec!["# User stuff", "*.log", "# More user stuff", "*.tmp"]
+        );
+    }
+
+    #[test]
+    fn test_write_block_creates_and_removes_the_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = temp.path();
+
+        write_block(project, &entries(&["/.claude/"])).unwrap();
+        assert!(project.join(".gitignore").exists());
+
+        write_block(project, &[]).unwrap();
+        assert!(!project.join(".gitignore").exists());
+    }
+}
@@ -0,0 +1,444 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::instructions::{self, SKILLS_SECTION};
+use crate::{fs_utils, DeployTarget, PlannedPath, Result, RhinolabsError, TargetPaths};
+
+/// File name of the install manifest, written at the project root
+pub const INSTALL_MANIFEST_FILE: &str = ".rhinolabs-install.json";
+
+/// Record of exactly what a project profile install deployed, so uninstall can remove
+/// those paths and nothing else. Paths are relative to the project root.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallManifest {
+    pub profile_id: String,
+    #[serde(default)]
+    pub targets: Vec<InstalledTarget>,
+    /// ClaudeCode was installed without `.claude-plugin/` (`--no-plugin`); updates keep it off
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub no_plugin: bool,
+}
+
+/// Paths deployed for one target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledTarget {
+    pub target: DeployTarget,
+    /// The target's skills directory
+    pub skills_dir: PathBuf,
+    /// Skill entries, manifests and other files written by the install
+    #[serde(default)]
+    pub files: Vec<PathBuf>,
+    /// Generated instructions files (kept by `uninstall --keep-instructions`)
+    #[serde(default)]
+    pub instructions: Vec<PathBuf>,
+    /// Directories the install created; removed on uninstall only once empty
+    #[serde(default)]
+    pub dirs: Vec<PathBuf>,
+    /// Content hash of each skill's source when it was last deployed, so unchanged
+    /// skills can be skipped on the next install
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub skill_hashes: BTreeMap<String, String>,
+    /// Content hash of each instructions file the install created, as it last wrote
+    /// the whole file. Not refreshed once the user edits it, so uninstall only
+    /// deletes files that are still entirely generated.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub instruction_hashes: BTreeMap<PathBuf, String>,
+}
+
+impl InstalledTarget {
+    /// Hash the instructions files planned as fully generated, once the install wrote them
+    pub(crate) fn hash_instructions(&mut self, project: &Path) {
+        self.instruction_hashes
+            .retain(|path, hash| match fs::read(project.join(path)) {
+                Ok(content) => {
+                    *hash = fs_utils::hash_bytes(&content);
+                    true
+                }
+                Err(_) => false,
+            });
+    }
+
+    /// Whether the instructions file at `relative` is unchanged since the install wrote it
+    fn is_unedited(&self, project: &Path, relative: &Path) -> bool {
+        self.instruction_hashes.get(relative).is_some_and(|hash| {
+            fs::read(project.join(relative))
+                .is_ok_and(|content| fs_utils::hash_bytes(&content) == *hash)
+        })
+    }
+}
+
+impl InstallManifest {
+    /// Path of the manifest for a project
+    pub fn path(project: &Path) -> PathBuf {
+        project.join(INSTALL_MANIFEST_FILE)
+    }
+
+    /// Load a project's manifest, if it has one
+    pub fn load(project: &Path) -> Result<Option<Self>> {
+        let path = Self::path(project);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path)?;
+        let manifest = serde_json::from_str(&content).map_err(|e| {
+            RhinolabsError::ConfigError(format!("Invalid {}: {}", path.display(), e))
+        })?;
+        Ok(Some(manifest))
+    }
+
+    /// Write the manifest, or delete it once no targets are left
+    pub fn save(&self, project: &Path) -> Result<()> {
+        let path = Self::path(project);
+        if self.targets.is_empty() {
+            if path.exists() {
+                fs::remove_file(&path)?;
+            }
+            return Ok(());
+        }
+
+        crate::fs_utils::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Build the entry for `target` from an install plan, before the install runs.
+    ///
+    /// Directories are only recorded when they don't exist yet, so uninstall never
+    /// removes a directory the user already had. Instructions files that are new, or
+    /// unedited since the `previous` install, are marked for `hash_instructions`.
+    pub(crate) fn plan_target(
+        project: &Path,
+        target: DeployTarget,
+        skills_dir: &Path,
+        plan: &[PlannedPath],
+        previous: Option<&InstallManifest>,
+    ) -> InstalledTarget {
+        let relative = |path: &Path| path.strip_prefix(project).unwrap_or(path).to_path_buf();
+
+        let mut entry = InstalledTarget {
+            target,
+            skills_dir: relative(skills_dir),
+            files: Vec::new(),
+            instructions: Vec::new(),
+            dirs: Vec::new(),
+            skill_hashes: BTreeMap::new(),
+            instruction_hashes: BTreeMap::new(),
+        };
+
+        if let Some(previous) = previous.and_then(|m| m.targets.iter().find(|t| t.target == target))
+        {
+            for path in previous.instruction_hashes.keys() {
+                if previous.is_unedited(project, path) {
+                    entry.instruction_hashes.insert(path.clone(), String::new());
+                }
+            }
+        }
+
+        for planned in plan.iter().filter(|p| p.target == target) {
+            let path = relative(&planned.path);
+            if path == Path::new(INSTALL_MANIFEST_FILE) || path == Path::new(".gitignore") {
+                // The manifest itself and the shared .gitignore block aren't per-target files
+                continue;
+            } else if path == entry.skills_dir {
+                // The skills dir itself is only recorded (below) if the install creates it
+            } else if Self::is_instructions_file(&path) {
+                if !project.join(&path).exists() {
+                    entry.instruction_hashes.insert(path.clone(), String::new());
+                }
+                entry.instructions.push(path.clone());
+            } else {
+                entry.files.push(path.clone());
+            }
+
+            for dir in path.ancestors().skip(1) {
+                if dir.as_os_str().is_empty() || project.join(dir).exists() {
+                    break;
+                }
+                if !entry.dirs.iter().any(|d| d == dir) {
+                    entry.dirs.push(dir.to_path_buf());
+                }
+            }
+        }
+
+        entry
+    }
+
+    /// Add a target's entry, merging with what an earlier install recorded for it
+    pub(crate) fn record(&mut self, entry: InstalledTarget) {
+        let Some(existing) = self.targets.iter_mut().find(|t| t.target == entry.target) else {
+            self.targets.push(entry);
+            return;
+        };
+
+        existing.skills_dir = entry.skills_dir;
+        existing.skill_hashes.extend(entry.skill_hashes);
+        existing.instruction_hashes.extend(entry.instruction_hashes);
+        for (list, new) in [
+            (&mut existing.files, entry.files),
+            (&mut existing.instructions, entry.instructions),
+            (&mut existing.dirs, entry.dirs),
+        ] {
+            for path in new {
+                if !list.contains(&path) {
+                    list.push(path);
+                }
+            }
+        }
+    }
+
+    /// Remove the recorded paths of `target` and drop it from the manifest.
+    ///
+    /// Instructions files still as generated are deleted; in edited ones only the
+    /// managed skills section is stripped (see `remove_instructions`), and
+    /// `opencode.json` only loses its entry for the generated markdown. Files another
+    /// recorded target also uses are left alone. Recorded directories are removed
+    /// deepest first and only when empty; anything the user added inside them is left
+    /// alone. Returns false if the target had no entry.
+    pub(crate) fn remove_target(
+        &mut self,
+        project: &Path,
+        target: DeployTarget,
+        keep_instructions: bool,
+    ) -> Result<bool> {
+        let Some(index) = self.targets.iter().position(|t| t.target == target) else {
+            return Ok(false);
+        };
+        let entry = self.targets.remove(index);
+
+        if !keep_instructions {
+            for relative in &entry.instructions {
+                let shared = self
+                    .targets
+                    .iter()
+                    .any(|t| t.instructions.contains(relative));
+                if !Self::is_safe(relative) || shared {
+                    continue;
+                }
+                if *relative
+                    == TargetPaths::instructions_path(DeployTarget::OpenCode, Path::new(""))
+                    && crate::Profiles::remove_opencode_config(&project.join(relative))?
+                {
+                    continue;
+                }
+                if entry.is_unedited(project, relative) {
+                    fs::remove_file(project.join(relative))?;
+                } else {
+                    Self::remove_instructions(&project.join(relative))?;
+                }
+            }
+        }
+
+        for relative in &entry.files {
+            if !Self::is_safe(relative) {
+                continue;
+            }
+            let path = project.join(relative);
+            match fs::symlink_metadata(&path) {
+                Ok(meta) if meta.is_dir() => fs::remove_dir_all(&path)?,
+                Ok(_) => fs::remove_file(&path)?,
+                Err(_) => {}
+            }
+        }
+
+        let mut dirs: Vec<&PathBuf> = entry.dirs.iter().filter(|d| Self::is_safe(d)).collect();
+        dirs.sort_by_key(|d| std::cmp::Reverse(d.components().count()));
+        for relative in dirs {
+            let dir = project.join(relative);
+            let is_empty = fs::read_dir(&dir)
+                .map(|mut entries| entries.next().is_none())
+                .unwrap_or(false);
+            if is_empty {
+                fs::remove_dir(&dir)?;
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Strip the managed skills section from an instructions file the user edited,
+    /// deleting the file only if nothing else is left. Files without the section
+    /// are left alone.
+    fn remove_instructions(path: &Path) -> Result<()> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        match instructions::remove_section(&content, SKILLS_SECTION) {
+            Some(rest) if rest.trim().is_empty() => fs::remove_file(path)?,
+            Some(rest) => crate::fs_utils::write(path, rest)?,
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// `.gitignore` patterns covering what the recorded installs generated: the
+    /// top-most directory an install created, or the path itself when it was written
+    /// into a directory that already existed. Instructions files that existed before
+    /// the install belong to the project and are left out. Includes the manifest
+    /// while any target is recorded.
+    pub fn gitignore_entries(&self) -> Vec<String> {
+        let mut entries = BTreeSet::new();
+        for entry in &self.targets {
+            let created_instructions = entry
+                .instructions
+                .iter()
+                .filter(|path| entry.instruction_hashes.contains_key(*path));
+            for path in entry.files.iter().chain(created_instructions) {
+                if !Self::is_safe(path) {
+                    continue;
+                }
+                let created_dir = path
+                    .ancestors()
+                    .skip(1)
+                    .filter(|dir| entry.dirs.iter().any(|d| d == dir))
+                    .last();
+                entries.insert(match created_dir {
+                    Some(dir) => format!("/{}/", dir.to_string_lossy().replace('\\', "/")),
+                    None => format!("/{}", path.to_string_lossy().replace('\\', "/")),
+                });
+            }
+        }
+        if !self.targets.is_empty() {
+            entries.insert(format!("/{}", INSTALL_MANIFEST_FILE));
+        }
+        entries.into_iter().collect()
+    }
+
+    /// Rewrite the project's `.gitignore` block from the recorded targets, if the
+    /// project opted in to one (see `Profiles::add_to_gitignore`)
+    pub(crate) fn refresh_gitignore(&self, project: &Path) -> Result<()> {
+        if crate::gitignore::has_block(project) {
+            crate::gitignore::write_block(project, &self.gitignore_entries())?;
+        }
+        Ok(())
+    }
+
+    /// Recorded source hashes for `target`'s skills, if it was installed before
+    pub(crate) fn skill_hashes(&self, target: DeployTarget) -> Option<&BTreeMap<String, String>> {
+        self.targets
+            .iter()
+            .find(|t| t.target == target)
+            .map(|t| &t.skill_hashes)
+    }
+
+    /// Entries inside the recorded skills directories that the install did not create,
+    /// i.e. content the user added after installing
+    pub fn drift(&self, project: &Path) -> Result<Vec<PathBuf>> {
+        let mut untracked = Vec::new();
+        for entry in &self.targets {
+            let skills_dir = project.join(&entry.skills_dir);
+            if !Self::is_safe(&entry.skills_dir) || !skills_dir.is_dir() {
+                continue;
+            }
+            for item in fs::read_dir(&skills_dir)? {
+                let relative = entry.skills_dir.join(item?.file_name());
+                if !entry.files.contains(&relative) {
+                    untracked.push(relative);
+                }
+            }
+        }
+        untracked.sort();
+        Ok(untracked)
+    }
+
+    /// Root-level files (CLAUDE.md, AGENTS.md, ...) and copilot instructions
+    fn is_instructions_file(path: &Path) -> bool {
+        path.components().count() == 1 || path == Path::new(".github/copilot-instructions.md")
+    }
+
+    /// Only plain relative paths inside the project are ever removed
+    fn is_safe(path: &Path) -> bool {
+        !path.as_os_str().is_empty() && path.components().all(|c| matches!(c, Component::Normal(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(files: &[&str]) -> InstalledTarget {
+        InstalledTarget {
+            target: DeployTarget::ClaudeCode,
+            skills_dir: PathBuf::from(".claude/skills"),
+            files: files.iter().map(PathBuf::from).collect(),
+            instructions: vec![PathBuf::from("CLAUDE.md")],
+            dirs: Vec::new(),
+            skill_hashes: BTreeMap::new(),
+            instruction_hashes: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_record_merges_entries_for_the_same_target() {
+        let mut manifest = InstallManifest::default();
+        manifest.record(entry(&[".claude/skills/a"]));
+        manifest.record(entry(&[".claude/skills/a", ".claude/skills/b"]));
+
+        assert_eq!(manifest.targets.len(), 1);
+        assert_eq!(
+            manifest.targets[0].files,
+            vec![
+                PathBuf::from(".claude/skills/a"),
+                PathBuf::from(".claude/skills/b")
+            ]
+        );
+        assert_eq!(manifest.targets[0].instructions.len(), 1);
+    }
+
+    #[test]
+    fn test_remove_target_skips_paths_outside_the_project() {
+        let root = tempfile::tempdir().unwrap();
+        let project = root.path().join("project");
+        fs::create_dir_all(project.join(".claude/skills/a")).unwrap();
+        fs::write(root.path().join("outside.md"), "keep").unwrap();
+        fs::write(project.join("CLAUDE.md"), "keep").unwrap();
+
+        let mut manifest = InstallManifest::default();
+        manifest.record(entry(&[".claude/skills/a", "../outside.md"]));
+
+        assert!(manifest
+            .remove_target(&project, DeployTarget::ClaudeCode, true)
+            .unwrap());
+        assert!(!project.join(".claude/skills/a").exists());
+        assert!(root.path().join("outside.md").exists());
+        // keep_instructions leaves CLAUDE.md alone
+        assert!(project.join("CLAUDE.md").exists());
+        assert!(!manifest
+            .remove_target(&project, DeployTarget::ClaudeCode, true)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_gitignore_entries_use_created_directories() {
+        let mut claude = entry(&[".claude/skills/a", ".claude/skills/b"]);
+        claude.dirs = vec![PathBuf::from(".claude/skills"), PathBuf::from(".claude")];
+        let mut amp = entry(&[".agents/skills/a"]);
+        amp.target = DeployTarget::Amp;
+        amp.instructions = vec![PathBuf::from("AGENTS.md")];
+        // The install created AGENTS.md; CLAUDE.md was already there
+        amp.instruction_hashes
+            .insert(PathBuf::from("AGENTS.md"), "hash".into());
+
+        let manifest = InstallManifest {
+            profile_id: "react".into(),
+            targets: vec![claude, amp],
+            no_plugin: false,
+        };
+        assert_eq!(
+            manifest.gitignore_entries(),
+            vec![
+                "/.agents/skills/a",
+                "/.claude/",
+                "/.rhinolabs-install.json",
+                "/AGENTS.md",
+            ]
+        );
+        assert!(InstallManifest::default().gitignore_entries().is_empty());
+    }
+}
@@ -1,14 +1,24 @@
-use crate::{fs_utils, Paths, Result, RhinolabsError, Version};
+use crate::version::PluginDownload;
+use crate::{fs_utils, DeployTarget, Paths, Result, RhinolabsError, Version};
+use sha2::{Digest, Sha256};
 use std::fs;
 use std::path::Path;
 
 pub struct Installer {
     dry_run: bool,
+    verify: bool,
+    version: Option<String>,
+    targets: Vec<DeployTarget>,
 }
 
 impl Installer {
     pub fn new() -> Self {
-        Self { dry_run: false }
+        Self {
+            dry_run: false,
+            verify: true,
+            version: None,
+            targets: vec![DeployTarget::ClaudeCode],
+        }
     }
 
     pub fn dry_run(mut self, enabled: bool) -> Self {
@@ -16,12 +26,36 @@ impl Installer {
         self
     }
 
-    /// Install plugin from GitHub release
-    pub async fn install(&self) -> Result<()> {
-        // Check if Claude Code is installed
-        if !Paths::is_claude_code_installed() {
+    /// Check the downloaded plugin against the release's `SHA256SUMS` (on by default).
+    /// Turning this off allows installing releases that publish no checksums.
+    pub fn verify(mut self, enabled: bool) -> Self {
+        self.verify = enabled;
+        self
+    }
+
+    /// Install the release tagged `version` (e.g. `v1.2.0` or `1.2.0`) instead of the latest
+    pub fn version(mut self, version: Option<String>) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Targets the plugin is being installed for (Claude Code by default). Claude Code
+    /// only has to be installed when it's one of them.
+    pub fn targets(mut self, targets: Vec<DeployTarget>) -> Self {
+        self.targets = targets;
+        self
+    }
+
+    fn check_claude_code(&self, claude_code_installed: bool) -> Result<()> {
+        if self.targets.contains(&DeployTarget::ClaudeCode) && !claude_code_installed {
             return Err(RhinolabsError::ClaudeCodeNotFound);
         }
+        Ok(())
+    }
+
+    /// Install plugin from GitHub release
+    pub async fn install(&self) -> Result<()> {
+        self.check_claude_code(Paths::is_claude_code_installed())?;
 
         // Check if already installed
         if Paths::is_plugin_installed() {
@@ -32,13 +66,24 @@ impl Installer {
         }
 
         if self.dry_run {
-            println!("[DRY RUN] Would install plugin from GitHub releases");
+            match &self.version {
+                Some(version) => {
+                    println!(
+                        "[DRY RUN] Would install plugin {} from GitHub releases",
+                        version
+                    )
+                }
+                None => println!("[DRY RUN] Would install plugin from GitHub releases"),
+            }
             return Ok(());
         }
 
-        // Download plugin zip from latest release
-        let download_url = Version::get_latest_download_url().await?;
-        let zip_data = self.download_file(&download_url).await?;
+        // Download plugin zip from the requested (or latest) release
+        let download = Version::plugin_download(self.version.as_deref()).await?;
+        let zip_data = self.download_file(&download.url).await?;
+        if self.verify {
+            self.verify_checksum(&download, &zip_data).await?;
+        }
 
         // Extract to plugin directory
         let plugin_dir = Paths::plugin_dir()?;
@@ -46,7 +91,7 @@ impl Installer {
 
         // Save version info
         let version_info = Version {
-            version: Version::current(),
+            version: download.version,
             installed_at: chrono::Utc::now(),
         };
         version_info.save()?;
@@ -56,9 +101,7 @@ impl Installer {
 
     /// Install from local directory (for development)
     pub fn install_from_local(&self, source_dir: &Path) -> Result<()> {
-        if !Paths::is_claude_code_installed() {
-            return Err(RhinolabsError::ClaudeCodeNotFound);
-        }
+        self.check_claude_code(Paths::is_claude_code_installed())?;
 
         if Paths::is_plugin_installed() {
             let plugin_dir = Paths::plugin_dir()?;
@@ -119,7 +162,7 @@ impl Installer {
 
     /// Download file from URL
     async fn download_file(&self, url: &str) -> Result<Vec<u8>> {
-        let response = reqwest::get(url).await?;
+        let response = crate::network::send(crate::network::client()?.get(url)).await?;
 
         if !response.status().is_success() {
             return Err(RhinolabsError::DownloadFailed(format!(
@@ -132,6 +175,30 @@ impl Installer {
         Ok(bytes.to_vec())
     }
 
+    /// Compare `data` with the plugin's entry in the release's `SHA256SUMS`
+    async fn verify_checksum(&self, download: &PluginDownload, data: &[u8]) -> Result<()> {
+        let checksums_url = download.checksums_url.as_deref().ok_or_else(|| {
+            RhinolabsError::DownloadFailed(format!(
+                "The release has no SHA256SUMS to verify {} against (use --no-verify to install it anyway)",
+                download.name
+            ))
+        })?;
+        let checksums =
+            String::from_utf8_lossy(&self.download_file(checksums_url).await?).into_owned();
+        let expected = expected_sha256(&checksums, &download.name).ok_or_else(|| {
+            RhinolabsError::DownloadFailed(format!("SHA256SUMS has no entry for {}", download.name))
+        })?;
+
+        let actual = format!("{:x}", Sha256::digest(data));
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(RhinolabsError::DownloadFailed(format!(
+                "Checksum mismatch for {}: got sha256 {}, expected {}",
+                download.name, actual, expected
+            )));
+        }
+        Ok(())
+    }
+
     /// Extract zip file to directory
     fn extract_zip(&self, zip_data: &[u8], target_dir: &Path) -> Result<()> {
         let cursor = std::io::Cursor::new(zip_data);
@@ -158,6 +225,15 @@ impl Installer {
     }
 }
 
+/// Hash listed for `file_name` in `sha256sum` output (`<hex>  <name>`, or
+/// `<hex> *<name>` in binary mode)
+pub(crate) fn expected_sha256<'a>(checksums: &'a str, file_name: &str) -> Option<&'a str> {
+    checksums.lines().find_map(|line| {
+        let (hash, name) = line.trim().split_once(char::is_whitespace)?;
+        (name.trim_start().trim_start_matches('*') == file_name).then_some(hash)
+    })
+}
+
 impl Default for Installer {
     fn default() -> Self {
         Self::new()
@@ -169,6 +245,19 @@ mod tests {
     use super::*;
     use std::io::Write;
 
+    #[test]
+    fn test_claude_code_is_only_required_when_targeted() {
+        let claude_only = Installer::new();
+        assert!(matches!(
+            claude_only.check_claude_code(false),
+            Err(RhinolabsError::ClaudeCodeNotFound)
+        ));
+        assert!(claude_only.check_claude_code(true).is_ok());
+
+        let amp_only = Installer::new().targets(vec![DeployTarget::Amp, DeployTarget::OpenCode]);
+        assert!(amp_only.check_claude_code(false).is_ok());
+    }
+
     #[test]
     fn test_extract_zip() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -233,6 +322,69 @@ mod tests {
         // In a real scenario, we'd need to mock Paths methods
     }
 
+    #[test]
+    fn test_expected_sha256() {
+        let checksums = "\
+aaaa  rhinolabs-ai-linux-x64
+bbbb *rhinolabs-claude-1.2.3.zip
+";
+        assert_eq!(
+            expected_sha256(checksums, "rhinolabs-claude-1.2.3.zip"),
+            Some("bbbb")
+        );
+        assert_eq!(
+            expected_sha256(checksums, "rhinolabs-ai-linux-x64"),
+            Some("aaaa")
+        );
+        assert_eq!(expected_sha256(checksums, "rhinolabs-claude.zip"), None);
+    }
+
+    #[tokio::test]
+    async fn test_verify_checksum() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let zip_data = create_test_zip();
+        let digest = format!("{:x}", Sha256::digest(&zip_data));
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/SHA256SUMS.txt"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(format!("{}  rhinolabs-claude-1.2.3.zip\n", digest)),
+            )
+            .mount(&server)
+            .await;
+
+        let download = PluginDownload {
+            version: "1.2.3".into(),
+            name: "rhinolabs-claude-1.2.3.zip".into(),
+            url: format!("{}/rhinolabs-claude-1.2.3.zip", server.uri()),
+            checksums_url: Some(format!("{}/SHA256SUMS.txt", server.uri())),
+        };
+        let installer = Installer::new();
+        installer
+            .verify_checksum(&download, &zip_data)
+            .await
+            .unwrap();
+
+        let err = installer
+            .verify_checksum(&download, b"tampered")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Checksum mismatch"));
+
+        let unsigned = PluginDownload {
+            checksums_url: None,
+            ..download
+        };
+        let err = installer
+            .verify_checksum(&unsigned, &zip_data)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("--no-verify"));
+    }
+
     // Helper function to create a test zip file in memory
     fn create_test_zip() -> Vec<u8> {
         let mut zip_data = Vec::new();
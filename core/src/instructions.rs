@@ -10,6 +10,62 @@ pub struct Instructions {
     pub last_modified: String,
 }
 
+/// A named block of CLAUDE.md between `<!-- rhinolabs:section:<name>:start -->`
+/// and `<!-- rhinolabs:section:<name>:end -->` markers. Generated project
+/// instructions files mark their skill tables the same way (section `skills`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstructionsSection {
+    pub name: String,
+    /// Text between the markers, without the surrounding newlines
+    pub content: String,
+}
+
+const SECTION_PREFIX: &str = "<!-- rhinolabs:section:";
+
+/// Section around the skill tables of generated project instructions files; only
+/// it is rewritten when an existing file is updated, and stripped on uninstall
+pub(crate) const SKILLS_SECTION: &str = "skills";
+
+pub(crate) fn section_start(name: &str) -> String {
+    format!("{}{}:start -->", SECTION_PREFIX, name)
+}
+
+pub(crate) fn section_end(name: &str) -> String {
+    format!("{}{}:end -->", SECTION_PREFIX, name)
+}
+
+/// Byte range of the named section, markers included
+pub(crate) fn find_section(text: &str, name: &str) -> Option<(usize, usize)> {
+    let start_marker = section_start(name);
+    let end_marker = section_end(name);
+    let start = text.find(&start_marker)?;
+    let end = start + text[start..].find(&end_marker)? + end_marker.len();
+    Some((start, end))
+}
+
+/// `existing` with its `name` section swapped for the one in `generated`, or
+/// `None` if either lacks a well-formed start/end marker pair
+pub(crate) fn replace_section(existing: &str, generated: &str, name: &str) -> Option<String> {
+    let (start, end) = find_section(existing, name)?;
+    let (new_start, new_end) = find_section(generated, name)?;
+    Some(format!(
+        "{}{}{}",
+        &existing[..start],
+        &generated[new_start..new_end],
+        &existing[end..]
+    ))
+}
+
+/// `text` without its `name` section (and the newline after it), or `None` if
+/// the section isn't there
+pub(crate) fn remove_section(text: &str, name: &str) -> Option<String> {
+    let (start, end) = find_section(text, name)?;
+    let rest = &text[end..];
+    let rest = rest.strip_prefix('\n').unwrap_or(rest);
+    Some(format!("{}{}", &text[..start], rest))
+}
+
 pub struct InstructionsManager;
 
 impl InstructionsManager {
@@ -69,10 +125,82 @@ impl InstructionsManager {
             ));
         }
 
-        fs::write(&path, content)?;
+        crate::fs_utils::write(&path, content)?;
 
         Ok(())
     }
+
+    /// The managed sections of CLAUDE.md, in file order
+    pub fn sections() -> Result<Vec<InstructionsSection>> {
+        let content = Self::get()?.content;
+        let mut sections = Vec::new();
+        let mut rest = content.as_str();
+        while let Some(pos) = rest.find(SECTION_PREFIX) {
+            let after = &rest[pos + SECTION_PREFIX.len()..];
+            let name = after.split(':').next().unwrap_or_default();
+            if after[name.len()..].starts_with(":start -->") {
+                if let Some((_, end)) = find_section(&rest[pos..], name) {
+                    let inner =
+                        &rest[pos + section_start(name).len()..pos + end - section_end(name).len()];
+                    sections.push(InstructionsSection {
+                        name: name.to_string(),
+                        content: inner.trim_matches('\n').to_string(),
+                    });
+                    rest = &rest[pos + end..];
+                    continue;
+                }
+            }
+            rest = after;
+        }
+        Ok(sections)
+    }
+
+    /// Content of one managed section, if CLAUDE.md has it
+    pub fn get_section(name: &str) -> Result<Option<String>> {
+        Ok(Self::sections()?
+            .into_iter()
+            .find(|section| section.name == name)
+            .map(|section| section.content))
+    }
+
+    /// Rewrite one managed section, leaving the rest of CLAUDE.md untouched.
+    /// A section that does not exist yet is appended to the end of the file.
+    pub fn update_section(name: &str, content: &str) -> Result<()> {
+        Self::validate_section_name(name)?;
+
+        let existing = Self::get()?.content;
+        let block = format!(
+            "{}\n{}\n{}",
+            section_start(name),
+            content.trim_matches('\n'),
+            section_end(name)
+        );
+        let updated = match find_section(&existing, name) {
+            Some((start, end)) => {
+                format!("{}{}{}", &existing[..start], block, &existing[end..])
+            }
+            None if existing.trim().is_empty() => format!("{}\n", block),
+            None => format!("{}\n\n{}\n", existing.trim_end(), block),
+        };
+
+        Self::update(&updated)
+    }
+
+    /// Section names are lowercase letters, digits and dashes so they fit in the markers
+    fn validate_section_name(name: &str) -> Result<()> {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if valid {
+            Ok(())
+        } else {
+            Err(RhinolabsError::ConfigError(format!(
+                "Invalid section name '{}': use lowercase letters, digits and dashes",
+                name
+            )))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -245,4 +373,84 @@ mod tests {
         let retrieved = InstructionsManager::get().expect("Should get");
         assert_eq!(retrieved.content, original);
     }
+
+    // ============================================
+    // Managed section Tests
+    // ============================================
+
+    #[test]
+    fn test_update_section_rewrites_only_that_section() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+
+        fs::write(
+            env.instructions_path(),
+            "# Team Instructions\n\nWritten by hand.\n\n\
+             <!-- rhinolabs:section:standards:start -->\n## Standards\n\nOld rules.\n<!-- rhinolabs:section:standards:end -->\n\n\
+             ## Notes\n\nAlso by hand.\n",
+        )
+        .unwrap();
+
+        InstructionsManager::update_section("standards", "## Standards\n\nNew rules.").unwrap();
+
+        let content = fs::read_to_string(env.instructions_path()).unwrap();
+        assert!(content.starts_with("# Team Instructions\n\nWritten by hand.\n"));
+        assert!(content.ends_with("## Notes\n\nAlso by hand.\n"));
+        assert!(!content.contains("Old rules."));
+        assert_eq!(
+            InstructionsManager::get_section("standards").unwrap(),
+            Some("## Standards\n\nNew rules.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_update_section_appends_new_sections_in_order() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+
+        InstructionsManager::update_section("standards", "## Standards").unwrap();
+        fs::write(
+            env.instructions_path(),
+            format!(
+                "# Intro\n\n{}",
+                fs::read_to_string(env.instructions_path()).unwrap()
+            ),
+        )
+        .unwrap();
+        InstructionsManager::update_section("skills", "## Skills\n\n- react-19").unwrap();
+
+        let sections = InstructionsManager::sections().unwrap();
+        assert_eq!(
+            sections,
+            vec![
+                InstructionsSection {
+                    name: "standards".into(),
+                    content: "## Standards".into(),
+                },
+                InstructionsSection {
+                    name: "skills".into(),
+                    content: "## Skills\n\n- react-19".into(),
+                },
+            ]
+        );
+        assert!(InstructionsManager::get()
+            .unwrap()
+            .content
+            .starts_with("# Intro\n"));
+        assert_eq!(InstructionsManager::get_section("missing").unwrap(), None);
+    }
+
+    #[test]
+    fn test_update_section_rejects_names_that_break_markers() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+
+        for name in ["", "Standards", "a:b", "with space"] {
+            assert!(
+                InstructionsManager::update_section(name, "x").is_err(),
+                "{}",
+                name
+            );
+        }
+    }
 }
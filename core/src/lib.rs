@@ -1,13 +1,17 @@
+pub mod changelog;
 pub mod deploy;
 pub mod diagnostics;
 pub mod error;
 pub mod fs_utils;
 pub mod git;
+pub mod gitignore;
+pub mod install_manifest;
 pub mod installer;
 pub mod instructions;
 pub mod manifest;
 pub mod mcp_config;
 pub mod mcp_sync;
+pub mod network;
 pub mod output_styles;
 pub mod paths;
 pub mod profiles;
@@ -18,39 +22,57 @@ pub mod skills;
 pub mod targets;
 pub mod updater;
 pub mod version;
+pub mod warnings;
 
 #[cfg(test)]
 mod test_utils;
 
-pub use deploy::{ConfigManifest, Deploy, DeployResult, SyncResult};
+pub use deploy::{
+    ConfigManifest, Deploy, DeployResult, SnapshotImport, SnapshotManifest, SnapshotSecret,
+    SyncResult,
+};
 pub use diagnostics::Doctor;
 pub use error::{Result, RhinolabsError};
+pub use git::CommitInfo;
+pub use install_manifest::{InstallManifest, InstalledTarget, INSTALL_MANIFEST_FILE};
 pub use installer::Installer;
-pub use instructions::{Instructions, InstructionsManager};
-pub use manifest::{Author, Manifest, PluginManifest};
-pub use mcp_config::{McpConfig, McpConfigManager, McpServer, McpSettings};
-pub use mcp_sync::McpSync;
+pub use instructions::{Instructions, InstructionsManager, InstructionsSection};
+pub use manifest::{Author, Manifest, ManifestPaths, PluginManifest};
+pub use mcp_config::{
+    is_secret_key, McpConfig, McpConfigManager, McpImportResult, McpMergeStrategy, McpServer,
+    McpSettings, REDACTED_VALUE,
+};
+pub use mcp_sync::{McpSync, McpSyncReport};
 pub use output_styles::{OutputStyle, OutputStyles};
 pub use paths::Paths;
 pub use profiles::{
-    AutoInvokeRule, CreateProfileInput, Profile, ProfileInstallResult, ProfileSyncResult,
-    ProfileType, Profiles, SkillInstallError, UpdateAutoInvokeInput, UpdateProfileInput,
+    AutoInvokeRule, CreateProfileInput, InstallOptions, PlannedPath, Profile, ProfileExport,
+    ProfileInstallResult, ProfileSyncResult, ProfileType, Profiles, SkillInstallError,
+    TargetInstallError, TargetSkills, UpdateAutoInvokeInput, UpdateProfileInput,
 };
 pub use project::{
-    GitHubConfig, Project, ProjectConfig, ProjectStatus, ReleaseAsset, DEFAULT_GITHUB_OWNER,
-    DEFAULT_GITHUB_REPO,
+    GitHubConfig, Project, ProjectConfig, ProjectStatus, ReleaseAsset, ReleaseInfo,
+    DEFAULT_GITHUB_OWNER, DEFAULT_GITHUB_REPO,
+};
+pub use rag::{
+    Rag, RagConfig, RagIndexReport, RagIndexSettings, RagResult, RagSettings, RagSkippedFile,
+    DEFAULT_RAG_QUERY_LIMIT, RAG_INDEX_BATCH_SIZE, RAG_INDEX_MAX_FILE_BYTES,
 };
-pub use rag::{Rag, RagConfig, RagSettings};
 pub use settings::{
     AttributionConfig, PermissionConfig, PluginSettings, Settings, StatusLineConfig,
 };
 pub use skills::{
-    CreateSkillInput, InstallSkillInput, RemoteSkill, RemoteSkillFile, Skill, SkillCategory,
-    SkillSchema, SkillSource, SkillSourceType, Skills, UpdateSkillInput,
+    BulkSkillInstallResult, CreateSkillInput, DuplicateSkillName, InstallSkillInput, IssueLevel,
+    RemoteSkill, RemoteSkillFetch, RemoteSkillFile, Skill, SkillArchiveMeta, SkillCategory,
+    SkillContentMatch, SkillDiff, SkillInstallResult, SkillListWarnings, SkillSchema, SkillSource,
+    SkillSourceType, SkillUpdate, SkillUpdateCheck, Skills, SkillsConfigProblem, SkillsShQuery,
+    SkillsShSort, UpdateSkillInput, UpdateSkillSourceInput, ValidationIssue, DEFAULT_SKILLS_PATH,
 };
 pub use targets::{
-    ClaudeCodeDeployer, DeployTarget, GenericDeployer, InstructionsDeployer, McpDeployer,
-    SkillDeployer, TargetDetector, TargetPaths,
+    ClaudeCodeDeployer, CustomTarget, DeployTarget, GenericDeployer, InstructionsDeployer,
+    McpDeployer, SkillDeployer, TargetDetector, TargetPaths, TargetStatus,
+    OPENCODE_INSTRUCTIONS_FILE,
 };
-pub use updater::Updater;
-pub use version::Version;
+pub use updater::{BinaryReplace, StagedBinary, UpdatePlan, Updater};
+pub use version::{BumpKind, Version};
+pub use warnings::Warnings;
@@ -1,19 +1,48 @@
-use crate::{Paths, Result};
+use crate::{Paths, Result, RhinolabsError, Version};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Author {
+    #[serde(default)]
     pub name: String,
 }
 
+/// One path or a list of paths, relative to the plugin root (e.g. `"./commands"`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ManifestPaths {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl ManifestPaths {
+    pub fn as_slice(&self) -> &[String] {
+        match self {
+            Self::One(path) => std::slice::from_ref(path),
+            Self::Many(paths) => paths,
+        }
+    }
+}
+
+// Missing fields deserialize as empty so that `Manifest::validate` can name them
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginManifest {
+    #[serde(default)]
     pub name: String,
+    #[serde(default)]
     pub description: String,
+    #[serde(default)]
     pub version: String,
+    #[serde(default)]
     pub author: Author,
+    /// Extra command files or directories
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commands: Option<ManifestPaths>,
+    /// Extra skill directories
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skills: Option<ManifestPaths>,
 }
 
 impl Default for PluginManifest {
@@ -25,15 +54,24 @@ impl Default for PluginManifest {
             author: Author {
                 name: "Rhinolabs".into(),
             },
+            commands: None,
+            skills: None,
         }
     }
 }
 
+fn invalid_field(field: &str, reason: impl std::fmt::Display) -> RhinolabsError {
+    RhinolabsError::ConfigError(format!(
+        "Invalid plugin manifest field '{}': {}",
+        field, reason
+    ))
+}
+
 pub struct Manifest;
 
 impl Manifest {
     /// Get the path to plugin.json
-    fn manifest_path() -> Result<PathBuf> {
+    pub(crate) fn manifest_path() -> Result<PathBuf> {
         Ok(Paths::plugin_dir()?
             .join(".claude-plugin")
             .join("plugin.json"))
@@ -54,9 +92,57 @@ impl Manifest {
         Ok(manifest)
     }
 
-    /// Update the plugin manifest
+    /// Check the manifest against what Claude Code requires of a plugin, so a
+    /// malformed one is caught here rather than silently rejected.
+    ///
+    /// Errors name the offending field, e.g. `Invalid plugin manifest field 'version': ...`.
+    pub fn validate(manifest: &PluginManifest) -> Result<()> {
+        Self::validate_in(manifest, &Paths::plugin_dir()?)
+    }
+
+    fn validate_in(manifest: &PluginManifest, plugin_dir: &Path) -> Result<()> {
+        if manifest.name.trim().is_empty() {
+            return Err(invalid_field("name", "is required"));
+        }
+        if manifest.name.chars().any(char::is_whitespace) {
+            return Err(invalid_field("name", "must not contain spaces"));
+        }
+        if manifest.version.trim().is_empty() {
+            return Err(invalid_field("version", "is required"));
+        }
+        if !Version::is_semver(&manifest.version) {
+            return Err(invalid_field(
+                "version",
+                format!(
+                    "'{}' is not a semantic version like 1.2.0",
+                    manifest.version
+                ),
+            ));
+        }
+        if manifest.author.name.trim().is_empty() {
+            return Err(invalid_field("author.name", "is required"));
+        }
+
+        for (field, paths) in [
+            ("commands", &manifest.commands),
+            ("skills", &manifest.skills),
+        ] {
+            for path in paths.iter().flat_map(ManifestPaths::as_slice) {
+                if !plugin_dir.join(path).exists() {
+                    return Err(invalid_field(
+                        field,
+                        format!("'{}' does not exist in the plugin", path),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Update the plugin manifest after validating it
     /// Creates the directory if it doesn't exist
     pub fn update(manifest: &PluginManifest) -> Result<()> {
+        Self::validate(manifest)?;
         let path = Self::manifest_path()?;
 
         // Create parent directory if it doesn't exist
@@ -67,7 +153,7 @@ impl Manifest {
         }
 
         let content = serde_json::to_string_pretty(manifest)?;
-        fs::write(&path, content)?;
+        crate::fs_utils::write(&path, content)?;
 
         Ok(())
     }
@@ -95,6 +181,8 @@ mod tests {
             author: Author {
                 name: "Test Author".into(),
             },
+            commands: None,
+            skills: None,
         };
 
         let json = serde_json::to_string(&manifest).unwrap();
@@ -114,4 +202,50 @@ mod tests {
         assert!(path.to_str().unwrap().contains("plugin.json"));
         assert!(path.to_str().unwrap().contains(".claude-plugin"));
     }
+
+    #[test]
+    fn test_validate_names_the_offending_field() {
+        let plugin = tempfile::TempDir::new().unwrap();
+        let validate = |edit: fn(&mut PluginManifest)| {
+            let mut manifest = PluginManifest::default();
+            edit(&mut manifest);
+            Manifest::validate_in(&manifest, plugin.path())
+                .err()
+                .map(|e| e.to_string())
+        };
+
+        assert_eq!(validate(|_| {}), None);
+        for (edit, field) in [
+            (
+                (|m| m.name = " ".into()) as fn(&mut PluginManifest),
+                "'name'",
+            ),
+            (|m| m.version = String::new(), "'version'"),
+            (|m| m.version = "v1.2".into(), "'version'"),
+            (|m| m.author.name = String::new(), "'author.name'"),
+            (
+                |m| m.commands = Some(ManifestPaths::One("./commands".into())),
+                "'commands'",
+            ),
+        ] {
+            let err = validate(edit).unwrap();
+            assert!(err.contains(field), "{}", err);
+        }
+
+        fs::create_dir_all(plugin.path().join("commands")).unwrap();
+        assert_eq!(
+            validate(|m| m.commands = Some(ManifestPaths::Many(vec!["./commands".into()]))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_missing_fields_deserialize_for_validation() {
+        let manifest: PluginManifest = serde_json::from_str(r#"{ "name": "x" }"#).unwrap();
+        assert!(manifest.author.name.is_empty());
+        assert!(manifest.version.is_empty());
+
+        let json = serde_json::to_value(PluginManifest::default()).unwrap();
+        assert!(json.get("commands").is_none());
+    }
 }
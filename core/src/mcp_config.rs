@@ -1,8 +1,49 @@
 use crate::{Paths, Result, RhinolabsError};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Env var / header name fragments that mark a value as secret
+const SECRET_KEY_PATTERNS: &[&str] = &["TOKEN", "KEY", "SECRET", "PASSWORD", "AUTHORIZATION"];
+
+/// Replacement shown instead of a secret value
+pub const REDACTED_VALUE: &str = "****";
+
+/// Key in the managed `.mcp.json` holding disabled servers. Claude Code only reads
+/// `mcpServers`, so servers stored here are kept but not started.
+const DISABLED_SERVERS_KEY: &str = "disabledMcpServers";
+
+/// Whether an env var or header name looks like it holds a secret
+pub fn is_secret_key(key: &str) -> bool {
+    let upper = key.to_uppercase();
+    SECRET_KEY_PATTERNS.iter().any(|p| upper.contains(p))
+}
+
+/// Mask secret values inside every `env` / `headers` object of a raw MCP config document.
+/// Used when printing config that hasn't been parsed into `McpServer`s.
+pub(crate) fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                if key == "env" || key == "headers" {
+                    if let serde_json::Value::Object(vars) = child {
+                        for (name, var) in vars.iter_mut() {
+                            if is_secret_key(name) {
+                                *var = serde_json::Value::String(REDACTED_VALUE.to_string());
+                            }
+                        }
+                        continue;
+                    }
+                }
+                redact_json(child);
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
 
 /// MCP Server configuration supporting both stdio and HTTP transports.
 ///
@@ -15,7 +56,10 @@ use std::path::PathBuf;
 /// - `url`: The HTTP URL of the MCP server
 /// - `transport`: Must be "http"
 /// - `headers`: Optional HTTP headers (e.g., Authorization)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// A server with `enabled: false` stays in the managed config but is left out of
+/// what Claude Code loads.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct McpServer {
     // stdio transport fields
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -32,6 +76,17 @@ pub struct McpServer {
     pub transport: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub headers: Option<HashMap<String, String>>,
+
+    #[serde(default = "enabled_default", skip_serializing_if = "is_enabled")]
+    pub enabled: bool,
+}
+
+fn enabled_default() -> bool {
+    true
+}
+
+fn is_enabled(enabled: &bool) -> bool {
+    *enabled
 }
 
 impl McpServer {
@@ -44,6 +99,7 @@ impl McpServer {
             url: None,
             transport: None,
             headers: None,
+            enabled: true,
         }
     }
 
@@ -56,6 +112,7 @@ impl McpServer {
             url: Some(url),
             transport: Some("http".to_string()),
             headers: None,
+            enabled: true,
         }
     }
 
@@ -68,6 +125,101 @@ impl McpServer {
     pub fn is_stdio(&self) -> bool {
         self.command.is_some()
     }
+
+    /// The values that may hold `${VAR}` placeholders: `args`, `url`, `env` and `headers`
+    fn placeholder_values_mut(&mut self) -> Vec<&mut String> {
+        let mut values: Vec<&mut String> = self.args.iter_mut().collect();
+        values.extend(self.url.iter_mut());
+        for map in [&mut self.env, &mut self.headers].into_iter().flatten() {
+            values.extend(map.values_mut());
+        }
+        values
+    }
+
+    /// Whether any `args`, `url`, `env` or `headers` value has a `${VAR}` placeholder
+    pub(crate) fn has_env_placeholders(&self) -> bool {
+        let mut server = self.clone();
+        server
+            .placeholder_values_mut()
+            .iter()
+            .any(|value| value.contains("${"))
+    }
+
+    /// Check that exactly one transport is configured with its required field,
+    /// and that an http/sse `url` is an absolute `http(s)://` URL
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        match (&self.command, &self.url) {
+            (Some(command), None) if !command.trim().is_empty() => Ok(()),
+            (None, Some(url)) if !url.trim().is_empty() => match reqwest::Url::parse(url) {
+                Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(()),
+                Ok(parsed) => Err(format!(
+                    "`url` must use http or https, not '{}'",
+                    parsed.scheme()
+                )),
+                Err(e) => Err(format!("`url` '{}' is malformed: {}", url, e)),
+            },
+            (Some(_), Some(_)) => Err("both `command` and `url` are set".into()),
+            (None, None) => Err("needs a `command` (stdio) or `url` (http)".into()),
+            _ => Err("`command`/`url` must not be empty".into()),
+        }
+    }
+
+    /// Copy of this server with secret `env` and `headers` values replaced by `****`.
+    ///
+    /// A key is secret if `secret_keys` flags it `true`, or if it isn't listed
+    /// there and its name looks secret (see `is_secret_key`).
+    pub fn redacted(&self, secret_keys: &HashMap<String, bool>) -> Self {
+        let mask = |values: &Option<HashMap<String, String>>| {
+            values.as_ref().map(|map| {
+                map.iter()
+                    .map(|(k, v)| {
+                        let secret = secret_keys
+                            .get(k)
+                            .copied()
+                            .unwrap_or_else(|| is_secret_key(k));
+                        let value = if secret {
+                            REDACTED_VALUE.to_string()
+                        } else {
+                            v.clone()
+                        };
+                        (k.clone(), value)
+                    })
+                    .collect()
+            })
+        };
+
+        Self {
+            env: mask(&self.env),
+            headers: mask(&self.headers),
+            ..self.clone()
+        }
+    }
+}
+
+/// Debug output never includes env or header values, so servers can be logged safely.
+impl std::fmt::Debug for McpServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keys_only = |values: &Option<HashMap<String, String>>| {
+            values.as_ref().map(|map| {
+                let mut keys: Vec<_> = map
+                    .keys()
+                    .map(|k| format!("{}={}", k, REDACTED_VALUE))
+                    .collect();
+                keys.sort();
+                keys
+            })
+        };
+
+        f.debug_struct("McpServer")
+            .field("command", &self.command)
+            .field("args", &self.args)
+            .field("env", &keys_only(&self.env))
+            .field("url", &self.url)
+            .field("transport", &self.transport)
+            .field("headers", &keys_only(&self.headers))
+            .field("enabled", &self.enabled)
+            .finish()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -76,6 +228,14 @@ pub struct McpSettings {
     pub default_timeout: u32,
     pub retry_attempts: u32,
     pub log_level: String,
+    /// Explicit secret flags per env/header key: `true` always masks the value,
+    /// `false` never does. Keys not listed fall back to name-based detection.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub secret_keys: HashMap<String, bool>,
+    /// When resolving `${VAR}` placeholders, fail on unset variables instead of
+    /// leaving the placeholder as-is
+    #[serde(default)]
+    pub strict_env: bool,
 }
 
 impl Default for McpSettings {
@@ -84,6 +244,8 @@ impl Default for McpSettings {
             default_timeout: 30000,
             retry_attempts: 3,
             log_level: "info".into(),
+            secret_keys: HashMap::new(),
+            strict_env: false,
         }
     }
 }
@@ -108,6 +270,136 @@ impl Default for McpConfig {
     }
 }
 
+/// How to treat a server that already exists under the same name with a
+/// different definition
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum McpMergeStrategy {
+    /// Leave the managed definition untouched
+    #[default]
+    KeepExisting,
+    /// Replace the managed definition with the incoming one
+    Overwrite,
+}
+
+/// Outcome of importing a project's `.mcp.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpImportResult {
+    /// Servers written to the managed config (new, or overwritten conflicts)
+    pub imported: Vec<String>,
+    /// Servers whose name exists with a different definition
+    pub conflicts: Vec<String>,
+    /// Servers already managed with an identical definition
+    pub unchanged: Vec<String>,
+    /// Servers skipped because their definition is invalid ("name: reason")
+    pub invalid: Vec<String>,
+}
+
+/// Expand `${VAR}` and `${VAR:-default}` placeholders from the process
+/// environment. Unset variables without a default are recorded in `missing`
+/// and left as the literal placeholder.
+fn expand_env(value: &str, missing: &mut Vec<String>) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        let Some(len) = rest[start + 2..].find('}') else {
+            break;
+        };
+        let placeholder = &rest[start..start + 2 + len + 1];
+        let inner = &rest[start + 2..start + 2 + len];
+        let (name, default) = match inner.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (inner, None),
+        };
+
+        out.push_str(&rest[..start]);
+        match (std::env::var(name), default) {
+            (Ok(resolved), _) => out.push_str(&resolved),
+            (Err(_), Some(default)) => out.push_str(default),
+            (Err(_), None) => {
+                if !missing.iter().any(|m| m == name) {
+                    missing.push(name.to_string());
+                }
+                out.push_str(placeholder);
+            }
+        }
+        rest = &rest[start + placeholder.len()..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// `value` with each `${VAR}` placeholder (but not `${VAR:-default}`) replaced by
+/// `reference(VAR)`
+fn rewrite_env(value: &str, reference: &impl Fn(&str) -> String) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        let Some(len) = rest[start + 2..].find('}') else {
+            break;
+        };
+        let placeholder = &rest[start..start + 2 + len + 1];
+        let name = &rest[start + 2..start + 2 + len];
+
+        out.push_str(&rest[..start]);
+        if name.contains(":-") {
+            out.push_str(placeholder);
+        } else {
+            out.push_str(&reference(name));
+        }
+        rest = &rest[start + placeholder.len()..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// The parts of an MCP config document checked by `validate_json`
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct McpDocument {
+    #[serde(default)]
+    mcp_servers: ServerEntries,
+}
+
+/// `mcpServers` entries in document order, keeping duplicate names that a
+/// `HashMap` would silently collapse
+#[derive(Default)]
+struct ServerEntries(Vec<(String, serde_json::Value)>);
+
+impl<'de> Deserialize<'de> for ServerEntries {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for EntriesVisitor {
+            type Value = ServerEntries;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an object of MCP servers")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> std::result::Result<Self::Value, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(ServerEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
 pub struct McpConfigManager;
 
 impl McpConfigManager {
@@ -116,7 +408,7 @@ impl McpConfigManager {
         Paths::mcp_config_path()
     }
 
-    /// Get the full MCP config
+    /// Get the full MCP config, disabled servers included
     pub fn get() -> Result<McpConfig> {
         let path = Self::config_path()?;
 
@@ -124,30 +416,141 @@ impl McpConfigManager {
             return Ok(McpConfig::default());
         }
 
-        let content = fs::read_to_string(&path)?;
-        let config: McpConfig = serde_json::from_str(&content)?;
+        let mut document: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        let disabled = document
+            .as_object_mut()
+            .and_then(|object| object.remove(DISABLED_SERVERS_KEY));
+        let mut config: McpConfig = serde_json::from_value(document)?;
+
+        if let Some(disabled) = disabled {
+            let disabled: HashMap<String, McpServer> = serde_json::from_value(disabled)?;
+            for (name, mut server) in disabled {
+                server.enabled = false;
+                config.mcp_servers.entry(name).or_insert(server);
+            }
+        }
 
         Ok(config)
     }
 
+    /// Keep the servers disabled in the current config disabled in `document`, a
+    /// config about to replace it: they are moved under `disabledMcpServers`, and the
+    /// ones `document` doesn't define are carried over as they were.
+    pub(crate) fn carry_over_disabled(document: &mut serde_json::Value) -> Result<()> {
+        let path = Self::config_path()?;
+        if !path.exists() {
+            return Ok(());
+        }
+        let mut current: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        let Some(serde_json::Value::Object(disabled)) = current
+            .as_object_mut()
+            .and_then(|object| object.remove(DISABLED_SERVERS_KEY))
+        else {
+            return Ok(());
+        };
+        let Some(object) = document.as_object_mut() else {
+            return Ok(());
+        };
+
+        let mut carried = match object.remove(DISABLED_SERVERS_KEY) {
+            Some(serde_json::Value::Object(incoming)) => incoming,
+            _ => serde_json::Map::new(),
+        };
+        for (name, server) in disabled {
+            let incoming = object
+                .get_mut("mcpServers")
+                .and_then(|servers| servers.as_object_mut())
+                .and_then(|servers| servers.remove(&name));
+            carried.entry(name).or_insert(incoming.unwrap_or(server));
+        }
+        object.insert(
+            DISABLED_SERVERS_KEY.into(),
+            serde_json::Value::Object(carried),
+        );
+
+        Ok(())
+    }
+
     /// Update the full MCP config
-    /// Creates the directory if it doesn't exist
+    /// Creates the directory if it doesn't exist. Disabled servers are written under
+    /// `disabledMcpServers` so Claude Code doesn't start them.
     pub fn update(config: &McpConfig) -> Result<()> {
-        let path = Self::config_path()?;
+        let (enabled, disabled): (HashMap<_, _>, HashMap<_, _>) = config
+            .mcp_servers
+            .clone()
+            .into_iter()
+            .partition(|(_, server)| server.enabled);
 
-        // Create parent directory if it doesn't exist
-        if let Some(parent) = path.parent() {
-            if !parent.exists() {
-                fs::create_dir_all(parent)?;
+        let mut document = serde_json::to_value(McpConfig {
+            mcp_servers: enabled,
+            ..config.clone()
+        })?;
+        if !disabled.is_empty() {
+            if let Some(object) = document.as_object_mut() {
+                object.insert(DISABLED_SERVERS_KEY.into(), serde_json::to_value(disabled)?);
             }
         }
 
-        let content = serde_json::to_string_pretty(config)?;
-        fs::write(&path, content)?;
+        let content = serde_json::to_string_pretty(&document)?;
+        crate::paths::atomic_write(&Self::config_path()?, content, false)?;
 
         Ok(())
     }
 
+    /// Copy of `config` with `${VAR}` placeholders in server `args`, `env`,
+    /// `url` and `headers` replaced from the process environment.
+    ///
+    /// With `settings.strict_env`, an unset variable is an error; otherwise the
+    /// placeholder is kept. The stored config is never rewritten with the
+    /// resolved values, so secrets stay out of the file on disk.
+    pub fn resolve_env(config: &McpConfig) -> Result<McpConfig> {
+        let mut resolved = config.clone();
+        let mut servers: Vec<_> = resolved.mcp_servers.iter_mut().collect();
+        servers.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (name, server) in servers {
+            if !server.enabled {
+                continue;
+            }
+            let mut missing = Vec::new();
+            for value in server.placeholder_values_mut() {
+                *value = expand_env(value, &mut missing);
+            }
+
+            if config.settings.strict_env && !missing.is_empty() {
+                missing.sort();
+                return Err(RhinolabsError::ConfigError(format!(
+                    "MCP server '{}' references unset environment variable(s): {}",
+                    name,
+                    missing.join(", ")
+                )));
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Copy of `config` with each plain `${VAR}` placeholder in the enabled servers
+    /// rewritten as `reference(VAR)`, for targets that expand variables themselves.
+    /// Placeholders with a default (`${VAR:-x}`) are kept for `resolve_env`.
+    pub(crate) fn rewrite_env_references(
+        config: &McpConfig,
+        reference: impl Fn(&str) -> String,
+    ) -> McpConfig {
+        let mut rewritten = config.clone();
+        for server in rewritten.mcp_servers.values_mut().filter(|s| s.enabled) {
+            for value in server.placeholder_values_mut() {
+                *value = rewrite_env(value, &reference);
+            }
+        }
+        rewritten
+    }
+
+    /// Get the MCP config with `${VAR}` placeholders resolved (see `resolve_env`)
+    pub fn get_resolved() -> Result<McpConfig> {
+        Self::resolve_env(&Self::get()?)
+    }
+
     // ========================================
     // MCP Servers
     // ========================================
@@ -164,8 +567,45 @@ impl McpConfigManager {
         Ok(config.mcp_servers.get(name).cloned())
     }
 
+    /// Check a server definition before it is stored under `name`
+    pub fn validate_server(name: &str, server: &McpServer) -> Result<()> {
+        if name.trim().is_empty() {
+            return Err(RhinolabsError::ConfigError(
+                "MCP server name must not be empty".into(),
+            ));
+        }
+
+        server.validate().map_err(|reason| {
+            RhinolabsError::ConfigError(format!("Invalid MCP server '{}': {}", name, reason))
+        })
+    }
+
+    /// Check a full MCP config document (e.g. one fetched by `sync-mcp`):
+    /// every server must be valid and no server name may appear twice.
+    pub fn validate_json(content: &str) -> Result<()> {
+        let document: McpDocument = serde_json::from_str(content)?;
+
+        let mut seen = HashSet::new();
+        for (name, raw) in document.mcp_servers.0 {
+            if !seen.insert(name.clone()) {
+                return Err(RhinolabsError::ConfigError(format!(
+                    "Duplicate MCP server name '{}'",
+                    name
+                )));
+            }
+
+            let server: McpServer = serde_json::from_value(raw).map_err(|e| {
+                RhinolabsError::ConfigError(format!("Invalid MCP server '{}': {}", name, e))
+            })?;
+            Self::validate_server(&name, &server)?;
+        }
+
+        Ok(())
+    }
+
     /// Add a new MCP server
     pub fn add_server(name: &str, server: McpServer) -> Result<()> {
+        Self::validate_server(name, &server)?;
         let mut config = Self::get()?;
 
         if config.mcp_servers.contains_key(name) {
@@ -181,6 +621,7 @@ impl McpConfigManager {
 
     /// Update an existing MCP server
     pub fn update_server(name: &str, server: McpServer) -> Result<()> {
+        Self::validate_server(name, &server)?;
         let mut config = Self::get()?;
 
         if !config.mcp_servers.contains_key(name) {
@@ -194,6 +635,24 @@ impl McpConfigManager {
         Self::update(&config)
     }
 
+    /// Turn a server on or off without removing its definition
+    pub fn set_enabled(name: &str, enabled: bool) -> Result<()> {
+        let mut config = Self::get()?;
+
+        let Some(server) = config.mcp_servers.get_mut(name) else {
+            return Err(RhinolabsError::ConfigError(format!(
+                "MCP server '{}' not found",
+                name
+            )));
+        };
+        if server.enabled == enabled {
+            return Ok(());
+        }
+        server.enabled = enabled;
+
+        Self::update(&config)
+    }
+
     /// Remove an MCP server
     pub fn remove_server(name: &str) -> Result<()> {
         let mut config = Self::get()?;
@@ -208,6 +667,107 @@ impl McpConfigManager {
         Self::update(&config)
     }
 
+    /// Import the servers from `<project_path>/.mcp.json` into the managed config.
+    ///
+    /// Invalid entries are skipped and reported; name clashes with a different
+    /// definition are resolved by `strategy`.
+    pub fn import_project_mcp(
+        project_path: &Path,
+        strategy: McpMergeStrategy,
+    ) -> Result<McpImportResult> {
+        let file = project_path.join(".mcp.json");
+        if !file.exists() {
+            return Err(RhinolabsError::ConfigError(format!(
+                "No .mcp.json found in {}",
+                project_path.display()
+            )));
+        }
+
+        let content: serde_json::Value = serde_json::from_str(&fs::read_to_string(&file)?)?;
+        let servers = content
+            .get("mcpServers")
+            .and_then(|v| v.as_object())
+            .ok_or_else(|| {
+                RhinolabsError::ConfigError(format!(
+                    "{} has no \"mcpServers\" object",
+                    file.display()
+                ))
+            })?;
+
+        let mut config = Self::get()?;
+        let mut result = McpImportResult::default();
+
+        for (name, raw) in servers {
+            let server = match Self::parse_project_server(raw) {
+                Ok(server) => server,
+                Err(reason) => {
+                    result.invalid.push(format!("{}: {}", name, reason));
+                    continue;
+                }
+            };
+
+            // A disabled server stays disabled whatever the project file says
+            match config.mcp_servers.get(name) {
+                Some(existing)
+                    if *existing
+                        == McpServer {
+                            enabled: existing.enabled,
+                            ..server.clone()
+                        } =>
+                {
+                    result.unchanged.push(name.clone())
+                }
+                Some(existing) => {
+                    result.conflicts.push(name.clone());
+                    if strategy == McpMergeStrategy::Overwrite {
+                        let server = McpServer {
+                            enabled: existing.enabled,
+                            ..server
+                        };
+                        config.mcp_servers.insert(name.clone(), server);
+                        result.imported.push(name.clone());
+                    }
+                }
+                None => {
+                    config.mcp_servers.insert(name.clone(), server);
+                    result.imported.push(name.clone());
+                }
+            }
+        }
+
+        if !result.imported.is_empty() {
+            Self::update(&config)?;
+        }
+
+        for names in [
+            &mut result.imported,
+            &mut result.conflicts,
+            &mut result.unchanged,
+            &mut result.invalid,
+        ] {
+            names.sort();
+        }
+
+        Ok(result)
+    }
+
+    /// Parse one `.mcp.json` server entry. Claude Code spells the transport as
+    /// `type`; it's mapped onto `transport` (stdio is implied by `command`).
+    fn parse_project_server(raw: &serde_json::Value) -> std::result::Result<McpServer, String> {
+        let mut raw = raw.clone();
+        if let Some(object) = raw.as_object_mut() {
+            if let Some(kind) = object.remove("type") {
+                if kind.as_str() != Some("stdio") && !object.contains_key("transport") {
+                    object.insert("transport".into(), kind);
+                }
+            }
+        }
+
+        let server: McpServer = serde_json::from_value(raw).map_err(|e| e.to_string())?;
+        server.validate()?;
+        Ok(server)
+    }
+
     // ========================================
     // MCP Settings
     // ========================================
@@ -312,4 +872,392 @@ mod tests {
         let path = path.unwrap();
         assert!(path.to_str().unwrap().contains(".mcp.json"));
     }
+
+    fn server_with_env(pairs: &[(&str, &str)]) -> McpServer {
+        let mut server = McpServer::stdio("npx".into(), vec![]);
+        server.env = Some(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        );
+        server
+    }
+
+    #[test]
+    fn test_is_secret_key() {
+        assert!(is_secret_key("GITHUB_TOKEN"));
+        assert!(is_secret_key("api_key"));
+        assert!(is_secret_key("CLIENT_SECRET"));
+        assert!(is_secret_key("DB_PASSWORD"));
+        assert!(is_secret_key("Authorization"));
+        assert!(!is_secret_key("NODE_ENV"));
+        assert!(!is_secret_key("LOG_LEVEL"));
+    }
+
+    #[test]
+    fn test_redacted_masks_secret_env_values() {
+        let server = server_with_env(&[("GITHUB_TOKEN", "ghp_abc123"), ("NODE_ENV", "prod")]);
+
+        let redacted = server.redacted(&HashMap::new());
+        let env = redacted.env.unwrap();
+        assert_eq!(env["GITHUB_TOKEN"], REDACTED_VALUE);
+        assert_eq!(env["NODE_ENV"], "prod");
+        // Original is untouched
+        assert_eq!(server.env.unwrap()["GITHUB_TOKEN"], "ghp_abc123");
+    }
+
+    #[test]
+    fn test_redacted_masks_authorization_header() {
+        let mut server = McpServer::http("https://example.com/mcp".into());
+        server.headers = Some(HashMap::from([(
+            "Authorization".to_string(),
+            "Bearer xyz".to_string(),
+        )]));
+
+        let redacted = server.redacted(&HashMap::new());
+        assert_eq!(redacted.headers.unwrap()["Authorization"], REDACTED_VALUE);
+    }
+
+    #[test]
+    fn test_redacted_respects_explicit_secret_flags() {
+        let server = server_with_env(&[("TOKEN_LIMIT", "4096"), ("WORKSPACE_ID", "ws-42")]);
+        let flags = HashMap::from([
+            ("TOKEN_LIMIT".to_string(), false),
+            ("WORKSPACE_ID".to_string(), true),
+        ]);
+
+        let env = server.redacted(&flags).env.unwrap();
+        assert_eq!(env["TOKEN_LIMIT"], "4096");
+        assert_eq!(env["WORKSPACE_ID"], REDACTED_VALUE);
+    }
+
+    #[test]
+    fn test_debug_never_prints_env_values() {
+        let server = server_with_env(&[("GITHUB_TOKEN", "ghp_abc123"), ("NODE_ENV", "prod")]);
+
+        let debug = format!("{:?}", server);
+        assert!(debug.contains("GITHUB_TOKEN"));
+        assert!(!debug.contains("ghp_abc123"));
+        assert!(!debug.contains("prod"));
+    }
+
+    #[test]
+    fn test_mcp_settings_secret_keys_default_empty() {
+        let json = r#"{"defaultTimeout": 1000, "retryAttempts": 1, "logLevel": "warn"}"#;
+        let settings: McpSettings = serde_json::from_str(json).unwrap();
+        assert!(settings.secret_keys.is_empty());
+
+        // Empty map is not written back
+        let out = serde_json::to_string(&settings).unwrap();
+        assert!(!out.contains("secretKeys"));
+    }
+
+    #[test]
+    fn test_redact_json_masks_nested_env_and_headers() {
+        let mut value = serde_json::json!({
+            "mcpServers": {
+                "github": { "command": "npx", "env": { "GITHUB_TOKEN": "ghp_abc", "DEBUG": "1" } },
+                "remote": { "url": "https://x", "headers": { "Authorization": "Bearer y" } }
+            }
+        });
+
+        redact_json(&mut value);
+
+        let servers = &value["mcpServers"];
+        assert_eq!(servers["github"]["env"]["GITHUB_TOKEN"], REDACTED_VALUE);
+        assert_eq!(servers["github"]["env"]["DEBUG"], "1");
+        assert_eq!(
+            servers["remote"]["headers"]["Authorization"],
+            REDACTED_VALUE
+        );
+    }
+
+    const PROJECT_MCP_JSON: &str = r#"{
+        "mcpServers": {
+            "github": {
+                "command": "npx",
+                "args": ["-y", "@modelcontextprotocol/server-github"],
+                "env": { "GITHUB_TOKEN": "ghp_project" }
+            },
+            "docs": { "type": "http", "url": "https://docs.example.com/mcp" },
+            "broken": { "args": ["no-command"] }
+        }
+    }"#;
+
+    #[test]
+    fn test_import_project_mcp_adds_valid_servers() {
+        use crate::test_utils::{TestEnv, ENV_MUTEX};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+        let project = tempfile::tempdir().unwrap();
+        fs::write(project.path().join(".mcp.json"), PROJECT_MCP_JSON).unwrap();
+
+        let result =
+            McpConfigManager::import_project_mcp(project.path(), McpMergeStrategy::default())
+                .unwrap();
+
+        assert_eq!(result.imported, vec!["docs", "github"]);
+        assert_eq!(result.invalid.len(), 1);
+        assert!(result.invalid[0].starts_with("broken:"));
+
+        let servers = McpConfigManager::list_servers().unwrap();
+        assert_eq!(servers.len(), 2);
+        assert!(servers["docs"].is_http());
+        assert_eq!(servers["docs"].transport.as_deref(), Some("http"));
+        assert_eq!(servers["github"].command.as_deref(), Some("npx"));
+
+        // A second import changes nothing
+        let again =
+            McpConfigManager::import_project_mcp(project.path(), McpMergeStrategy::default())
+                .unwrap();
+        assert!(again.imported.is_empty());
+        assert_eq!(again.unchanged, vec!["docs", "github"]);
+    }
+
+    #[test]
+    fn test_import_project_mcp_conflicts_follow_strategy() {
+        use crate::test_utils::{TestEnv, ENV_MUTEX};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+        McpConfigManager::add_server("github", McpServer::stdio("gh-mcp".into(), vec![])).unwrap();
+        let project = tempfile::tempdir().unwrap();
+        fs::write(project.path().join(".mcp.json"), PROJECT_MCP_JSON).unwrap();
+
+        let result =
+            McpConfigManager::import_project_mcp(project.path(), McpMergeStrategy::KeepExisting)
+                .unwrap();
+        assert_eq!(result.conflicts, vec!["github"]);
+        assert_eq!(result.imported, vec!["docs"]);
+        let github = McpConfigManager::get_server("github").unwrap().unwrap();
+        assert_eq!(github.command.as_deref(), Some("gh-mcp"));
+
+        let result =
+            McpConfigManager::import_project_mcp(project.path(), McpMergeStrategy::Overwrite)
+                .unwrap();
+        assert_eq!(result.conflicts, vec!["github"]);
+        assert_eq!(result.imported, vec!["github"]);
+        let github = McpConfigManager::get_server("github").unwrap().unwrap();
+        assert_eq!(github.command.as_deref(), Some("npx"));
+    }
+
+    #[test]
+    fn test_import_project_mcp_requires_file() {
+        let project = tempfile::tempdir().unwrap();
+        let result =
+            McpConfigManager::import_project_mcp(project.path(), McpMergeStrategy::default());
+        assert!(result.unwrap_err().to_string().contains("No .mcp.json"));
+    }
+
+    #[test]
+    fn test_validate_server_matrix() {
+        let with_command = |command: &str| McpServer::stdio(command.into(), vec![]);
+        let with_url = |url: &str| McpServer::http(url.into());
+        let mut both = with_command("npx");
+        both.url = Some("https://example.com/mcp".into());
+        let neither = McpServer {
+            command: None,
+            ..with_command("npx")
+        };
+
+        let cases: Vec<(&str, McpServer, Option<&str>)> = vec![
+            ("git", with_command("npx"), None),
+            ("docs", with_url("https://docs.example.com/mcp"), None),
+            ("local", with_url("http://localhost:3000/sse"), None),
+            ("", with_command("npx"), Some("name must not be empty")),
+            ("git", with_command(""), Some("must not be empty")),
+            ("git", with_command("   "), Some("must not be empty")),
+            ("docs", with_url("docs.example.com/mcp"), Some("malformed")),
+            ("docs", with_url("http://"), Some("malformed")),
+            (
+                "docs",
+                with_url("ftp://example.com/mcp"),
+                Some("http or https"),
+            ),
+            ("both", both, Some("both `command` and `url`")),
+            ("none", neither, Some("needs a `command`")),
+        ];
+
+        for (name, server, expected) in cases {
+            let result = McpConfigManager::validate_server(name, &server);
+            match expected {
+                None => assert!(result.is_ok(), "{:?} should be valid", server),
+                Some(fragment) => {
+                    let err = result.unwrap_err();
+                    assert!(matches!(err, RhinolabsError::ConfigError(_)));
+                    assert!(
+                        err.to_string().contains(fragment),
+                        "'{}' should mention '{}'",
+                        err,
+                        fragment
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_server_rejects_invalid_and_duplicate() {
+        use crate::test_utils::{TestEnv, ENV_MUTEX};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+
+        let err =
+            McpConfigManager::add_server("docs", McpServer::http("not a url".into())).unwrap_err();
+        assert!(err.to_string().contains("Invalid MCP server 'docs'"));
+        assert!(McpConfigManager::list_servers().unwrap().is_empty());
+
+        McpConfigManager::add_server("git", McpServer::stdio("npx".into(), vec![])).unwrap();
+        let err = McpConfigManager::add_server("git", McpServer::stdio("uvx".into(), vec![]))
+            .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        let err = McpConfigManager::update_server("git", McpServer::stdio("".into(), vec![]))
+            .unwrap_err();
+        assert!(err.to_string().contains("must not be empty"));
+    }
+
+    #[test]
+    fn test_validate_json_rejects_duplicate_and_invalid_servers() {
+        let valid = r#"{"mcpServers": {
+            "git": { "command": "npx", "args": ["server-git"] },
+            "docs": { "url": "https://docs.example.com/mcp", "transport": "sse" }
+        }}"#;
+        assert!(McpConfigManager::validate_json(valid).is_ok());
+
+        let duplicate = r#"{"mcpServers": {
+            "git": { "command": "npx" },
+            "git": { "command": "uvx" }
+        }}"#;
+        let err = McpConfigManager::validate_json(duplicate).unwrap_err();
+        assert!(err.to_string().contains("Duplicate MCP server name 'git'"));
+
+        let malformed = r#"{"mcpServers": { "docs": { "url": "::nope::" } }}"#;
+        let err = McpConfigManager::validate_json(malformed).unwrap_err();
+        assert!(err.to_string().contains("Invalid MCP server 'docs'"));
+    }
+
+    fn server_with_placeholders() -> McpServer {
+        let mut server = McpServer::stdio(
+            "npx".into(),
+            vec!["--token=${RHINOLABS_TEST_MCP_TOKEN}".into()],
+        );
+        server.env = Some(HashMap::from([
+            (
+                "GITHUB_TOKEN".to_string(),
+                "${RHINOLABS_TEST_MCP_TOKEN}".to_string(),
+            ),
+            (
+                "REGION".to_string(),
+                "${RHINOLABS_TEST_MCP_UNSET:-eu-west-1}".to_string(),
+            ),
+            (
+                "HOST".to_string(),
+                "${RHINOLABS_TEST_MCP_UNSET}".to_string(),
+            ),
+        ]));
+        server
+    }
+
+    #[test]
+    fn test_resolve_env_expands_placeholders() {
+        use crate::test_utils::ENV_MUTEX;
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        std::env::set_var("RHINOLABS_TEST_MCP_TOKEN", "ghp_secret");
+        std::env::remove_var("RHINOLABS_TEST_MCP_UNSET");
+
+        let mut config = McpConfig::default();
+        config
+            .mcp_servers
+            .insert("github".into(), server_with_placeholders());
+
+        let resolved = McpConfigManager::resolve_env(&config).unwrap();
+        let server = &resolved.mcp_servers["github"];
+        let env = server.env.as_ref().unwrap();
+        assert_eq!(server.args, vec!["--token=ghp_secret"]);
+        assert_eq!(env["GITHUB_TOKEN"], "ghp_secret");
+        assert_eq!(env["REGION"], "eu-west-1");
+        // Non-strict: unset variables keep their placeholder
+        assert_eq!(env["HOST"], "${RHINOLABS_TEST_MCP_UNSET}");
+
+        config.settings.strict_env = true;
+        let err = McpConfigManager::resolve_env(&config).unwrap_err();
+        assert!(err.to_string().contains(
+            "'github' references unset environment variable(s): RHINOLABS_TEST_MCP_UNSET"
+        ));
+
+        std::env::remove_var("RHINOLABS_TEST_MCP_TOKEN");
+    }
+
+    #[test]
+    fn test_stored_config_keeps_placeholders() {
+        use crate::test_utils::{TestEnv, ENV_MUTEX};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+        std::env::set_var("RHINOLABS_TEST_MCP_TOKEN", "ghp_secret");
+
+        McpConfigManager::add_server("github", server_with_placeholders()).unwrap();
+
+        let on_disk = fs::read_to_string(McpConfigManager::config_path().unwrap()).unwrap();
+        assert!(on_disk.contains("${RHINOLABS_TEST_MCP_TOKEN}"));
+        assert!(!on_disk.contains("ghp_secret"));
+
+        let resolved = McpConfigManager::get_resolved().unwrap();
+        assert_eq!(
+            resolved.mcp_servers["github"].env.as_ref().unwrap()["GITHUB_TOKEN"],
+            "ghp_secret"
+        );
+
+        std::env::remove_var("RHINOLABS_TEST_MCP_TOKEN");
+    }
+
+    #[test]
+    fn test_disabled_server_kept_but_not_deployed() {
+        use crate::test_utils::{TestEnv, ENV_MUTEX};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+
+        McpConfigManager::add_server("git", McpServer::stdio("npx".into(), vec![])).unwrap();
+        McpConfigManager::add_server("docs", McpServer::http("https://docs.example.com".into()))
+            .unwrap();
+        McpConfigManager::set_enabled("docs", false).unwrap();
+
+        let on_disk: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(McpConfigManager::config_path().unwrap()).unwrap(),
+        )
+        .unwrap();
+        assert!(on_disk["mcpServers"].get("git").is_some());
+        assert!(on_disk["mcpServers"].get("docs").is_none());
+        assert!(on_disk[DISABLED_SERVERS_KEY].get("docs").is_some());
+
+        let servers = McpConfigManager::list_servers().unwrap();
+        assert!(servers["git"].enabled);
+        assert!(!servers["docs"].enabled);
+
+        McpConfigManager::set_enabled("docs", true).unwrap();
+        let on_disk = fs::read_to_string(McpConfigManager::config_path().unwrap()).unwrap();
+        assert!(!on_disk.contains(DISABLED_SERVERS_KEY));
+        assert!(
+            McpConfigManager::get_server("docs")
+                .unwrap()
+                .unwrap()
+                .enabled
+        );
+
+        assert!(McpConfigManager::set_enabled("missing", false).is_err());
+    }
+
+    #[test]
+    fn test_server_without_enabled_field_defaults_to_enabled() {
+        let server: McpServer = serde_json::from_str(r#"{"command": "npx"}"#).unwrap();
+        assert!(server.enabled);
+        // Enabled is the default, so it isn't written out
+        assert!(!serde_json::to_string(&server).unwrap().contains("enabled"));
+    }
 }
@@ -1,15 +1,34 @@
-use crate::{Paths, Result, RhinolabsError};
+use crate::{
+    DeployTarget, GenericDeployer, McpConfigManager, McpDeployer, Paths, Result, RhinolabsError,
+    Warnings,
+};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fs;
+use std::path::Path;
 
 pub enum McpSource {
     Remote(String),
     Local(String),
 }
 
+/// Where a sync wrote the MCP servers
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpSyncReport {
+    /// Targets whose MCP config was written (or would be, in a dry run)
+    pub deployed: Vec<DeployTarget>,
+    /// Requested targets without MCP support
+    pub skipped: Vec<DeployTarget>,
+    /// Non-fatal problems, e.g. secrets written in plain text to a target's config
+    #[serde(default)]
+    pub warnings: Warnings,
+}
+
 pub struct McpSync {
     source: McpSource,
     dry_run: bool,
+    targets: Vec<DeployTarget>,
 }
 
 impl McpSync {
@@ -17,6 +36,7 @@ impl McpSync {
         Self {
             source: McpSource::Remote(url),
             dry_run: false,
+            targets: vec![DeployTarget::ClaudeCode],
         }
     }
 
@@ -24,16 +44,24 @@ impl McpSync {
         Self {
             source: McpSource::Local(path),
             dry_run: false,
+            targets: vec![DeployTarget::ClaudeCode],
         }
     }
 
+    /// Also deploy the synced servers to these targets. The managed config, which
+    /// Claude Code reads, is always updated.
+    pub fn targets(mut self, targets: Vec<DeployTarget>) -> Self {
+        self.targets = targets;
+        self
+    }
+
     pub fn dry_run(mut self, enabled: bool) -> Self {
         self.dry_run = enabled;
         self
     }
 
     /// Sync MCP configuration
-    pub async fn sync(&self) -> Result<()> {
+    pub async fn sync(&self) -> Result<McpSyncReport> {
         // Check if plugin is installed
         if !Paths::is_plugin_installed() {
             return Err(RhinolabsError::PluginNotInstalled);
@@ -45,28 +73,68 @@ impl McpSync {
             McpSource::Local(path) => self.read_local(path)?,
         };
 
-        // Validate JSON
-        let _config: Value = serde_json::from_str(&config_json)?;
+        // Validate JSON and every server definition
+        let mut config: Value = serde_json::from_str(&config_json)?;
+        McpConfigManager::validate_json(&config_json)?;
+
+        // Servers the user disabled stay disabled
+        McpConfigManager::carry_over_disabled(&mut config)?;
 
         if self.dry_run {
+            // Never echo secrets from the incoming config
+            let mut preview = config;
+            crate::mcp_config::redact_json(&mut preview);
             println!("[DRY RUN] Would update MCP configuration");
-            println!("{}", config_json);
-            return Ok(());
+            println!("{}", serde_json::to_string_pretty(&preview)?);
+            return Ok(self.plan());
         }
 
-        // Backup current config
-        self.backup_current_config()?;
+        // Write new config, backing up the current one
+        crate::paths::atomic_write(
+            &Paths::mcp_config_path()?,
+            serde_json::to_string_pretty(&config)?,
+            true,
+        )?;
+
+        let mut report = self.plan();
+        let config = McpConfigManager::get()?;
+        for target in &report.deployed {
+            if *target != DeployTarget::ClaudeCode {
+                let warnings = GenericDeployer::new(*target).deploy_mcp(&config)?;
+                for warning in &warnings {
+                    report.warnings.push(warning.clone());
+                }
+            }
+        }
 
-        // Write new config
-        let config_path = Paths::mcp_config_path()?;
-        fs::write(config_path, config_json)?;
+        Ok(report)
+    }
 
-        Ok(())
+    /// Split the requested targets into those that take MCP servers and those skipped
+    fn plan(&self) -> McpSyncReport {
+        let mut report = McpSyncReport::default();
+        for target in &self.targets {
+            if report.deployed.contains(target) || report.skipped.contains(target) {
+                continue;
+            }
+            if target.supports_mcp() {
+                report.deployed.push(*target);
+            } else {
+                tracing::info!(
+                    "skipping MCP sync for {}: the target has no MCP support",
+                    target.display_name()
+                );
+                report.skipped.push(*target);
+            }
+        }
+        report
     }
 
     /// Fetch configuration from remote URL
     async fn fetch_remote(&self, url: &str) -> Result<String> {
-        let response = reqwest::get(url).await?;
+        let response = crate::network::send(crate::network::client()?.get(url))
+            .await
+            .map_err(crate::network::request_error)?;
 
         if !response.status().is_success() {
             return Err(RhinolabsError::McpSyncFailed(format!(
@@ -79,29 +147,36 @@ impl McpSync {
         Ok(text)
     }
 
-    /// Read configuration from local file
+    /// Read configuration from local file. YAML and TOML files are converted to
+    /// JSON, picked by extension; other extensions are tried as JSON, then YAML.
     fn read_local(&self, path: &str) -> Result<String> {
         let content = fs::read_to_string(path)?;
-        Ok(content)
-    }
-
-    /// Backup current MCP config
-    fn backup_current_config(&self) -> Result<()> {
-        let config_path = Paths::mcp_config_path()?;
-
-        if config_path.exists() {
-            let backup_path = config_path
-                .parent()
-                .ok_or_else(|| RhinolabsError::Other("Invalid config path".into()))?
-                .join(format!(
-                    ".mcp.json.backup.{}",
-                    chrono::Utc::now().format("%Y%m%d_%H%M%S")
-                ));
-
-            fs::copy(&config_path, &backup_path)?;
-        }
-
-        Ok(())
+        let extension = Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_lowercase);
+
+        let config: Value = match extension.as_deref() {
+            Some("json") => return Ok(content),
+            Some("yaml" | "yml") => serde_yaml::from_str(&content).map_err(|e| {
+                RhinolabsError::McpSyncFailed(format!("Invalid YAML in {}: {}", path, e))
+            })?,
+            Some("toml") => toml::from_str(&content).map_err(|e| {
+                RhinolabsError::McpSyncFailed(format!("Invalid TOML in {}: {}", path, e))
+            })?,
+            _ => {
+                if serde_json::from_str::<Value>(&content).is_ok() {
+                    return Ok(content);
+                }
+                serde_yaml::from_str(&content).map_err(|e| {
+                    RhinolabsError::McpSyncFailed(format!(
+                        "{} is neither JSON nor YAML: {}",
+                        path, e
+                    ))
+                })?
+            }
+        };
+        Ok(serde_json::to_string_pretty(&config)?)
     }
 }
 
@@ -195,4 +270,115 @@ mod tests {
         // Dry run should not fail for JSON validation
         // Note: Will still fail if plugin not installed, but that's expected
     }
+
+    #[test]
+    fn test_plan_skips_targets_without_mcp() {
+        let sync = McpSync::from_local("mcp.json".into()).targets(vec![
+            DeployTarget::Amp,
+            crate::test_utils::acme_target(),
+            DeployTarget::Amp,
+        ]);
+
+        let report = sync.plan();
+        assert_eq!(report.deployed, vec![DeployTarget::Amp]);
+        assert_eq!(report.skipped, vec![crate::test_utils::acme_target()]);
+    }
+
+    #[test]
+    fn test_read_local_converts_yaml_and_toml() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let files = [
+            (
+                "mcp.yaml",
+                "mcpServers:\n  git:\n    command: npx\n    args: [\"-y\", \"git-mcp\"]\n",
+            ),
+            (
+                "mcp.toml",
+                "[mcpServers.git]\ncommand = \"npx\"\nargs = [\"-y\", \"git-mcp\"]\n",
+            ),
+            // Unknown extension: not JSON, so read as YAML
+            (
+                "mcp.conf",
+                "mcpServers:\n  git:\n    command: npx\n    args: [\"-y\", \"git-mcp\"]\n",
+            ),
+        ];
+
+        for (name, content) in files {
+            let path = temp_dir.path().join(name);
+            fs::write(&path, content).unwrap();
+            let path = path.to_str().unwrap();
+
+            let json = McpSync::from_local(path.into()).read_local(path).unwrap();
+            McpConfigManager::validate_json(&json).unwrap();
+            let config: Value = serde_json::from_str(&json).unwrap();
+            assert_eq!(config["mcpServers"]["git"]["command"], "npx", "{}", name);
+            assert_eq!(
+                config["mcpServers"]["git"]["args"][1], "git-mcp",
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_sync_keeps_disabled_servers_disabled() {
+        use crate::test_utils::{TestEnv, ENV_MUTEX};
+        use crate::McpServer;
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+
+        McpConfigManager::add_server("git", McpServer::stdio("npx".into(), vec![])).unwrap();
+        McpConfigManager::add_server("docs", McpServer::http("https://old.example.com".into()))
+            .unwrap();
+        McpConfigManager::add_server("local", McpServer::stdio("./local".into(), vec![])).unwrap();
+        McpConfigManager::set_enabled("docs", false).unwrap();
+        McpConfigManager::set_enabled("local", false).unwrap();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let incoming = temp_dir.path().join("mcp.json");
+        fs::write(
+            &incoming,
+            r#"{ "mcpServers": {
+                "git": { "command": "npx", "args": ["-y", "git-mcp"] },
+                "docs": { "url": "https://new.example.com" }
+            } }"#,
+        )
+        .unwrap();
+
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(McpSync::from_local(incoming.to_str().unwrap().into()).sync())
+            .unwrap();
+
+        let servers = McpConfigManager::list_servers().unwrap();
+        assert!(servers["git"].enabled);
+        assert_eq!(servers["git"].args, vec!["-y", "git-mcp"]);
+        // The synced definition is taken, but the server stays disabled
+        assert!(!servers["docs"].enabled);
+        assert_eq!(
+            servers["docs"].url.as_deref(),
+            Some("https://new.example.com")
+        );
+        // A disabled server the sync doesn't know about is kept
+        assert!(!servers["local"].enabled);
+
+        let on_disk: Value =
+            serde_json::from_str(&fs::read_to_string(Paths::mcp_config_path().unwrap()).unwrap())
+                .unwrap();
+        assert!(on_disk["mcpServers"].get("docs").is_none());
+    }
+
+    #[test]
+    fn test_read_local_reports_invalid_toml() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("mcp.toml");
+        fs::write(&path, "mcpServers = [").unwrap();
+        let path = path.to_str().unwrap();
+
+        let err = McpSync::from_local(path.into())
+            .read_local(path)
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid TOML"));
+    }
 }
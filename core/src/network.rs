@@ -0,0 +1,277 @@
+//! Offline mode: a process-wide switch (set by the CLI's `--offline` flag or the
+//! `RHINOLABS_OFFLINE` env var) that makes every network call fail fast, plus the
+//! shared HTTP client with its timeouts, proxy settings and retry policy.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use crate::{Result, RhinolabsError};
+
+/// Env var that enables offline mode when set to `1`, `true`, `yes` or `on`
+pub const OFFLINE_ENV: &str = "RHINOLABS_OFFLINE";
+
+/// Env var with a proxy URL for every request. Takes precedence over
+/// `HTTPS_PROXY`/`HTTP_PROXY`; hosts in `NO_PROXY` still connect directly.
+pub const PROXY_ENV: &str = "RHINOLABS_PROXY";
+
+/// Timeout for establishing a connection, applied to every request
+pub const HTTP_CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Timeout for a whole request (including the body) sent with [`send_with_retry`]
+pub const HTTP_REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// How many times [`send_with_retry`] retries a transient failure
+pub const HTTP_MAX_RETRIES: u32 = 2;
+
+/// Delay before the first retry; doubled for every further attempt
+const RETRY_BASE_DELAY: Duration = if cfg!(test) {
+    Duration::from_millis(1)
+} else {
+    Duration::from_millis(500)
+};
+
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable offline mode for this process
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+/// Whether network access is disabled, by `set_offline` or `RHINOLABS_OFFLINE`
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed) || env_enables(std::env::var(OFFLINE_ENV).ok().as_deref())
+}
+
+/// Fail with an "offline" error when offline mode is on
+pub fn ensure_online() -> Result<()> {
+    if is_offline() {
+        return Err(RhinolabsError::NetworkError(format!(
+            "offline mode is enabled (--offline or {}=1); no network requests are made",
+            OFFLINE_ENV
+        )));
+    }
+    Ok(())
+}
+
+/// HTTP client for outgoing requests; errors immediately in offline mode.
+///
+/// Only the connect timeout is set here, so large downloads are not cut off;
+/// API calls get a whole-request timeout from [`send_with_retry`].
+pub fn client() -> Result<reqwest::Client> {
+    ensure_online()?;
+    client_builder()?
+        .build()
+        .map_err(|e| RhinolabsError::NetworkError(e.to_string()))
+}
+
+/// Client builder with the shared connect timeout and proxy settings, for callers
+/// that need to tune it further. Without `RHINOLABS_PROXY` the standard
+/// `HTTPS_PROXY`/`HTTP_PROXY`/`NO_PROXY` variables apply.
+pub fn client_builder() -> Result<reqwest::ClientBuilder> {
+    let builder = reqwest::Client::builder().connect_timeout(HTTP_CONNECT_TIMEOUT);
+    Ok(
+        match explicit_proxy(std::env::var(PROXY_ENV).ok().as_deref())? {
+            Some(proxy) => builder.proxy(proxy),
+            None => builder,
+        },
+    )
+}
+
+/// Turn a failed request into a `NetworkError`; connection failures get a hint
+/// about the proxy variables, since a missing proxy is the usual cause.
+pub fn request_error(e: reqwest::Error) -> RhinolabsError {
+    if e.is_connect() {
+        return RhinolabsError::NetworkError(format!(
+            "{} (behind a proxy? set HTTPS_PROXY/HTTP_PROXY and NO_PROXY, or {} to override them)",
+            e, PROXY_ENV
+        ));
+    }
+    RhinolabsError::NetworkError(e.to_string())
+}
+
+/// Send a request once, logging its URL and response status (`-v` in the CLI)
+pub(crate) async fn send(request: reqwest::RequestBuilder) -> reqwest::Result<reqwest::Response> {
+    let (client, request) = request.build_split();
+    execute(&client, request?).await
+}
+
+/// Send a request with [`HTTP_REQUEST_TIMEOUT`], retrying up to [`HTTP_MAX_RETRIES`]
+/// times with exponential backoff on 5xx, 429, timeouts and connection errors.
+///
+/// Any other response (including 404) is returned as-is on the first attempt.
+pub(crate) async fn send_with_retry(
+    request: reqwest::RequestBuilder,
+) -> reqwest::Result<reqwest::Response> {
+    let (client, request) = request.timeout(HTTP_REQUEST_TIMEOUT).build_split();
+    let mut request = request?;
+    let mut attempt = 0;
+    loop {
+        // Streaming bodies can't be cloned; such requests are sent once
+        let retry = request.try_clone();
+        let outcome = execute(&client, request).await;
+        let transient = match &outcome {
+            Ok(response) => is_transient(response.status()),
+            Err(e) => e.is_timeout() || e.is_connect(),
+        };
+
+        match retry {
+            Some(next) if transient && attempt < HTTP_MAX_RETRIES => {
+                let delay = RETRY_BASE_DELAY * 2u32.pow(attempt);
+                tracing::debug!(
+                    "retrying {} in {:?} (attempt {} of {})",
+                    log_url(next.url()),
+                    delay,
+                    attempt + 1,
+                    HTTP_MAX_RETRIES
+                );
+                tokio::time::sleep(delay).await;
+                request = next;
+                attempt += 1;
+            }
+            _ => return outcome,
+        }
+    }
+}
+
+async fn execute(
+    client: &reqwest::Client,
+    request: reqwest::Request,
+) -> reqwest::Result<reqwest::Response> {
+    let method = request.method().clone();
+    let url = log_url(request.url());
+    tracing::info!("{} {}", method, url);
+
+    let outcome = client.execute(request).await;
+    match &outcome {
+        Ok(response) => tracing::info!("{} {} -> {}", method, url, response.status()),
+        Err(e) => tracing::info!("{} {} failed: {}", method, url, e),
+    }
+    outcome
+}
+
+/// The URL without its query string, which may carry signatures (presigned URLs)
+fn log_url(url: &reqwest::Url) -> String {
+    let mut url = url.clone();
+    url.set_query(None);
+    url.to_string()
+}
+
+fn is_transient(status: reqwest::StatusCode) -> bool {
+    status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+}
+
+fn explicit_proxy(value: Option<&str>) -> Result<Option<reqwest::Proxy>> {
+    let Some(url) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    let proxy = reqwest::Proxy::all(url)
+        .map_err(|e| RhinolabsError::ConfigError(format!("Invalid {}: {}", PROXY_ENV, e)))?;
+    Ok(Some(proxy.no_proxy(reqwest::NoProxy::from_env())))
+}
+
+fn env_enables(value: Option<&str>) -> bool {
+    matches!(
+        value.map(|v| v.trim().to_ascii_lowercase()).as_deref(),
+        Some("1" | "true" | "yes" | "on")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_enables_truthy_values_only() {
+        for value in ["1", "true", "YES", " on "] {
+            assert!(env_enables(Some(value)), "{}", value);
+        }
+        for value in ["", "0", "false", "off"] {
+            assert!(!env_enables(Some(value)), "{}", value);
+        }
+        assert!(!env_enables(None));
+    }
+
+    #[test]
+    fn test_explicit_proxy_parses_rhinolabs_proxy() {
+        assert!(explicit_proxy(None).unwrap().is_none());
+        assert!(explicit_proxy(Some("  ")).unwrap().is_none());
+        assert!(explicit_proxy(Some("http://proxy.corp:3128"))
+            .unwrap()
+            .is_some());
+
+        let err = explicit_proxy(Some("not a url")).unwrap_err();
+        assert!(err.to_string().contains(PROXY_ENV));
+    }
+
+    #[tokio::test]
+    async fn test_request_error_mentions_proxy_on_connect_failure() {
+        // Bind and drop a listener so the port is closed
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let err = reqwest::Client::new()
+            .get(format!("http://127.0.0.1:{}", port))
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(request_error(err).to_string().contains(PROXY_ENV));
+    }
+
+    #[tokio::test]
+    async fn test_send_with_retry_retries_transient_errors() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = reqwest::Client::new();
+        let response = send_with_retry(client.get(format!("{}/flaky", server.uri())))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.text().await.unwrap(), "ok");
+    }
+
+    #[test]
+    fn test_log_url_drops_query() {
+        let url =
+            reqwest::Url::parse("https://r2.example.com/b/x.zip?X-Amz-Signature=abc").unwrap();
+        assert_eq!(log_url(&url), "https://r2.example.com/b/x.zip");
+    }
+
+    #[tokio::test]
+    async fn test_send_with_retry_does_not_retry_not_found() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = reqwest::Client::new();
+        let response = send_with_retry(client.get(server.uri())).await.unwrap();
+
+        assert_eq!(response.status(), 404);
+    }
+}
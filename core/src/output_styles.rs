@@ -57,8 +57,9 @@ impl OutputStyles {
         Ok((frontmatter, markdown_content.to_string()))
     }
 
-    /// Generate frontmatter and content for writing
-    fn generate_style_file(style: &OutputStyle) -> Result<String> {
+    /// Generate frontmatter and content for writing; this is also the file
+    /// deployed to Claude Code's `output-styles/`
+    pub(crate) fn generate_style_file(style: &OutputStyle) -> Result<String> {
         let frontmatter = OutputStyleFrontmatter {
             name: style.name.clone(),
             description: style.description.clone(),
@@ -146,6 +147,14 @@ impl OutputStyles {
         Ok(Some(Self::load_from_path(&path)?))
     }
 
+    /// The file a profile install deploys for output style `id`, as it would be written
+    pub fn render(id: &str) -> Result<String> {
+        let style = Self::get(id)?.ok_or_else(|| {
+            RhinolabsError::ConfigError(format!("Output style '{}' not found", id))
+        })?;
+        Self::generate_style_file(&style)
+    }
+
     /// Get the currently active output style
     pub fn get_active() -> Result<Option<OutputStyle>> {
         let settings = Settings::get()?;
@@ -201,7 +210,7 @@ impl OutputStyles {
         };
 
         let file_content = Self::generate_style_file(&style)?;
-        fs::write(&path, file_content)?;
+        crate::fs_utils::write(&path, file_content)?;
 
         Ok(style)
     }
@@ -233,7 +242,7 @@ impl OutputStyles {
 
         let path = Self::styles_dir()?.join(Self::id_to_filename(id));
         let file_content = Self::generate_style_file(&style)?;
-        fs::write(&path, file_content)?;
+        crate::fs_utils::write(&path, file_content)?;
 
         Ok(())
     }
@@ -384,6 +393,20 @@ description: No keep-coding field
         assert!(file_content.contains("# Content"));
     }
 
+    #[test]
+    fn test_render_produces_the_deployed_file() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_styles_dir();
+        env.create_style("terse", "Terse", "Short answers", true, "Be brief.");
+
+        assert_eq!(
+            OutputStyles::render("terse").unwrap(),
+            "---\nname: Terse\ndescription: Short answers\nkeep-coding-instructions: true\n---\n\nBe brief."
+        );
+        assert!(OutputStyles::render("missing").is_err());
+    }
+
     // ============================================
     // get_style_path() Tests
     // ============================================
@@ -1,4 +1,6 @@
 use crate::{Result, RhinolabsError};
+use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
 /// Platform-specific path resolution
@@ -129,6 +131,119 @@ impl Paths {
     }
 }
 
+/// Write `contents` to `path` through a temp file in the same directory and a rename,
+/// so a failed write never leaves a truncated file behind. Parent directories are
+/// created, and a symlinked `path` is written through to its target.
+///
+/// With `backup`, an existing file is first copied to `<name>.backup.<timestamp>`
+/// (see [`backup_file`]); the backup's path is returned.
+pub(crate) fn atomic_write(
+    path: &Path,
+    contents: impl AsRef<[u8]>,
+    backup: bool,
+) -> Result<Option<PathBuf>> {
+    let is_symlink = fs::symlink_metadata(path)
+        .map(|meta| meta.file_type().is_symlink())
+        .unwrap_or(false);
+    let path = if is_symlink {
+        fs::canonicalize(path)?
+    } else {
+        path.to_path_buf()
+    };
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    fs::create_dir_all(dir)?;
+
+    let existing = fs::metadata(&path).ok();
+    let backup_path = match &existing {
+        Some(_) if backup => Some(backup_file(&path)?),
+        _ => None,
+    };
+
+    let mut temp = tempfile::NamedTempFile::new_in(dir)?;
+    temp.write_all(contents.as_ref())?;
+    temp.as_file().sync_all()?;
+    if let Some(meta) = existing {
+        fs::set_permissions(temp.path(), meta.permissions())?;
+    }
+    temp.persist(&path).map_err(|e| e.error)?;
+    tracing::info!("wrote {}", path.display());
+
+    Ok(backup_path)
+}
+
+/// How many backups `atomic_write` keeps of each file
+const BACKUPS_KEPT: usize = 5;
+
+/// Copy `path` to `<name>.backup.<YYYYMMDD_HHMMSS>`, adding `-<n>` after the
+/// newest backup from the same second, and delete all but the newest `BACKUPS_KEPT`.
+fn backup_file(path: &Path) -> Result<PathBuf> {
+    let stamp = chrono::Utc::now().format("%Y%m%d_%H%M%S").to_string();
+    let mut counter = list_backups(path)?
+        .iter()
+        .filter(|((s, _), _)| *s == stamp)
+        .map(|((_, n), _)| n + 1)
+        .max()
+        .unwrap_or(0);
+
+    let (backup_path, mut file) = loop {
+        let suffix = match counter {
+            0 => format!("backup.{}", stamp),
+            n => format!("backup.{}-{}", stamp, n),
+        };
+        let candidate = crate::fs_utils::sibling_with_suffix(path, &suffix);
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&candidate)
+        {
+            Ok(file) => break (candidate, file),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => counter += 1,
+            Err(e) => return Err(e.into()),
+        }
+    };
+    std::io::copy(&mut fs::File::open(path)?, &mut file)?;
+    fs::set_permissions(&backup_path, fs::metadata(path)?.permissions())?;
+
+    // Pruning is best-effort; an extra backup is better than a failed write
+    if let Ok(backups) = list_backups(path) {
+        let excess = backups.len().saturating_sub(BACKUPS_KEPT);
+        for (_, old) in backups.into_iter().take(excess) {
+            let _ = fs::remove_file(old);
+        }
+    }
+    Ok(backup_path)
+}
+
+/// Backups of `path`, oldest first: ordered by stamp, then by the same-second counter
+fn list_backups(path: &Path) -> Result<Vec<((String, u32), PathBuf)>> {
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let prefix = format!(
+        "{}.backup.",
+        path.file_name().unwrap_or_default().to_string_lossy()
+    );
+
+    let mut backups: Vec<((String, u32), PathBuf)> = fs::read_dir(dir)?
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let suffix = name.strip_prefix(&prefix)?;
+            let key = match suffix.split_once('-') {
+                Some((stamp, counter)) => (stamp.to_string(), counter.parse().ok()?),
+                None => (suffix.to_string(), 0),
+            };
+            Some((key, entry.path()))
+        })
+        .collect();
+    backups.sort();
+    Ok(backups)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,4 +359,58 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_atomic_write_replaces_file_and_backs_up() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("nested").join("config.json");
+
+        assert_eq!(atomic_write(&path, "{\"v\": 1}", true).unwrap(), None);
+        let backup = atomic_write(&path, "{\"v\": 2}", true).unwrap().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"v\": 2}");
+        assert_eq!(fs::read_to_string(&backup).unwrap(), "{\"v\": 1}");
+        let name = backup.file_name().unwrap().to_str().unwrap();
+        assert!(name.starts_with("config.json.backup."));
+        // Only the file and its backup remain; no temp files are left behind
+        assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn test_atomic_write_keeps_unique_backups_up_to_the_cap() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join(".mcp.json");
+
+        atomic_write(&path, "0", false).unwrap();
+        let backups: Vec<PathBuf> = (1..=BACKUPS_KEPT + 2)
+            .map(|n| atomic_write(&path, n.to_string(), true).unwrap().unwrap())
+            .collect();
+
+        // Writes within the same second don't overwrite each other's backups
+        let remaining: Vec<PathBuf> = backups.iter().filter(|b| b.exists()).cloned().collect();
+        assert_eq!(remaining.len(), BACKUPS_KEPT);
+        assert_eq!(remaining, backups[2..]);
+        assert_eq!(
+            fs::read_to_string(backups.last().unwrap()).unwrap(),
+            (BACKUPS_KEPT + 1).to_string()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_atomic_write_keeps_symlinks() {
+        let temp = tempfile::tempdir().unwrap();
+        let real = temp.path().join("real.json");
+        let link = temp.path().join("link.json");
+        fs::write(&real, "old").unwrap();
+        std::os::unix::fs::symlink(&real, &link).unwrap();
+
+        atomic_write(&link, "new", false).unwrap();
+
+        assert!(fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(fs::read_to_string(&real).unwrap(), "new");
+    }
 }
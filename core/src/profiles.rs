@@ -1,12 +1,19 @@
 use crate::{
-    fs_utils, targets::TargetPaths, DeployTarget, InstructionsManager, OutputStyle, OutputStyles,
-    Paths, Result, RhinolabsError, Settings, Skill, Skills,
+    fs_utils,
+    install_manifest::{InstallManifest, InstalledTarget},
+    instructions::{self, SKILLS_SECTION},
+    targets::{TargetPaths, OPENCODE_INSTRUCTIONS_FILE},
+    DeployTarget, InstructionsManager, OutputStyle, OutputStyles, Paths, Result, RhinolabsError,
+    Settings, Skill, Skills, Warnings,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// JSON schema referenced by generated `opencode.json` files
+const OPENCODE_CONFIG_SCHEMA: &str = "https://opencode.ai/config.json";
+
 // ============================================
 // Profile Types
 // ============================================
@@ -61,6 +68,21 @@ pub struct Profile {
     /// Generate AGENTS.md as master file
     #[serde(default)]
     pub generate_agents: bool,
+    /// Generate GEMINI.md even when Antigravity is not a deploy target
+    #[serde(default)]
+    pub generate_gemini: bool,
+    /// Generate opencode.json even when OpenCode is not a deploy target
+    #[serde(default)]
+    pub generate_opencode: bool,
+    /// Free-form labels for organizing profiles (lowercase, deduplicated)
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Parent profile whose skills, rules and instructions this profile inherits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+    /// Output style installed with this User profile instead of the globally active one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_style: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -69,6 +91,26 @@ fn default_true() -> bool {
     true
 }
 
+/// Trim, lowercase and deduplicate tags, keeping first-seen order
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if !tag.is_empty() && !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    normalized
+}
+
+impl Profile {
+    /// Whether the profile carries `tag` (compared case-insensitively)
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = tag.trim().to_lowercase();
+        self.tags.contains(&tag)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateProfileInput {
@@ -84,6 +126,12 @@ pub struct CreateProfileInput {
     pub generate_copilot: bool,
     #[serde(default)]
     pub generate_agents: bool,
+    #[serde(default)]
+    pub generate_gemini: bool,
+    #[serde(default)]
+    pub generate_opencode: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,6 +143,10 @@ pub struct UpdateProfileInput {
     pub instructions: Option<String>,
     pub generate_copilot: Option<bool>,
     pub generate_agents: Option<bool>,
+    pub generate_gemini: Option<bool>,
+    pub generate_opencode: Option<bool>,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -116,6 +168,31 @@ struct GeneratedAiContent {
 // Installation Result
 // ============================================
 
+/// How `Profiles::install` deploys a profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstallOptions {
+    /// Write `.claude-plugin/plugin.json` for ClaudeCode project installs
+    pub create_plugin_manifest: bool,
+    /// Leave globally disabled skills out instead of deploying them anyway
+    pub only_enabled: bool,
+    /// Redeploy skills even when unchanged since the last install
+    pub force: bool,
+    /// Replace existing instructions files that have no managed section markers
+    /// (see `Profiles::update_installed`) instead of leaving them alone
+    pub overwrite_instructions: bool,
+}
+
+impl Default for InstallOptions {
+    fn default() -> Self {
+        Self {
+            create_plugin_manifest: true,
+            only_enabled: false,
+            force: false,
+            overwrite_instructions: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProfileInstallResult {
@@ -123,6 +200,13 @@ pub struct ProfileInstallResult {
     pub profile_name: String,
     pub target_path: String,
     pub skills_installed: Vec<String>,
+    /// Installed skills that were left as is on every target because their source did
+    /// not change since the last install (a subset of `skills_installed`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skills_unchanged: Vec<String>,
+    /// Skills actually redeployed, per installed target
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skills_refreshed: Vec<TargetSkills>,
     pub skills_failed: Vec<SkillInstallError>,
     /// For Main-Profile: indicates if instructions were installed
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -133,9 +217,21 @@ pub struct ProfileInstallResult {
     /// For Main-Profile: indicates if output style was installed
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_style_installed: Option<String>,
+    /// For Project profiles deployed to ClaudeCode: whether `.claude-plugin/plugin.json` was written
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plugin_manifest_installed: Option<bool>,
+    /// Skills left out because they are globally disabled (`only_enabled` installs)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skills_skipped_disabled: Vec<String>,
     /// Which deploy targets were installed to
     #[serde(default)]
     pub targets_installed: Vec<DeployTarget>,
+    /// Targets that could not be installed; the others were still deployed
+    #[serde(default)]
+    pub targets_failed: Vec<TargetInstallError>,
+    /// Wall-clock time of the install, rounded up to whole milliseconds
+    #[serde(default)]
+    pub duration_ms: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -145,15 +241,116 @@ pub struct SkillInstallError {
     pub error: String,
 }
 
+/// Outcome of staging one target's skills during install
+struct StagedSkills {
+    /// `None` in a dry run
+    staged: Option<fs_utils::StagedDir>,
+    installed: Vec<String>,
+    unchanged: Vec<String>,
+    failed: Vec<SkillInstallError>,
+    /// Source hash of each deployed skill, recorded in the install manifest
+    hashes: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetSkills {
+    pub target: DeployTarget,
+    pub skills: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetInstallError {
+    pub target: DeployTarget,
+    pub error: String,
+}
+
+/// A path `Profiles::install` would create or overwrite, as computed by `Profiles::install_plan`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedPath {
+    /// The target the path is deployed for (supplementary files belong to the primary target)
+    pub target: DeployTarget,
+    pub path: PathBuf,
+}
+
+/// Every path an install writes goes through here, so a dry run (`install_plan`)
+/// lists exactly what the install itself would write. Writes are staged until `commit`.
+struct InstallRecorder {
+    dry_run: bool,
+    planned: Vec<PlannedPath>,
+    staged: Option<fs_utils::StagedFiles>,
+}
+
+impl InstallRecorder {
+    fn new(dry_run: bool) -> Self {
+        Self {
+            dry_run,
+            planned: Vec::new(),
+            staged: None,
+        }
+    }
+
+    /// Note that the install writes `path` for `target`
+    fn add(&mut self, target: DeployTarget, path: &Path) {
+        if !self.planned.iter().any(|p| p.path == path) {
+            self.planned.push(PlannedPath {
+                target,
+                path: path.to_path_buf(),
+            });
+        }
+    }
+
+    /// Stage `contents` for `path`, written on `commit`; a dry run only notes the path
+    fn write(
+        &mut self,
+        target: DeployTarget,
+        path: &Path,
+        contents: impl AsRef<[u8]>,
+    ) -> Result<()> {
+        self.add(target, path);
+        if !self.dry_run {
+            self.staged
+                .get_or_insert_with(fs_utils::StagedFiles::new)
+                .write(path, contents)?;
+        }
+        Ok(())
+    }
+
+    /// Move everything staged since the last commit into place
+    fn commit(&mut self) -> Result<()> {
+        self.staged
+            .take()
+            .map_or(Ok(()), fs_utils::StagedFiles::commit)
+    }
+
+    /// Drop anything staged since the last commit
+    fn discard(&mut self) {
+        self.staged = None;
+    }
+}
+
 // ============================================
 // Profile Sync Result
 // ============================================
 
+/// A profile plus its instructions, as written by `Profiles::export`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileExport {
+    pub profile: Profile,
+    /// Content of the profile's instructions file
+    #[serde(default)]
+    pub instructions: Option<String>,
+}
+
 /// Result of syncing a project's installed skills with its declared profile
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProfileSyncResult {
-    /// "synced" (no changes needed), "updated" (changes applied), "no_profile" (no plugin.json)
+    /// "synced" (no changes needed), "updated" (changes applied), "out_of_sync"
+    /// (changes needed, from [`Profiles::diff_project`]), "no_profile" (no plugin.json)
     pub status: String,
     /// Skills that were missing and got installed
     pub added: Vec<String>,
@@ -164,6 +361,9 @@ pub struct ProfileSyncResult {
     /// Profile ID from plugin.json (None if no_profile)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub profile_id: Option<String>,
+    /// Non-fatal problems, e.g. a declared skill that could not be installed
+    #[serde(default)]
+    pub warnings: Warnings,
 }
 
 // ============================================
@@ -274,6 +474,11 @@ impl Profiles {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: now.clone(),
             updated_at: now,
         }
@@ -425,15 +630,8 @@ IMPORTANT: When you detect any of these contexts, IMMEDIATELY read the correspon
 
     /// Save profiles config
     fn save_config(config: &ProfilesConfig) -> Result<()> {
-        let path = Self::config_path()?;
-
-        // Create parent directory if needed
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
         let content = serde_json::to_string_pretty(config)?;
-        fs::write(&path, content)?;
+        crate::paths::atomic_write(&Self::config_path()?, content, false)?;
         Ok(())
     }
 
@@ -447,12 +645,86 @@ IMPORTANT: When you detect any of these contexts, IMMEDIATELY read the correspon
         Ok(config.profiles)
     }
 
+    /// List profiles carrying `tag` (case-insensitive)
+    pub fn list_by_tag(tag: &str) -> Result<Vec<Profile>> {
+        Ok(Self::list()?
+            .into_iter()
+            .filter(|p| p.has_tag(tag))
+            .collect())
+    }
+
     /// Get a specific profile by id
     pub fn get(id: &str) -> Result<Option<Profile>> {
         let config = Self::load_config()?;
         Ok(config.profiles.into_iter().find(|p| p.id == id))
     }
 
+    /// Add a tag to a profile (no-op if already tagged)
+    pub fn add_tag(id: &str, tag: &str) -> Result<Profile> {
+        Self::edit_tags(id, |tags| tags.push(tag.to_string()))
+    }
+
+    /// Remove a tag from a profile (no-op if not tagged)
+    pub fn remove_tag(id: &str, tag: &str) -> Result<Profile> {
+        let tag = tag.trim().to_lowercase();
+        Self::edit_tags(id, |tags| tags.retain(|t| *t != tag))
+    }
+
+    fn edit_tags(id: &str, edit: impl FnOnce(&mut Vec<String>)) -> Result<Profile> {
+        let mut config = Self::load_config()?;
+
+        let profile = config
+            .profiles
+            .iter_mut()
+            .find(|p| p.id == id)
+            .ok_or_else(|| RhinolabsError::ConfigError(format!("Profile '{}' not found", id)))?;
+
+        edit(&mut profile.tags);
+        profile.tags = normalize_tags(std::mem::take(&mut profile.tags));
+        profile.updated_at = chrono::Utc::now().to_rfc3339();
+
+        let updated = profile.clone();
+        Self::save_config(&config)?;
+
+        Ok(updated)
+    }
+
+    /// Set the output style a User profile installs, or `None` to use the
+    /// globally active one again
+    pub fn set_output_style(id: &str, style_id: Option<&str>) -> Result<Profile> {
+        if let Some(style_id) = style_id {
+            if OutputStyles::get(style_id)?.is_none() {
+                return Err(RhinolabsError::ConfigError(format!(
+                    "Output style '{}' not found",
+                    style_id
+                )));
+            }
+        }
+
+        let mut config = Self::load_config()?;
+
+        let profile = config
+            .profiles
+            .iter_mut()
+            .find(|p| p.id == id)
+            .ok_or_else(|| RhinolabsError::ConfigError(format!("Profile '{}' not found", id)))?;
+
+        if style_id.is_some() && profile.profile_type != ProfileType::User {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Profile '{}' is a project profile; output styles are only installed with user profiles",
+                id
+            )));
+        }
+
+        profile.output_style = style_id.map(str::to_lowercase);
+        profile.updated_at = chrono::Utc::now().to_rfc3339();
+
+        let updated = profile.clone();
+        Self::save_config(&config)?;
+
+        Ok(updated)
+    }
+
     /// Create a new profile
     pub fn create(input: CreateProfileInput) -> Result<Profile> {
         let mut config = Self::load_config()?;
@@ -495,6 +767,11 @@ IMPORTANT: When you detect any of these contexts, IMMEDIATELY read the correspon
             instructions,
             generate_copilot: input.generate_copilot,
             generate_agents: input.generate_agents,
+            generate_gemini: input.generate_gemini,
+            generate_opencode: input.generate_opencode,
+            tags: normalize_tags(input.tags),
+            extends: None,
+            output_style: None,
             created_at: now.clone(),
             updated_at: now,
         };
@@ -508,7 +785,7 @@ IMPORTANT: When you detect any of these contexts, IMMEDIATELY read the correspon
             if let Some(parent) = path.parent() {
                 fs::create_dir_all(parent)?;
             }
-            fs::write(&path, content)?;
+            crate::fs_utils::write(&path, content)?;
         }
 
         Ok(profile)
@@ -539,6 +816,15 @@ IMPORTANT: When you detect any of these contexts, IMMEDIATELY read the correspon
         if let Some(generate_agents) = input.generate_agents {
             profile.generate_agents = generate_agents;
         }
+        if let Some(generate_gemini) = input.generate_gemini {
+            profile.generate_gemini = generate_gemini;
+        }
+        if let Some(generate_opencode) = input.generate_opencode {
+            profile.generate_opencode = generate_opencode;
+        }
+        if let Some(tags) = input.tags {
+            profile.tags = normalize_tags(tags);
+        }
         // Note: profile_type is intentionally NOT updated.
         // Main-Profile is User, all others are Project. This cannot be changed.
 
@@ -550,6 +836,121 @@ IMPORTANT: When you detect any of these contexts, IMMEDIATELY read the correspon
         Ok(updated)
     }
 
+    /// Copy a profile's skills, auto-invoke rules, instructions and generation flags
+    /// into a new Project profile. Cloning the Main-Profile also yields a Project profile.
+    pub fn clone(source_id: &str, new_id: &str, new_name: &str) -> Result<Profile> {
+        let mut config = Self::load_config()?;
+
+        let source = config
+            .profiles
+            .iter()
+            .find(|p| p.id == source_id)
+            .cloned()
+            .ok_or_else(|| {
+                RhinolabsError::ConfigError(format!("Profile '{}' not found", source_id))
+            })?;
+
+        if config.profiles.iter().any(|p| p.id == new_id) {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Profile '{}' already exists",
+                new_id
+            )));
+        }
+
+        // The instructions file is authoritative once it exists
+        let instructions = match Self::get_instructions(source_id)? {
+            content if content.is_empty() => source.instructions,
+            content => Some(content),
+        };
+
+        let now = chrono::Utc::now().to_rfc3339();
+        let profile = Profile {
+            id: new_id.to_string(),
+            name: new_name.to_string(),
+            profile_type: ProfileType::Project,
+            instructions,
+            created_at: now.clone(),
+            updated_at: now,
+            ..source
+        };
+
+        config.profiles.push(profile.clone());
+        Self::save_config(&config)?;
+
+        if let Some(ref content) = profile.instructions {
+            let path = Self::get_instructions_path(&profile.id)?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            crate::fs_utils::write(&path, content)?;
+        }
+
+        Ok(profile)
+    }
+
+    /// Write a profile and its instructions to `dest` as a single JSON file
+    pub fn export(profile_id: &str, dest: &Path) -> Result<()> {
+        let mut profile = Self::get(profile_id)?.ok_or_else(|| {
+            RhinolabsError::ConfigError(format!("Profile '{}' not found", profile_id))
+        })?;
+
+        let instructions = match Self::get_instructions(profile_id)? {
+            content if content.is_empty() => profile.instructions.take(),
+            content => Some(content),
+        };
+        profile.instructions = None;
+
+        let export = ProfileExport {
+            profile,
+            instructions,
+        };
+        if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        crate::fs_utils::write(dest, serde_json::to_string_pretty(&export)?)?;
+        Ok(())
+    }
+
+    /// Add a profile from a file written by `export`.
+    /// Timestamps are regenerated and a `User` profile is imported as `Project`.
+    pub fn import(path: &Path) -> Result<Profile> {
+        let content = fs::read_to_string(path)?;
+        let export: ProfileExport = serde_json::from_str(&content).map_err(|e| {
+            RhinolabsError::ConfigError(format!("Invalid profile file '{}': {}", path.display(), e))
+        })?;
+
+        let mut config = Self::load_config()?;
+        if config.profiles.iter().any(|p| p.id == export.profile.id) {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Profile '{}' already exists",
+                export.profile.id
+            )));
+        }
+
+        let now = chrono::Utc::now().to_rfc3339();
+        let profile = Profile {
+            profile_type: ProfileType::Project,
+            instructions: export.instructions.or(export.profile.instructions),
+            tags: normalize_tags(export.profile.tags),
+            created_at: now.clone(),
+            updated_at: now,
+            ..export.profile
+        };
+
+        config.profiles.push(profile.clone());
+        Self::save_config(&config)?;
+
+        if let Some(ref content) = profile.instructions {
+            let path = Self::get_instructions_path(&profile.id)?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            crate::fs_utils::write(&path, content)?;
+        }
+
+        Ok(profile)
+    }
+
     /// Delete a profile
     pub fn delete(id: &str) -> Result<()> {
         // Protect Main-Profile from deletion
@@ -605,44 +1006,59 @@ IMPORTANT: When you detect any of these contexts, IMMEDIATELY read the correspon
         Ok(updated)
     }
 
-    /// Get skills assigned to a profile
-    pub fn get_profile_skills(profile_id: &str) -> Result<Vec<Skill>> {
-        let profile = Self::get(profile_id)?.ok_or_else(|| {
-            RhinolabsError::ConfigError(format!("Profile '{}' not found", profile_id))
-        })?;
+    /// Check that a skill may be added to `profile_id`: the profile must exist,
+    /// and the Main Profile is refused unless `allow_main` is set.
+    pub fn check_skill_assignment(profile_id: &str, allow_main: bool) -> Result<()> {
+        if Self::get(profile_id)?.is_none() {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Profile '{}' not found",
+                profile_id
+            )));
+        }
 
-        let mut skills = Vec::new();
-        for skill_id in &profile.skills {
-            if let Some(skill) = Skills::get(skill_id)? {
-                skills.push(skill);
-            }
+        if profile_id == "main" && !allow_main {
+            return Err(RhinolabsError::ConfigError(
+                "Refusing to assign to the Main Profile without explicit opt-in".into(),
+            ));
         }
 
-        Ok(skills)
+        Ok(())
     }
 
-    /// Get profiles that contain a specific skill
-    pub fn get_profiles_for_skill(skill_id: &str) -> Result<Vec<Profile>> {
-        let config = Self::load_config()?;
-        let profiles: Vec<Profile> = config
+    /// Append a skill to a profile's skills (no-op if already present).
+    /// The skill must be installed.
+    pub fn add_skill(profile_id: &str, skill_id: &str, allow_main: bool) -> Result<Profile> {
+        Self::check_skill_assignment(profile_id, allow_main)?;
+        if Skills::get(skill_id)?.is_none() {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Skill '{}' not found",
+                skill_id
+            )));
+        }
+
+        let mut config = Self::load_config()?;
+        let profile = config
             .profiles
-            .into_iter()
-            .filter(|p| p.skills.contains(&skill_id.to_string()))
-            .collect();
-        Ok(profiles)
-    }
+            .iter_mut()
+            .find(|p| p.id == profile_id)
+            .ok_or_else(|| {
+                RhinolabsError::ConfigError(format!("Profile '{}' not found", profile_id))
+            })?;
 
-    // ============================================
-    // Auto-invoke Rules Management
-    // ============================================
+        if !profile.skills.iter().any(|s| s == skill_id) {
+            profile.skills.push(skill_id.to_string());
+            profile.updated_at = chrono::Utc::now().to_rfc3339();
+        }
 
-    /// Update auto-invoke rules for a profile
-    pub fn update_auto_invoke_rules(
-        profile_id: &str,
-        rules: Vec<AutoInvokeRule>,
-    ) -> Result<Profile> {
-        let mut config = Self::load_config()?;
+        let updated = profile.clone();
+        Self::save_config(&config)?;
+
+        Ok(updated)
+    }
 
+    /// Remove a skill from a profile's skills (no-op if not present)
+    pub fn remove_skill(profile_id: &str, skill_id: &str) -> Result<Profile> {
+        let mut config = Self::load_config()?;
         let profile = config
             .profiles
             .iter_mut()
@@ -651,8 +1067,10 @@ IMPORTANT: When you detect any of these contexts, IMMEDIATELY read the correspon
                 RhinolabsError::ConfigError(format!("Profile '{}' not found", profile_id))
             })?;
 
-        profile.auto_invoke_rules = rules;
-        profile.updated_at = chrono::Utc::now().to_rfc3339();
+        if profile.skills.iter().any(|s| s == skill_id) {
+            profile.skills.retain(|s| s != skill_id);
+            profile.updated_at = chrono::Utc::now().to_rfc3339();
+        }
 
         let updated = profile.clone();
         Self::save_config(&config)?;
@@ -660,9 +1078,173 @@ IMPORTANT: When you detect any of these contexts, IMMEDIATELY read the correspon
         Ok(updated)
     }
 
-    /// Get auto-invoke rules for a profile
-    pub fn get_auto_invoke_rules(profile_id: &str) -> Result<Vec<AutoInvokeRule>> {
-        let profile = Self::get(profile_id)?.ok_or_else(|| {
+    /// Point every profile's skills and auto-invoke rules at `new_id` instead of
+    /// `old_id`. Returns the ids of the profiles that changed.
+    pub fn rename_skill_references(old_id: &str, new_id: &str) -> Result<Vec<String>> {
+        let mut config = Self::load_config()?;
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut changed = Vec::new();
+
+        for profile in &mut config.profiles {
+            let in_skills = profile.skills.iter().any(|s| s == old_id);
+            let in_rules = profile
+                .auto_invoke_rules
+                .iter()
+                .any(|r| r.skill_id == old_id);
+            if !in_skills && !in_rules {
+                continue;
+            }
+
+            if in_skills {
+                if profile.skills.iter().any(|s| s == new_id) {
+                    profile.skills.retain(|s| s != old_id);
+                } else {
+                    for skill in profile.skills.iter_mut().filter(|s| *s == old_id) {
+                        *skill = new_id.to_string();
+                    }
+                }
+            }
+            for rule in &mut profile.auto_invoke_rules {
+                if rule.skill_id == old_id {
+                    rule.skill_id = new_id.to_string();
+                }
+            }
+
+            profile.updated_at = now.clone();
+            changed.push(profile.id.clone());
+        }
+
+        if !changed.is_empty() {
+            Self::save_config(&config)?;
+        }
+        Ok(changed)
+    }
+
+    /// The profile as installed: its `extends` chain merged in, ancestors first.
+    /// Skills and auto-invoke rules are deduplicated (the child's rule wins),
+    /// instructions are concatenated with a `---` break between profiles and the
+    /// output style is the child's unless only an ancestor sets one.
+    pub fn resolve(profile_id: &str) -> Result<Profile> {
+        let config = Self::load_config()?;
+        let find = |id: &str| config.profiles.iter().find(|p| p.id == id);
+
+        let mut chain = vec![find(profile_id).ok_or_else(|| {
+            RhinolabsError::ConfigError(format!("Profile '{}' not found", profile_id))
+        })?];
+        while let Some(parent_id) = &chain[chain.len() - 1].extends {
+            if chain.iter().any(|p| &p.id == parent_id) {
+                let cycle: Vec<&str> = chain.iter().map(|p| p.id.as_str()).collect();
+                return Err(RhinolabsError::ConfigError(format!(
+                    "Profile inheritance cycle: {} -> {}",
+                    cycle.join(" -> "),
+                    parent_id
+                )));
+            }
+            let parent = find(parent_id).ok_or_else(|| {
+                RhinolabsError::ConfigError(format!(
+                    "Profile '{}' extends unknown profile '{}'",
+                    chain[chain.len() - 1].id,
+                    parent_id
+                ))
+            })?;
+            chain.push(parent);
+        }
+
+        let mut resolved = chain[0].clone();
+        if chain.len() == 1 {
+            return Ok(resolved);
+        }
+
+        let mut skills: Vec<String> = Vec::new();
+        let mut rules: Vec<AutoInvokeRule> = Vec::new();
+        let mut instructions: Vec<&str> = Vec::new();
+        for profile in chain.iter().rev() {
+            for skill in &profile.skills {
+                if !skills.contains(skill) {
+                    skills.push(skill.clone());
+                }
+            }
+            for rule in &profile.auto_invoke_rules {
+                rules.retain(|r| r.skill_id != rule.skill_id);
+                rules.push(rule.clone());
+            }
+            if let Some(content) = profile.instructions.as_deref().map(str::trim) {
+                if !content.is_empty() {
+                    instructions.push(content);
+                }
+            }
+        }
+
+        resolved.skills = skills;
+        resolved.auto_invoke_rules = rules;
+        // The nearest profile that names an output style wins
+        resolved.output_style = chain.iter().find_map(|p| p.output_style.clone());
+        resolved.instructions = if instructions.is_empty() {
+            None
+        } else {
+            Some(instructions.join("\n\n---\n\n"))
+        };
+        Ok(resolved)
+    }
+
+    /// Get skills assigned to a profile
+    pub fn get_profile_skills(profile_id: &str) -> Result<Vec<Skill>> {
+        let profile = Self::get(profile_id)?.ok_or_else(|| {
+            RhinolabsError::ConfigError(format!("Profile '{}' not found", profile_id))
+        })?;
+
+        let mut skills = Vec::new();
+        for skill_id in &profile.skills {
+            if let Some(skill) = Skills::get(skill_id)? {
+                skills.push(skill);
+            }
+        }
+
+        Ok(skills)
+    }
+
+    /// Get profiles that contain a specific skill
+    pub fn get_profiles_for_skill(skill_id: &str) -> Result<Vec<Profile>> {
+        let config = Self::load_config()?;
+        let profiles: Vec<Profile> = config
+            .profiles
+            .into_iter()
+            .filter(|p| p.skills.contains(&skill_id.to_string()))
+            .collect();
+        Ok(profiles)
+    }
+
+    // ============================================
+    // Auto-invoke Rules Management
+    // ============================================
+
+    /// Update auto-invoke rules for a profile
+    pub fn update_auto_invoke_rules(
+        profile_id: &str,
+        rules: Vec<AutoInvokeRule>,
+    ) -> Result<Profile> {
+        let mut config = Self::load_config()?;
+
+        let profile = config
+            .profiles
+            .iter_mut()
+            .find(|p| p.id == profile_id)
+            .ok_or_else(|| {
+                RhinolabsError::ConfigError(format!("Profile '{}' not found", profile_id))
+            })?;
+
+        profile.auto_invoke_rules = rules;
+        profile.updated_at = chrono::Utc::now().to_rfc3339();
+
+        let updated = profile.clone();
+        Self::save_config(&config)?;
+
+        Ok(updated)
+    }
+
+    /// Get auto-invoke rules for a profile
+    pub fn get_auto_invoke_rules(profile_id: &str) -> Result<Vec<AutoInvokeRule>> {
+        let profile = Self::get(profile_id)?.ok_or_else(|| {
             RhinolabsError::ConfigError(format!("Profile '{}' not found", profile_id))
         })?;
         Ok(profile.auto_invoke_rules)
@@ -719,134 +1301,510 @@ IMPORTANT: When you detect any of these contexts, IMMEDIATELY read the correspon
     /// For User profiles (Main-Profile): installs to ~/.claude/ (and other targets) including:
     ///   - Skills → ~/.claude/skills/ (or target-specific user skills dir)
     ///   - Instructions → ~/.claude/CLAUDE.md
-    ///   - Settings → ~/.claude/settings.json
+    ///   - Settings → ~/.claude/settings.json (merged over the user's own keys)
     ///   - Output Style → ~/.claude/output-styles/
     ///
     /// For Project profiles: installs as a plugin to target_path/ including:
-    ///   - Plugin manifest → target_path/.claude-plugin/plugin.json (ClaudeCode only)
+    ///   - Plugin manifest → target_path/.claude-plugin/plugin.json (ClaudeCode only,
+    ///     skipped when `create_plugin_manifest` is false)
     ///   - Skills → target_path/.claude/skills/ (or target-specific project skills dir)
     ///   - CLAUDE.md/AGENTS.md/GEMINI.md → target_path/ (generated from profile)
     ///
     /// If `targets` is `None`, defaults to `[ClaudeCode]` for backward compatibility.
+    ///
+    /// All of the profile's skills are deployed by default, even globally disabled ones.
+    /// With `only_enabled`, disabled skills are left out (and out of the generated
+    /// instructions) and reported in `skills_skipped_disabled`. Skills disabled for a
+    /// single target (`Skills::toggle_for_target`) are always left out of that target.
+    ///
+    /// Skill directories are staged and swapped into place only after a target's install
+    /// succeeds, so a failure partway leaves that target's existing installation untouched.
+    /// A failing target is recorded in `targets_failed` and the remaining targets are still
+    /// installed; the install only errors when every target failed.
+    ///
+    /// Project installs are incremental: a skill whose source is unchanged since the last
+    /// install (per the hashes in `.rhinolabs-install.json`) is left as is and reported in
+    /// `skills_unchanged`. With `force`, every skill is redeployed. Existing instructions
+    /// files only have their managed skills section regenerated; ones without it are
+    /// replaced only with `overwrite_instructions`.
     pub fn install(
         profile_id: &str,
         target_path: Option<&Path>,
         targets: Option<&[DeployTarget]>,
+        options: InstallOptions,
     ) -> Result<ProfileInstallResult> {
-        let profile = Self::get(profile_id)?.ok_or_else(|| {
-            RhinolabsError::ConfigError(format!("Profile '{}' not found", profile_id))
-        })?;
+        let mut recorder = InstallRecorder::new(false);
+        Self::install_inner(profile_id, target_path, targets, options, &mut recorder)
+    }
+
+    /// Every path `install` would write, without touching the filesystem.
+    ///
+    /// This is a dry run of `install` itself with the same arguments, so it follows
+    /// the same rules: skills unchanged since the last install and instructions files
+    /// that would be left as they are don't appear, while the install manifest does.
+    /// The skills directory's `.gitignore` is listed whenever skills are deployed,
+    /// as if every skill could be symlinked.
+    pub fn install_plan(
+        profile_id: &str,
+        target_path: Option<&Path>,
+        targets: Option<&[DeployTarget]>,
+        options: InstallOptions,
+    ) -> Result<Vec<PlannedPath>> {
+        let mut recorder = InstallRecorder::new(true);
+        Self::install_inner(profile_id, target_path, targets, options, &mut recorder)?;
+        Ok(recorder.planned)
+    }
+
+    fn install_inner(
+        profile_id: &str,
+        target_path: Option<&Path>,
+        targets: Option<&[DeployTarget]>,
+        options: InstallOptions,
+        recorder: &mut InstallRecorder,
+    ) -> Result<ProfileInstallResult> {
+        let InstallOptions {
+            create_plugin_manifest,
+            only_enabled,
+            force,
+            overwrite_instructions,
+        } = options;
+        let started = std::time::Instant::now();
+        let mut profile = Self::resolve(profile_id)?;
+        let skills_skipped_disabled = Self::drop_disabled_skills(&mut profile, only_enabled)?;
 
         let default_targets = [DeployTarget::ClaudeCode];
         let effective_targets = targets.unwrap_or(&default_targets);
 
+        // Determine base target path for result display
+        let base_target = Self::install_base(&profile, target_path)?;
+
+        // Project installs record what they deploy so uninstall can remove exactly that.
+        // Entries are planned up front, while it's still known which paths already existed.
+        let previous_manifest = match profile.profile_type {
+            ProfileType::Project => InstallManifest::load(&base_target)?,
+            ProfileType::User => None,
+        };
+        let mut planned_entries: Vec<InstalledTarget> =
+            if profile.profile_type == ProfileType::Project && !recorder.dry_run {
+                let plan =
+                    Self::install_plan(profile_id, target_path, Some(effective_targets), options)?;
+                effective_targets
+                    .iter()
+                    .map(|target| {
+                        let skills_dir = TargetPaths::project_skills_dir(*target, &base_target);
+                        InstallManifest::plan_target(
+                            &base_target,
+                            *target,
+                            &skills_dir,
+                            &plan,
+                            previous_manifest.as_ref(),
+                        )
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
         let mut skills_installed = Vec::new();
+        let mut skills_refreshed = Vec::new();
         let mut skills_failed = Vec::new();
+        let mut targets_failed = Vec::new();
+
+        // For Main-Profile (User type): also install instructions, settings, and output style
+        // For Project profiles: install as a plugin structure
+        let mut instructions_installed = None;
+        let mut settings_installed = None;
+        let mut output_style_installed = None;
 
-        // Install skills to each target
+        // Everything is written into staged copies that are only swapped into place once the
+        // target's install succeeded; any error drops them and leaves the originals untouched.
+        // Project installs stage the target's config dir (`.claude` → `.claude.XXXXXX.tmp`);
+        // user installs can't swap `~/.claude`, so they stage only the skills dir. Instructions
+        // and other files outside it are staged next to their final path. Targets are
+        // committed one after the other, so targets sharing a directory each build on the
+        // last. A dry run stages nothing.
+        let mut targets_installed = Vec::new();
         for target in effective_targets {
-            let skills_target = match profile.profile_type {
-                ProfileType::User => TargetPaths::user_skills_dir(*target)?,
-                ProfileType::Project => {
-                    let path = target_path.ok_or_else(|| {
-                        RhinolabsError::ConfigError("Project profiles require a target path".into())
-                    })?;
-                    TargetPaths::project_skills_dir(*target, path)
-                }
+            let previous_hashes = match (&previous_manifest, force) {
+                (Some(manifest), false) => manifest.skill_hashes(*target),
+                _ => None,
             };
+            let installed = Self::profile_for_target(&profile, *target, only_enabled).and_then(
+                |target_profile| {
+                    let result = Self::stage_target_skills(
+                        &target_profile,
+                        &base_target,
+                        *target,
+                        previous_hashes,
+                        recorder,
+                    )?;
+                    if profile.profile_type == ProfileType::User {
+                        let (instructions, settings, style) =
+                            Self::install_main_profile_config_for_targets(
+                                &profile,
+                                &[*target],
+                                recorder,
+                            )?;
+                        instructions_installed = instructions_installed.or(instructions);
+                        settings_installed = settings_installed.or(settings);
+                        output_style_installed = output_style_installed.take().or(style);
+                    } else {
+                        Self::install_project_target_files(
+                            &base_target,
+                            &target_profile,
+                            *target,
+                            create_plugin_manifest,
+                            overwrite_instructions,
+                            recorder,
+                        )?;
+                    }
+                    Ok(result)
+                },
+            );
+            let committed = installed.and_then(|mut result| {
+                if let Some(staged) = result.staged.take() {
+                    staged.commit()?;
+                }
+                recorder.commit()?;
+                Ok(result)
+            });
+            recorder.discard();
 
-            fs::create_dir_all(&skills_target)?;
-
-            for skill_id in &profile.skills {
-                match Self::install_skill(skill_id, &skills_target) {
-                    Ok(_) => {
-                        if !skills_installed.contains(skill_id) {
-                            skills_installed.push(skill_id.clone());
+            match committed {
+                Ok(result) => {
+                    targets_installed.push(*target);
+                    if let Some(entry) = planned_entries.iter_mut().find(|e| e.target == *target) {
+                        entry.skill_hashes = result.hashes;
+                    }
+                    let refreshed: Vec<String> = result
+                        .installed
+                        .iter()
+                        .filter(|skill_id| !result.unchanged.contains(skill_id))
+                        .cloned()
+                        .collect();
+                    skills_refreshed.push(TargetSkills {
+                        target: *target,
+                        skills: refreshed,
+                    });
+                    for skill_id in result.installed {
+                        if !skills_installed.contains(&skill_id) {
+                            skills_installed.push(skill_id);
                         }
                     }
-                    Err(e) => {
+                    for failure in result.failed {
                         if !skills_failed
                             .iter()
-                            .any(|f: &SkillInstallError| f.skill_id == *skill_id)
+                            .any(|f: &SkillInstallError| f.skill_id == failure.skill_id)
                         {
-                            skills_failed.push(SkillInstallError {
-                                skill_id: skill_id.clone(),
-                                error: e.to_string(),
-                            });
+                            skills_failed.push(failure);
                         }
                     }
                 }
+                Err(e) => targets_failed.push(TargetInstallError {
+                    target: *target,
+                    error: e.to_string(),
+                }),
             }
+        }
 
-            // Generate .gitignore for symlinked skills (Project profiles only)
-            if profile.profile_type == ProfileType::Project {
-                Self::generate_skills_gitignore(&skills_target, &profile.skills)?;
-            }
+        if targets_installed.is_empty() {
+            let reasons = targets_failed
+                .iter()
+                .map(|f| format!("{}: {}", f.target.display_name(), f.error))
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(RhinolabsError::InstallationFailed(reasons));
         }
 
-        // Determine base target path for result display
-        let base_target = match profile.profile_type {
-            ProfileType::User => Self::claude_user_dir()?,
-            ProfileType::Project => target_path
-                .ok_or_else(|| {
-                    RhinolabsError::ConfigError("Project profiles require a target path".into())
-                })?
-                .to_path_buf(),
-        };
+        // A skill counts as unchanged only if no installed target redeployed it
+        let skills_unchanged = skills_installed
+            .iter()
+            .filter(|skill_id| !skills_refreshed.iter().any(|t| t.skills.contains(skill_id)))
+            .cloned()
+            .collect();
 
-        // For Main-Profile (User type): also install instructions, settings, and output style
-        // For Project profiles: install as a plugin structure
-        let (instructions_installed, settings_installed, output_style_installed) = if profile
-            .profile_type
-            == ProfileType::User
-        {
-            Self::install_main_profile_config_for_targets(effective_targets)?
-        } else {
-            Self::install_project_profile_for_targets(&base_target, &profile, effective_targets)?
-        };
+        let mut plugin_manifest_installed = None;
+        if profile.profile_type == ProfileType::Project {
+            Self::install_project_supplementary_files(
+                &base_target,
+                &profile,
+                &targets_installed,
+                recorder,
+            )?;
+            recorder.commit()?;
+            instructions_installed = Some(true);
+            if targets_installed.contains(&DeployTarget::ClaudeCode) {
+                plugin_manifest_installed = Some(create_plugin_manifest);
+            }
+
+            let mut manifest = previous_manifest.unwrap_or_default();
+            manifest.profile_id = profile.id.clone();
+            if plugin_manifest_installed.is_some() {
+                manifest.no_plugin = !create_plugin_manifest;
+            }
+            for mut entry in planned_entries {
+                if targets_installed.contains(&entry.target) {
+                    entry.hash_instructions(&base_target);
+                    manifest.record(entry);
+                }
+            }
+            if recorder.dry_run {
+                let primary = targets_installed[0];
+                recorder.add(primary, &InstallManifest::path(&base_target));
+                if crate::gitignore::has_block(&base_target) {
+                    recorder.add(primary, &base_target.join(".gitignore"));
+                }
+            } else {
+                manifest.save(&base_target)?;
+                manifest.refresh_gitignore(&base_target)?;
+            }
+        }
+
+        // Report failures in the order targets were requested
+        targets_failed.sort_by_key(|f| {
+            effective_targets
+                .iter()
+                .position(|t| *t == f.target)
+                .unwrap_or(usize::MAX)
+        });
 
         Ok(ProfileInstallResult {
             profile_id: profile.id,
             profile_name: profile.name,
             target_path: base_target.display().to_string(),
             skills_installed,
+            skills_unchanged,
+            skills_refreshed,
             skills_failed,
             instructions_installed,
             settings_installed,
             output_style_installed,
-            targets_installed: effective_targets.to_vec(),
+            plugin_manifest_installed,
+            skills_skipped_disabled,
+            targets_installed,
+            targets_failed,
+            duration_ms: started.elapsed().as_micros().div_ceil(1000) as u64,
         })
     }
 
-    /// Install Project Profile for multiple deploy targets.
-    ///
-    /// For each target:
-    /// 1. Generate instructions content with correct skill path references
-    /// 2. Write instructions file (CLAUDE.md / AGENTS.md / GEMINI.md)
+    /// Files a project install writes `target`'s instructions to: the instructions
+    /// file, plus the markdown it points at for OpenCode
+    fn instructions_paths(target: DeployTarget, base: &Path) -> Vec<PathBuf> {
+        let mut paths = vec![TargetPaths::instructions_path(target, base)];
+        if target == DeployTarget::OpenCode {
+            paths.push(TargetPaths::opencode_instructions_path(base));
+        }
+        paths
+    }
+
+    /// With `only_enabled`, remove globally disabled skills (and their auto-invoke
+    /// rules) from the profile. Returns the skills that were left out.
+    fn drop_disabled_skills(profile: &mut Profile, only_enabled: bool) -> Result<Vec<String>> {
+        if !only_enabled {
+            return Ok(Vec::new());
+        }
+
+        let disabled = Skills::disabled_ids()?;
+        let (skipped, kept): (Vec<String>, Vec<String>) = std::mem::take(&mut profile.skills)
+            .into_iter()
+            .partition(|id| disabled.contains(id));
+        profile.skills = kept;
+        profile
+            .auto_invoke_rules
+            .retain(|rule| !skipped.contains(&rule.skill_id));
+        Ok(skipped)
+    }
+
+    /// The profile as deployed to `target`: skills that don't deploy there (see
+    /// `Skills::deployed_to_target`) and their auto-invoke rules are left out
+    fn profile_for_target(
+        profile: &Profile,
+        target: DeployTarget,
+        only_enabled: bool,
+    ) -> Result<Profile> {
+        let deployed = Skills::deployed_to_target(&profile.skills, target, only_enabled)?;
+        let mut profile = profile.clone();
+        let (kept, left_out): (Vec<String>, Vec<String>) = std::mem::take(&mut profile.skills)
+            .into_iter()
+            .partition(|id| deployed.contains(id));
+        profile.skills = kept;
+        profile
+            .auto_invoke_rules
+            .retain(|rule| !left_out.contains(&rule.skill_id));
+        Ok(profile)
+    }
+
+    /// Where a profile installs to: the user config dir for User profiles, `target_path`
+    /// (required) for Project profiles
+    fn install_base(profile: &Profile, target_path: Option<&Path>) -> Result<PathBuf> {
+        match profile.profile_type {
+            ProfileType::User => Self::claude_user_dir(),
+            ProfileType::Project => Ok(target_path
+                .ok_or_else(|| {
+                    RhinolabsError::ConfigError("Project profiles require a target path".into())
+                })?
+                .to_path_buf()),
+        }
+    }
+
+    /// Stage a target's skills directory and install the profile's skills into it.
     ///
-    /// Only for ClaudeCode target:
-    /// 3. Create .claude-plugin/plugin.json manifest
-    /// 4. Optionally write .github/copilot-instructions.md
-    fn install_project_profile_for_targets(
-        target_path: &Path,
+    /// A skill whose source hash matches `previous_hashes` and that is still deployed
+    /// is not redeployed and is reported as unchanged. A dry run checks each skill
+    /// against the live skills directory instead of staging it.
+    fn stage_target_skills(
         profile: &Profile,
-        targets: &[DeployTarget],
-    ) -> Result<(Option<bool>, Option<bool>, Option<String>)> {
-        for target in targets {
-            // Generate instructions content for this specific target
-            let instructions_content = Self::generate_instructions_for_target(profile, *target);
+        base_target: &Path,
+        target: DeployTarget,
+        previous_hashes: Option<&BTreeMap<String, String>>,
+        recorder: &mut InstallRecorder,
+    ) -> Result<StagedSkills> {
+        let skills_dir = match profile.profile_type {
+            ProfileType::User => TargetPaths::user_skills_dir(target)?,
+            ProfileType::Project => TargetPaths::project_skills_dir(target, base_target),
+        };
+        recorder.add(target, &skills_dir);
+
+        // Skills whose source didn't change since the last install are left in place and
+        // not copied into the staged dir
+        let hashes: Vec<Option<String>> = profile
+            .skills
+            .iter()
+            .map(|skill_id| {
+                Skills::get_skill_path(skill_id)
+                    .and_then(|source| fs_utils::hash_dir(&source))
+                    .ok()
+            })
+            .collect();
+        let unchanged: Vec<bool> = profile
+            .skills
+            .iter()
+            .zip(&hashes)
+            .map(|(skill_id, hash)| {
+                fs::symlink_metadata(skills_dir.join(skill_id)).is_ok()
+                    && hash.is_some()
+                    && previous_hashes.and_then(|h| h.get(skill_id)) == hash.as_ref()
+            })
+            .collect();
+        let kept_skills = |skills_rel: &Path| -> Vec<PathBuf> {
+            profile
+                .skills
+                .iter()
+                .zip(&unchanged)
+                .filter(|(_, unchanged)| **unchanged)
+                .map(|(skill_id, _)| skills_rel.join(skill_id))
+                .collect()
+        };
 
-            // Write instructions file (only if it doesn't exist — don't overwrite user's custom file)
-            let instructions_path = TargetPaths::instructions_path(*target, target_path);
-            if !instructions_path.exists() {
-                fs::write(&instructions_path, &instructions_content)?;
+        let (staged, skills_target) = match profile.profile_type {
+            _ if recorder.dry_run => (None, skills_dir.clone()),
+            ProfileType::User => {
+                let staged =
+                    fs_utils::StagedDir::begin_keeping(&skills_dir, &kept_skills(Path::new("")))?;
+                let skills_target = staged.path().to_path_buf();
+                (Some(staged), skills_target)
             }
+            ProfileType::Project => {
+                let config_dir = TargetPaths::project_config_dir(target, base_target);
+                let skills_rel = skills_dir
+                    .strip_prefix(&config_dir)
+                    .map(Path::to_path_buf)
+                    .unwrap_or_else(|_| PathBuf::from("skills"));
+                let staged =
+                    fs_utils::StagedDir::begin_keeping(&config_dir, &kept_skills(&skills_rel))?;
+                let skills_target = staged.path().join(skills_rel);
+                (Some(staged), skills_target)
+            }
+        };
 
-            // ClaudeCode-specific: create plugin manifest and copilot instructions
-            if *target == DeployTarget::ClaudeCode {
-                let plugin_dir = target_path.join(".claude-plugin");
-                fs::create_dir_all(&plugin_dir)?;
+        if !recorder.dry_run {
+            fs::create_dir_all(&skills_target)?;
+        }
+
+        let mut result = StagedSkills {
+            staged,
+            installed: Vec::new(),
+            unchanged: Vec::new(),
+            failed: Vec::new(),
+            hashes: BTreeMap::new(),
+        };
+        let mut symlinked = Vec::new();
+        for ((skill_id, hash), is_unchanged) in profile.skills.iter().zip(hashes).zip(unchanged) {
+            let outcome = if is_unchanged {
+                Ok(())
+            } else {
+                recorder.add(target, &skills_dir.join(skill_id));
+                if recorder.dry_run {
+                    Skills::get_skill_path(skill_id).map(|_| ())
+                } else {
+                    Self::install_skill(skill_id, &skills_target)
+                }
+            };
+            match outcome {
+                Ok(()) => {
+                    // Unchanged skills are only moved into the staged dir on commit
+                    let deployed = if is_unchanged {
+                        skills_dir.join(skill_id)
+                    } else {
+                        skills_target.join(skill_id)
+                    };
+                    if fs::symlink_metadata(&deployed).is_ok_and(|m| m.file_type().is_symlink()) {
+                        symlinked.push(skill_id.as_str());
+                    }
+                    if let Some(hash) = hash {
+                        result.hashes.insert(skill_id.clone(), hash);
+                    }
+                    if is_unchanged {
+                        result.unchanged.push(skill_id.clone());
+                    }
+                    result.installed.push(skill_id.clone());
+                }
+                Err(e) => result.failed.push(SkillInstallError {
+                    skill_id: skill_id.clone(),
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        // Generate .gitignore for symlinked skills (Project profiles only)
+        if profile.profile_type == ProfileType::Project {
+            let gitignore = skills_dir.join(".gitignore");
+            if recorder.dry_run {
+                if !profile.skills.is_empty() {
+                    recorder.add(target, &gitignore);
+                }
+            } else if Self::write_skills_gitignore(&skills_target, symlinked)? {
+                recorder.add(target, &gitignore);
+            }
+        }
+
+        Ok(result)
+    }
 
+    /// Write one target's instructions file, plus the plugin manifest (unless
+    /// `create_plugin_manifest` is false) and copilot instructions for ClaudeCode.
+    fn install_project_target_files(
+        target_path: &Path,
+        profile: &Profile,
+        target: DeployTarget,
+        create_plugin_manifest: bool,
+        overwrite: bool,
+        recorder: &mut InstallRecorder,
+    ) -> Result<()> {
+        // Generate instructions content for this specific target
+        let instructions_content = Self::generate_instructions_for_target(profile, target);
+        Self::write_target_instructions(
+            recorder,
+            target,
+            target_path,
+            target,
+            &instructions_content,
+            overwrite,
+        )?;
+
+        // ClaudeCode-specific: create plugin manifest and copilot instructions
+        if target == DeployTarget::ClaudeCode {
+            if create_plugin_manifest {
                 let plugin_manifest = serde_json::json!({
                     "name": format!("profile-{}", profile.id),
                     "description": profile.description,
@@ -862,46 +1820,222 @@ IMPORTANT: When you detect any of these contexts, IMMEDIATELY read the correspon
                     }
                 });
 
-                let manifest_path = plugin_dir.join("plugin.json");
-                fs::write(
+                let manifest_path = target_path.join(".claude-plugin").join("plugin.json");
+                recorder.write(
+                    target,
                     &manifest_path,
                     serde_json::to_string_pretty(&plugin_manifest)?,
                 )?;
+            }
 
-                // Create .github/copilot-instructions.md if enabled
-                if profile.generate_copilot {
-                    let github_dir = target_path.join(".github");
-                    fs::create_dir_all(&github_dir)?;
-                    let copilot_path = github_dir.join("copilot-instructions.md");
-                    let copilot_content = Self::generate_copilot_instructions(profile);
-                    fs::write(&copilot_path, &copilot_content)?;
-                }
+            // Create .github/copilot-instructions.md if enabled
+            if profile.generate_copilot {
+                let copilot_path = target_path.join(".github").join("copilot-instructions.md");
+                let copilot_content = Self::generate_copilot_instructions(profile);
+                recorder.write(target, &copilot_path, copilot_content)?;
             }
         }
 
-        // If generate_agents is true and Amp is NOT in targets, still generate AGENTS.md
-        // as a supplementary cross-reference file (with .claude/skills/ paths)
-        if profile.generate_agents && !targets.contains(&DeployTarget::Amp) {
-            let agents_path = target_path.join("AGENTS.md");
-            let content = Self::generate_ai_instructions_content(profile);
-            fs::write(&agents_path, &content.agents_md)?;
+        Ok(())
+    }
+
+    /// Write `content` where `target` reads its project instructions.
+    ///
+    /// Markdown targets get it in their instructions file. OpenCode's `opencode.json`
+    /// is config, not markdown: the content goes to a separate markdown file and
+    /// `opencode.json` only points at it. Written paths are recorded for `owner`, the
+    /// installed target the files belong to.
+    fn write_target_instructions(
+        recorder: &mut InstallRecorder,
+        owner: DeployTarget,
+        target_path: &Path,
+        target: DeployTarget,
+        content: &str,
+        overwrite: bool,
+    ) -> Result<()> {
+        if target != DeployTarget::OpenCode {
+            let path = TargetPaths::instructions_path(target, target_path);
+            return Self::write_instructions_file(recorder, owner, &path, content, overwrite);
         }
 
-        Ok((Some(true), None, None))
+        let markdown_path = TargetPaths::opencode_instructions_path(target_path);
+        Self::write_instructions_file(recorder, owner, &markdown_path, content, overwrite)?;
+        Self::write_opencode_config(
+            recorder,
+            owner,
+            &TargetPaths::instructions_path(target, target_path),
+            overwrite,
+        )
     }
 
-    /// Generate content for CLAUDE.md, copilot-instructions.md, and AGENTS.md
-    fn generate_ai_instructions_content(profile: &Profile) -> GeneratedAiContent {
-        // Build auto-invoke table
-        let auto_invoke_table = if !profile.auto_invoke_rules.is_empty() {
-            let rows: Vec<String> = profile
-                .auto_invoke_rules
-                .iter()
-                .map(|rule| {
-                    format!(
-                        "| {} | {} | `.claude/skills/{}/SKILL.md` |",
-                        rule.trigger, rule.skill_id, rule.skill_id
-                    )
+    /// Make sure `opencode.json` lists the generated instructions file, keeping any
+    /// other settings (MCP servers, models, ...) already in it.
+    ///
+    /// A file holding raw markdown from an older install is replaced; any other file
+    /// that isn't a JSON object is left alone unless `overwrite`.
+    fn write_opencode_config(
+        recorder: &mut InstallRecorder,
+        owner: DeployTarget,
+        path: &Path,
+        overwrite: bool,
+    ) -> Result<()> {
+        let existing = match fs::read_to_string(path) {
+            Ok(existing) => Some(existing),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut config = match existing.as_deref().map(serde_json::from_str) {
+            None => serde_json::Map::new(),
+            Some(Ok(serde_json::Value::Object(config))) => config,
+            Some(_)
+                if overwrite
+                    || existing
+                        .as_deref()
+                        .unwrap_or_default()
+                        .contains(&instructions::section_start(SKILLS_SECTION)) =>
+            {
+                serde_json::Map::new()
+            }
+            Some(_) => return Ok(()),
+        };
+
+        config
+            .entry("$schema")
+            .or_insert_with(|| OPENCODE_CONFIG_SCHEMA.into());
+        let instructions = config
+            .entry("instructions")
+            .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+        match instructions {
+            serde_json::Value::Array(paths)
+                if paths.iter().any(|p| p == OPENCODE_INSTRUCTIONS_FILE) => {}
+            serde_json::Value::Array(paths) => paths.push(OPENCODE_INSTRUCTIONS_FILE.into()),
+            other => *other = serde_json::json!([OPENCODE_INSTRUCTIONS_FILE]),
+        }
+
+        let updated = serde_json::to_string_pretty(&config)?;
+        if existing.as_deref() != Some(updated.as_str()) {
+            recorder.write(owner, path, updated)?;
+        }
+        Ok(())
+    }
+
+    /// Take the generated instructions file out of `opencode.json`'s `instructions`
+    /// list, keeping the user's other settings. The file is deleted once nothing but
+    /// the schema reference is left.
+    ///
+    /// Returns false, without touching it, when the file isn't a JSON object.
+    pub(crate) fn remove_opencode_config(path: &Path) -> Result<bool> {
+        let existing = match fs::read_to_string(path) {
+            Ok(existing) => existing,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(true),
+            Err(e) => return Err(e.into()),
+        };
+        let Ok(serde_json::Value::Object(mut config)) = serde_json::from_str(&existing) else {
+            return Ok(false);
+        };
+
+        if let Some(serde_json::Value::Array(paths)) = config.get_mut("instructions") {
+            paths.retain(|p| p != OPENCODE_INSTRUCTIONS_FILE);
+            if paths.is_empty() {
+                config.remove("instructions");
+            }
+        }
+
+        if config.keys().all(|key| key == "$schema") {
+            fs::remove_file(path)?;
+        } else {
+            let updated = serde_json::to_string_pretty(&config)?;
+            if updated != existing {
+                crate::fs_utils::write(path, updated)?;
+            }
+        }
+        Ok(true)
+    }
+
+    /// Write a generated instructions file without losing the user's edits.
+    ///
+    /// A missing file gets the full content. An existing file only has its managed
+    /// `skills` section (see `instructions::section_start`) regenerated; a file without
+    /// markers, e.g. from an install predating them, is left alone unless `overwrite`.
+    fn write_instructions_file(
+        recorder: &mut InstallRecorder,
+        owner: DeployTarget,
+        path: &Path,
+        content: &str,
+        overwrite: bool,
+    ) -> Result<()> {
+        let existing = match fs::read_to_string(path) {
+            Ok(existing) => existing,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return recorder.write(owner, path, content);
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        match instructions::replace_section(&existing, content, SKILLS_SECTION) {
+            Some(updated) if updated != existing => recorder.write(owner, path, updated)?,
+            Some(_) => {}
+            None if overwrite => recorder.write(owner, path, content)?,
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Write the AGENTS.md / GEMINI.md / opencode.json files a profile opts in to
+    /// when the matching target is not among the installed `targets`.
+    fn install_project_supplementary_files(
+        target_path: &Path,
+        profile: &Profile,
+        targets: &[DeployTarget],
+        recorder: &mut InstallRecorder,
+    ) -> Result<()> {
+        // Supplementary files belong to the primary target in the install manifest
+        let primary = targets.first().copied().unwrap_or_default();
+
+        // If generate_agents is true and Amp is NOT in targets, still generate AGENTS.md
+        // as a supplementary cross-reference file (with .claude/skills/ paths)
+        if profile.generate_agents && !targets.contains(&DeployTarget::Amp) {
+            let agents_path = target_path.join("AGENTS.md");
+            let content = Self::generate_ai_instructions_content(profile);
+            recorder.write(primary, &agents_path, content.agents_md)?;
+        }
+
+        // Same for GEMINI.md / opencode.json: profiles can opt in to these files
+        // without deploying to Antigravity / OpenCode. Skill paths point at the
+        // primary target's skills directory, since that's where skills were installed.
+        for (enabled, extra) in [
+            (profile.generate_gemini, DeployTarget::Antigravity),
+            (profile.generate_opencode, DeployTarget::OpenCode),
+        ] {
+            if enabled && !targets.contains(&extra) {
+                let content = Self::generate_instructions_markdown(profile, extra, primary);
+                Self::write_target_instructions(
+                    recorder,
+                    primary,
+                    target_path,
+                    extra,
+                    &content,
+                    true,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Generate content for CLAUDE.md, copilot-instructions.md, and AGENTS.md
+    fn generate_ai_instructions_content(profile: &Profile) -> GeneratedAiContent {
+        // Build auto-invoke table
+        let auto_invoke_table = if !profile.auto_invoke_rules.is_empty() {
+            let rows: Vec<String> = profile
+                .auto_invoke_rules
+                .iter()
+                .map(|rule| {
+                    format!(
+                        "| {} | {} | `.claude/skills/{}/SKILL.md` |",
+                        rule.trigger, rule.skill_id, rule.skill_id
+                    )
                 })
                 .collect();
 
@@ -1108,7 +2242,30 @@ This profile generates:
     /// Generate instructions content for a specific deploy target.
     /// Returns a single string of instructions content with correct skill path references.
     fn generate_instructions_for_target(profile: &Profile, target: DeployTarget) -> String {
-        let skills_prefix = target.project_skills_prefix();
+        Self::generate_instructions_markdown(profile, target, target)
+    }
+
+    /// Instructions markdown written for `target`, referencing skills where
+    /// `skills_target` installed them (they differ for supplementary files).
+    ///
+    /// For OpenCode this is the file `opencode.json` points at, not `opencode.json` itself.
+    fn generate_instructions_markdown(
+        profile: &Profile,
+        target: DeployTarget,
+        skills_target: DeployTarget,
+    ) -> String {
+        let skills_prefix = skills_target.project_skills_prefix();
+        let (title, intro) = match target {
+            DeployTarget::Antigravity => (
+                "Gemini Instructions",
+                "> Project context for Gemini in Antigravity\n",
+            ),
+            DeployTarget::OpenCode => (
+                "OpenCode Instructions",
+                "> Loaded through the `instructions` list in opencode.json\n",
+            ),
+            _ => ("Project Instructions", ""),
+        };
 
         // Build auto-invoke table
         let auto_invoke_table = if !profile.auto_invoke_rules.is_empty() {
@@ -1160,26 +2317,34 @@ IMPORTANT: Load these skills based on context:
             _ => String::new(),
         };
 
+        // The skill tables sit inside managed-section markers so `profile update` can
+        // regenerate them without touching anything the user edited around them
         format!(
-            r#"# Project Instructions
+            r#"# {}
 
-> Auto-generated by rhinolabs-ai | Profile: {}
-> Run `rhinolabs-ai profile update` to regenerate
+{}> Auto-generated by rhinolabs-ai | Profile: {}
+> Run `rhinolabs-ai profile update` to regenerate the skills section
 
-{}{}## Available Skills
+{}{}
+{}## Available Skills
 
 Skills in `{}/`:
 
+{}
 {}
 
 ---
 *Installed by rhinolabs-ai | Profile: {}*
 "#,
+            title,
+            intro,
             profile.id,
-            auto_invoke_table,
             custom_instructions,
+            instructions::section_start(SKILLS_SECTION),
+            auto_invoke_table,
             skills_prefix,
             skills_list,
+            instructions::section_end(SKILLS_SECTION),
             profile.id
         )
     }
@@ -1192,37 +2357,50 @@ Skills in `{}/`:
 
     /// Install Main-Profile configuration for multiple targets
     fn install_main_profile_config_for_targets(
+        profile: &Profile,
         targets: &[DeployTarget],
+        recorder: &mut InstallRecorder,
     ) -> Result<(Option<bool>, Option<bool>, Option<String>)> {
         let mut instructions_installed = None;
         let mut output_style_installed = None;
 
         for target in targets {
             let config_dir = TargetPaths::user_config_dir(*target)?;
-            fs::create_dir_all(&config_dir)?;
 
             // Install Instructions
             let instructions = InstructionsManager::get()?;
             if !instructions.content.is_empty() {
                 let target_path = TargetPaths::instructions_path(*target, &config_dir);
-                fs::write(&target_path, &instructions.content)?;
+                recorder.write(*target, &target_path, &instructions.content)?;
                 instructions_installed = Some(true);
             }
 
             // ClaudeCode-specific: install settings.json and output styles
             if *target == DeployTarget::ClaudeCode {
-                let settings = Settings::get()?;
-                let settings_target = config_dir.join("settings.json");
-                let settings_json = serde_json::to_string_pretty(&settings)?;
-                fs::write(&settings_target, settings_json)?;
-
-                if let Ok(Some(style)) = OutputStyles::get_active() {
-                    let styles_dir = config_dir.join("output-styles");
-                    fs::create_dir_all(&styles_dir)?;
-
-                    let style_content = Self::generate_output_style_content(&style);
-                    let style_path = styles_dir.join(format!("{}.md", style.id));
-                    fs::write(&style_path, style_content)?;
+                let style = match &profile.output_style {
+                    Some(_) => Self::output_style_for(profile)?,
+                    None => OutputStyles::get_active().ok().flatten(),
+                };
+
+                // A profile's own style is made the active one in the deployed
+                // settings without changing the global setting
+                let mut settings = Settings::get()?;
+                if let (Some(style), Some(_)) = (&style, &profile.output_style) {
+                    settings.output_style = style.name.clone();
+                }
+                let settings_path = config_dir.join("settings.json");
+                recorder.add(*target, &settings_path);
+                if !recorder.dry_run {
+                    fs::create_dir_all(&config_dir)?;
+                    Settings::write_merged(&settings_path, &settings)?;
+                }
+
+                if let Some(style) = style {
+                    let style_content = OutputStyles::generate_style_file(&style)?;
+                    let style_path = config_dir
+                        .join("output-styles")
+                        .join(format!("{}.md", style.id));
+                    recorder.write(*target, &style_path, style_content)?;
                     output_style_installed = Some(style.name.clone());
                 }
             }
@@ -1241,12 +2419,25 @@ Skills in `{}/`:
         ))
     }
 
-    /// Generate output style file content with frontmatter
-    fn generate_output_style_content(style: &OutputStyle) -> String {
-        format!(
-            "---\nname: {}\ndescription: {}\nkeepCodingInstructions: {}\n---\n\n{}",
-            style.name, style.description, style.keep_coding_instructions, style.content
-        )
+    /// The output style installing `profile` deploys: its own when it names one,
+    /// otherwise the globally active style
+    pub fn output_style_for(profile: &Profile) -> Result<Option<OutputStyle>> {
+        let Some(style_id) = &profile.output_style else {
+            return OutputStyles::get_active();
+        };
+        OutputStyles::get(style_id)?.map(Some).ok_or_else(|| {
+            RhinolabsError::ConfigError(format!(
+                "Profile '{}' uses output style '{}', which does not exist",
+                profile.id, style_id
+            ))
+        })
+    }
+
+    /// Where the main profile install deploys an output style for Claude Code
+    pub(crate) fn user_output_style_path(style: &OutputStyle) -> Result<PathBuf> {
+        Ok(TargetPaths::user_config_dir(DeployTarget::ClaudeCode)?
+            .join("output-styles")
+            .join(format!("{}.md", style.id)))
     }
 
     /// Install a single skill to a target skills directory
@@ -1262,8 +2453,8 @@ Skills in `{}/`:
     /// Skills that were copied (fallback when symlinks aren't supported) remain tracked by git.
     ///
     /// The generated section is delimited by markers so it can be updated on re-runs
-    /// without affecting user-added entries.
-    fn generate_skills_gitignore(skills_dir: &Path, skill_ids: &[String]) -> Result<()> {
+    /// without affecting user-added entries. Returns whether the section was written.
+    fn generate_skills_gitignore(skills_dir: &Path, skill_ids: &[String]) -> Result<bool> {
         // Detect which installed skills are symlinks
         let mut symlinked: Vec<&str> = Vec::new();
         for skill_id in skill_ids {
@@ -1274,7 +2465,12 @@ Skills in `{}/`:
                 }
             }
         }
+        Self::write_skills_gitignore(skills_dir, symlinked)
+    }
 
+    /// Write the skills `.gitignore` section listing `symlinked` skills, as described on
+    /// `generate_skills_gitignore`
+    fn write_skills_gitignore(skills_dir: &Path, mut symlinked: Vec<&str>) -> Result<bool> {
         let gitignore_path = skills_dir.join(".gitignore");
 
         // If no symlinks exist, remove auto-generated section (or skip entirely)
@@ -1288,7 +2484,7 @@ Skills in `{}/`:
                     fs::write(&gitignore_path, cleaned)?;
                 }
             }
-            return Ok(());
+            return Ok(false);
         }
 
         // Preserve existing user entries (everything outside our auto-generated section)
@@ -1308,54 +2504,82 @@ Skills in `{}/`:
         }
 
         // Auto-generated section
-        content
-            .push_str("# Auto-generated by rhinolabs-ai — symlinked skills (machine-specific)\n");
-        content.push_str("# Re-run `rlai profile install` to regenerate\n");
+        content.push_str(&crate::gitignore::block_start(
+            "symlinked skills (machine-specific)",
+        ));
+        content.push_str("\n# Re-run `rlai profile install` to regenerate\n");
         symlinked.sort();
-        for skill_id in &symlinked {
+        for skill_id in symlinked {
             content.push_str(skill_id);
             content.push('\n');
         }
-        content.push_str("# End rhinolabs-ai\n");
+        content.push_str(crate::gitignore::BLOCK_END);
+        content.push('\n');
 
         fs::write(&gitignore_path, content)?;
-        Ok(())
+        Ok(true)
     }
 
-    /// Remove the auto-generated section from a `.gitignore` content string,
-    /// preserving everything outside the markers.
+    /// A `.gitignore`'s content without its rhinolabs-ai blocks
     fn strip_auto_section(content: &str) -> String {
-        let mut result = String::new();
-        let mut in_auto_section = false;
-
-        for line in content.lines() {
-            if line.contains("Auto-generated by rhinolabs-ai") {
-                in_auto_section = true;
-                continue;
-            }
-            if in_auto_section && line.contains("End rhinolabs-ai") {
-                in_auto_section = false;
-                continue;
-            }
-            if !in_auto_section {
-                result.push_str(line);
-                result.push('\n');
-            }
-        }
+        crate::gitignore::user_lines(content)
+            .iter()
+            .map(|line| format!("{}\n", line))
+            .collect()
+    }
 
-        result
+    /// Add the files a project profile install generated to the project's `.gitignore`,
+    /// in a block rhinolabs-ai keeps up to date on later installs and uninstalls.
+    /// Only paths recorded in `.rhinolabs-install.json` are listed. Returns the
+    /// entries in the block.
+    pub fn add_to_gitignore(target_path: &Path) -> Result<Vec<String>> {
+        let manifest = InstallManifest::load(target_path)?.ok_or_else(|| {
+            RhinolabsError::ConfigError(format!(
+                "No profile installation found at {}",
+                target_path.display()
+            ))
+        })?;
+        crate::gitignore::write_block(target_path, &manifest.gitignore_entries())
     }
 
     /// Uninstall a profile from a target path.
     /// If `targets` is `None`, removes ALL known target artifacts.
-    pub fn uninstall(target_path: &Path, targets: Option<&[DeployTarget]>) -> Result<()> {
+    /// With `keep_instructions`, instruction files (CLAUDE.md, AGENTS.md, ...) are
+    /// left in place even if rhinolabs-ai generated them.
+    ///
+    /// When the project has a `.rhinolabs-install.json` manifest, only the paths it
+    /// records are removed (directories only once empty) and the manifest is deleted
+    /// with its last target. Older installs without one fall back to removing the
+    /// targets' config directories and generated files.
+    pub fn uninstall(
+        target_path: &Path,
+        targets: Option<&[DeployTarget]>,
+        keep_instructions: bool,
+    ) -> Result<()> {
         let effective_targets = targets.unwrap_or_else(|| DeployTarget::all());
 
+        // Installs that recorded a manifest are removed precisely from it
+        if let Some(mut manifest) = InstallManifest::load(target_path)? {
+            let recorded: Vec<DeployTarget> = manifest.targets.iter().map(|t| t.target).collect();
+            let mut removed_any = false;
+            for target in targets.unwrap_or(&recorded) {
+                removed_any |= manifest.remove_target(target_path, *target, keep_instructions)?;
+            }
+            if !removed_any {
+                return Err(RhinolabsError::ConfigError(format!(
+                    "No profile installation found at {} for the selected targets",
+                    target_path.display()
+                )));
+            }
+            manifest.save(target_path)?;
+            return manifest.refresh_gitignore(target_path);
+        }
+
         // Check if any installation exists
         let has_any = effective_targets.iter().any(|target| {
             let config_dir = TargetPaths::project_config_dir(*target, target_path);
             let instructions = TargetPaths::instructions_path(*target, target_path);
-            config_dir.exists() || instructions.exists()
+            config_dir.exists() || (!keep_instructions && instructions.exists())
         }) || target_path.join(".claude-plugin").exists();
 
         if !has_any {
@@ -1372,12 +2596,21 @@ Skills in `{}/`:
                 fs::remove_dir_all(&config_dir)?;
             }
 
-            // Remove instructions file if generated by rhinolabs-ai
-            let instructions_path = TargetPaths::instructions_path(*target, target_path);
-            if instructions_path.exists() {
-                if let Ok(content) = fs::read_to_string(&instructions_path) {
-                    if content.contains("rhinolabs-ai") {
-                        fs::remove_file(&instructions_path)?;
+            // Remove instructions files if generated by rhinolabs-ai
+            for instructions_path in Self::instructions_paths(*target, target_path) {
+                // opencode.json only loses its entry for the generated markdown
+                if !keep_instructions
+                    && instructions_path == TargetPaths::instructions_path(*target, target_path)
+                    && *target == DeployTarget::OpenCode
+                    && Self::remove_opencode_config(&instructions_path)?
+                {
+                    continue;
+                }
+                if !keep_instructions && instructions_path.exists() {
+                    if let Ok(content) = fs::read_to_string(&instructions_path) {
+                        if content.contains("rhinolabs-ai") {
+                            fs::remove_file(&instructions_path)?;
+                        }
                     }
                 }
             }
@@ -1392,7 +2625,7 @@ Skills in `{}/`:
 
                 // Remove .github/copilot-instructions.md only if generated by us
                 let copilot_md = target_path.join(".github").join("copilot-instructions.md");
-                if copilot_md.exists() {
+                if !keep_instructions && copilot_md.exists() {
                     if let Ok(content) = fs::read_to_string(&copilot_md) {
                         if content.contains("Generated by rhinolabs-ai")
                             || content.contains("Auto-generated by rhinolabs-ai")
@@ -1416,59 +2649,81 @@ Skills in `{}/`:
         Ok(())
     }
 
-    /// Update an installed profile (re-install with latest skill versions)
+    /// Update an installed profile (re-install with latest skill versions).
+    ///
+    /// Skills whose source is unchanged since the last install are skipped and listed in
+    /// `skills_unchanged`; with `options.force`, every skill is redeployed.
+    ///
+    /// Existing instructions files only have their managed skills section regenerated.
+    /// Files without the section markers are left alone, or overwritten with
+    /// `options.overwrite_instructions`.
+    ///
+    /// A project installed without the plugin manifest (`--no-plugin`, recorded in
+    /// `.rhinolabs-install.json`) keeps it off; run `install` again to add it back.
     pub fn update_installed(
         profile_id: &str,
         target_path: Option<&Path>,
         targets: Option<&[DeployTarget]>,
+        mut options: InstallOptions,
     ) -> Result<ProfileInstallResult> {
-        // Simply re-install - install_skill already handles removing existing
-        Self::install(profile_id, target_path, targets)
+        let profile = Self::resolve(profile_id)?;
+        if profile.profile_type == ProfileType::Project {
+            let base = Self::install_base(&profile, target_path)?;
+            if InstallManifest::load(&base)?.is_some_and(|manifest| manifest.no_plugin) {
+                options.create_plugin_manifest = false;
+            }
+        }
+        Self::install(profile_id, target_path, targets, options)
+    }
+
+    /// Paths whose changes alter what installing `profile_id` deploys: the skills
+    /// directory, the profile's instructions file and the profiles config.
+    /// Paths that do not exist yet are included too.
+    pub fn watch_paths(profile_id: &str) -> Result<Vec<PathBuf>> {
+        // Fail early for an unknown profile instead of watching nothing useful
+        Self::resolve(profile_id)?;
+        Ok(vec![
+            Paths::plugin_dir()?.join("skills"),
+            Self::get_instructions_path(profile_id)?,
+            Self::config_path()?,
+        ])
     }
 
     // ============================================
     // Profile Sync (declared vs installed)
     // ============================================
 
-    /// Sync a project's installed skills with what its profile declares.
+    /// Compare a project's installed skills with what its profile declares,
+    /// without changing anything.
     ///
-    /// Reads `.claude-plugin/plugin.json` to find the declared profile and skills,
-    /// compares with what's actually installed in `.claude/skills/`, and reconciles:
-    /// - Missing skills → installed
-    /// - Extra skills (not in profile) → removed
-    /// - Already correct → unchanged
-    pub fn sync_project(project_path: &Path) -> Result<ProfileSyncResult> {
-        let plugin_json = project_path.join(".claude-plugin").join("plugin.json");
+    /// Returns what [`Self::sync_project`] would do: `added` lists the declared
+    /// skills that are missing, `removed` the installed skills the profile no
+    /// longer declares. Status is "synced", "out_of_sync" or "no_profile".
+    pub fn diff_project(project_path: &Path) -> Result<ProfileSyncResult> {
+        let no_profile = |profile_id: Option<String>| ProfileSyncResult {
+            status: "no_profile".to_string(),
+            added: Vec::new(),
+            removed: Vec::new(),
+            unchanged: Vec::new(),
+            profile_id,
+            warnings: Warnings::new(),
+        };
 
+        let plugin_json = project_path.join(".claude-plugin").join("plugin.json");
         if !plugin_json.exists() {
-            return Ok(ProfileSyncResult {
-                status: "no_profile".to_string(),
-                added: Vec::new(),
-                removed: Vec::new(),
-                unchanged: Vec::new(),
-                profile_id: None,
-            });
+            return Ok(no_profile(None));
         }
 
-        // Read plugin.json to get profile info and declared skills
+        // Read plugin.json to get the installed profile
         let content = fs::read_to_string(&plugin_json)?;
         let manifest: serde_json::Value = serde_json::from_str(&content)?;
-
         let profile_id = manifest["profile"]["id"].as_str().unwrap_or("").to_string();
 
         // Get the declared skills from the profile config
         let declared_skills: Vec<String> = match Self::get(&profile_id)? {
-            Some(profile) => profile.skills,
-            None => {
-                // Profile not in config anymore — nothing to sync
-                return Ok(ProfileSyncResult {
-                    status: "no_profile".to_string(),
-                    added: Vec::new(),
-                    removed: Vec::new(),
-                    unchanged: Vec::new(),
-                    profile_id: Some(profile_id),
-                });
-            }
+            Some(_) => Self::resolve(&profile_id)?.skills,
+            // Profile not in config anymore — nothing to compare against
+            None => return Ok(no_profile(Some(profile_id))),
         };
 
         // Get currently installed skills (directories/symlinks in .claude/skills/)
@@ -1491,45 +2746,76 @@ Skills in `{}/`:
             }
         }
 
-        // Compute diff
-        let declared_set: std::collections::HashSet<&str> =
-            declared_skills.iter().map(|s| s.as_str()).collect();
-        let installed_set: std::collections::HashSet<&str> =
-            installed_skills.iter().map(|s| s.as_str()).collect();
-
-        let to_add: Vec<String> = declared_set
-            .difference(&installed_set)
-            .map(|s| s.to_string())
+        let mut added: Vec<String> = declared_skills
+            .iter()
+            .filter(|skill| !installed_skills.contains(skill))
+            .cloned()
             .collect();
-        let to_remove: Vec<String> = installed_set
-            .difference(&declared_set)
-            .map(|s| s.to_string())
+        let mut removed: Vec<String> = installed_skills
+            .iter()
+            .filter(|skill| !declared_skills.contains(skill))
+            .cloned()
             .collect();
-        let unchanged: Vec<String> = declared_set
-            .intersection(&installed_set)
-            .map(|s| s.to_string())
+        let mut unchanged: Vec<String> = declared_skills
+            .iter()
+            .filter(|skill| installed_skills.contains(skill))
+            .cloned()
             .collect();
+        added.sort();
+        added.dedup();
+        removed.sort();
+        unchanged.sort();
+        unchanged.dedup();
+
+        let status = if added.is_empty() && removed.is_empty() {
+            "synced"
+        } else {
+            "out_of_sync"
+        };
+        Ok(ProfileSyncResult {
+            status: status.to_string(),
+            added,
+            removed,
+            unchanged,
+            profile_id: Some(profile_id),
+            warnings: Warnings::new(),
+        })
+    }
 
-        if to_add.is_empty() && to_remove.is_empty() {
-            return Ok(ProfileSyncResult {
-                status: "synced".to_string(),
-                added: Vec::new(),
-                removed: Vec::new(),
-                unchanged,
-                profile_id: Some(profile_id),
-            });
-        }
+    /// Sync a project's installed skills with what its profile declares.
+    ///
+    /// Reads `.claude-plugin/plugin.json` to find the declared profile and skills,
+    /// compares with what's actually installed in `.claude/skills/` (see
+    /// [`Self::diff_project`]), and reconciles:
+    /// - Missing skills → installed
+    /// - Extra skills (not in profile) → removed
+    /// - Already correct → unchanged
+    pub fn sync_project(project_path: &Path) -> Result<ProfileSyncResult> {
+        let diff = Self::diff_project(project_path)?;
+        let (Some(profile_id), "out_of_sync") = (diff.profile_id.clone(), diff.status.as_str())
+        else {
+            return Ok(diff);
+        };
+        let ProfileSyncResult {
+            added: to_add,
+            removed: to_remove,
+            unchanged,
+            ..
+        } = diff;
+        let declared_skills = Self::resolve(&profile_id)?.skills;
+        let skills_dir = TargetPaths::project_skills_dir(DeployTarget::ClaudeCode, project_path);
 
         // Apply changes
         fs::create_dir_all(&skills_dir)?;
 
         // Install missing skills
         let mut added = Vec::new();
+        let mut warnings = Warnings::new();
         for skill_id in &to_add {
             match Self::install_skill(skill_id, &skills_dir) {
                 Ok(_) => added.push(skill_id.clone()),
                 Err(e) => {
-                    eprintln!("Warning: could not install skill '{}': {}", skill_id, e);
+                    warnings.push(format!("Could not install skill '{}': {}", skill_id, e));
                 }
             }
         }
@@ -1558,6 +2844,7 @@ Skills in `{}/`:
             removed,
             unchanged,
             profile_id: Some(profile_id),
+            warnings,
         })
     }
 
@@ -1679,7 +2966,7 @@ Skills in `{}/`:
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::{TestEnv as BaseTestEnv, ENV_MUTEX};
+    use crate::test_utils::{HomeEnv, TestEnv as BaseTestEnv, ENV_MUTEX};
     use crate::DeployTarget;
 
     struct TestEnv {
@@ -1775,6 +3062,9 @@ mod tests {
             instructions: None,
             generate_copilot: true,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
         };
 
         // Note: This test would need proper config path override mechanism
@@ -1790,6 +3080,11 @@ mod tests {
             instructions: Some("# Test Instructions".to_string()),
             generate_copilot: input.generate_copilot,
             generate_agents: input.generate_agents,
+            generate_gemini: input.generate_gemini,
+            generate_opencode: input.generate_opencode,
+            tags: input.tags.clone(),
+            extends: None,
+            output_style: None,
             created_at: now.clone(),
             updated_at: now,
         };
@@ -1819,6 +3114,11 @@ mod tests {
             instructions: Some("# My Instructions".to_string()),
             generate_copilot: true,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
@@ -1844,6 +3144,8 @@ mod tests {
             profile_name: "React Stack".to_string(),
             target_path: "/project/.claude/skills".to_string(),
             skills_installed: vec!["react-19".to_string(), "typescript".to_string()],
+            skills_unchanged: Vec::new(),
+            skills_refreshed: Vec::new(),
             skills_failed: vec![SkillInstallError {
                 skill_id: "missing-skill".to_string(),
                 error: "Skill not found".to_string(),
@@ -1851,7 +3153,11 @@ mod tests {
             instructions_installed: None,
             settings_installed: None,
             output_style_installed: None,
+            plugin_manifest_installed: None,
+            skills_skipped_disabled: vec![],
             targets_installed: vec![DeployTarget::ClaudeCode],
+            targets_failed: vec![],
+            duration_ms: 0,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -1869,11 +3175,17 @@ mod tests {
             profile_name: "Main Profile".to_string(),
             target_path: "~/.claude/skills".to_string(),
             skills_installed: vec!["general-skill".to_string()],
+            skills_unchanged: Vec::new(),
+            skills_refreshed: Vec::new(),
             skills_failed: vec![],
             instructions_installed: Some(true),
             settings_installed: Some(true),
             output_style_installed: Some("Rhinolabs".to_string()),
+            plugin_manifest_installed: None,
+            skills_skipped_disabled: vec![],
             targets_installed: vec![DeployTarget::ClaudeCode],
+            targets_failed: vec![],
+            duration_ms: 0,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -1990,6 +3302,11 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
@@ -2016,6 +3333,11 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
@@ -2038,12 +3360,18 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
 
         let content =
             Profiles::generate_instructions_for_target(&profile, DeployTarget::Antigravity);
+        assert!(content.starts_with("# Gemini Instructions"));
         assert!(content.contains(".agent/skills/"));
         assert!(content.contains(".agent/skills/react-19/SKILL.md"));
         assert!(!content.contains(".agents/skills/"));
@@ -2062,6 +3390,11 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
@@ -2088,6 +3421,11 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
@@ -2110,6 +3448,11 @@ mod tests {
             instructions: Some("Use strict TypeScript always.".to_string()),
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
@@ -2131,6 +3474,11 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
@@ -2163,6 +3511,11 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
@@ -2186,7 +3539,9 @@ mod tests {
     }
 
     #[test]
-    fn test_install_project_profile_for_targets_amp_creates_agents_md() {
+    fn test_install_project_amp_creates_agents_md() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
         let target_dir = tempfile::tempdir().unwrap();
         let profile = Profile {
             id: "test".to_string(),
@@ -2198,13 +3553,24 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
 
+        setup_install_profile(&env, &profile);
+
         let targets = [DeployTarget::Amp];
-        let result =
-            Profiles::install_project_profile_for_targets(target_dir.path(), &profile, &targets);
+        let result = Profiles::install(
+            &profile.id,
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        );
         assert!(result.is_ok());
 
         // AGENTS.md should exist (Amp's instructions file)
@@ -2229,7 +3595,50 @@ mod tests {
     }
 
     #[test]
-    fn test_install_project_profile_for_targets_multi_target() {
+    fn test_install_project_custom_target() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let target_dir = tempfile::tempdir().unwrap();
+        let profile = Profile {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            description: "Test profile".to_string(),
+            profile_type: ProfileType::Project,
+            skills: vec!["react-19".to_string()],
+            auto_invoke_rules: Vec::new(),
+            instructions: None,
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+        };
+
+        setup_install_profile(&env, &profile);
+
+        let targets = [crate::test_utils::acme_target()];
+        Profiles::install(
+            &profile.id,
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        let content = fs::read_to_string(target_dir.path().join("ACME.md")).unwrap();
+        assert!(content.contains("`.acme/skills/react-19/SKILL.md`"));
+        assert!(!target_dir.path().join("CLAUDE.md").exists());
+        assert!(!target_dir.path().join(".claude-plugin").exists());
+    }
+
+    #[test]
+    fn test_install_project_multi_target() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
         let target_dir = tempfile::tempdir().unwrap();
         let profile = Profile {
             id: "test".to_string(),
@@ -2241,13 +3650,24 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
 
+        setup_install_profile(&env, &profile);
+
         let targets = [DeployTarget::ClaudeCode, DeployTarget::Amp];
-        let result =
-            Profiles::install_project_profile_for_targets(target_dir.path(), &profile, &targets);
+        let result = Profiles::install(
+            &profile.id,
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        );
         assert!(result.is_ok());
 
         // Both CLAUDE.md and AGENTS.md should exist
@@ -2266,7 +3686,9 @@ mod tests {
     }
 
     #[test]
-    fn test_install_project_profile_for_targets_claudecode_creates_plugin_manifest() {
+    fn test_install_project_claudecode_creates_plugin_manifest() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
         let target_dir = tempfile::tempdir().unwrap();
         let profile = Profile {
             id: "my-proj".to_string(),
@@ -2278,13 +3700,25 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
 
+        setup_install_profile(&env, &profile);
+
         let targets = [DeployTarget::ClaudeCode];
-        Profiles::install_project_profile_for_targets(target_dir.path(), &profile, &targets)
-            .unwrap();
+        Profiles::install(
+            &profile.id,
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
 
         let manifest_path = target_dir.path().join(".claude-plugin").join("plugin.json");
         assert!(manifest_path.exists());
@@ -2296,7 +3730,9 @@ mod tests {
     }
 
     #[test]
-    fn test_install_project_profile_for_targets_copilot_only_for_claudecode() {
+    fn test_install_project_copilot_only_for_claudecode() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
         let target_dir = tempfile::tempdir().unwrap();
         let profile = Profile {
             id: "test".to_string(),
@@ -2308,14 +3744,26 @@ mod tests {
             instructions: None,
             generate_copilot: true,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
 
         // Install only to Amp → no copilot file
+        setup_install_profile(&env, &profile);
+
         let targets = [DeployTarget::Amp];
-        Profiles::install_project_profile_for_targets(target_dir.path(), &profile, &targets)
-            .unwrap();
+        Profiles::install(
+            &profile.id,
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
         assert!(
             !target_dir.path().join(".github").exists(),
             "copilot-instructions.md should NOT be created for Amp target"
@@ -2324,8 +3772,13 @@ mod tests {
         // Install to ClaudeCode → copilot file should be created
         let target_dir2 = tempfile::tempdir().unwrap();
         let targets = [DeployTarget::ClaudeCode];
-        Profiles::install_project_profile_for_targets(target_dir2.path(), &profile, &targets)
-            .unwrap();
+        Profiles::install(
+            &profile.id,
+            Some(target_dir2.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
         assert!(
             target_dir2
                 .path()
@@ -2337,7 +3790,9 @@ mod tests {
     }
 
     #[test]
-    fn test_install_project_profile_for_targets_generate_agents_supplementary() {
+    fn test_install_project_generate_agents_supplementary() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
         let target_dir = tempfile::tempdir().unwrap();
         let profile = Profile {
             id: "test".to_string(),
@@ -2349,15 +3804,27 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: true, // generate supplementary AGENTS.md
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
 
         // Install only to ClaudeCode with generate_agents=true
         // Should still create AGENTS.md as supplementary cross-reference
+        setup_install_profile(&env, &profile);
+
         let targets = [DeployTarget::ClaudeCode];
-        Profiles::install_project_profile_for_targets(target_dir.path(), &profile, &targets)
-            .unwrap();
+        Profiles::install(
+            &profile.id,
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
 
         assert!(target_dir.path().join("CLAUDE.md").exists());
         assert!(
@@ -2367,7 +3834,9 @@ mod tests {
     }
 
     #[test]
-    fn test_install_project_profile_for_targets_no_supplementary_agents_when_amp_target() {
+    fn test_install_project_no_supplementary_agents_when_amp_target() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
         let target_dir = tempfile::tempdir().unwrap();
         let profile = Profile {
             id: "test".to_string(),
@@ -2379,15 +3848,27 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: true,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
 
         // When Amp IS in targets, generate_agents should NOT create supplementary AGENTS.md
         // (because Amp's primary instructions file IS AGENTS.md already)
+        setup_install_profile(&env, &profile);
+
         let targets = [DeployTarget::ClaudeCode, DeployTarget::Amp];
-        Profiles::install_project_profile_for_targets(target_dir.path(), &profile, &targets)
-            .unwrap();
+        Profiles::install(
+            &profile.id,
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
 
         // AGENTS.md should exist (from Amp target), but should use .agents/skills/ prefix
         let agents_content = fs::read_to_string(target_dir.path().join("AGENTS.md")).unwrap();
@@ -2395,16 +3876,10 @@ mod tests {
     }
 
     #[test]
-    fn test_install_project_profile_does_not_overwrite_existing_instructions() {
+    fn test_install_generate_gemini_and_opencode() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
         let target_dir = tempfile::tempdir().unwrap();
-
-        // Pre-create a custom AGENTS.md
-        fs::write(
-            target_dir.path().join("AGENTS.md"),
-            "# My Custom AGENTS.md\nDo not overwrite me.",
-        )
-        .unwrap();
-
         let profile = Profile {
             id: "test".to_string(),
             name: "Test".to_string(),
@@ -2415,27 +3890,351 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: true,
+            generate_opencode: true,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
 
-        let targets = [DeployTarget::Amp];
-        Profiles::install_project_profile_for_targets(target_dir.path(), &profile, &targets)
-            .unwrap();
+        setup_install_profile(&env, &profile);
 
-        // The pre-existing file should NOT be overwritten
-        let content = fs::read_to_string(target_dir.path().join("AGENTS.md")).unwrap();
-        assert!(
-            content.contains("Do not overwrite me."),
-            "Existing instructions file should not be overwritten"
-        );
+        // Only ClaudeCode selected: both supplementary files come from the profile flags
+        let targets = [DeployTarget::ClaudeCode];
+        Profiles::install(
+            "test",
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        let gemini = fs::read_to_string(target_dir.path().join("GEMINI.md")).unwrap();
+        let opencode = fs::read_to_string(target_dir.path().join("OPENCODE.md")).unwrap();
+        // Skill references point at where skills were actually installed
+        assert!(gemini.starts_with("# Gemini Instructions"));
+        assert!(gemini.contains(".claude/skills/skill-a/SKILL.md"));
+        assert!(opencode.contains(".claude/skills/skill-a/SKILL.md"));
+
+        // opencode.json is config that points at the markdown, not markdown itself
+        let config: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(target_dir.path().join("opencode.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(config["$schema"], OPENCODE_CONFIG_SCHEMA);
+        assert_eq!(config["instructions"], serde_json::json!(["OPENCODE.md"]));
     }
 
     #[test]
-    fn test_install_project_profile_all_four_targets() {
+    fn test_install_opencode_keeps_existing_opencode_config() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
         let target_dir = tempfile::tempdir().unwrap();
         let profile = Profile {
-            id: "full".to_string(),
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            description: "Desc".to_string(),
+            profile_type: ProfileType::Project,
+            skills: vec!["skill-a".to_string()],
+            auto_invoke_rules: Vec::new(),
+            instructions: None,
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+        };
+        setup_install_profile(&env, &profile);
+        let config_path = target_dir.path().join("opencode.json");
+        fs::write(
+            &config_path,
+            r#"{"model": "anthropic/claude", "instructions": ["CONTRIBUTING.md"]}"#,
+        )
+        .unwrap();
+
+        // Installing twice lists the generated file once, after the user's own entries
+        let targets = [DeployTarget::OpenCode];
+        for _ in 0..2 {
+            Profiles::install(
+                "test",
+                Some(target_dir.path()),
+                Some(&targets),
+                InstallOptions::default(),
+            )
+            .unwrap();
+        }
+
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(config["model"], "anthropic/claude");
+        assert_eq!(
+            config["instructions"],
+            serde_json::json!(["CONTRIBUTING.md", "OPENCODE.md"])
+        );
+        let markdown = fs::read_to_string(target_dir.path().join("OPENCODE.md")).unwrap();
+        assert!(markdown.contains(".opencode/skills/skill-a/SKILL.md"));
+
+        // Raw markdown left in opencode.json by an older install is replaced
+        fs::write(
+            &config_path,
+            Profiles::generate_instructions_for_target(&profile, DeployTarget::OpenCode),
+        )
+        .unwrap();
+        Profiles::install(
+            "test",
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(config["instructions"], serde_json::json!(["OPENCODE.md"]));
+    }
+
+    #[test]
+    fn test_uninstall_removes_only_the_generated_entry_from_opencode_config() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        let config_path = project.path().join("opencode.json");
+        fs::write(
+            &config_path,
+            r#"{"model": "anthropic/claude", "instructions": ["CONTRIBUTING.md"]}"#,
+        )
+        .unwrap();
+        let targets = [DeployTarget::OpenCode];
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        Profiles::uninstall(project.path(), None, false).unwrap();
+
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(config["model"], "anthropic/claude");
+        assert_eq!(
+            config["instructions"],
+            serde_json::json!(["CONTRIBUTING.md"])
+        );
+        assert!(!project.path().join("OPENCODE.md").exists());
+
+        // A config the install created is removed along with its only entry
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+        fs::remove_file(&config_path).unwrap();
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+        Profiles::uninstall(project.path(), None, false).unwrap();
+        assert!(!config_path.exists());
+    }
+
+    #[test]
+    fn test_legacy_uninstall_keeps_opencode_config_consistent() {
+        let project = tempfile::tempdir().unwrap();
+        fs::create_dir_all(project.path().join(".opencode/skills")).unwrap();
+        fs::write(
+            project.path().join("OPENCODE.md"),
+            "*Installed by rhinolabs-ai*",
+        )
+        .unwrap();
+        fs::write(
+            project.path().join("opencode.json"),
+            r#"{"model": "anthropic/claude", "instructions": ["OPENCODE.md"]}"#,
+        )
+        .unwrap();
+
+        Profiles::uninstall(project.path(), Some(&[DeployTarget::OpenCode]), false).unwrap();
+
+        assert!(!project.path().join("OPENCODE.md").exists());
+        let config: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(project.path().join("opencode.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(config["model"], "anthropic/claude");
+        assert!(config.get("instructions").is_none());
+    }
+
+    #[test]
+    fn test_install_project_skips_gemini_and_opencode_when_disabled() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let target_dir = tempfile::tempdir().unwrap();
+        let profile = Profile {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            description: "Desc".to_string(),
+            profile_type: ProfileType::Project,
+            skills: vec!["skill-a".to_string()],
+            auto_invoke_rules: Vec::new(),
+            instructions: None,
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+        };
+
+        setup_install_profile(&env, &profile);
+
+        let targets = [DeployTarget::ClaudeCode];
+        Profiles::install(
+            &profile.id,
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        assert!(target_dir.path().join("CLAUDE.md").exists());
+        assert!(!target_dir.path().join("GEMINI.md").exists());
+        assert!(!target_dir.path().join("opencode.json").exists());
+    }
+
+    #[test]
+    fn test_install_project_gemini_flag_defers_to_antigravity_target() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let target_dir = tempfile::tempdir().unwrap();
+        let profile = Profile {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            description: "Desc".to_string(),
+            profile_type: ProfileType::Project,
+            skills: vec!["skill-a".to_string()],
+            auto_invoke_rules: Vec::new(),
+            instructions: None,
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: true,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+        };
+
+        // Antigravity selected: GEMINI.md is the target's own file with its skill prefix
+        setup_install_profile(&env, &profile);
+
+        let targets = [DeployTarget::ClaudeCode, DeployTarget::Antigravity];
+        Profiles::install(
+            &profile.id,
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        let gemini = fs::read_to_string(target_dir.path().join("GEMINI.md")).unwrap();
+        assert!(gemini.contains(&format!(
+            "{}/skill-a/SKILL.md",
+            DeployTarget::Antigravity.project_skills_prefix()
+        )));
+    }
+
+    #[test]
+    fn test_profile_deserialize_without_gemini_opencode_flags() {
+        let json = r#"{
+            "id": "legacy",
+            "name": "Legacy",
+            "description": "",
+            "profileType": "project",
+            "skills": [],
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": "2024-01-01T00:00:00Z"
+        }"#;
+
+        let profile: Profile = serde_json::from_str(json).unwrap();
+        assert!(!profile.generate_gemini);
+        assert!(!profile.generate_opencode);
+    }
+
+    #[test]
+    fn test_install_project_profile_does_not_overwrite_existing_instructions() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let target_dir = tempfile::tempdir().unwrap();
+
+        // Pre-create a custom AGENTS.md
+        fs::write(
+            target_dir.path().join("AGENTS.md"),
+            "# My Custom AGENTS.md\nDo not overwrite me.",
+        )
+        .unwrap();
+
+        let profile = Profile {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            description: "Desc".to_string(),
+            profile_type: ProfileType::Project,
+            skills: vec!["skill-a".to_string()],
+            auto_invoke_rules: Vec::new(),
+            instructions: None,
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+        };
+
+        setup_install_profile(&env, &profile);
+
+        let targets = [DeployTarget::Amp];
+        Profiles::install(
+            &profile.id,
+            Some(target_dir.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        // The pre-existing file should NOT be overwritten
+        let content = fs::read_to_string(target_dir.path().join("AGENTS.md")).unwrap();
+        assert!(
+            content.contains("Do not overwrite me."),
+            "Existing instructions file should not be overwritten"
+        );
+    }
+
+    #[test]
+    fn test_install_project_profile_all_four_targets() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let target_dir = tempfile::tempdir().unwrap();
+        let profile = Profile {
+            id: "full".to_string(),
             name: "Full".to_string(),
             description: "All targets".to_string(),
             profile_type: ProfileType::Project,
@@ -2444,13 +4243,25 @@ mod tests {
             instructions: None,
             generate_copilot: false,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
         };
 
+        setup_install_profile(&env, &profile);
+
         let targets = DeployTarget::all();
-        Profiles::install_project_profile_for_targets(target_dir.path(), &profile, targets)
-            .unwrap();
+        Profiles::install(
+            &profile.id,
+            Some(target_dir.path()),
+            Some(targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
 
         // All four instructions files should exist
         assert!(target_dir.path().join("CLAUDE.md").exists());
@@ -2458,6 +4269,16 @@ mod tests {
         assert!(target_dir.path().join("GEMINI.md").exists());
         assert!(target_dir.path().join("opencode.json").exists());
 
+        // Each is in the format its tool reads
+        let gemini = fs::read_to_string(target_dir.path().join("GEMINI.md")).unwrap();
+        assert!(gemini.starts_with("# Gemini Instructions"));
+        assert!(gemini.contains(".agent/skills/react-19/SKILL.md"));
+        let opencode = fs::read_to_string(target_dir.path().join("opencode.json")).unwrap();
+        let config: serde_json::Value = serde_json::from_str(&opencode).unwrap();
+        assert_eq!(config["instructions"], serde_json::json!(["OPENCODE.md"]));
+        let opencode_md = fs::read_to_string(target_dir.path().join("OPENCODE.md")).unwrap();
+        assert!(opencode_md.contains(".opencode/skills/react-19/SKILL.md"));
+
         // Only ClaudeCode gets .claude-plugin
         assert!(target_dir.path().join(".claude-plugin").exists());
     }
@@ -2481,7 +4302,7 @@ mod tests {
         .unwrap();
 
         let targets = [DeployTarget::Amp];
-        let result = Profiles::uninstall(target_dir.path(), Some(&targets));
+        let result = Profiles::uninstall(target_dir.path(), Some(&targets), false);
         assert!(result.is_ok());
 
         // Amp artifacts should be removed
@@ -2518,7 +4339,7 @@ mod tests {
 
         // Uninstall ONLY Amp
         let targets = [DeployTarget::Amp];
-        Profiles::uninstall(target_dir.path(), Some(&targets)).unwrap();
+        Profiles::uninstall(target_dir.path(), Some(&targets), false).unwrap();
 
         // Amp artifacts should be gone
         assert!(!agents_dir.exists());
@@ -2570,7 +4391,7 @@ mod tests {
         .unwrap();
 
         // Uninstall with None (= remove all)
-        Profiles::uninstall(target_dir.path(), None).unwrap();
+        Profiles::uninstall(target_dir.path(), None, false).unwrap();
 
         assert!(!target_dir.path().join(".claude").exists());
         assert!(!target_dir.path().join(".agents").exists());
@@ -2596,7 +4417,7 @@ mod tests {
         fs::create_dir_all(target_dir.path().join(".claude").join("skills")).unwrap();
 
         let targets = [DeployTarget::ClaudeCode];
-        Profiles::uninstall(target_dir.path(), Some(&targets)).unwrap();
+        Profiles::uninstall(target_dir.path(), Some(&targets), false).unwrap();
 
         // The .claude dir should be removed (it's a config dir)
         assert!(!target_dir.path().join(".claude").exists());
@@ -2618,7 +4439,7 @@ mod tests {
         fs::write(plugin_dir.join("plugin.json"), "{}").unwrap();
 
         let targets = [DeployTarget::ClaudeCode];
-        Profiles::uninstall(target_dir.path(), Some(&targets)).unwrap();
+        Profiles::uninstall(target_dir.path(), Some(&targets), false).unwrap();
 
         assert!(
             !plugin_dir.exists(),
@@ -2631,7 +4452,7 @@ mod tests {
         let target_dir = tempfile::tempdir().unwrap();
 
         // Empty directory — nothing to uninstall
-        let result = Profiles::uninstall(target_dir.path(), None);
+        let result = Profiles::uninstall(target_dir.path(), None, false);
         assert!(result.is_err());
     }
 
@@ -2666,11 +4487,17 @@ mod tests {
             profile_name: "Test".to_string(),
             target_path: "/project".to_string(),
             skills_installed: vec!["react-19".to_string()],
+            skills_unchanged: Vec::new(),
+            skills_refreshed: Vec::new(),
             skills_failed: vec![],
             instructions_installed: Some(true),
             settings_installed: None,
             output_style_installed: None,
+            plugin_manifest_installed: None,
+            skills_skipped_disabled: vec![],
             targets_installed: vec![DeployTarget::ClaudeCode, DeployTarget::Amp],
+            targets_failed: vec![],
+            duration_ms: 0,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -2696,6 +4523,9 @@ mod tests {
             instructions: None,
             generate_copilot: true,
             generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
         };
 
         let json = serde_json::to_string(&input).unwrap();
@@ -2742,6 +4572,11 @@ mod tests {
                 instructions: None,
                 generate_copilot: false,
                 generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
                 created_at: "2026-01-01T00:00:00Z".to_string(),
                 updated_at: "2026-01-01T00:00:00Z".to_string(),
             }],
@@ -2792,6 +4627,11 @@ mod tests {
                 instructions: None,
                 generate_copilot: false,
                 generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
                 created_at: "2024-01-01T00:00:00Z".to_string(),
                 updated_at: "2024-01-01T00:00:00Z".to_string(),
             }],
@@ -2827,6 +4667,11 @@ mod tests {
                 instructions: None,
                 generate_copilot: false,
                 generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
                 created_at: "2024-01-01T00:00:00Z".to_string(),
                 updated_at: "2024-01-01T00:00:00Z".to_string(),
             }],
@@ -2892,6 +4737,11 @@ mod tests {
                     instructions: None,
                     generate_copilot: false,
                     generate_agents: false,
+                    generate_gemini: false,
+                    generate_opencode: false,
+                    tags: Vec::new(),
+                    extends: None,
+                    output_style: None,
                     created_at: now.clone(),
                     updated_at: now.clone(),
                 },
@@ -2905,6 +4755,11 @@ mod tests {
                     instructions: Some("Use strict mode.".to_string()),
                     generate_copilot: true,
                     generate_agents: false,
+                    generate_gemini: false,
+                    generate_opencode: false,
+                    tags: Vec::new(),
+                    extends: None,
+                    output_style: None,
                     created_at: now.clone(),
                     updated_at: now,
                 },
@@ -2962,6 +4817,11 @@ mod tests {
                 instructions: None,
                 generate_copilot: false,
                 generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
                 created_at: now.clone(),
                 updated_at: now,
             }],
@@ -3194,6 +5054,7 @@ mod tests {
             removed: vec!["old-skill".to_string()],
             unchanged: vec!["react-patterns".to_string(), "tailwind-4".to_string()],
             profile_id: Some("react-stack".to_string()),
+            warnings: Warnings::new(),
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -3220,6 +5081,7 @@ mod tests {
             removed: Vec::new(),
             unchanged: Vec::new(),
             profile_id: None,
+            warnings: Warnings::new(),
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -3236,6 +5098,7 @@ mod tests {
             removed: Vec::new(),
             unchanged: vec!["skill-a".to_string(), "skill-b".to_string()],
             profile_id: Some("test-profile".to_string()),
+            warnings: Warnings::new(),
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -3316,6 +5179,11 @@ mod tests {
                 instructions: None,
                 generate_copilot: false,
                 generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
                 created_at: now.clone(),
                 updated_at: now,
             }],
@@ -3376,6 +5244,11 @@ mod tests {
                 instructions: None,
                 generate_copilot: false,
                 generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
                 created_at: now.clone(),
                 updated_at: now,
             }],
@@ -3433,6 +5306,11 @@ mod tests {
                 instructions: None,
                 generate_copilot: false,
                 generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
                 created_at: now.clone(),
                 updated_at: now,
             }],
@@ -3470,6 +5348,77 @@ mod tests {
         assert!(!skills_dir.join("skill-extra").exists());
     }
 
+    #[test]
+    fn test_diff_project_reports_changes_without_applying_them() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("skill-a", "Skill A", "Desc A", "Content A");
+        env.create_skill("skill-b", "Skill B", "Desc B", "Content B");
+
+        let now = chrono::Utc::now().to_rfc3339();
+        let config = ProfilesConfig {
+            profiles: vec![Profile {
+                id: "test-profile".to_string(),
+                name: "Test Profile".to_string(),
+                description: "Test".to_string(),
+                profile_type: ProfileType::Project,
+                skills: vec!["skill-a".to_string(), "skill-b".to_string()],
+                auto_invoke_rules: Vec::new(),
+                instructions: None,
+                generate_copilot: false,
+                generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
+                created_at: now.clone(),
+                updated_at: now,
+            }],
+            default_user_profile: None,
+        };
+        env.create_profiles_config(&config);
+
+        let project_dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            Profiles::diff_project(project_dir.path()).unwrap().status,
+            "no_profile"
+        );
+
+        let plugin_dir = project_dir.path().join(".claude-plugin");
+        fs::create_dir_all(&plugin_dir).unwrap();
+        let manifest = serde_json::json!({
+            "profile": { "id": "test-profile", "name": "Test Profile" }
+        });
+        fs::write(
+            plugin_dir.join("plugin.json"),
+            serde_json::to_string(&manifest).unwrap(),
+        )
+        .unwrap();
+
+        let skills_dir = project_dir.path().join(".claude").join("skills");
+        fs::create_dir_all(skills_dir.join("skill-a")).unwrap();
+        fs::create_dir_all(skills_dir.join("skill-extra")).unwrap();
+
+        let diff = Profiles::diff_project(project_dir.path()).unwrap();
+        assert_eq!(diff.status, "out_of_sync");
+        assert_eq!(diff.profile_id.as_deref(), Some("test-profile"));
+        assert_eq!(diff.added, vec!["skill-b".to_string()]);
+        assert_eq!(diff.removed, vec!["skill-extra".to_string()]);
+        assert_eq!(diff.unchanged, vec!["skill-a".to_string()]);
+
+        // Nothing was touched
+        assert!(!skills_dir.join("skill-b").exists());
+        assert!(skills_dir.join("skill-extra").exists());
+
+        // Once synced, the diff is empty
+        Profiles::sync_project(project_dir.path()).unwrap();
+        let diff = Profiles::diff_project(project_dir.path()).unwrap();
+        assert_eq!(diff.status, "synced");
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+    }
+
     #[test]
     fn test_sync_project_ignores_dotfiles() {
         let _lock = ENV_MUTEX.lock().unwrap();
@@ -3490,6 +5439,11 @@ mod tests {
                 instructions: None,
                 generate_copilot: false,
                 generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
                 created_at: now.clone(),
                 updated_at: now,
             }],
@@ -3522,4 +5476,1552 @@ mod tests {
         assert_eq!(result.status, "synced");
         assert!(result.removed.is_empty());
     }
+
+    // ============================================
+    // Install Atomicity Tests
+    // ============================================
+
+    /// No `<name>.XXXXXX.tmp` / `.bak` staging dirs left next to the project's files
+    fn assert_no_staging_leftovers(dir: &Path) {
+        let leftovers: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .filter(|name| name.ends_with(".tmp") || name.ends_with(".bak"))
+            .collect();
+        assert!(leftovers.is_empty(), "staging leftovers: {:?}", leftovers);
+    }
+
+    /// Register `profile`, with a stub for each of its skills, so it can go through
+    /// `Profiles::install`
+    fn setup_install_profile(env: &TestEnv, profile: &Profile) {
+        env.setup_skills_dir();
+        for skill_id in &profile.skills {
+            env.create_skill(skill_id, skill_id, "Test skill", "# Skill");
+        }
+        env.create_profiles_config(&ProfilesConfig {
+            profiles: vec![profile.clone()],
+            default_user_profile: None,
+        });
+    }
+
+    fn setup_atomic_install_profile(env: &TestEnv) {
+        env.setup_skills_dir();
+        env.create_skill("skill-a", "Skill A", "First", "# A");
+        env.create_skill("skill-b", "Skill B", "Second", "# B");
+
+        let now = chrono::Utc::now().to_rfc3339();
+        env.create_profiles_config(&ProfilesConfig {
+            profiles: vec![Profile {
+                id: "atomic".to_string(),
+                name: "Atomic".to_string(),
+                description: "Atomic install profile".to_string(),
+                profile_type: ProfileType::Project,
+                skills: vec!["skill-a".to_string(), "skill-b".to_string()],
+                auto_invoke_rules: Vec::new(),
+                instructions: None,
+                generate_copilot: false,
+                generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
+                created_at: now.clone(),
+                updated_at: now,
+            }],
+            default_user_profile: None,
+        });
+    }
+
+    #[test]
+    fn test_install_project_failure_leaves_original_claude_dir_untouched() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        let claude_dir = project.path().join(".claude");
+        fs::create_dir_all(claude_dir.join("skills").join("old-skill")).unwrap();
+        fs::write(claude_dir.join("settings.local.json"), "{\"local\": true}").unwrap();
+        fs::write(
+            claude_dir.join("skills").join("old-skill").join("SKILL.md"),
+            "# Old",
+        )
+        .unwrap();
+
+        // A file where the plugin manifest dir should go makes the install fail
+        // after all skills were written
+        fs::write(project.path().join(".claude-plugin"), "not a dir").unwrap();
+
+        let result = Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        );
+        assert!(result.is_err());
+
+        // Original .claude is exactly as before
+        assert_eq!(
+            fs::read_to_string(claude_dir.join("settings.local.json")).unwrap(),
+            "{\"local\": true}"
+        );
+        assert!(claude_dir.join("skills/old-skill/SKILL.md").exists());
+        assert!(!claude_dir.join("skills/skill-a").exists());
+        assert!(!claude_dir.join("skills/skill-b").exists());
+        // Instructions were staged along with the skills, so nothing was written
+        assert!(!project.path().join("CLAUDE.md").exists());
+        // Staging dir was rolled back
+        assert_no_staging_leftovers(project.path());
+    }
+
+    #[test]
+    fn test_install_project_success_swaps_staged_dir_into_place() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        let claude_dir = project.path().join(".claude");
+        fs::create_dir_all(&claude_dir).unwrap();
+        fs::write(claude_dir.join("settings.local.json"), "{}").unwrap();
+
+        let result = Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.skills_installed, vec!["skill-a", "skill-b"]);
+        assert!(claude_dir.join("settings.local.json").exists());
+        assert!(claude_dir.join("skills/skill-a/SKILL.md").exists());
+        assert!(claude_dir.join("skills/skill-b/SKILL.md").exists());
+        assert_no_staging_leftovers(project.path());
+    }
+
+    #[test]
+    fn test_update_installed_skips_unchanged_skills() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+        let project = tempfile::tempdir().unwrap();
+        let update = |force: bool| {
+            Profiles::update_installed(
+                "atomic",
+                Some(project.path()),
+                None,
+                InstallOptions {
+                    force,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        };
+
+        let first = Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        assert!(first.skills_unchanged.is_empty());
+
+        let second = update(false);
+        assert_eq!(second.skills_installed, vec!["skill-a", "skill-b"]);
+        assert_eq!(second.skills_unchanged, vec!["skill-a", "skill-b"]);
+
+        let source = Skills::get_skill_path("skill-a").unwrap();
+        fs::write(source.join("notes.md"), "new file").unwrap();
+        assert_eq!(update(false).skills_unchanged, vec!["skill-b"]);
+
+        // A skill removed from the project by hand is redeployed despite its hash
+        fs::remove_dir_all(project.path().join(".claude/skills/skill-b")).unwrap();
+        assert_eq!(update(false).skills_unchanged, vec!["skill-a"]);
+        assert!(project.path().join(".claude/skills/skill-b").exists());
+
+        assert!(update(true).skills_unchanged.is_empty());
+    }
+
+    #[test]
+    fn test_update_reports_refreshed_skills_per_target() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+        let project = tempfile::tempdir().unwrap();
+        let targets = [DeployTarget::ClaudeCode, DeployTarget::Amp];
+
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+        fs::remove_dir_all(project.path().join(".agents/skills/skill-b")).unwrap();
+
+        let result = Profiles::update_installed(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+        let refreshed: Vec<(DeployTarget, Vec<String>)> = result
+            .skills_refreshed
+            .into_iter()
+            .map(|t| (t.target, t.skills))
+            .collect();
+        assert_eq!(
+            refreshed,
+            vec![
+                (DeployTarget::ClaudeCode, vec![]),
+                (DeployTarget::Amp, vec!["skill-b".to_string()]),
+            ]
+        );
+        // skill-b was still redeployed somewhere, so only skill-a counts as unchanged
+        assert_eq!(result.skills_unchanged, vec!["skill-a"]);
+        assert!(project.path().join(".agents/skills/skill-b").exists());
+        assert!(project.path().join(".agents/skills/skill-a").exists());
+    }
+
+    #[test]
+    fn test_watch_paths_cover_skills_instructions_and_config() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let paths = Profiles::watch_paths("atomic").unwrap();
+        let skill_dir = Skills::get_skill_path("skill-a").unwrap();
+        assert!(paths.iter().any(|p| skill_dir.starts_with(p)));
+        assert!(paths.contains(&Profiles::get_instructions_path("atomic").unwrap()));
+        assert!(paths.contains(&Profiles::config_path().unwrap()));
+
+        assert!(Profiles::watch_paths("missing").is_err());
+    }
+
+    #[test]
+    fn test_install_plan_matches_install_without_writing() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        let targets = [DeployTarget::ClaudeCode, DeployTarget::Amp];
+        let plan = Profiles::install_plan(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(fs::read_dir(project.path()).unwrap().count(), 0);
+        let paths: Vec<&Path> = plan.iter().map(|p| p.path.as_path()).collect();
+        assert!(paths.contains(&project.path().join(".claude/skills/skill-a").as_path()));
+        assert!(paths.contains(&project.path().join(".agents/skills/skill-b").as_path()));
+        assert!(paths.contains(&project.path().join("CLAUDE.md").as_path()));
+        assert!(paths.contains(&project.path().join("AGENTS.md").as_path()));
+        assert!(paths.contains(&project.path().join(".claude-plugin/plugin.json").as_path()));
+        assert!(paths.contains(&InstallManifest::path(project.path()).as_path()));
+
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+        for path in paths {
+            assert!(
+                path.exists(),
+                "planned path not created: {}",
+                path.display()
+            );
+        }
+
+        // Without the plugin manifest, and keeping the now-existing instructions files
+        let plan = Profiles::install_plan(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions {
+                create_plugin_manifest: false,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(plan.iter().all(|p| !p.path.ends_with("plugin.json")));
+        assert!(plan.iter().all(|p| !p.path.ends_with("CLAUDE.md")));
+        // Skills the install would skip as unchanged aren't listed either
+        assert!(plan.iter().all(|p| !p.path.ends_with("skill-a")));
+    }
+
+    #[test]
+    fn test_install_plan_lists_existing_instructions_it_would_rewrite() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        let claude_md = project.path().join("CLAUDE.md");
+        fs::write(
+            &claude_md,
+            format!(
+                "# Notes\n\n{}\nstale\n{}\n",
+                instructions::section_start(SKILLS_SECTION),
+                instructions::section_end(SKILLS_SECTION)
+            ),
+        )
+        .unwrap();
+
+        let plan = Profiles::install_plan(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        assert!(plan.iter().any(|p| p.path == claude_md));
+
+        // Once the section is current, the file is no longer part of the plan
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        let plan = Profiles::install_plan(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        assert!(plan.iter().all(|p| p.path != claude_md));
+    }
+
+    #[test]
+    fn test_uninstall_with_manifest_removes_only_recorded_paths() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        fs::create_dir_all(project.path().join(".claude")).unwrap();
+        fs::write(project.path().join(".claude/settings.local.json"), "{}").unwrap();
+
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        let manifest = InstallManifest::load(project.path()).unwrap().unwrap();
+        assert_eq!(manifest.profile_id, "atomic");
+        assert_eq!(manifest.targets[0].target, DeployTarget::ClaudeCode);
+        // .claude already existed, so only the dirs the install created are recorded
+        assert!(!manifest.targets[0].dirs.contains(&PathBuf::from(".claude")));
+        assert!(manifest.targets[0]
+            .dirs
+            .contains(&PathBuf::from(".claude-plugin")));
+
+        // A skill the user added by hand shows up as drift and survives uninstall
+        let own_skill = project.path().join(".claude/skills/my-own");
+        fs::create_dir_all(&own_skill).unwrap();
+        assert_eq!(
+            manifest.drift(project.path()).unwrap(),
+            vec![PathBuf::from(".claude/skills/my-own")]
+        );
+
+        Profiles::uninstall(project.path(), None, false).unwrap();
+
+        assert!(!project.path().join(".claude/skills/skill-a").exists());
+        assert!(!project.path().join(".claude-plugin").exists());
+        assert!(!project.path().join("CLAUDE.md").exists());
+        assert!(own_skill.exists());
+        assert!(project.path().join(".claude/settings.local.json").exists());
+        assert!(!InstallManifest::path(project.path()).exists());
+    }
+
+    #[test]
+    fn test_gitignore_block_follows_installed_targets() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        let gitignore = project.path().join(".gitignore");
+        fs::write(&gitignore, "node_modules/\n").unwrap();
+
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        let entries = Profiles::add_to_gitignore(project.path()).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                "/.claude-plugin/",
+                "/.claude/",
+                "/.rhinolabs-install.json",
+                "/CLAUDE.md"
+            ]
+        );
+
+        // Later installs keep the block in line with what they generated
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            Some(&[DeployTarget::Amp]),
+            InstallOptions::default(),
+        )
+        .unwrap();
+        let content = fs::read_to_string(&gitignore).unwrap();
+        assert!(content.starts_with("node_modules/\n"));
+        assert!(content.contains("/AGENTS.md"));
+        assert!(content.contains("/.agents/"));
+
+        Profiles::uninstall(project.path(), Some(&[DeployTarget::ClaudeCode]), false).unwrap();
+        let content = fs::read_to_string(&gitignore).unwrap();
+        assert!(!content.contains("/CLAUDE.md"));
+        assert!(content.contains("/AGENTS.md"));
+
+        Profiles::uninstall(project.path(), None, false).unwrap();
+        assert_eq!(fs::read_to_string(&gitignore).unwrap(), "node_modules/\n");
+    }
+
+    #[test]
+    fn test_install_continues_when_one_target_fails() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        // A regular file where Amp's config dir should go makes that target fail
+        fs::write(project.path().join(".agents"), "not a dir").unwrap();
+
+        let targets = [DeployTarget::ClaudeCode, DeployTarget::Amp];
+        let result = Profiles::install(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.targets_installed, vec![DeployTarget::ClaudeCode]);
+        assert_eq!(result.targets_failed.len(), 1);
+        assert_eq!(result.targets_failed[0].target, DeployTarget::Amp);
+        assert!(!result.targets_failed[0].error.is_empty());
+
+        // ClaudeCode was still fully deployed
+        assert!(project
+            .path()
+            .join(".claude/skills/skill-a/SKILL.md")
+            .exists());
+        assert!(project.path().join("CLAUDE.md").exists());
+        assert!(project.path().join(".claude-plugin/plugin.json").exists());
+        // Amp left as it was
+        assert!(project.path().join(".agents").is_file());
+        assert!(!project.path().join("AGENTS.md").exists());
+        assert_no_staging_leftovers(project.path());
+    }
+
+    #[test]
+    fn test_install_errors_when_every_target_fails() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        fs::write(project.path().join(".agents"), "not a dir").unwrap();
+        fs::write(project.path().join(".opencode"), "not a dir").unwrap();
+
+        let targets = [DeployTarget::Amp, DeployTarget::OpenCode];
+        let err = Profiles::install(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("Amp"), "{}", message);
+        assert!(message.contains("OpenCode"), "{}", message);
+    }
+
+    #[test]
+    fn test_install_without_plugin_manifest() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        let result = Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions {
+                create_plugin_manifest: false,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(result.plugin_manifest_installed, Some(false));
+        assert!(!project.path().join(".claude-plugin").exists());
+        assert!(project
+            .path()
+            .join(".claude/skills/skill-a/SKILL.md")
+            .exists());
+        assert!(project.path().join("CLAUDE.md").exists());
+
+        // Uninstall doesn't need the plugin dir to be there
+        Profiles::uninstall(project.path(), None, false).unwrap();
+        assert!(!project.path().join(".claude").exists());
+        assert!(!project.path().join("CLAUDE.md").exists());
+    }
+
+    #[test]
+    fn test_uninstall_keep_instructions_preserves_generated_claude_md() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        let generated = fs::read_to_string(project.path().join("CLAUDE.md")).unwrap();
+        assert!(generated.contains("rhinolabs-ai"));
+
+        Profiles::uninstall(project.path(), None, true).unwrap();
+
+        assert!(!project.path().join(".claude/skills").exists());
+        assert!(!project.path().join(".claude-plugin").exists());
+        assert_eq!(
+            fs::read_to_string(project.path().join("CLAUDE.md")).unwrap(),
+            generated
+        );
+    }
+
+    #[test]
+    fn test_uninstall_keeps_user_edits_to_generated_instructions() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        let claude_md = project.path().join("CLAUDE.md");
+        let generated = fs::read_to_string(&claude_md).unwrap();
+        fs::write(
+            &claude_md,
+            format!("{}\n## Team notes\n\nRun tests first.\n", generated),
+        )
+        .unwrap();
+
+        // A reinstall keeps the file marked as edited
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        Profiles::uninstall(project.path(), None, false).unwrap();
+
+        let remaining = fs::read_to_string(&claude_md).unwrap();
+        assert!(remaining.contains("Run tests first."));
+        assert!(!remaining.contains(&instructions::section_start(SKILLS_SECTION)));
+        assert!(!remaining.contains("skill-a"));
+    }
+
+    #[test]
+    fn test_update_keeps_plugin_manifest_off_after_no_plugin_install() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        let no_plugin = InstallOptions {
+            create_plugin_manifest: false,
+            ..Default::default()
+        };
+        Profiles::install("atomic", Some(project.path()), None, no_plugin).unwrap();
+        assert!(
+            InstallManifest::load(project.path())
+                .unwrap()
+                .unwrap()
+                .no_plugin
+        );
+
+        let result = Profiles::update_installed(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(result.plugin_manifest_installed, Some(false));
+        assert!(!project.path().join(".claude-plugin").exists());
+
+        // A new install with the plugin manifest turns it back on
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        assert!(project.path().join(".claude-plugin/plugin.json").exists());
+        assert!(
+            !InstallManifest::load(project.path())
+                .unwrap()
+                .unwrap()
+                .no_plugin
+        );
+    }
+
+    #[test]
+    fn test_install_with_plugin_manifest() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        let result = Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.plugin_manifest_installed, Some(true));
+        assert!(project
+            .path()
+            .join(".claude-plugin")
+            .join("plugin.json")
+            .exists());
+    }
+
+    #[test]
+    fn test_install_only_enabled_skips_disabled_skills() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+        Skills::toggle("skill-b", false).unwrap();
+
+        let project = tempfile::tempdir().unwrap();
+        let result = Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions {
+                only_enabled: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(result.skills_installed, vec!["skill-a"]);
+        assert_eq!(result.skills_skipped_disabled, vec!["skill-b"]);
+        assert!(project.path().join(".claude/skills/skill-a").exists());
+        assert!(!project.path().join(".claude/skills/skill-b").exists());
+        let claude_md = fs::read_to_string(project.path().join("CLAUDE.md")).unwrap();
+        assert!(!claude_md.contains("skill-b"));
+    }
+
+    #[test]
+    fn test_install_skips_skills_disabled_for_a_target() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+        Skills::toggle_for_target("skill-b", DeployTarget::Amp, false).unwrap();
+
+        let project = tempfile::tempdir().unwrap();
+        let targets = [DeployTarget::ClaudeCode, DeployTarget::Amp];
+        let plan = Profiles::install_plan(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        assert!(project.path().join(".claude/skills/skill-b").exists());
+        assert!(project.path().join(".agents/skills/skill-a").exists());
+        assert!(!project.path().join(".agents/skills/skill-b").exists());
+        assert!(!plan
+            .iter()
+            .any(|p| p.path == project.path().join(".agents/skills/skill-b")));
+        let agents_md = fs::read_to_string(project.path().join("AGENTS.md")).unwrap();
+        assert!(!agents_md.contains("skill-b"));
+    }
+
+    #[test]
+    fn test_install_keeps_globally_disabled_skills_off_every_target() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+        Skills::toggle("skill-b", false).unwrap();
+        Skills::toggle_for_target("skill-b", DeployTarget::Amp, true).unwrap();
+
+        let project = tempfile::tempdir().unwrap();
+        let targets = [DeployTarget::ClaudeCode, DeployTarget::Amp];
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            Some(&targets),
+            InstallOptions {
+                only_enabled: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert!(!project.path().join(".claude/skills/skill-b").exists());
+        assert!(!project.path().join(".agents/skills/skill-b").exists());
+        assert!(project.path().join(".agents/skills/skill-a").exists());
+        let agents_md = fs::read_to_string(project.path().join("AGENTS.md")).unwrap();
+        assert!(!agents_md.contains("skill-b"));
+    }
+
+    #[test]
+    fn test_update_rewrites_only_the_managed_instructions_section() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        let claude_md = project.path().join("CLAUDE.md");
+        let edited = format!(
+            "{}\n## Team Notes\n\nKeep this.\n",
+            fs::read_to_string(&claude_md).unwrap()
+        );
+        fs::write(&claude_md, &edited).unwrap();
+
+        Skills::toggle_for_target("skill-b", DeployTarget::ClaudeCode, false).unwrap();
+        Profiles::update_installed(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        let updated = fs::read_to_string(&claude_md).unwrap();
+        assert!(updated.contains("## Team Notes\n\nKeep this."));
+        assert!(updated.contains("`skill-a`"));
+        assert!(!updated.contains("`skill-b`"));
+    }
+
+    #[test]
+    fn test_update_overwrites_instructions_without_markers_only_on_request() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+
+        let project = tempfile::tempdir().unwrap();
+        let claude_md = project.path().join("CLAUDE.md");
+        fs::write(&claude_md, "# Hand-written\n").unwrap();
+
+        Profiles::update_installed(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(fs::read_to_string(&claude_md).unwrap(), "# Hand-written\n");
+
+        // Redeploying skills with `force` doesn't touch the hand-written file either
+        Profiles::update_installed(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions {
+                force: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(fs::read_to_string(&claude_md).unwrap(), "# Hand-written\n");
+
+        Profiles::update_installed(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions {
+                overwrite_instructions: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let forced = fs::read_to_string(&claude_md).unwrap();
+        assert!(forced.contains(&instructions::section_start(SKILLS_SECTION)));
+        assert!(forced.contains("`skill-b`"));
+    }
+
+    #[test]
+    fn test_install_deploys_disabled_skills_by_default() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+        Skills::toggle("skill-b", false).unwrap();
+
+        let project = tempfile::tempdir().unwrap();
+        let result = Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.skills_installed, vec!["skill-a", "skill-b"]);
+        assert!(result.skills_skipped_disabled.is_empty());
+        assert!(project.path().join(".claude/skills/skill-b").exists());
+    }
+
+    #[test]
+    fn test_install_reports_duration() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_atomic_install_profile(&env);
+        let project = tempfile::tempdir().unwrap();
+
+        let result = Profiles::install(
+            "atomic",
+            Some(project.path()),
+            None,
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        assert!(result.duration_ms > 0);
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["durationMs"], result.duration_ms);
+    }
+
+    #[test]
+    fn test_list_skills_with_profiles() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("shared", "Shared", "Used twice", "# Shared");
+        env.create_skill("lonely", "Lonely", "Used nowhere", "# Lonely");
+
+        let now = chrono::Utc::now().to_rfc3339();
+        let profile = |id: &str| Profile {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            profile_type: ProfileType::Project,
+            skills: vec!["shared".to_string()],
+            auto_invoke_rules: Vec::new(),
+            instructions: None,
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
+            created_at: now.clone(),
+            updated_at: now.clone(),
+        };
+        env.create_profiles_config(&ProfilesConfig {
+            profiles: vec![profile("frontend"), profile("backend")],
+            default_user_profile: None,
+        });
+
+        let skills = Skills::list_with_profiles().unwrap();
+        let profile_ids = |id: &str| {
+            skills
+                .iter()
+                .find(|s| s.id == id)
+                .and_then(|s| s.profile_ids.clone())
+                .unwrap()
+        };
+
+        assert_eq!(profile_ids("shared"), vec!["frontend", "backend"]);
+        assert!(profile_ids("lonely").is_empty());
+        // Plain list leaves profile membership out
+        assert!(Skills::list()
+            .unwrap()
+            .iter()
+            .all(|s| s.profile_ids.is_none()));
+    }
+
+    fn setup_frontend_profile(env: &TestEnv) {
+        let now = chrono::Utc::now().to_rfc3339();
+        env.create_profiles_config(&ProfilesConfig {
+            profiles: vec![Profile {
+                id: "frontend".to_string(),
+                name: "Frontend".to_string(),
+                description: String::new(),
+                profile_type: ProfileType::Project,
+                skills: vec!["existing".to_string()],
+                auto_invoke_rules: Vec::new(),
+                instructions: None,
+                generate_copilot: false,
+                generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
+                created_at: now.clone(),
+                updated_at: now,
+            }],
+            default_user_profile: None,
+        });
+    }
+
+    const REMOTE_SKILL_MD: &str =
+        "---\nname: Fetched\ndescription: From a source\n---\n\n# Fetched";
+
+    #[test]
+    fn test_install_skill_with_profile_assignment() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        setup_frontend_profile(&env);
+
+        let result = Skills::install_from_source(
+            "fetched",
+            REMOTE_SKILL_MD,
+            "community",
+            "Community",
+            Some("frontend"),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(result.skill.id, "fetched");
+        assert_eq!(result.assigned_profile.as_deref(), Some("frontend"));
+        assert!(env.skills_dir().join("fetched/SKILL.md").exists());
+        let profile = Profiles::get("frontend").unwrap().unwrap();
+        assert_eq!(profile.skills, vec!["existing", "fetched"]);
+    }
+
+    #[test]
+    fn test_install_skill_refuses_main_profile_assignment() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        setup_frontend_profile(&env);
+
+        let result = Skills::install_from_source(
+            "fetched",
+            REMOTE_SKILL_MD,
+            "community",
+            "Community",
+            Some("main"),
+            false,
+        );
+
+        assert!(result.is_err());
+        // Validation happens before anything is written
+        assert!(!env.skills_dir().join("fetched").exists());
+
+        let result = Skills::install_from_source(
+            "fetched",
+            REMOTE_SKILL_MD,
+            "community",
+            "Community",
+            Some("main"),
+            true,
+        )
+        .unwrap();
+        assert_eq!(result.assigned_profile.as_deref(), Some("main"));
+        assert!(Profiles::get("main")
+            .unwrap()
+            .unwrap()
+            .skills
+            .contains(&"fetched".to_string()));
+    }
+
+    #[test]
+    fn test_install_skill_assigns_other_user_profiles_without_opt_in() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        setup_frontend_profile(&env);
+        // User profiles other than main come from the plugin's profiles.json
+        let mut config = Profiles::load_config().unwrap();
+        let mut personal = config.profiles[0].clone();
+        personal.id = "personal".to_string();
+        personal.profile_type = ProfileType::User;
+        personal.skills = Vec::new();
+        config.profiles.push(personal);
+        Profiles::save_config(&config).unwrap();
+
+        let result = Skills::install_from_source(
+            "fetched",
+            REMOTE_SKILL_MD,
+            "community",
+            "Community",
+            Some("personal"),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(result.assigned_profile.as_deref(), Some("personal"));
+        let profile = Profiles::get("personal").unwrap().unwrap();
+        assert_eq!(profile.skills, vec!["fetched"]);
+    }
+
+    #[test]
+    fn test_install_skill_assignment_to_unknown_profile_fails() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        setup_frontend_profile(&env);
+
+        let result = Skills::install_from_source(
+            "fetched",
+            REMOTE_SKILL_MD,
+            "community",
+            "Community",
+            Some("ghost"),
+            false,
+        );
+
+        assert!(result.unwrap_err().to_string().contains("not found"));
+        assert!(!env.skills_dir().join("fetched").exists());
+    }
+
+    #[test]
+    fn test_clone_profile_copies_skills_rules_and_instructions() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        setup_frontend_profile(&env);
+        Profiles::update_auto_invoke_rules(
+            "frontend",
+            vec![AutoInvokeRule {
+                skill_id: "existing".to_string(),
+                trigger: "Editing components".to_string(),
+                description: "UI work".to_string(),
+            }],
+        )
+        .unwrap();
+        Profiles::update_instructions("frontend", "# Frontend rules").unwrap();
+
+        let clone = Profiles::clone("frontend", "frontend-v2", "Frontend v2").unwrap();
+
+        assert_eq!(clone.id, "frontend-v2");
+        assert_eq!(clone.name, "Frontend v2");
+        assert_eq!(clone.profile_type, ProfileType::Project);
+        assert_eq!(clone.skills, vec!["existing"]);
+        assert_eq!(clone.auto_invoke_rules.len(), 1);
+        assert_eq!(
+            Profiles::get_instructions("frontend-v2").unwrap(),
+            "# Frontend rules"
+        );
+
+        // Editing the clone leaves the source untouched
+        Profiles::remove_skill("frontend-v2", "existing").unwrap();
+        let source = Profiles::get("frontend").unwrap().unwrap();
+        assert_eq!(source.skills, vec!["existing"]);
+    }
+
+    #[test]
+    fn test_export_and_import_profile_round_trip() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        setup_frontend_profile(&env);
+        Profiles::update_instructions("frontend", "# Shared rules").unwrap();
+
+        let out = tempfile::tempdir().unwrap();
+        let file = out.path().join("frontend.json");
+        Profiles::export("frontend", &file).unwrap();
+
+        let exported: ProfileExport =
+            serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+        assert_eq!(exported.instructions.as_deref(), Some("# Shared rules"));
+
+        Profiles::delete("frontend").unwrap();
+        fs::remove_file(Profiles::get_instructions_path("frontend").unwrap()).unwrap();
+
+        let imported = Profiles::import(&file).unwrap();
+        assert_eq!(imported.id, "frontend");
+        assert_eq!(imported.skills, vec!["existing"]);
+        assert_eq!(
+            Profiles::get_instructions("frontend").unwrap(),
+            "# Shared rules"
+        );
+
+        let err = Profiles::import(&file).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_import_profile_downgrades_user_type() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        setup_frontend_profile(&env);
+
+        let mut profile = Profiles::get("frontend").unwrap().unwrap();
+        profile.id = "shared-main".to_string();
+        profile.profile_type = ProfileType::User;
+        let out = tempfile::tempdir().unwrap();
+        let file = out.path().join("shared.json");
+        let export = ProfileExport {
+            profile,
+            instructions: None,
+        };
+        fs::write(&file, serde_json::to_string(&export).unwrap()).unwrap();
+
+        let imported = Profiles::import(&file).unwrap();
+
+        assert_eq!(imported.profile_type, ProfileType::Project);
+    }
+
+    #[test]
+    fn test_rename_skill_migrates_config_and_profiles() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        setup_frontend_profile(&env);
+        env.create_skill("existing", "Existing", "Built-in", "# body");
+        Skills::install_from_source("reakt", REMOTE_SKILL_MD, "acme", "Acme", None, false).unwrap();
+        Skills::set_category("reakt", crate::SkillCategory::Frontend).unwrap();
+        Skills::toggle("reakt", false).unwrap();
+        Profiles::add_skill("frontend", "reakt", false).unwrap();
+        Profiles::update_auto_invoke_rules(
+            "frontend",
+            vec![AutoInvokeRule {
+                skill_id: "reakt".to_string(),
+                trigger: "Editing components".to_string(),
+                description: "UI work".to_string(),
+            }],
+        )
+        .unwrap();
+
+        let skill = Skills::rename("reakt", "react").unwrap();
+
+        assert_eq!(skill.id, "react");
+        assert_eq!(skill.source_id.as_deref(), Some("acme"));
+        assert_eq!(skill.category, crate::SkillCategory::Frontend);
+        assert!(!skill.enabled);
+        assert!(!skill.is_modified);
+        assert!(Skills::get("reakt").unwrap().is_none());
+
+        let profile = Profiles::get("frontend").unwrap().unwrap();
+        assert_eq!(profile.skills, vec!["existing", "react"]);
+        assert_eq!(profile.auto_invoke_rules[0].skill_id, "react");
+
+        let err = Skills::rename("react", "existing").unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        let err = Skills::rename("existing", "renamed").unwrap_err();
+        assert!(err.to_string().contains("built-in"));
+        assert!(Skills::rename("react", "Bad Id").is_err());
+    }
+
+    #[test]
+    fn test_rename_skill_references_dedupes_existing_target() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        setup_frontend_profile(&env);
+        let mut config = Profiles::load_config().unwrap();
+        let frontend = config
+            .profiles
+            .iter_mut()
+            .find(|p| p.id == "frontend")
+            .unwrap();
+        frontend.skills = vec!["old".into(), "new".into()];
+        Profiles::save_config(&config).unwrap();
+
+        let changed = Profiles::rename_skill_references("old", "new").unwrap();
+
+        assert_eq!(changed, vec!["frontend"]);
+        assert_eq!(
+            Profiles::get("frontend").unwrap().unwrap().skills,
+            vec!["new"]
+        );
+        assert!(Profiles::rename_skill_references("old", "new")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_clone_profile_rejects_existing_or_unknown_ids() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        setup_frontend_profile(&env);
+
+        let err = Profiles::clone("frontend", "frontend", "Dup").unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        let err = Profiles::clone("ghost", "new", "New").unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[test]
+    fn test_add_and_remove_profile_skill() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("react", "React", "React patterns", "# React");
+        setup_frontend_profile(&env);
+
+        let profile = Profiles::add_skill("frontend", "react", false).unwrap();
+        assert_eq!(profile.skills, vec!["existing", "react"]);
+
+        let again = Profiles::add_skill("frontend", "react", false).unwrap();
+        assert_eq!(again.skills, vec!["existing", "react"]);
+        assert_eq!(again.updated_at, profile.updated_at);
+
+        let removed = Profiles::remove_skill("frontend", "existing").unwrap();
+        assert_eq!(removed.skills, vec!["react"]);
+
+        let absent = Profiles::remove_skill("frontend", "never-added").unwrap();
+        assert_eq!(absent.skills, vec!["react"]);
+        assert_eq!(absent.updated_at, removed.updated_at);
+
+        assert!(Profiles::remove_skill("ghost", "react").is_err());
+    }
+
+    #[test]
+    fn test_add_unknown_skill_to_profile_fails() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        setup_frontend_profile(&env);
+
+        let err = Profiles::add_skill("frontend", "missing", false).unwrap_err();
+
+        assert!(err.to_string().contains("Skill 'missing' not found"));
+        let profile = Profiles::get("frontend").unwrap().unwrap();
+        assert_eq!(profile.skills, vec!["existing"]);
+    }
+
+    fn inheriting_profile(
+        id: &str,
+        extends: Option<&str>,
+        skills: &[&str],
+        instructions: &str,
+    ) -> Profile {
+        let now = chrono::Utc::now().to_rfc3339();
+        Profile {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            profile_type: ProfileType::Project,
+            skills: skills.iter().map(|s| s.to_string()).collect(),
+            auto_invoke_rules: Vec::new(),
+            instructions: Some(instructions.to_string()),
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: extends.map(String::from),
+            output_style: None,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_resolve_merges_parent_skills_and_instructions() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("standards", "Standards", "Corporate", "# Standards");
+        env.create_skill("react", "React", "React patterns", "# React");
+        env.create_profiles_config(&ProfilesConfig {
+            profiles: vec![
+                inheriting_profile("corporate", None, &["standards", "react"], "# Corporate"),
+                inheriting_profile("web", Some("corporate"), &["react"], "# Web"),
+            ],
+            default_user_profile: None,
+        });
+
+        let web = Profiles::resolve("web").unwrap();
+        assert_eq!(web.skills, vec!["standards", "react"]);
+        assert_eq!(
+            web.instructions.as_deref(),
+            Some("# Corporate\n\n---\n\n# Web")
+        );
+
+        let target = tempfile::tempdir().unwrap();
+        let result = Profiles::install(
+            "web",
+            Some(target.path()),
+            None,
+            InstallOptions {
+                create_plugin_manifest: false,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(result.skills_installed, vec!["standards", "react"]);
+        let claude_md = fs::read_to_string(target.path().join("CLAUDE.md")).unwrap();
+        assert!(claude_md.contains("# Corporate"));
+        assert!(claude_md.contains("# Web"));
+    }
+
+    #[test]
+    fn test_resolve_rejects_cycles_and_unknown_parents() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.create_profiles_config(&ProfilesConfig {
+            profiles: vec![
+                inheriting_profile("a", Some("b"), &[], ""),
+                inheriting_profile("b", Some("a"), &[], ""),
+                inheriting_profile("orphan", Some("gone"), &[], ""),
+            ],
+            default_user_profile: None,
+        });
+
+        let err = Profiles::resolve("a").unwrap_err().to_string();
+        assert!(err.contains("cycle: a -> b -> a"), "{}", err);
+
+        let err = Profiles::resolve("orphan").unwrap_err().to_string();
+        assert!(err.contains("extends unknown profile 'gone'"), "{}", err);
+    }
+
+    fn create_tagged_profile(id: &str, tags: &[&str]) -> Profile {
+        Profiles::create(CreateProfileInput {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            profile_type: ProfileType::Project,
+            skills: Vec::new(),
+            instructions: Some(String::new()),
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_profile_tags_normalized_on_create() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+
+        let profile = create_tagged_profile("web", &["Frontend", " frontend ", "Client-X", ""]);
+
+        assert_eq!(profile.tags, vec!["frontend", "client-x"]);
+    }
+
+    #[test]
+    fn test_list_profiles_by_tag() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+        create_tagged_profile("web", &["frontend", "client-x"]);
+        create_tagged_profile("api", &["backend", "client-x"]);
+        create_tagged_profile("scratch", &[]);
+
+        let ids = |tag: &str| -> Vec<String> {
+            Profiles::list_by_tag(tag)
+                .unwrap()
+                .into_iter()
+                .map(|p| p.id)
+                .collect()
+        };
+
+        assert_eq!(ids("client-x"), vec!["web", "api"]);
+        assert_eq!(ids("FRONTEND"), vec!["web"]);
+        assert!(ids("mobile").is_empty());
+    }
+
+    #[test]
+    fn test_add_and_remove_profile_tag() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+        create_tagged_profile("web", &["frontend"]);
+
+        let profile = Profiles::add_tag("web", "Client-X").unwrap();
+        assert_eq!(profile.tags, vec!["frontend", "client-x"]);
+        let profile = Profiles::add_tag("web", "frontend").unwrap();
+        assert_eq!(profile.tags, vec!["frontend", "client-x"]);
+
+        let profile = Profiles::remove_tag("web", "FRONTEND").unwrap();
+        assert_eq!(profile.tags, vec!["client-x"]);
+        assert_eq!(
+            Profiles::get("web").unwrap().unwrap().tags,
+            vec!["client-x"]
+        );
+
+        assert!(Profiles::add_tag("ghost", "x").is_err());
+    }
+
+    #[test]
+    fn test_profile_without_tags_field_deserializes() {
+        let json = r#"{
+            "id": "legacy",
+            "name": "Legacy",
+            "description": "",
+            "profileType": "project",
+            "skills": [],
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": "2024-01-01T00:00:00Z"
+        }"#;
+
+        let profile: Profile = serde_json::from_str(json).unwrap();
+        assert!(profile.tags.is_empty());
+        assert!(profile.extends.is_none());
+        assert!(serde_json::to_value(&profile)
+            .unwrap()
+            .get("extends")
+            .is_none());
+
+        let roundtrip: Profile =
+            serde_json::from_str(&serde_json::to_string(&profile).unwrap()).unwrap();
+        assert!(roundtrip.tags.is_empty());
+    }
+
+    #[test]
+    fn test_install_main_profile_to_amp_only_writes_amp_dirs() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let home = HomeEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("skill-a", "Skill A", "First", "# A");
+
+        let now = chrono::Utc::now().to_rfc3339();
+        env.create_profiles_config(&ProfilesConfig {
+            profiles: vec![Profile {
+                id: "main".to_string(),
+                name: "Main".to_string(),
+                description: "Main profile".to_string(),
+                profile_type: ProfileType::User,
+                skills: vec!["skill-a".to_string()],
+                auto_invoke_rules: Vec::new(),
+                instructions: None,
+                generate_copilot: false,
+                generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
+                created_at: now.clone(),
+                updated_at: now,
+            }],
+            default_user_profile: Some("main".to_string()),
+        });
+
+        let result = Profiles::install(
+            "main",
+            None,
+            Some(&[DeployTarget::Amp]),
+            InstallOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.targets_installed, vec![DeployTarget::Amp]);
+        assert_eq!(result.skills_installed, vec!["skill-a"]);
+        let amp_skills = TargetPaths::user_skills_dir(DeployTarget::Amp).unwrap();
+        assert!(amp_skills.starts_with(home.dir.path()));
+        assert!(amp_skills.join("skill-a").join("SKILL.md").exists());
+        assert!(!home.dir.path().join(".claude").exists());
+    }
+
+    #[test]
+    fn test_install_main_profile_uses_its_own_output_style() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let home = HomeEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("skill-a", "Skill A", "First", "# A");
+        OutputStyles::create("Global", "Active everywhere", false, "Global tone.").unwrap();
+        OutputStyles::set_active("global").unwrap();
+        OutputStyles::create("Terse", "Short answers", true, "Be brief.").unwrap();
+
+        let now = chrono::Utc::now().to_rfc3339();
+        env.create_profiles_config(&ProfilesConfig {
+            profiles: vec![Profile {
+                id: "main".to_string(),
+                name: "Main".to_string(),
+                description: "Main profile".to_string(),
+                profile_type: ProfileType::User,
+                skills: vec!["skill-a".to_string()],
+                auto_invoke_rules: Vec::new(),
+                instructions: None,
+                generate_copilot: false,
+                generate_agents: false,
+                generate_gemini: false,
+                generate_opencode: false,
+                tags: Vec::new(),
+                extends: None,
+                output_style: None,
+                created_at: now.clone(),
+                updated_at: now,
+            }],
+            default_user_profile: Some("main".to_string()),
+        });
+
+        assert!(Profiles::set_output_style("main", Some("missing")).is_err());
+        Profiles::set_output_style("main", Some("terse")).unwrap();
+
+        let claude_dir = home.dir.path().join(".claude");
+        let plan = Profiles::install_plan("main", None, None, InstallOptions::default()).unwrap();
+        let style_path = claude_dir.join("output-styles").join("terse.md");
+        assert!(plan.iter().any(|p| p.path == style_path));
+        assert!(plan.iter().all(|p| !p.path.ends_with("global.md")));
+
+        let result = Profiles::install("main", None, None, InstallOptions::default()).unwrap();
+        assert_eq!(result.output_style_installed.as_deref(), Some("Terse"));
+
+        assert!(claude_dir.join("output-styles").join("terse.md").exists());
+        assert!(!claude_dir.join("output-styles").join("global.md").exists());
+        let settings: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(claude_dir.join("settings.json")).unwrap())
+                .unwrap();
+        assert_eq!(settings["outputStyle"], "Terse");
+        // The global active style is left alone
+        assert_eq!(OutputStyles::get_active().unwrap().unwrap().id, "global");
+
+        // A style deleted after being chosen fails the install instead of
+        // silently falling back
+        OutputStyles::delete("terse").unwrap();
+        let err = Profiles::install("main", None, None, InstallOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("output style 'terse'"), "{}", err);
+    }
 }
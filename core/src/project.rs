@@ -1,4 +1,5 @@
-use crate::{Paths, Result, RhinolabsError};
+use crate::git::GitOperations;
+use crate::{BumpKind, Paths, Result, RhinolabsError, Version};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -79,6 +80,59 @@ pub struct ProjectStatus {
     pub latest_release: Option<String>,
 }
 
+/// A published GitHub release and its changelog
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseInfo {
+    /// Tag without the `v` prefix
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Release notes (markdown)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<String>,
+}
+
+impl ReleaseInfo {
+    /// Read a release from the GitHub API representation
+    fn from_github(release: &serde_json::Value) -> Option<Self> {
+        let text = |key: &str| {
+            release[key]
+                .as_str()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        };
+        Some(Self {
+            version: release["tag_name"]
+                .as_str()?
+                .trim_start_matches('v')
+                .to_string(),
+            name: text("name"),
+            body: text("body"),
+            published_at: text("published_at"),
+        })
+    }
+
+    /// Releases newer than `from` and no newer than `to`, newest first.
+    /// Releases whose tag isn't a semver version are left out.
+    pub fn changelog_between(releases: &[Self], from: &str, to: &str) -> Vec<Self> {
+        let parse = |v: &str| semver::Version::parse(v.trim_start_matches('v')).ok();
+        let (Some(from), Some(to)) = (parse(from), parse(to)) else {
+            return Vec::new();
+        };
+
+        let mut changelog: Vec<(semver::Version, Self)> = releases
+            .iter()
+            .filter_map(|r| parse(&r.version).map(|v| (v, r.clone())))
+            .filter(|(v, _)| *v > from && *v <= to)
+            .collect();
+        changelog.sort_by(|a, b| b.0.cmp(&a.0));
+        changelog.into_iter().map(|(_, r)| r).collect()
+    }
+}
+
 pub struct Project;
 
 impl Project {
@@ -112,7 +166,7 @@ impl Project {
         }
 
         let content = serde_json::to_string_pretty(config)?;
-        fs::write(&path, content)?;
+        crate::fs_utils::write(&path, content)?;
 
         Ok(())
     }
@@ -227,8 +281,8 @@ impl Project {
         }
     }
 
-    /// Fetch latest release from GitHub
-    pub async fn fetch_latest_release() -> Result<Option<String>> {
+    /// Fetch latest release from GitHub, with its changelog
+    pub async fn fetch_latest_release() -> Result<Option<ReleaseInfo>> {
         let config = Self::get_config()?;
 
         if config.github.owner.is_empty() || config.github.repo.is_empty() {
@@ -240,30 +294,80 @@ impl Project {
             config.github.owner, config.github.repo
         );
 
-        let client = reqwest::Client::new();
-        let response = client
-            .get(&url)
-            .header("User-Agent", "rhinolabs-gui")
-            .send()
-            .await;
+        let client = crate::network::client()?;
+        let response =
+            crate::network::send(client.get(&url).header("User-Agent", "rhinolabs-gui")).await;
 
         match response {
             Ok(resp) if resp.status().is_success() => {
                 let release: serde_json::Value = resp.json().await?;
-                Ok(release["tag_name"]
-                    .as_str()
-                    .map(|s| s.trim_start_matches('v').to_string()))
+                Ok(ReleaseInfo::from_github(&release))
             }
             _ => Ok(None),
         }
     }
 
-    /// Create a new release
+    /// Published (non-draft, non-prerelease) releases, most recent 100
+    pub async fn fetch_releases() -> Result<Vec<ReleaseInfo>> {
+        let config = Self::get_config()?;
+        if config.github.owner.is_empty() || config.github.repo.is_empty() {
+            return Err(RhinolabsError::ConfigError(
+                "GitHub repository not configured. Run the GUI to set owner/repo first.".into(),
+            ));
+        }
+
+        Self::fetch_releases_from_url(&format!(
+            "https://api.github.com/repos/{}/{}/releases?per_page=100",
+            config.github.owner, config.github.repo
+        ))
+        .await
+    }
+
+    async fn fetch_releases_from_url(url: &str) -> Result<Vec<ReleaseInfo>> {
+        let client = crate::network::client()?;
+        let response = crate::network::send(client.get(url).header("User-Agent", "rhinolabs-cli"))
+            .await
+            .map_err(crate::network::request_error)?;
+        if !response.status().is_success() {
+            return Err(RhinolabsError::NetworkError(format!(
+                "Failed to fetch releases: HTTP {}",
+                response.status()
+            )));
+        }
+
+        let releases: Vec<serde_json::Value> = response.json().await?;
+        Ok(releases
+            .iter()
+            .filter(|r| r["draft"] != true && r["prerelease"] != true)
+            .filter_map(ReleaseInfo::from_github)
+            .collect())
+    }
+
+    /// Release notes generated from the commits since the latest version tag
+    /// (all commits if nothing has been tagged yet), as a starting point to edit
+    pub fn default_changelog() -> Result<String> {
+        let plugin_dir = Paths::plugin_dir()?;
+        let tag = GitOperations::latest_version_tag(&plugin_dir)?;
+        let commits = GitOperations::commits_since_tag(&plugin_dir, tag.as_deref())?;
+        Ok(crate::changelog::from_commits(&commits))
+    }
+
+    /// Create a new release.
+    ///
+    /// A blank `changelog` is replaced by [`Self::default_changelog`] when the
+    /// project has `auto_changelog` enabled.
     pub async fn create_release(
         version: &str,
         changelog: &str,
         prerelease: bool,
     ) -> Result<String> {
+        if !Version::is_semver(version) {
+            return Err(RhinolabsError::InvalidVersion(format!(
+                "'{}' is not a semantic version like 1.2.3",
+                version
+            )));
+        }
+
         let config = Self::get_config()?;
 
         if config.github.owner.is_empty() || config.github.repo.is_empty() {
@@ -282,6 +386,12 @@ impl Project {
             config.github.owner, config.github.repo
         );
 
+        let changelog = if changelog.trim().is_empty() && config.auto_changelog {
+            Self::default_changelog()?
+        } else {
+            changelog.to_string()
+        };
+
         let tag = format!("v{}", version);
         let body = serde_json::json!({
             "tag_name": tag,
@@ -291,15 +401,16 @@ impl Project {
             "draft": false,
         });
 
-        let client = reqwest::Client::new();
-        let response = client
-            .post(&url)
-            .header("User-Agent", "rhinolabs-gui")
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Accept", "application/vnd.github+json")
-            .json(&body)
-            .send()
-            .await?;
+        let client = crate::network::client()?;
+        let response = crate::network::send(
+            client
+                .post(&url)
+                .header("User-Agent", "rhinolabs-gui")
+                .header("Authorization", format!("Bearer {}", token))
+                .header("Accept", "application/vnd.github+json")
+                .json(&body),
+        )
+        .await?;
 
         if !response.status().is_success() {
             let error_text = response.text().await.unwrap_or_default();
@@ -316,7 +427,7 @@ impl Project {
     }
 
     /// Bump version in plugin.json
-    pub fn bump_version(bump_type: &str) -> Result<String> {
+    pub fn bump_version(kind: BumpKind) -> Result<String> {
         let plugin_dir = Paths::plugin_dir()?;
         let manifest_path = plugin_dir.join(".claude-plugin").join("plugin.json");
 
@@ -331,39 +442,21 @@ impl Project {
             .as_str()
             .ok_or_else(|| RhinolabsError::ConfigError("version not found in manifest".into()))?;
 
-        let new_version = Self::calculate_new_version(current_version, bump_type)?;
+        let new_version = Version::bump(current_version, kind)?;
 
         manifest["version"] = serde_json::Value::String(new_version.clone());
 
         let new_content = serde_json::to_string_pretty(&manifest)?;
-        fs::write(&manifest_path, new_content)?;
+        crate::fs_utils::write(&manifest_path, new_content)?;
 
         Ok(new_version)
     }
-
-    fn calculate_new_version(current: &str, bump_type: &str) -> Result<String> {
-        let version = semver::Version::parse(current)
-            .map_err(|e| RhinolabsError::InvalidVersion(e.to_string()))?;
-
-        let new_version = match bump_type {
-            "major" => semver::Version::new(version.major + 1, 0, 0),
-            "minor" => semver::Version::new(version.major, version.minor + 1, 0),
-            "patch" => semver::Version::new(version.major, version.minor, version.patch + 1),
-            _ => {
-                return Err(RhinolabsError::ConfigError(format!(
-                    "Invalid bump type: {}. Use major, minor, or patch",
-                    bump_type
-                )))
-            }
-        };
-
-        Ok(new_version.to_string())
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::{TestEnv, ENV_MUTEX};
 
     #[test]
     fn test_project_config_default() {
@@ -377,30 +470,73 @@ mod tests {
     }
 
     #[test]
-    fn test_bump_version_patch() {
-        let result = Project::calculate_new_version("1.0.0", "patch");
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "1.0.1");
+    fn test_bump_version_updates_plugin_json() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        let manifest_dir = env.plugin_dir().join(".claude-plugin");
+        fs::create_dir_all(&manifest_dir).unwrap();
+        fs::write(
+            manifest_dir.join("plugin.json"),
+            r#"{ "name": "rhinolabs-claude", "version": "1.2.3", "author": { "name": "Rhinolabs" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(Project::bump_version(BumpKind::Patch).unwrap(), "1.2.4");
+        assert_eq!(Project::bump_version(BumpKind::Minor).unwrap(), "1.3.0");
+        assert_eq!(Project::bump_version(BumpKind::Major).unwrap(), "2.0.0");
+        let manifest: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(manifest_dir.join("plugin.json")).unwrap())
+                .unwrap();
+        assert_eq!(manifest["version"], "2.0.0");
+        assert_eq!(manifest["author"]["name"], "Rhinolabs");
+
+        fs::write(manifest_dir.join("plugin.json"), r#"{ "version": "1.2" }"#).unwrap();
+        let err = Project::bump_version(BumpKind::Patch).unwrap_err();
+        assert!(err.to_string().contains("'1.2'"), "{}", err);
     }
 
     #[test]
-    fn test_bump_version_minor() {
-        let result = Project::calculate_new_version("1.2.3", "minor");
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "1.3.0");
-    }
+    fn test_default_changelog_lists_commits_since_latest_tag() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        assert_eq!(Project::default_changelog().unwrap(), "");
+
+        let repo = git2::Repository::init(env.plugin_dir()).unwrap();
+        let signature = git2::Signature::now("Dev", "dev@example.com").unwrap();
+        let tree = repo
+            .find_tree(repo.index().unwrap().write_tree().unwrap())
+            .unwrap();
+        let mut parent: Option<git2::Commit> = None;
+        for subject in ["feat: initial release", "fix: handle empty profiles"] {
+            let parents: Vec<&git2::Commit> = parent.iter().collect();
+            let id = repo
+                .commit(
+                    Some("HEAD"),
+                    &signature,
+                    &signature,
+                    subject,
+                    &tree,
+                    &parents,
+                )
+                .unwrap();
+            if parent.is_none() {
+                repo.tag_lightweight("v1.0.0", &repo.find_object(id, None).unwrap(), false)
+                    .unwrap();
+            }
+            parent = Some(repo.find_commit(id).unwrap());
+        }
 
-    #[test]
-    fn test_bump_version_major() {
-        let result = Project::calculate_new_version("1.2.3", "major");
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "2.0.0");
+        let changelog = Project::default_changelog().unwrap();
+        assert!(changelog.contains("### Fixes\n\n- handle empty profiles"));
+        assert!(!changelog.contains("initial release"));
     }
 
-    #[test]
-    fn test_bump_version_invalid() {
-        let result = Project::calculate_new_version("1.0.0", "invalid");
-        assert!(result.is_err());
+    #[tokio::test]
+    async fn test_create_release_rejects_non_semver_before_calling_github() {
+        let err = Project::create_release("1.x", "changes", false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RhinolabsError::InvalidVersion(_)), "{}", err);
     }
 
     #[test]
@@ -417,4 +553,64 @@ mod tests {
         let parsed: GitHubConfig = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.owner, "rhinolabs");
     }
+
+    fn release(version: &str) -> ReleaseInfo {
+        ReleaseInfo {
+            version: version.into(),
+            name: None,
+            body: Some(format!("Changes in {}", version)),
+            published_at: None,
+        }
+    }
+
+    #[test]
+    fn test_changelog_between() {
+        let releases = vec![
+            release("1.2.0"),
+            release("1.0.0"),
+            release("1.10.0"),
+            release("1.1.0"),
+            release("nightly"),
+        ];
+
+        let versions = |from, to| -> Vec<String> {
+            ReleaseInfo::changelog_between(&releases, from, to)
+                .into_iter()
+                .map(|r| r.version)
+                .collect()
+        };
+        assert_eq!(versions("1.0.0", "v1.10.0"), ["1.10.0", "1.2.0", "1.1.0"]);
+        assert_eq!(versions("1.1.0", "1.2.0"), ["1.2.0"]);
+        assert!(versions("1.2.0", "1.0.0").is_empty());
+        assert!(versions("dev", "1.2.0").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_releases_skips_drafts_and_prereleases() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "tag_name": "v1.3.0-beta.1", "prerelease": true, "body": "beta" },
+                { "tag_name": "v1.2.0", "name": "1.2.0", "body": "- Faster sync\n", "published_at": "2026-01-02T00:00:00Z" },
+                { "tag_name": "v1.1.0", "draft": true },
+            ])))
+            .mount(&server)
+            .await;
+
+        let releases = Project::fetch_releases_from_url(&server.uri())
+            .await
+            .unwrap();
+        assert_eq!(
+            releases,
+            vec![ReleaseInfo {
+                version: "1.2.0".into(),
+                name: Some("1.2.0".into()),
+                body: Some("- Faster sync".into()),
+                published_at: Some("2026-01-02T00:00:00Z".into()),
+            }]
+        );
+    }
 }
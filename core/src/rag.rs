@@ -5,11 +5,31 @@
 
 use crate::{Paths, Result, RhinolabsError};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 const RAG_CONFIG_FILE: &str = "rag.json";
 const DEFAULT_MCP_URL: &str = "https://rhinolabs-rag-mcp.rhinolabs.workers.dev";
+const DEFAULT_CHUNK_SIZE: usize = 1000;
+const DEFAULT_CHUNK_OVERLAP: usize = 200;
+const DEFAULT_EMBEDDING_MODEL: &str = "@cf/baai/bge-base-en-v1.5";
+
+/// Path of the Worker's MCP endpoint, relative to its base URL
+const MCP_ENDPOINT: &str = "/mcp";
+/// MCP tool the Worker exposes for similarity search over a project's memory
+const RAG_SEARCH_TOOL: &str = "rag_search";
+/// Worker endpoint that chunks, embeds and stores documents
+const INGEST_ENDPOINT: &str = "/ingest";
+/// How many snippets a query returns unless asked otherwise
+pub const DEFAULT_RAG_QUERY_LIMIT: usize = 5;
+/// Documents sent per ingest request
+pub const RAG_INDEX_BATCH_SIZE: usize = 20;
+/// Files larger than this are not indexed
+pub const RAG_INDEX_MAX_FILE_BYTES: u64 = 512 * 1024;
+const DEFAULT_INDEX_EXTENSIONS: &[&str] = &[
+    "md", "mdx", "txt", "rs", "ts", "tsx", "js", "jsx", "py", "go", "java", "toml", "yaml", "yml",
+];
 
 /// Local RAG project configuration
 ///
@@ -26,6 +46,51 @@ pub struct RagConfig {
     /// Optional MCP Worker URL override (uses default if not specified)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mcp_url: Option<String>,
+
+    /// How `rag index` chunks and embeds this project's documents
+    #[serde(default)]
+    pub settings: RagIndexSettings,
+}
+
+/// A snippet of project memory returned by [`Rag::query`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RagResult {
+    #[serde(alias = "text")]
+    pub content: String,
+
+    /// Similarity score, when the Worker reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+
+    /// Document the snippet was taken from, when the Worker reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+/// A file sent to the Worker by [`Rag::index`]
+#[derive(Debug, Clone, Serialize)]
+struct RagDocument {
+    path: String,
+    content: String,
+}
+
+/// A file [`Rag::index`] left out
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RagSkippedFile {
+    /// Path relative to the project
+    pub path: String,
+    pub reason: String,
+}
+
+/// Outcome of [`Rag::index`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RagIndexReport {
+    /// Documents the Worker accepted
+    pub indexed: usize,
+    pub skipped: Vec<RagSkippedFile>,
 }
 
 /// Global RAG settings (stored in ~/.config/rhinolabs-ai/rag-settings.json)
@@ -39,6 +104,93 @@ pub struct RagSettings {
     pub admin_key: Option<String>,
 }
 
+/// Per-project indexing settings (the `settings` object in `.claude/rag.json`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RagIndexSettings {
+    /// Maximum characters per document chunk when indexing
+    #[serde(default = "default_chunk_size")]
+    pub chunk_size: usize,
+
+    /// Characters shared between consecutive chunks (must be < chunk_size)
+    #[serde(default = "default_chunk_overlap")]
+    pub chunk_overlap: usize,
+
+    /// Embedding model the Worker uses for indexed chunks
+    #[serde(default = "default_embedding_model")]
+    pub embedding_model: String,
+
+    /// File extensions (without the dot) that `rag index` sends to the Worker
+    #[serde(default = "default_index_extensions")]
+    pub index_extensions: Vec<String>,
+}
+
+fn default_chunk_size() -> usize {
+    DEFAULT_CHUNK_SIZE
+}
+
+fn default_chunk_overlap() -> usize {
+    DEFAULT_CHUNK_OVERLAP
+}
+
+fn default_embedding_model() -> String {
+    DEFAULT_EMBEDDING_MODEL.to_string()
+}
+
+fn default_index_extensions() -> Vec<String> {
+    DEFAULT_INDEX_EXTENSIONS
+        .iter()
+        .map(|e| e.to_string())
+        .collect()
+}
+
+impl Default for RagIndexSettings {
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            chunk_overlap: DEFAULT_CHUNK_OVERLAP,
+            embedding_model: DEFAULT_EMBEDDING_MODEL.to_string(),
+            index_extensions: default_index_extensions(),
+        }
+    }
+}
+
+impl RagIndexSettings {
+    /// Setting keys accepted by `Rag::set_setting`
+    pub const KEYS: &'static [&'static str] = &[
+        "chunk-size",
+        "chunk-overlap",
+        "embedding-model",
+        "index-extensions",
+    ];
+
+    /// Check that chunking settings are usable
+    pub fn validate(&self) -> Result<()> {
+        if self.chunk_size == 0 {
+            return Err(RhinolabsError::ConfigError(
+                "chunk-size must be greater than 0".into(),
+            ));
+        }
+        if self.chunk_overlap >= self.chunk_size {
+            return Err(RhinolabsError::ConfigError(format!(
+                "chunk-overlap ({}) must be smaller than chunk-size ({})",
+                self.chunk_overlap, self.chunk_size
+            )));
+        }
+        if self.embedding_model.trim().is_empty() {
+            return Err(RhinolabsError::ConfigError(
+                "embedding-model cannot be empty".into(),
+            ));
+        }
+        if self.index_extensions.is_empty() {
+            return Err(RhinolabsError::ConfigError(
+                "index-extensions must list at least one extension".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 /// RAG configuration management
 pub struct Rag;
 
@@ -78,7 +230,7 @@ impl Rag {
         }
 
         let content = serde_json::to_string_pretty(config)?;
-        fs::write(&path, content)?;
+        crate::fs_utils::write(&path, content)?;
         Ok(())
     }
 
@@ -107,7 +259,7 @@ impl Rag {
         }
 
         let content = serde_json::to_string_pretty(settings)?;
-        fs::write(&path, content)?;
+        crate::fs_utils::write(&path, content)?;
         Ok(())
     }
 
@@ -127,6 +279,7 @@ impl Rag {
             project_id: project_id.to_string(),
             api_key: api_key.to_string(),
             mcp_url: None,
+            settings: RagIndexSettings::default(),
         };
 
         Self::save_config(project_path, &config)?;
@@ -182,6 +335,312 @@ impl Rag {
         let settings = Self::load_settings()?;
         Ok(settings.admin_key)
     }
+
+    /// Search the project's indexed memory through the MCP Worker's `rag_search` tool
+    ///
+    /// Uses the project ID and API key from `.claude/rag.json` and returns at most
+    /// `limit` snippets, best match first.
+    pub async fn query(project_path: &Path, text: &str, limit: usize) -> Result<Vec<RagResult>> {
+        let config = Self::require_config(project_path)?;
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {
+                "name": RAG_SEARCH_TOOL,
+                "arguments": {
+                    "project_id": config.project_id,
+                    "query": text,
+                    "limit": limit,
+                },
+            },
+        });
+
+        let response = Self::post_to_worker(&config, MCP_ENDPOINT, &request).await?;
+        let event_stream = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("text/event-stream"));
+        let body = response.text().await?;
+        let mut results = Self::parse_search_reply(&body, event_stream)?;
+        results.truncate(limit);
+        Ok(results)
+    }
+
+    /// Send the project's files under `paths` to the MCP Worker's ingest endpoint
+    ///
+    /// Directories are walked respecting `.gitignore`; only files with one of the
+    /// `index-extensions` are sent, in batches of [`RAG_INDEX_BATCH_SIZE`]. Binary files
+    /// and files over [`RAG_INDEX_MAX_FILE_BYTES`] are skipped. `on_progress` is called
+    /// with (documents sent, documents total) after every batch.
+    pub async fn index(
+        project_path: &Path,
+        paths: &[PathBuf],
+        mut on_progress: impl FnMut(usize, usize),
+    ) -> Result<RagIndexReport> {
+        let config = Self::require_config(project_path)?;
+        let settings = &config.settings;
+        settings.validate()?;
+        let (documents, skipped) =
+            Self::collect_documents(project_path, paths, &settings.index_extensions)?;
+
+        let total = documents.len();
+        let mut indexed = 0;
+        for batch in documents.chunks(RAG_INDEX_BATCH_SIZE) {
+            let request = serde_json::json!({
+                "projectId": config.project_id,
+                "chunkSize": settings.chunk_size,
+                "chunkOverlap": settings.chunk_overlap,
+                "embeddingModel": settings.embedding_model,
+                "documents": batch,
+            });
+            Self::post_to_worker(&config, INGEST_ENDPOINT, &request).await?;
+            indexed += batch.len();
+            on_progress(indexed, total);
+        }
+
+        Ok(RagIndexReport { indexed, skipped })
+    }
+
+    /// Files under `paths` to index, with their paths relative to `project_path`
+    fn collect_documents(
+        project_path: &Path,
+        paths: &[PathBuf],
+        extensions: &[String],
+    ) -> Result<(Vec<RagDocument>, Vec<RagSkippedFile>)> {
+        let roots: Vec<PathBuf> = if paths.is_empty() {
+            vec![project_path.to_path_buf()]
+        } else {
+            paths.iter().map(|p| project_path.join(p)).collect()
+        };
+
+        let mut documents = Vec::new();
+        let mut skipped = Vec::new();
+        // Overlapping roots reach the same file more than once
+        let mut seen = HashSet::new();
+        for root in roots {
+            if !root.exists() {
+                return Err(RhinolabsError::Other(format!(
+                    "Path not found: {}",
+                    root.display()
+                )));
+            }
+
+            // .gitignore is honored even outside a git checkout
+            let walker = ignore::WalkBuilder::new(&root).require_git(false).build();
+            for entry in walker {
+                let entry = entry.map_err(|e| RhinolabsError::Other(e.to_string()))?;
+                let path = entry.path();
+                if !entry.file_type().is_some_and(|t| t.is_file())
+                    || !Self::has_index_extension(path, extensions)
+                {
+                    continue;
+                }
+
+                let relative = path
+                    .strip_prefix(project_path)
+                    .unwrap_or(path)
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                if !seen.insert(relative.clone()) {
+                    continue;
+                }
+
+                let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+                if size > RAG_INDEX_MAX_FILE_BYTES {
+                    skipped.push(RagSkippedFile {
+                        path: relative,
+                        reason: format!("larger than {} KB", RAG_INDEX_MAX_FILE_BYTES / 1024),
+                    });
+                    continue;
+                }
+
+                let bytes = fs::read(path)?;
+                match String::from_utf8(bytes) {
+                    Ok(content) if !content.contains('\0') => {
+                        if !content.trim().is_empty() {
+                            documents.push(RagDocument {
+                                path: relative,
+                                content,
+                            });
+                        }
+                    }
+                    _ => skipped.push(RagSkippedFile {
+                        path: relative,
+                        reason: "binary".to_string(),
+                    }),
+                }
+            }
+        }
+
+        Ok((documents, skipped))
+    }
+
+    fn has_index_extension(path: &Path, extensions: &[String]) -> bool {
+        path.extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .is_some_and(|ext| extensions.contains(&ext))
+    }
+
+    /// The project's RAG config, or an error pointing to `rag init`
+    fn require_config(project_path: &Path) -> Result<RagConfig> {
+        Self::load_config(project_path)?.ok_or_else(|| {
+            RhinolabsError::ConfigError(
+                "RAG is not initialized for this project. \
+                 Run 'rhinolabs-ai rag init --project <id> --api-key <key>' first."
+                    .to_string(),
+            )
+        })
+    }
+
+    /// POST `body` to `endpoint` on the project's Worker with its API key. A rejected
+    /// key and any other unsuccessful status become errors.
+    async fn post_to_worker(
+        config: &RagConfig,
+        endpoint: &str,
+        body: &serde_json::Value,
+    ) -> Result<reqwest::Response> {
+        let url = format!(
+            "{}{}",
+            Self::get_mcp_url(config).trim_end_matches('/'),
+            endpoint
+        );
+        let client = crate::network::client()?;
+        let response = crate::network::send_with_retry(
+            client
+                .post(&url)
+                .header("Authorization", format!("Bearer {}", config.api_key))
+                .header("Accept", "application/json, text/event-stream")
+                .json(body),
+        )
+        .await
+        .map_err(crate::network::request_error)?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Err(RhinolabsError::NetworkError(format!(
+                "the MCP Worker rejected the API key for project '{}' ({}). \
+                 Check the key in .claude/rag.json",
+                config.project_id, status
+            )));
+        }
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(RhinolabsError::NetworkError(format!(
+                "the MCP Worker returned {}: {}",
+                status,
+                body.trim()
+            )));
+        }
+        Ok(response)
+    }
+
+    /// Read the snippets out of a JSON-RPC `tools/call` reply. Structured results are
+    /// used when present; otherwise each text content item is one snippet.
+    fn parse_search_reply(body: &str, event_stream: bool) -> Result<Vec<RagResult>> {
+        // Streamable HTTP servers may answer with an SSE stream whose last event is the reply
+        let json = if event_stream {
+            body.lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(str::trim)
+                .next_back()
+                .unwrap_or_default()
+        } else {
+            body
+        };
+        let reply: serde_json::Value = serde_json::from_str(json)?;
+
+        if let Some(error) = reply.get("error") {
+            return Err(RhinolabsError::Other(format!(
+                "RAG query failed: {}",
+                error["message"].as_str().unwrap_or("unknown error")
+            )));
+        }
+
+        let result = &reply["result"];
+        let texts: Vec<String> = result["content"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|item| item["type"] == "text")
+            .filter_map(|item| item["text"].as_str().map(str::to_string))
+            .collect();
+
+        if result["isError"] == true {
+            return Err(RhinolabsError::Other(format!(
+                "RAG query failed: {}",
+                texts.join(" ")
+            )));
+        }
+
+        if let Some(results) = result
+            .get("structuredContent")
+            .and_then(|c| c.get("results"))
+        {
+            return Ok(serde_json::from_value(results.clone())?);
+        }
+
+        Ok(texts
+            .into_iter()
+            .map(|content| RagResult {
+                content,
+                score: None,
+                source: None,
+            })
+            .collect())
+    }
+
+    /// Set one of the project's indexing settings (`chunk-size`, `chunk-overlap`,
+    /// `embedding-model`, `index-extensions`)
+    ///
+    /// The updated settings are validated before being saved to `.claude/rag.json`.
+    pub fn set_setting(project_path: &Path, key: &str, value: &str) -> Result<RagIndexSettings> {
+        let mut config = Self::require_config(project_path)?;
+        config.settings = Self::apply_setting(config.settings, key, value)?;
+        Self::save_config(project_path, &config)?;
+        Ok(config.settings)
+    }
+
+    fn apply_setting(
+        mut settings: RagIndexSettings,
+        key: &str,
+        value: &str,
+    ) -> Result<RagIndexSettings> {
+        let parse_usize = |value: &str| {
+            value.trim().parse::<usize>().map_err(|_| {
+                RhinolabsError::ConfigError(format!(
+                    "Invalid value for {}: '{}' is not a positive integer",
+                    key, value
+                ))
+            })
+        };
+
+        match key.replace('_', "-").as_str() {
+            "chunk-size" => settings.chunk_size = parse_usize(value)?,
+            "chunk-overlap" => settings.chunk_overlap = parse_usize(value)?,
+            "embedding-model" => settings.embedding_model = value.trim().to_string(),
+            // Comma-separated; `md`, `.md` and `*.md` are all accepted
+            "index-extensions" => {
+                settings.index_extensions = value
+                    .split(',')
+                    .map(|e| e.trim().trim_start_matches('*').trim_start_matches('.'))
+                    .filter(|e| !e.is_empty())
+                    .map(str::to_lowercase)
+                    .collect()
+            }
+            _ => {
+                return Err(RhinolabsError::ConfigError(format!(
+                    "Unknown RAG setting '{}'. Valid keys: {}",
+                    key,
+                    RagIndexSettings::KEYS.join(", ")
+                )))
+            }
+        }
+
+        settings.validate()?;
+        Ok(settings)
+    }
 }
 
 #[cfg(test)]
@@ -195,6 +654,7 @@ mod tests {
             project_id: "test-project".to_string(),
             api_key: "rl_abc123".to_string(),
             mcp_url: None,
+            settings: RagIndexSettings::default(),
         };
 
         let json = serde_json::to_string_pretty(&config).unwrap();
@@ -212,6 +672,7 @@ mod tests {
             project_id: "test".to_string(),
             api_key: "rl_xxx".to_string(),
             mcp_url: Some("https://custom.example.com".to_string()),
+            settings: RagIndexSettings::default(),
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -223,6 +684,200 @@ mod tests {
         let settings = RagSettings::default();
         assert!(settings.default_mcp_url.is_none());
         assert!(settings.admin_key.is_none());
+
+        let settings = RagIndexSettings::default();
+        assert_eq!(settings.chunk_size, DEFAULT_CHUNK_SIZE);
+        assert_eq!(settings.chunk_overlap, DEFAULT_CHUNK_OVERLAP);
+        assert_eq!(settings.embedding_model, DEFAULT_EMBEDDING_MODEL);
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn test_rag_config_without_settings_uses_defaults() {
+        let json = r#"{"projectId": "my-project", "apiKey": "rl_abc123"}"#;
+        let config: RagConfig = serde_json::from_str(json).unwrap();
+        let settings = config.settings;
+
+        assert_eq!(settings.chunk_size, DEFAULT_CHUNK_SIZE);
+        assert_eq!(settings.chunk_overlap, DEFAULT_CHUNK_OVERLAP);
+        assert_eq!(settings.embedding_model, DEFAULT_EMBEDDING_MODEL);
+    }
+
+    #[test]
+    fn test_rag_settings_serialization_roundtrip() {
+        let settings = RagIndexSettings {
+            chunk_size: 512,
+            chunk_overlap: 64,
+            embedding_model: "@cf/baai/bge-large-en-v1.5".to_string(),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(json.contains("\"chunkSize\":512"));
+        assert!(json.contains("\"chunkOverlap\":64"));
+        assert!(json.contains("\"embeddingModel\""));
+
+        let parsed: RagIndexSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, settings);
+
+        let config = RagConfig {
+            project_id: "my-project".to_string(),
+            api_key: "rl_abc123".to_string(),
+            mcp_url: None,
+            settings: settings.clone(),
+        };
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["settings"]["chunkSize"], 512);
+    }
+
+    #[test]
+    fn test_rag_settings_validate_rejects_overlap_not_smaller_than_size() {
+        let settings = RagIndexSettings {
+            chunk_size: 500,
+            chunk_overlap: 500,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = RagIndexSettings {
+            chunk_size: 0,
+            chunk_overlap: 0,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_apply_setting() {
+        let settings =
+            Rag::apply_setting(RagIndexSettings::default(), "chunk-size", "2000").unwrap();
+        assert_eq!(settings.chunk_size, 2000);
+
+        // snake_case keys are accepted too
+        let settings = Rag::apply_setting(settings, "chunk_overlap", "300").unwrap();
+        assert_eq!(settings.chunk_overlap, 300);
+
+        let settings =
+            Rag::apply_setting(settings, "embedding-model", "@cf/baai/bge-small-en-v1.5").unwrap();
+        assert_eq!(settings.embedding_model, "@cf/baai/bge-small-en-v1.5");
+    }
+
+    #[test]
+    fn test_apply_setting_rejects_invalid_values() {
+        let defaults = RagIndexSettings::default();
+
+        assert!(Rag::apply_setting(defaults.clone(), "chunk-size", "abc").is_err());
+        assert!(Rag::apply_setting(defaults.clone(), "chunk-size", "-5").is_err());
+        // Overlap must stay below size
+        assert!(Rag::apply_setting(defaults.clone(), "chunk-overlap", "1000").is_err());
+        assert!(Rag::apply_setting(defaults.clone(), "embedding-model", "  ").is_err());
+        assert!(Rag::apply_setting(defaults.clone(), "index-extensions", " , ").is_err());
+        assert!(Rag::apply_setting(defaults, "unknown", "1").is_err());
+    }
+
+    #[test]
+    fn test_apply_setting_index_extensions_accepts_globs() {
+        let settings = Rag::apply_setting(
+            RagIndexSettings::default(),
+            "index-extensions",
+            "*.md, .RS,txt",
+        )
+        .unwrap();
+        assert_eq!(settings.index_extensions, ["md", "rs", "txt"]);
+    }
+
+    #[test]
+    fn test_collect_documents_respects_gitignore_and_skips_unindexable_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(".gitignore"), "dist/\nsecret.md\n").unwrap();
+        fs::write(root.join("README.md"), "# Project").unwrap();
+        fs::write(root.join("NOTES.MD"), "notes").unwrap();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), "pub fn f() {}").unwrap();
+        fs::create_dir_all(root.join("dist")).unwrap();
+        fs::write(root.join("dist/out.md"), "built").unwrap();
+        fs::write(root.join("secret.md"), "ignored").unwrap();
+        fs::write(root.join("logo.png"), [0x89, 0x50]).unwrap();
+        fs::write(root.join("data.txt"), b"abc\0def").unwrap();
+        let big = "x".repeat(RAG_INDEX_MAX_FILE_BYTES as usize + 1);
+        fs::write(root.join("big.md"), big).unwrap();
+
+        let (documents, skipped) =
+            Rag::collect_documents(root, &[], &default_index_extensions()).unwrap();
+
+        let mut paths: Vec<_> = documents.iter().map(|d| d.path.as_str()).collect();
+        paths.sort();
+        assert_eq!(paths, ["NOTES.MD", "README.md", "src/lib.rs"]);
+
+        let mut skipped: Vec<_> = skipped
+            .iter()
+            .map(|s| (s.path.as_str(), s.reason.as_str()))
+            .collect();
+        skipped.sort();
+        assert_eq!(skipped[0], ("big.md", "larger than 512 KB"));
+        assert_eq!(skipped[1], ("data.txt", "binary"));
+
+        let (documents, _) =
+            Rag::collect_documents(root, &[PathBuf::from("src")], &default_index_extensions())
+                .unwrap();
+        assert_eq!(documents.len(), 1);
+        assert_eq!(documents[0].path, "src/lib.rs");
+
+        // Overlapping roots index each file once
+        let (documents, _) = Rag::collect_documents(
+            root,
+            &[PathBuf::from("."), PathBuf::from("src")],
+            &default_index_extensions(),
+        )
+        .unwrap();
+        assert_eq!(documents.len(), 3);
+    }
+
+    #[test]
+    fn test_index_sends_documents_in_batches() {
+        use crate::test_utils::{HomeEnv, ENV_MUTEX};
+        use wiremock::matchers::{body_partial_json, header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _home = HomeEnv::new();
+        let rt = tokio::runtime::Runtime::new().unwrap();
+
+        let server = rt.block_on(MockServer::start());
+        rt.block_on(
+            Mock::given(method("POST"))
+                .and(path("/ingest"))
+                .and(header("Authorization", "Bearer rl_test123"))
+                .and(body_partial_json(serde_json::json!({
+                    "projectId": "my-project",
+                    "chunkSize": 512,
+                    "chunkOverlap": 64,
+                })))
+                .respond_with(ResponseTemplate::new(200))
+                .expect(2)
+                .mount(&server),
+        );
+
+        let temp_dir = TempDir::new().unwrap();
+        init_with_worker(temp_dir.path(), &server.uri());
+        Rag::set_setting(temp_dir.path(), "chunk-size", "512").unwrap();
+        Rag::set_setting(temp_dir.path(), "chunk-overlap", "64").unwrap();
+        for i in 0..RAG_INDEX_BATCH_SIZE + 1 {
+            fs::write(temp_dir.path().join(format!("doc-{}.md", i)), "content").unwrap();
+        }
+
+        let mut progress = Vec::new();
+        let report = rt
+            .block_on(Rag::index(temp_dir.path(), &[], |done, total| {
+                progress.push((done, total))
+            }))
+            .unwrap();
+
+        let total = RAG_INDEX_BATCH_SIZE + 1;
+        assert_eq!(report.indexed, total);
+        assert!(report.skipped.is_empty());
+        assert_eq!(progress, [(RAG_INDEX_BATCH_SIZE, total), (total, total)]);
     }
 
     #[test]
@@ -241,6 +896,7 @@ mod tests {
             project_id: "test".to_string(),
             api_key: "rl_xxx".to_string(),
             mcp_url: None,
+            settings: RagIndexSettings::default(),
         };
 
         let url = Rag::get_mcp_url(&config);
@@ -253,6 +909,7 @@ mod tests {
             project_id: "test".to_string(),
             api_key: "rl_xxx".to_string(),
             mcp_url: Some("https://custom.workers.dev".to_string()),
+            settings: RagIndexSettings::default(),
         };
 
         let url = Rag::get_mcp_url(&config);
@@ -310,4 +967,100 @@ mod tests {
         Rag::remove(project_path).unwrap();
         assert!(!Rag::is_configured(project_path).unwrap());
     }
+
+    fn init_with_worker(project_path: &Path, url: &str) {
+        let config = RagConfig {
+            project_id: "my-project".to_string(),
+            api_key: "rl_test123".to_string(),
+            mcp_url: Some(url.to_string()),
+            settings: RagIndexSettings::default(),
+        };
+        Rag::save_config(project_path, &config).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_query_requires_init() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let err = Rag::query(temp_dir.path(), "auth", DEFAULT_RAG_QUERY_LIMIT)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("rag init"));
+    }
+
+    #[tokio::test]
+    async fn test_query_calls_rag_search_tool() {
+        use wiremock::matchers::{body_partial_json, header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/mcp"))
+            .and(header("Authorization", "Bearer rl_test123"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "tools/call",
+                "params": {
+                    "name": "rag_search",
+                    "arguments": { "project_id": "my-project", "query": "auth", "limit": 2 }
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "content": [{ "type": "text", "text": "..." }],
+                    "structuredContent": { "results": [
+                        { "content": "We use JWT for auth", "score": 0.92, "source": "decisions/auth.md" },
+                        { "text": "Sessions expire after 1h" }
+                    ]}
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        init_with_worker(temp_dir.path(), &server.uri());
+
+        let results = Rag::query(temp_dir.path(), "auth", 2).await.unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].content, "We use JWT for auth");
+        assert_eq!(results[0].score, Some(0.92));
+        assert_eq!(results[0].source.as_deref(), Some("decisions/auth.md"));
+        assert_eq!(results[1].content, "Sessions expire after 1h");
+        assert_eq!(results[1].score, None);
+    }
+
+    #[tokio::test]
+    async fn test_query_reports_rejected_api_key() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::method("POST"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        init_with_worker(temp_dir.path(), &server.uri());
+
+        let err = Rag::query(temp_dir.path(), "auth", DEFAULT_RAG_QUERY_LIMIT)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("rejected the API key"));
+    }
+
+    #[test]
+    fn test_parse_search_reply_text_content_and_event_stream() {
+        let reply = r#"{"jsonrpc":"2.0","id":1,"result":{"content":[{"type":"text","text":"first"},{"type":"text","text":"second"}]}}"#;
+        let body = format!("event: message\ndata: {}\n\n", reply);
+
+        let results = Rag::parse_search_reply(&body, true).unwrap();
+        let contents: Vec<_> = results.iter().map(|r| r.content.as_str()).collect();
+        assert_eq!(contents, ["first", "second"]);
+
+        let error = r#"{"jsonrpc":"2.0","id":1,"result":{"isError":true,"content":[{"type":"text","text":"unknown project"}]}}"#;
+        let err = Rag::parse_search_reply(error, false).unwrap_err();
+        assert!(err.to_string().contains("unknown project"));
+    }
 }
@@ -2,7 +2,7 @@ use crate::{Paths, Result, RhinolabsError};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -87,6 +87,51 @@ impl Default for PluginSettings {
 
 pub struct Settings;
 
+/// Arrays that are unioned instead of replaced, so the user's own rules survive
+const UNION_ARRAYS: &[&[&str]] = &[&["permissions", "allow"], &["permissions", "deny"]];
+
+/// Deep-merge `managed` into `target`: objects are merged key by key, the arrays in
+/// `UNION_ARRAYS` get the managed entries they're missing appended, and any other
+/// value in `managed` replaces the one in `target`. Keys only `target` has are kept.
+fn merge_json(target: &mut serde_json::Value, managed: serde_json::Value) {
+    merge_json_at(target, managed, &mut Vec::new());
+}
+
+fn merge_json_at(
+    target: &mut serde_json::Value,
+    managed: serde_json::Value,
+    path: &mut Vec<String>,
+) {
+    match (target, managed) {
+        (serde_json::Value::Object(target), serde_json::Value::Object(managed)) => {
+            for (key, value) in managed {
+                match target.get_mut(&key) {
+                    Some(existing) => {
+                        path.push(key);
+                        merge_json_at(existing, value, path);
+                        path.pop();
+                    }
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (serde_json::Value::Array(target), serde_json::Value::Array(managed))
+            if UNION_ARRAYS
+                .iter()
+                .any(|union| union.iter().eq(path.iter())) =>
+        {
+            for value in managed {
+                if !target.contains(&value) {
+                    target.push(value);
+                }
+            }
+        }
+        (target, managed) => *target = managed,
+    }
+}
+
 impl Settings {
     /// Get the path to settings.json
     fn settings_path() -> Result<PathBuf> {
@@ -112,17 +157,41 @@ impl Settings {
     /// Update the plugin settings
     /// Creates the directory if it doesn't exist
     pub fn update(settings: &PluginSettings) -> Result<()> {
-        let path = Self::settings_path()?;
+        let content = serde_json::to_string_pretty(settings)?;
+        crate::paths::atomic_write(&Self::settings_path()?, content, false)?;
 
-        // Create parent directory if it doesn't exist
-        if let Some(parent) = path.parent() {
-            if !parent.exists() {
-                fs::create_dir_all(parent)?;
-            }
-        }
+        Ok(())
+    }
 
-        let content = serde_json::to_string_pretty(settings)?;
-        fs::write(&path, content)?;
+    /// Write `settings` into a user's Claude Code `settings.json`.
+    ///
+    /// The managed keys are deep-merged over what the file already holds, so the
+    /// user's own keys (hooks, model, ...) survive. Before an existing file is
+    /// changed it is copied to `settings.json.bak`.
+    pub fn write_merged(path: &Path, settings: &PluginSettings) -> Result<()> {
+        let managed = serde_json::to_value(settings)?;
+
+        let existing = if path.exists() {
+            Some(fs::read_to_string(path)?)
+        } else {
+            None
+        };
+        // A file that isn't a JSON object can't be merged into; the backup keeps it
+        let mut merged = existing
+            .as_deref()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(content).ok())
+            .filter(serde_json::Value::is_object)
+            .unwrap_or_else(|| serde_json::json!({}));
+        merge_json(&mut merged, managed);
+
+        let content = serde_json::to_string_pretty(&merged)?;
+        if existing.as_deref() == Some(content.as_str()) {
+            return Ok(());
+        }
+        if existing.is_some() {
+            fs::copy(path, crate::fs_utils::sibling_with_suffix(path, "bak"))?;
+        }
+        crate::paths::atomic_write(path, content, false)?;
 
         Ok(())
     }
@@ -286,4 +355,69 @@ mod tests {
         let path = path.unwrap();
         assert!(path.to_str().unwrap().contains("settings.json"));
     }
+
+    #[test]
+    fn test_write_merged_preserves_user_keys() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("settings.json");
+        let user = serde_json::json!({
+            "model": "opus",
+            "hooks": { "PreToolUse": [{ "matcher": "Bash" }] },
+            "env": { "MY_VAR": "1" },
+            "permissions": {
+                "allow": ["Bash(ls)"],
+                "deny": ["Read(.env)"],
+                "defaultMode": "plan"
+            }
+        });
+        fs::write(&path, serde_json::to_string_pretty(&user).unwrap()).unwrap();
+
+        let settings = PluginSettings::default();
+        Settings::write_merged(&path, &settings).unwrap();
+
+        let merged: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(merged["model"], "opus");
+        assert_eq!(merged["hooks"], user["hooks"]);
+        assert_eq!(merged["env"]["MY_VAR"], "1");
+        assert_eq!(merged["env"]["ENABLE_TOOL_SEARCH"], "true");
+        assert_eq!(merged["permissions"]["defaultMode"], "plan");
+        // The user's own rules are kept alongside the managed ones
+        let mut allow = vec!["Bash(ls)".to_string()];
+        allow.extend(
+            settings
+                .permissions
+                .allow
+                .iter()
+                .filter(|rule| *rule != "Bash(ls)")
+                .cloned(),
+        );
+        assert_eq!(
+            merged["permissions"]["allow"],
+            serde_json::to_value(&allow).unwrap()
+        );
+        assert_eq!(merged["permissions"]["deny"][0], "Read(.env)");
+        assert_eq!(merged["statusLine"]["type"], "command");
+
+        let backup = fs::read_to_string(temp.path().join("settings.json.bak")).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&backup).unwrap(),
+            user
+        );
+    }
+
+    #[test]
+    fn test_write_merged_creates_missing_file_without_backup() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("settings.json");
+
+        Settings::write_merged(&path, &PluginSettings::default()).unwrap();
+        // Unchanged content is not rewritten, so no backup of our own output either
+        Settings::write_merged(&path, &PluginSettings::default()).unwrap();
+
+        let written: PluginSettings =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written.output_style, "Rhinolabs");
+        assert!(!temp.path().join("settings.json.bak").exists());
+    }
 }
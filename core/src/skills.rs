@@ -1,7 +1,11 @@
-use crate::{Paths, Profile, Profiles, Result, RhinolabsError};
+use crate::git::{CommitInfo, GitOperations};
+use crate::{
+    AutoInvokeRule, DeployTarget, Paths, Profile, Profiles, Result, RhinolabsError,
+    SkillInstallError, Warnings,
+};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // ============================================
 // Skill Source Types
@@ -30,7 +34,7 @@ pub enum SkillSchema {
     Custom,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SkillSource {
     pub id: String,
@@ -48,12 +52,76 @@ pub struct SkillSource {
     pub schema: SkillSchema,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skill_count: Option<u32>,
+    /// Git branch to read skills from; `None` means `main`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Repo directory holding the skill folders; `None` means `skills`, an empty
+    /// string the repo root. A directory with its own SKILL.md is one (flat) skill.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skills_path: Option<String>,
+    /// Environment variable holding a GitHub token for this (private) source.
+    /// Only the variable name is stored, never the token itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_token_env: Option<String>,
 }
 
 fn default_fetchable() -> bool {
     false
 }
 
+/// Branch used when a source does not configure one
+pub const DEFAULT_BRANCH: &str = "main";
+/// Branch tried when the configured one does not exist
+const FALLBACK_BRANCH: &str = "master";
+/// Repo directory skills are listed from when a source does not configure one
+pub const DEFAULT_SKILLS_PATH: &str = "skills";
+/// Where the global GitHub token is read from, for error hints
+const GLOBAL_TOKEN_VARS: &str = "GITHUB_TOKEN or RHINOLABS_GITHUB_TOKEN";
+/// Maximum SKILL.md downloads in flight per GitHub source, to stay clear of rate limits
+const GITHUB_FETCH_CONCURRENCY: usize = 8;
+/// Default freshness of cached remote skill listings
+pub const DEFAULT_CACHE_TTL_SECS: u64 = 60 * 60;
+
+/// `dir/name` within a repo, where an empty `dir` is the repo root
+fn repo_path(dir: &str, name: &str) -> String {
+    if dir.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", dir, name)
+    }
+}
+
+/// Id of the single skill in a flat layout: the last component of the skills path,
+/// or the repo name when the skill sits at the repo root
+fn flat_skill_id<'a>(skills_path: &'a str, repo: &'a str) -> &'a str {
+    skills_path
+        .rsplit('/')
+        .next()
+        .filter(|s| !s.is_empty())
+        .unwrap_or(repo)
+}
+
+/// Repo directories that may hold `skill_id`: `{skills_path}/{skill_id}`, then the
+/// skills path itself when it is a flat skill with that id
+fn skill_dir_candidates(skills_path: &str, repo: &str, skill_id: &str) -> Vec<String> {
+    let mut candidates = vec![repo_path(skills_path, skill_id)];
+    if flat_skill_id(skills_path, repo) == skill_id {
+        candidates.push(skills_path.to_string());
+    }
+    candidates
+}
+
+/// The configured branch first, then `master` unless that was already tried. The
+/// fallback is only for a configured branch that does not exist (see
+/// `Skills::branch_exists`).
+fn branch_candidates(branch: &str) -> Vec<&str> {
+    if branch == FALLBACK_BRANCH {
+        vec![branch]
+    } else {
+        vec![branch, FALLBACK_BRANCH]
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RemoteSkill {
@@ -69,12 +137,183 @@ pub struct RemoteSkill {
     pub installed: bool,
 }
 
+impl RemoteSkill {
+    /// Case-insensitive match of `query` against the id, name or description
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        [&self.id, &self.name, &self.description]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&query))
+    }
+}
+
+/// Skills fetched from a source, plus any that were skipped along the way
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteSkillFetch {
+    pub skills: Vec<RemoteSkill>,
+    pub warnings: Warnings,
+}
+
+/// Name of the metadata file stored at the root of an exported skill archive
+const SKILL_ARCHIVE_META_FILE: &str = "rhinolabs-meta.json";
+
+/// Contents of `rhinolabs-meta.json`, so an import can restore what the skill directory alone cannot
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillArchiveMeta {
+    pub id: String,
+    pub category: SkillCategory,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_name: Option<String>,
+    pub exported_at: String,
+}
+
+/// Upstream status of a skill installed from a source
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillUpdate {
+    pub skill_id: String,
+    /// The source's SKILL.md differs from the one that was installed
+    pub has_update: bool,
+    /// The local SKILL.md was edited since install; updating would overwrite it
+    pub is_locally_modified: bool,
+}
+
+/// Result of `Skills::check_updates`, plus skills that could not be checked
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillUpdateCheck {
+    pub updates: Vec<SkillUpdate>,
+    pub warnings: Warnings,
+}
+
+/// Result of `Skills::diff`, plus why the comparison may not be against the original
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillDiff {
+    pub skill_id: String,
+    pub diff: String,
+    pub warnings: Warnings,
+}
+
+/// A line in an installed skill's files matching `Skills::search_content`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillContentMatch {
+    pub skill_id: String,
+    /// Path relative to the skill directory, e.g. `SKILL.md`
+    pub file: String,
+    /// 1-based line number
+    pub line: usize,
+    pub text: String,
+}
+
+/// An installed skill, plus the profile it was added to (if any)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillInstallResult {
+    #[serde(flatten)]
+    pub skill: Skill,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_profile: Option<String>,
+    /// For remote installs: the branch the files were downloaded from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct InstallSkillInput {
     pub source_id: String,
     pub skill_id: String,
 }
 
+/// Outcome of `Skills::install_many`: one entry per requested skill
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkSkillInstallResult {
+    pub skills_installed: Vec<String>,
+    /// Already present locally; left untouched
+    pub skills_skipped: Vec<String>,
+    pub skills_failed: Vec<SkillInstallError>,
+}
+
+impl SkillSource {
+    /// The branch to fetch from, defaulting to `main`
+    pub fn branch(&self) -> &str {
+        self.branch.as_deref().unwrap_or(DEFAULT_BRANCH)
+    }
+
+    /// The repo directory holding the skills, defaulting to `skills`; empty for the root
+    pub fn skills_path(&self) -> &str {
+        match self.skills_path.as_deref().map(|p| p.trim_matches('/')) {
+            Some(".") => "",
+            Some(path) => path,
+            None => DEFAULT_SKILLS_PATH,
+        }
+    }
+
+    /// Token for requests to this source: the one in its `auth_token_env` variable,
+    /// or the global GitHub token when it has none (see [`Skills::github_token`]).
+    /// Fails if the configured variable is not set.
+    pub fn auth_token(&self) -> Result<Option<String>> {
+        let Some(var) = &self.auth_token_env else {
+            return Ok(Skills::github_token());
+        };
+        match std::env::var(var) {
+            Ok(token) if !token.trim().is_empty() => Ok(Some(token.trim().to_string())),
+            _ => Err(RhinolabsError::ConfigError(format!(
+                "Source '{}' needs a GitHub token in ${}, which is not set",
+                self.id, var
+            ))),
+        }
+    }
+
+    /// Where this source's token comes from, for error hints: `$VAR` of its
+    /// `auth_token_env`, or the global token variables
+    fn token_var(&self) -> String {
+        self.auth_token_env
+            .as_ref()
+            .map_or(GLOBAL_TOKEN_VARS.to_string(), |var| format!("${}", var))
+    }
+}
+
+/// Hint for a GitHub 404, which is also what GitHub answers for a private repo the
+/// request can't see. `token_var` names where the token came from, or would come from.
+fn private_repo_hint(authenticated: bool, token_var: &str) -> String {
+    if authenticated {
+        format!(", or the token in {} cannot access it", token_var)
+    } else {
+        format!(
+            ". If the repository is private, set {} to a GitHub token that can access it",
+            token_var
+        )
+    }
+}
+
+/// Check that `name` can be an environment variable name, rejecting pasted tokens
+fn validate_token_env(name: &str) -> Result<()> {
+    const TOKEN_PREFIXES: &[&str] = &["ghp_", "gho_", "ghs_", "ghu_", "github_pat_"];
+    let valid = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(RhinolabsError::ConfigError(format!(
+            "Invalid token variable name '{}'",
+            name
+        )));
+    }
+    if TOKEN_PREFIXES.iter().any(|prefix| name.starts_with(prefix)) {
+        return Err(RhinolabsError::ConfigError(
+            "authTokenEnv takes the name of an environment variable holding the token, not the token itself".into(),
+        ));
+    }
+    Ok(())
+}
+
 // Default sources
 impl SkillSource {
     pub fn default_sources() -> Vec<SkillSource> {
@@ -89,6 +328,9 @@ impl SkillSource {
                 fetchable: true,
                 schema: SkillSchema::Standard,
                 skill_count: None,
+                branch: None,
+                skills_path: None,
+                auth_token_env: None,
             },
             SkillSource {
                 id: "vercel-agent-skills".to_string(),
@@ -100,6 +342,9 @@ impl SkillSource {
                 fetchable: true,
                 schema: SkillSchema::Standard,
                 skill_count: None,
+                branch: None,
+                skills_path: None,
+                auth_token_env: None,
             },
             SkillSource {
                 id: "awesome-claude-skills".to_string(),
@@ -111,6 +356,9 @@ impl SkillSource {
                 fetchable: false,
                 schema: SkillSchema::Custom, // Not applicable, browse only
                 skill_count: None,
+                branch: None,
+                skills_path: None,
+                auth_token_env: None,
             },
         ]
     }
@@ -120,8 +368,9 @@ impl SkillSource {
 // Skill Category
 // ============================================
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
+/// Serialized as its lowercase name (`AiSdk` as `"aisdk"`); any other
+/// non-empty string is a team-defined [`SkillCategory::Named`] category.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub enum SkillCategory {
     Corporate,
     Backend,
@@ -131,6 +380,77 @@ pub enum SkillCategory {
     Utilities,
     #[default]
     Custom,
+    /// A category outside the built-in set, e.g. `devops`
+    Named(String),
+}
+
+impl SkillCategory {
+    /// Category for a label. Built-in names match case-insensitively (`ai-sdk`
+    /// is accepted for `aisdk`); anything else becomes a lowercase `Named` label.
+    pub fn from_label(label: &str) -> Self {
+        let label = label.trim().to_lowercase();
+        match label.as_str() {
+            "corporate" => Self::Corporate,
+            "backend" => Self::Backend,
+            "frontend" => Self::Frontend,
+            "testing" => Self::Testing,
+            "aisdk" | "ai-sdk" => Self::AiSdk,
+            "utilities" => Self::Utilities,
+            "custom" | "" => Self::Custom,
+            _ => Self::Named(label),
+        }
+    }
+
+    /// The serialized name
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Corporate => "corporate",
+            Self::Backend => "backend",
+            Self::Frontend => "frontend",
+            Self::Testing => "testing",
+            Self::AiSdk => "aisdk",
+            Self::Utilities => "utilities",
+            Self::Custom => "custom",
+            Self::Named(label) => label,
+        }
+    }
+
+    /// Sort key for listings: built-in categories in declaration order, then
+    /// named ones alphabetically
+    fn sort_key(&self) -> (u8, &str) {
+        let rank = match self {
+            Self::Corporate => 0,
+            Self::Backend => 1,
+            Self::Frontend => 2,
+            Self::Testing => 3,
+            Self::AiSdk => 4,
+            Self::Utilities => 5,
+            Self::Custom => 6,
+            Self::Named(_) => 7,
+        };
+        (rank, self.as_str())
+    }
+}
+
+impl Serialize for SkillCategory {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SkillCategory {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let label = String::deserialize(deserializer)?;
+        if label.trim().is_empty() {
+            return Err(serde::de::Error::custom("category must not be empty"));
+        }
+        Ok(Self::from_label(&label))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -151,8 +471,89 @@ pub struct Skill {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_name: Option<String>,
     pub is_modified: bool,
+    /// Optional SKILL.md frontmatter metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Ids of profiles that include this skill (only set by `list_with_profiles`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_ids: Option<Vec<String>>,
+}
+
+/// A skill `name` shared by more than one skill directory
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateSkillName {
+    pub name: String,
+    pub skill_ids: Vec<String>,
+}
+
+/// Non-fatal issues detected while listing skills
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillListWarnings {
+    /// Names used by several skills; auto-invoke tables and pickers can't tell them apart
+    pub duplicate_names: Vec<DuplicateSkillName>,
+}
+
+impl SkillListWarnings {
+    pub fn is_empty(&self) -> bool {
+        self.duplicate_names.is_empty()
+    }
+}
+
+/// Severity of a `ValidationIssue`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueLevel {
+    /// Blocks publishing; `skill validate` exits non-zero
+    Error,
+    Warning,
+}
+
+/// A problem found in a skill by `Skills::validate` or in SKILL.md content by
+/// `Skills::validate_content`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    pub level: IssueLevel,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            level: IssueLevel::Error,
+            message: message.into(),
+        }
+    }
+
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            level: IssueLevel::Warning,
+            message: message.into(),
+        }
+    }
 }
 
+/// Agent Skills spec limits
+const SPEC_NAME_MAX_LEN: usize = 64;
+const SPEC_DESCRIPTION_MAX_LEN: usize = 1024;
+/// Entries the spec allows at the root of a skill directory
+const SPEC_ALLOWED_ROOT_ENTRIES: &[&str] = &[
+    "SKILL.md",
+    "scripts",
+    "references",
+    "assets",
+    "LICENSE",
+    "LICENSE.md",
+    "LICENSE.txt",
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateSkillInput {
     pub id: String,
@@ -160,6 +561,69 @@ pub struct CreateSkillInput {
     pub description: String,
     pub category: SkillCategory,
     pub content: String,
+    /// Import this directory as the skill instead of writing a new SKILL.md.
+    /// It must contain a SKILL.md; `name`, `description` and `content` are ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_dir: Option<PathBuf>,
+}
+
+/// Patterns from a `.skillignore` file, in `.gitignore` syntax (including `!` to
+/// re-include a path)
+struct SkillIgnore {
+    matcher: ignore::gitignore::Gitignore,
+}
+
+impl SkillIgnore {
+    const FILE_NAME: &'static str = ".skillignore";
+
+    fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(Self::FILE_NAME);
+        let content = if path.exists() {
+            fs::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+        Ok(Self::parse(&content))
+    }
+
+    /// Invalid patterns are skipped
+    fn parse(content: &str) -> Self {
+        let mut builder = ignore::gitignore::GitignoreBuilder::new("");
+        for line in content.lines() {
+            let _ = builder.add_line(None, line);
+        }
+        let matcher = builder
+            .build()
+            .unwrap_or_else(|_| ignore::gitignore::Gitignore::empty());
+        SkillIgnore { matcher }
+    }
+
+    fn is_ignored(&self, relative: &Path, is_dir: bool) -> bool {
+        let path = relative.to_string_lossy().replace('\\', "/");
+        if path == Self::FILE_NAME || path == ".git" {
+            return true;
+        }
+        self.matcher.matched(relative, is_dir).is_ignore()
+    }
+}
+
+/// Fields to change on a skill source; `None` leaves a field as is
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateSkillSourceInput {
+    pub enabled: Option<bool>,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub description: Option<String>,
+    pub fetchable: Option<bool>,
+    pub schema: Option<SkillSchema>,
+    /// An empty string resets the source to the default branch
+    pub branch: Option<String>,
+    /// Skills directory within the repo; an empty string (or `.`) means the repo root
+    #[serde(default)]
+    pub skills_path: Option<String>,
+    /// Name of the env var holding the source's token; an empty string clears it
+    #[serde(default)]
+    pub auth_token_env: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -171,10 +635,91 @@ pub struct UpdateSkillInput {
     pub category: Option<SkillCategory>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct SkillFrontmatter {
     name: String,
     description: String,
+    #[serde(
+        default,
+        deserialize_with = "yaml_scalar_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    version: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "yaml_scalar_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    author: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "yaml_scalar_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    license: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "yaml_string_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    tags: Vec<String>,
+}
+
+impl SkillFrontmatter {
+    fn new(name: &str, description: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Accept any YAML scalar (`version: 1.0` is a float) as a string; other shapes are ignored
+fn yaml_scalar_string<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error> {
+    Ok(match serde_yaml::Value::deserialize(deserializer)? {
+        serde_yaml::Value::String(s) => Some(s),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        serde_yaml::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    })
+}
+
+/// Read `categoryMap`, dropping entries whose category is empty or not a string
+/// instead of failing the whole config (any other name is a `SkillCategory::Named`)
+fn known_categories<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<std::collections::HashMap<String, SkillCategory>, D::Error> {
+    let raw = std::collections::HashMap::<String, serde_json::Value>::deserialize(deserializer)?;
+    Ok(raw
+        .into_iter()
+        .filter_map(|(id, value)| Some((id, serde_json::from_value(value).ok()?)))
+        .collect())
+}
+
+/// Accept tags as a YAML list or a comma-separated string
+fn yaml_string_list<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Vec<String>, D::Error> {
+    let items = match serde_yaml::Value::deserialize(deserializer)? {
+        serde_yaml::Value::Sequence(items) => items
+            .into_iter()
+            .filter_map(|item| match item {
+                serde_yaml::Value::String(s) => Some(s),
+                serde_yaml::Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+            .collect(),
+        serde_yaml::Value::String(s) => s.split(',').map(str::to_string).collect(),
+        _ => Vec::new(),
+    };
+    Ok(items
+        .into_iter()
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect())
 }
 
 /// Metadata for installed skills (tracks source and original content hash)
@@ -191,33 +736,225 @@ struct SkillMeta {
 #[serde(rename_all = "camelCase")]
 struct SkillsConfig {
     disabled: Vec<String>,
+    /// Skills left out of specific deploy targets (skill_id -> target ids).
+    /// Ids stay strings so a removed custom target doesn't make the config
+    /// unloadable. The global `disabled` list takes precedence.
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    disabled_by_target: std::collections::HashMap<String, Vec<String>>,
     custom: Vec<String>,
     sources: Vec<SkillSource>,
     #[serde(default)]
     skill_meta: std::collections::HashMap<String, SkillMeta>,
     /// User-defined category mappings (skill_id -> category)
     /// Takes precedence over hardcoded category constants
-    #[serde(default)]
+    #[serde(default, deserialize_with = "known_categories")]
     category_map: std::collections::HashMap<String, SkillCategory>,
+    /// GitHub token for authenticated API requests, used when no env var is set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    github_token: Option<String>,
+    /// How long cached remote listings stay fresh, in seconds (default 1 hour)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cache_ttl_secs: Option<u64>,
 }
 
-/// Built-in skill categories
-const CORPORATE_SKILLS: &[&str] = &[
-    "rhinolabs-standards",
-    "rhinolabs-architecture",
-    "rhinolabs-security",
-];
-const BACKEND_SKILLS: &[&str] = &[];
-const FRONTEND_SKILLS: &[&str] = &[
-    "react-patterns",
-    "typescript-best-practices",
-    "tailwind-4",
-    "zod-4",
-    "zustand-5",
-];
-const TESTING_SKILLS: &[&str] = &["testing-strategies", "playwright"];
-const AI_SDK_SKILLS: &[&str] = &["ai-sdk-core", "ai-sdk-react", "nextjs-integration"];
-const UTILITIES_SKILLS: &[&str] = &["skill-creator"];
+impl SkillsConfig {
+    fn is_disabled(&self, id: &str) -> bool {
+        self.disabled.iter().any(|s| s == id)
+    }
+
+    fn is_disabled_for(&self, id: &str, target: DeployTarget) -> bool {
+        self.disabled_by_target
+            .get(id)
+            .is_some_and(|targets| targets.iter().any(|t| t == target.id()))
+    }
+
+    /// Whether `id` deploys to `target`. Globally off beats per-target on; with
+    /// `include_global` unset only the per-target setting counts.
+    fn deploys_to(&self, id: &str, target: DeployTarget, include_global: bool) -> bool {
+        let globally_off = include_global && self.is_disabled(id);
+        !globally_off && !self.is_disabled_for(id, target)
+    }
+}
+
+/// Which skills.sh leaderboard to list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SkillsShSort {
+    #[default]
+    Hot,
+    Trending,
+    New,
+    AllTime,
+}
+
+impl SkillsShSort {
+    /// URL path segment of the listing
+    fn path(self) -> &'static str {
+        match self {
+            SkillsShSort::Hot => "hot",
+            SkillsShSort::Trending => "trending",
+            SkillsShSort::New => "new",
+            SkillsShSort::AllTime => "all-time",
+        }
+    }
+
+    /// Name of the array the listing page embeds its skills in. Only
+    /// `allTimeSkills` has been seen on the live site; the others follow its
+    /// naming, and a page without the named array is read from its first skills
+    /// array instead (see [`Skills::extract_skills_sh_data`]).
+    fn data_key(self) -> &'static str {
+        match self {
+            SkillsShSort::Hot => "hotSkills",
+            SkillsShSort::Trending => "trendingSkills",
+            SkillsShSort::New => "newSkills",
+            SkillsShSort::AllTime => "allTimeSkills",
+        }
+    }
+}
+
+impl std::str::FromStr for SkillsShSort {
+    type Err = RhinolabsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "hot" => Ok(Self::Hot),
+            "trending" => Ok(Self::Trending),
+            "new" => Ok(Self::New),
+            "all-time" => Ok(Self::AllTime),
+            _ => Err(RhinolabsError::ConfigError(format!(
+                "Invalid sort: {}. Use hot, trending, new, or all-time",
+                s
+            ))),
+        }
+    }
+}
+
+/// A skills.sh listing: a leaderboard, optionally narrowed to one category
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillsShQuery {
+    #[serde(default)]
+    pub sort: SkillsShSort,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+impl SkillsShQuery {
+    /// Listing URL under `source_url`. A source URL pointing at a specific page
+    /// (no trailing slash, not the site root) is used as is.
+    fn url(&self, source_url: &str) -> String {
+        let base = source_url.trim_end_matches('/');
+        if !source_url.ends_with('/') && base != "https://skills.sh" {
+            return source_url.to_string();
+        }
+        let Ok(mut url) = reqwest::Url::parse(source_url) else {
+            return source_url.to_string();
+        };
+
+        // Pushed segments are percent-encoded, so a category can't add path segments
+        if let Ok(mut segments) = url.path_segments_mut() {
+            segments.pop_if_empty().push(self.sort.path());
+            if let Some(category) = self.category.as_deref().map(str::trim) {
+                if !category.is_empty() {
+                    segments.push(category);
+                }
+            }
+        }
+        url.to_string()
+    }
+}
+
+/// Something wrong with `.skills-config.json`, as found by [`Skills::config_problem`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkillsConfigProblem {
+    /// The file can't be parsed, so every skills operation fails until it is repaired
+    Unreadable(String),
+    /// `categoryMap` entries (skill id, value) that are not categories (empty or
+    /// not a string); they are ignored on load and dropped the next time the config is saved
+    UnknownCategories(Vec<(String, String)>),
+}
+
+/// A source's remote skill listing as cached in `.skill-cache.json`.
+/// `url` and `branch` identify what was fetched, so editing the source invalidates it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedListing {
+    url: String,
+    branch: String,
+    fetched_at: chrono::DateTime<chrono::Utc>,
+    skills: Vec<RemoteSkill>,
+}
+
+/// Built-in skill categories, shipped with the plugin as `skill-categories.json`.
+/// This copy is used when the installed plugin has none (or an unreadable one).
+const DEFAULT_CATEGORIES: &str = include_str!("../../rhinolabs-claude/skill-categories.json");
+const CATEGORIES_FILE: &str = "skill-categories.json";
+
+/// Skill ids listed under each category. Keys other than the built-in names
+/// define named categories.
+#[derive(Debug, Default, Deserialize)]
+struct BuiltinCategories {
+    #[serde(default)]
+    corporate: Vec<String>,
+    #[serde(default)]
+    backend: Vec<String>,
+    #[serde(default)]
+    frontend: Vec<String>,
+    #[serde(default)]
+    testing: Vec<String>,
+    #[serde(default)]
+    aisdk: Vec<String>,
+    #[serde(default)]
+    utilities: Vec<String>,
+    #[serde(flatten)]
+    named: std::collections::BTreeMap<String, Vec<String>>,
+}
+
+impl BuiltinCategories {
+    /// The installed plugin's mapping, falling back to the embedded default
+    fn load() -> Self {
+        let installed = Paths::plugin_dir()
+            .map(|dir| dir.join(CATEGORIES_FILE))
+            .ok()
+            .filter(|path| path.exists());
+        if let Some(path) = installed {
+            match fs::read_to_string(&path)
+                .map_err(RhinolabsError::from)
+                .and_then(|content| Ok(serde_json::from_str(&content)?))
+            {
+                Ok(categories) => return categories,
+                Err(e) => tracing::warn!("Ignoring {}: {}", path.display(), e),
+            }
+        }
+        Self::embedded()
+    }
+
+    /// The default mapping compiled into the binary
+    fn embedded() -> Self {
+        serde_json::from_str(DEFAULT_CATEGORIES).unwrap_or_default()
+    }
+
+    /// Category of `id`; a skill listed twice gets the first one, so Corporate
+    /// wins and named categories come last
+    fn category(&self, id: &str) -> Option<SkillCategory> {
+        [
+            (&self.corporate, SkillCategory::Corporate),
+            (&self.backend, SkillCategory::Backend),
+            (&self.frontend, SkillCategory::Frontend),
+            (&self.testing, SkillCategory::Testing),
+            (&self.aisdk, SkillCategory::AiSdk),
+            (&self.utilities, SkillCategory::Utilities),
+        ]
+        .into_iter()
+        .chain(
+            self.named
+                .iter()
+                .map(|(label, ids)| (ids, SkillCategory::from_label(label))),
+        )
+        .find(|(ids, _)| ids.iter().any(|listed| listed == id))
+        .map(|(_, category)| category)
+    }
+}
 
 pub struct Skills;
 
@@ -248,36 +985,22 @@ impl Skills {
 
     /// Save skills config
     fn save_config(config: &SkillsConfig) -> Result<()> {
-        let path = Self::config_path()?;
         let content = serde_json::to_string_pretty(config)?;
-        fs::write(&path, content)?;
+        crate::paths::atomic_write(&Self::config_path()?, content, false)?;
         Ok(())
     }
 
     /// Determine the category of a skill by id
-    /// Priority: 1) user-defined in category_map, 2) hardcoded constants, 3) Custom
-    fn get_category(id: &str, config: &SkillsConfig) -> SkillCategory {
+    /// Priority: 1) user-defined in category_map, 2) `builtin` (see [`BuiltinCategories::load`]),
+    /// 3) Custom
+    fn get_category(id: &str, config: &SkillsConfig, builtin: &BuiltinCategories) -> SkillCategory {
         // First check user-defined category_map
         if let Some(category) = config.category_map.get(id) {
             return category.clone();
         }
 
-        // Fallback to hardcoded constants (built-in skills)
-        if CORPORATE_SKILLS.contains(&id) {
-            SkillCategory::Corporate
-        } else if BACKEND_SKILLS.contains(&id) {
-            SkillCategory::Backend
-        } else if FRONTEND_SKILLS.contains(&id) {
-            SkillCategory::Frontend
-        } else if TESTING_SKILLS.contains(&id) {
-            SkillCategory::Testing
-        } else if AI_SDK_SKILLS.contains(&id) {
-            SkillCategory::AiSdk
-        } else if UTILITIES_SKILLS.contains(&id) {
-            SkillCategory::Utilities
-        } else {
-            SkillCategory::Custom
-        }
+        // Fallback to the built-in mapping shipped with the plugin
+        builtin.category(id).unwrap_or(SkillCategory::Custom)
     }
 
     /// Parse frontmatter from a SKILL.md file
@@ -306,12 +1029,14 @@ impl Skills {
         Ok((frontmatter, markdown_content.to_string()))
     }
 
-    /// Generate SKILL.md content
-    fn generate_skill_file(name: &str, description: &str, content: &str) -> String {
-        format!(
-            "---\nname: {}\ndescription: {}\n---\n\n{}",
-            name, description, content
-        )
+    /// Generate SKILL.md content. Optional frontmatter fields are only written when set;
+    /// values are quoted as YAML needs, so names like `Fix: things` survive a reparse.
+    fn generate_skill_file(frontmatter: &SkillFrontmatter, content: &str) -> Result<String> {
+        let yaml = serde_yaml::to_string(frontmatter).map_err(|e| {
+            RhinolabsError::ConfigError(format!("Failed to serialize frontmatter: {}", e))
+        })?;
+
+        Ok(format!("---\n{}---\n\n{}", yaml, content))
     }
 
     /// Compute a simple hash of content for modification detection
@@ -324,7 +1049,11 @@ impl Skills {
     }
 
     /// Load a skill from a directory
-    fn load_from_dir(dir: &PathBuf, config: &SkillsConfig) -> Result<Skill> {
+    fn load_from_dir(
+        dir: &PathBuf,
+        config: &SkillsConfig,
+        builtin: &BuiltinCategories,
+    ) -> Result<Skill> {
         let skill_file = dir.join("SKILL.md");
 
         if !skill_file.exists() {
@@ -353,7 +1082,7 @@ impl Skills {
                 .cloned()
                 .unwrap_or(SkillCategory::Custom)
         } else {
-            Self::get_category(&id, config)
+            Self::get_category(&id, config, builtin)
         };
 
         // Get source info and modification status from meta
@@ -392,6 +1121,11 @@ impl Skills {
             source_id,
             source_name,
             is_modified,
+            version: frontmatter.version,
+            author: frontmatter.author,
+            license: frontmatter.license,
+            tags: frontmatter.tags,
+            profile_ids: None,
         })
     }
 
@@ -404,6 +1138,7 @@ impl Skills {
         }
 
         let config = Self::load_config()?;
+        let builtin = BuiltinCategories::load();
         let mut skills = Vec::new();
 
         for entry in fs::read_dir(&dir)? {
@@ -411,60 +1146,254 @@ impl Skills {
             let path = entry.path();
 
             if path.is_dir() {
-                if let Ok(skill) = Self::load_from_dir(&path, &config) {
+                if let Ok(skill) = Self::load_from_dir(&path, &config, &builtin) {
                     skills.push(skill);
                 }
             }
         }
 
-        // Sort: corporate first, then by category, then by name
+        // Sort: corporate first, then by category (named ones last), then by name
         skills.sort_by(|a, b| {
-            let cat_order = |cat: &SkillCategory| match cat {
-                SkillCategory::Corporate => 0,
-                SkillCategory::Backend => 1,
-                SkillCategory::Frontend => 2,
-                SkillCategory::Testing => 3,
-                SkillCategory::AiSdk => 4,
-                SkillCategory::Utilities => 5,
-                SkillCategory::Custom => 6,
-            };
-
-            cat_order(&a.category)
-                .cmp(&cat_order(&b.category))
+            a.category
+                .sort_key()
+                .cmp(&b.category.sort_key())
                 .then_with(|| a.name.cmp(&b.name))
         });
 
         Ok(skills)
     }
 
-    /// Get a specific skill by id
-    pub fn get(id: &str) -> Result<Option<Skill>> {
-        let dir = Self::skills_dir()?.join(id);
+    /// List all skills, each with the ids of the profiles that include it
+    pub fn list_with_profiles() -> Result<Vec<Skill>> {
+        let mut skills = Self::list()?;
+        Self::attach_profile_ids(&mut skills)?;
+        Ok(skills)
+    }
 
-        if !dir.exists() {
-            return Ok(None);
+    /// Fill in `profile_ids` for each skill. The skill → profiles map is built once.
+    pub fn attach_profile_ids(skills: &mut [Skill]) -> Result<()> {
+        let mut map = Profiles::get_skill_profile_map()?;
+        for skill in skills {
+            skill.profile_ids = Some(map.remove(&skill.id).unwrap_or_default());
         }
+        Ok(())
+    }
 
-        let config = Self::load_config()?;
-        Ok(Some(Self::load_from_dir(&dir, &config)?))
+    /// List all skills along with warnings about ambiguous entries,
+    /// such as two skills sharing the same `name` frontmatter.
+    pub fn list_with_warnings() -> Result<(Vec<Skill>, SkillListWarnings)> {
+        let skills = Self::list()?;
+        let warnings = SkillListWarnings {
+            duplicate_names: Self::find_duplicate_names(&skills),
+        };
+        Ok((skills, warnings))
     }
 
-    /// Get the path to a skill's directory
-    pub fn get_skill_path(id: &str) -> Result<std::path::PathBuf> {
-        let dir = Self::skills_dir()?.join(id);
+    /// Validate a skill's SKILL.md and layout.
+    ///
+    /// Content is checked with `validate_content`. A missing SKILL.md is an error.
+    /// With `strict`, the skill is also checked against the Agent Skills spec, and
+    /// breaches are errors: `name` matching `^[a-z0-9-]+$` and the directory, a
+    /// non-empty `description` within the length cap, SKILL.md at the root and no
+    /// unexpected top-level files.
+    pub fn validate(id: &str, strict: bool) -> Result<Vec<ValidationIssue>> {
+        let dir = Self::get_skill_path(id)?;
+        Self::validate_dir(id, &dir, strict)
+    }
 
-        if !dir.exists() {
-            return Err(RhinolabsError::ConfigError(format!(
-                "Skill '{}' not found",
-                id
-            )));
-        }
+    /// Lint SKILL.md content: the frontmatter must be present and parseable with a
+    /// non-empty `name`, the `description` should be set and short enough for
+    /// Claude's skill discovery, and the body should hold instructions.
+    pub fn validate_content(content: &str) -> Vec<ValidationIssue> {
+        Self::validate_content_with(content, false)
+    }
+
+    /// `validate_content`, with description problems escalated to errors in strict mode
+    fn validate_content_with(content: &str, strict: bool) -> Vec<ValidationIssue> {
+        let (frontmatter, body) = match Self::parse_skill_file(content) {
+            Ok(parsed) => parsed,
+            Err(RhinolabsError::ConfigError(problem)) => {
+                return vec![ValidationIssue::error(problem)]
+            }
+            Err(e) => return vec![ValidationIssue::error(e.to_string())],
+        };
+
+        let mut issues = Vec::new();
+        let description_issue = |message: String| {
+            if strict {
+                ValidationIssue::error(message)
+            } else {
+                ValidationIssue::warning(message)
+            }
+        };
+
+        if frontmatter.name.trim().is_empty() {
+            issues.push(ValidationIssue::error("Frontmatter has no `name`"));
+        }
+
+        let description = frontmatter.description.trim();
+        let description_len = description.chars().count();
+        if description.is_empty() {
+            issues.push(description_issue("Frontmatter has no `description`".into()));
+        } else if description_len > SPEC_DESCRIPTION_MAX_LEN {
+            issues.push(description_issue(format!(
+                "Description is {} characters; the limit for skill discovery is {}",
+                description_len, SPEC_DESCRIPTION_MAX_LEN
+            )));
+        } else if description_len < 20 {
+            issues.push(ValidationIssue::warning(
+                "Description is very short; say when the skill should be used",
+            ));
+        }
+
+        if body.trim().is_empty() {
+            issues.push(ValidationIssue::warning(
+                "SKILL.md has no instructions after the frontmatter",
+            ));
+        }
+
+        issues
+    }
+
+    fn validate_dir(id: &str, dir: &Path, strict: bool) -> Result<Vec<ValidationIssue>> {
+        let skill_file = dir.join("SKILL.md");
+        if !skill_file.is_file() {
+            return Ok(vec![ValidationIssue::error(
+                "SKILL.md not found at the skill root",
+            )]);
+        }
+
+        let content = fs::read_to_string(&skill_file)?;
+        let mut issues = Self::validate_content_with(&content, strict);
+
+        let Ok((frontmatter, _)) = Self::parse_skill_file(&content) else {
+            return Ok(issues);
+        };
+        let name = frontmatter.name.trim();
+
+        if strict {
+            Self::check_spec(id, dir, name, &mut issues)?;
+        } else if !name.is_empty() && name != id {
+            issues.push(ValidationIssue::warning(format!(
+                "Name '{}' differs from directory '{}'",
+                name, id
+            )));
+        }
+
+        Ok(issues)
+    }
+
+    /// Agent Skills spec checks for `validate --strict` that go beyond the content
+    /// checks: the name format and the directory layout
+    fn check_spec(
+        id: &str,
+        dir: &Path,
+        name: &str,
+        issues: &mut Vec<ValidationIssue>,
+    ) -> Result<()> {
+        let valid_chars = name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !name.is_empty() && !valid_chars {
+            issues.push(ValidationIssue::error(format!(
+                "name '{}' must match ^[a-z0-9-]+$",
+                name
+            )));
+        }
+        if name.len() > SPEC_NAME_MAX_LEN {
+            issues.push(ValidationIssue::error(format!(
+                "name is {} characters; the limit is {}",
+                name.len(),
+                SPEC_NAME_MAX_LEN
+            )));
+        }
+        if !name.is_empty() && name != id {
+            issues.push(ValidationIssue::error(format!(
+                "name '{}' must match the directory name '{}'",
+                name, id
+            )));
+        }
+
+        let mut unexpected: Vec<String> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| !SPEC_ALLOWED_ROOT_ENTRIES.contains(&name.as_str()))
+            .collect();
+        unexpected.sort();
+        for entry in unexpected {
+            issues.push(ValidationIssue::error(format!(
+                "'{}' is not allowed at the skill root (use scripts/, references/ or assets/)",
+                entry
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Group skill ids by name, keeping only names used more than once
+    fn find_duplicate_names(skills: &[Skill]) -> Vec<DuplicateSkillName> {
+        let mut by_name: std::collections::BTreeMap<&str, Vec<String>> =
+            std::collections::BTreeMap::new();
+        for skill in skills {
+            by_name
+                .entry(skill.name.as_str())
+                .or_default()
+                .push(skill.id.clone());
+        }
+
+        by_name
+            .into_iter()
+            .filter(|(_, ids)| ids.len() > 1)
+            .map(|(name, mut skill_ids)| {
+                skill_ids.sort();
+                DuplicateSkillName {
+                    name: name.to_string(),
+                    skill_ids,
+                }
+            })
+            .collect()
+    }
+
+    /// Get a specific skill by id
+    pub fn get(id: &str) -> Result<Option<Skill>> {
+        let dir = Self::skills_dir()?.join(id);
+
+        if !dir.exists() {
+            return Ok(None);
+        }
+
+        let config = Self::load_config()?;
+        Ok(Some(Self::load_from_dir(
+            &dir,
+            &config,
+            &BuiltinCategories::load(),
+        )?))
+    }
+
+    /// Commits that touched a skill's directory, newest first.
+    /// Empty when the skills directory is not under git.
+    pub fn history(id: &str) -> Result<Vec<CommitInfo>> {
+        let dir = Self::get_skill_path(id)?;
+        GitOperations::history(&dir)
+    }
+
+    /// Get the path to a skill's directory
+    pub fn get_skill_path(id: &str) -> Result<std::path::PathBuf> {
+        let dir = Self::skills_dir()?.join(id);
+
+        if !dir.exists() {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Skill '{}' not found",
+                id
+            )));
+        }
 
         Ok(dir)
     }
 
     /// Create a new custom skill
     pub fn create(input: CreateSkillInput) -> Result<Skill> {
+        Self::validate_id(&input.id)?;
         let skills_dir = Self::skills_dir()?;
         let skill_dir = skills_dir.join(&input.id);
 
@@ -475,6 +1404,11 @@ impl Skills {
             )));
         }
 
+        if let Some(source_dir) = &input.from_dir {
+            Self::import_dir(source_dir, &skill_dir)?;
+            return Self::register_custom(&input, &skill_dir);
+        }
+
         // Create skill directory (and all parent directories)
         fs::create_dir_all(&skill_dir).map_err(|e| {
             RhinolabsError::ConfigError(format!(
@@ -485,10 +1419,12 @@ impl Skills {
         })?;
 
         // Create SKILL.md
-        let file_content =
-            Self::generate_skill_file(&input.name, &input.description, &input.content);
+        let file_content = Self::generate_skill_file(
+            &SkillFrontmatter::new(&input.name, &input.description),
+            &input.content,
+        )?;
         let skill_file = skill_dir.join("SKILL.md");
-        fs::write(&skill_file, &file_content).map_err(|e| {
+        crate::fs_utils::write(&skill_file, &file_content).map_err(|e| {
             RhinolabsError::ConfigError(format!(
                 "Failed to write skill file '{}': {}",
                 skill_file.display(),
@@ -496,7 +1432,35 @@ impl Skills {
             ))
         })?;
 
-        // Update config to mark as custom and save category
+        Self::register_custom(&input, &skill_dir)
+    }
+
+    /// Copy a local skill directory (honouring its `.skillignore`) into `skill_dir`.
+    /// The source must have a parseable SKILL.md at its root.
+    fn import_dir(source_dir: &Path, skill_dir: &Path) -> Result<()> {
+        let skill_file = source_dir.join("SKILL.md");
+        if !skill_file.is_file() {
+            return Err(RhinolabsError::ConfigError(format!(
+                "'{}' has no SKILL.md at its root",
+                source_dir.display()
+            )));
+        }
+        Self::parse_skill_file(&fs::read_to_string(&skill_file)?)?;
+
+        let ignore = SkillIgnore::load(source_dir)?;
+        let copied = crate::fs_utils::copy_dir_filtered(source_dir, skill_dir, &|path, is_dir| {
+            ignore.is_ignored(path, is_dir)
+        });
+        if let Err(e) = copied {
+            let _ = fs::remove_dir_all(skill_dir);
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    /// Mark a newly created skill as custom, save its category and load it
+    fn register_custom(input: &CreateSkillInput, skill_dir: &Path) -> Result<Skill> {
         let mut config = Self::load_config().map_err(|e| {
             RhinolabsError::ConfigError(format!("Failed to load skills config: {}", e))
         })?;
@@ -504,7 +1468,9 @@ impl Skills {
 
         // Save category in category_map if not Custom (Custom is the default)
         if input.category != SkillCategory::Custom {
-            config.category_map.insert(input.id.clone(), input.category);
+            config
+                .category_map
+                .insert(input.id.clone(), input.category.clone());
         }
 
         Self::save_config(&config).map_err(|e| {
@@ -513,7 +1479,188 @@ impl Skills {
 
         // Return the created skill
         let config = Self::load_config()?;
-        Self::load_from_dir(&skill_dir, &config)
+        Self::load_from_dir(
+            &skill_dir.to_path_buf(),
+            &config,
+            &BuiltinCategories::load(),
+        )
+    }
+
+    /// Zip a skill into `<dest>/<skill_id>.zip` and return the archive path.
+    /// Files keep their paths relative to the skill directory (honouring `.skillignore`),
+    /// and `rhinolabs-meta.json` records the category and source.
+    pub fn export(skill_id: &str, dest: &Path) -> Result<PathBuf> {
+        use std::io::Write;
+        use zip::write::FileOptions;
+
+        let skill = Self::get(skill_id)?.ok_or_else(|| {
+            RhinolabsError::ConfigError(format!("Skill '{}' not found", skill_id))
+        })?;
+        let skill_dir = Self::skills_dir()?.join(skill_id);
+        let ignore = SkillIgnore::load(&skill_dir)?;
+
+        fs::create_dir_all(dest)?;
+        let archive_path = dest.join(format!("{}.zip", skill_id));
+        let mut zip = zip::ZipWriter::new(fs::File::create(&archive_path)?);
+        let options = FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+        let walker = walkdir::WalkDir::new(&skill_dir)
+            .min_depth(1)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| {
+                let relative = entry
+                    .path()
+                    .strip_prefix(&skill_dir)
+                    .unwrap_or(entry.path());
+                !ignore.is_ignored(relative, entry.file_type().is_dir())
+            });
+        for entry in walker {
+            let entry = entry.map_err(|e| RhinolabsError::Other(e.to_string()))?;
+            let relative = entry
+                .path()
+                .strip_prefix(&skill_dir)
+                .unwrap_or(entry.path())
+                .to_string_lossy()
+                .replace('\\', "/");
+
+            if entry.file_type().is_dir() {
+                zip.add_directory(relative, options)?;
+            } else {
+                zip.start_file(relative, options)?;
+                zip.write_all(&fs::read(entry.path())?)?;
+            }
+        }
+
+        let meta = SkillArchiveMeta {
+            id: skill.id,
+            category: skill.category,
+            source_id: skill.source_id,
+            source_name: skill.source_name,
+            exported_at: chrono::Utc::now().to_rfc3339(),
+        };
+        zip.start_file(SKILL_ARCHIVE_META_FILE, options)?;
+        zip.write_all(serde_json::to_string_pretty(&meta)?.as_bytes())?;
+        zip.finish()?;
+
+        Ok(archive_path)
+    }
+
+    /// Skill ids name a directory in the skills dir, so they can't be empty, `.`,
+    /// `..` or contain path separators
+    fn validate_id(id: &str) -> Result<()> {
+        if matches!(id, "" | "." | "..") || id.contains(['/', '\\']) {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Invalid skill id '{}': it must name a single directory",
+                id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Import a skill from a directory containing SKILL.md or a `.zip` made by `export`.
+    /// The skill is registered as custom; an archive's `rhinolabs-meta.json` restores
+    /// its id, category and source attribution.
+    pub fn import_from_path(path: &Path) -> Result<Skill> {
+        let staging;
+        let (source_dir, meta) = if path.is_dir() {
+            (path.to_path_buf(), None)
+        } else if path.extension().is_some_and(|ext| ext == "zip") {
+            staging = tempfile::tempdir()?;
+            let meta = Self::extract_archive(path, staging.path())?;
+            (staging.path().to_path_buf(), meta)
+        } else {
+            return Err(RhinolabsError::ConfigError(format!(
+                "'{}' is not a skill directory or .zip archive",
+                path.display()
+            )));
+        };
+
+        let id = match &meta {
+            Some(meta) => meta.id.clone(),
+            None => path
+                .file_stem()
+                .and_then(|n| n.to_str())
+                .ok_or_else(|| RhinolabsError::ConfigError("Invalid skill path".into()))?
+                .to_string(),
+        };
+        Self::validate_id(&id)?;
+
+        let skill_dir = Self::skills_dir()?.join(&id);
+        if skill_dir.exists() {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Skill '{}' already exists",
+                id
+            )));
+        }
+        Self::import_dir(&source_dir, &skill_dir)?;
+
+        let mut config = Self::load_config()?;
+        config.custom.push(id.clone());
+        if let Some(meta) = meta {
+            if meta.category != SkillCategory::Custom {
+                config.category_map.insert(id.clone(), meta.category);
+            }
+            if meta.source_id.is_some() || meta.source_name.is_some() {
+                config.skill_meta.insert(
+                    id.clone(),
+                    SkillMeta {
+                        source_id: meta.source_id,
+                        source_name: meta.source_name,
+                        original_hash: None,
+                    },
+                );
+            }
+        }
+        Self::save_config(&config)?;
+
+        let config = Self::load_config()?;
+        Self::load_from_dir(&skill_dir, &config, &BuiltinCategories::load())
+    }
+
+    /// Unpack an exported skill archive into `dest`, returning its metadata if present.
+    /// Entries that would escape `dest` are rejected.
+    fn extract_archive(archive_path: &Path, dest: &Path) -> Result<Option<SkillArchiveMeta>> {
+        use std::io::Read;
+
+        let mut archive = zip::ZipArchive::new(fs::File::open(archive_path)?)?;
+        let mut meta = None;
+
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            let relative = file.enclosed_name().map(Path::to_path_buf).ok_or_else(|| {
+                RhinolabsError::ConfigError(format!(
+                    "Archive entry '{}' has an unsafe path",
+                    file.name()
+                ))
+            })?;
+
+            if file.is_dir() {
+                fs::create_dir_all(dest.join(&relative))?;
+                continue;
+            }
+
+            let mut content = Vec::new();
+            file.read_to_end(&mut content)?;
+
+            if relative == Path::new(SKILL_ARCHIVE_META_FILE) {
+                meta = Some(serde_json::from_slice(&content).map_err(|e| {
+                    RhinolabsError::ConfigError(format!(
+                        "Invalid {}: {}",
+                        SKILL_ARCHIVE_META_FILE, e
+                    ))
+                })?);
+                continue;
+            }
+
+            let target = dest.join(&relative);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            crate::fs_utils::write(target, content)?;
+        }
+
+        Ok(meta)
     }
 
     /// Update an existing skill
@@ -528,7 +1675,7 @@ impl Skills {
         }
 
         let config = Self::load_config()?;
-        let mut skill = Self::load_from_dir(&skill_dir, &config)?;
+        let mut skill = Self::load_from_dir(&skill_dir, &config, &BuiltinCategories::load())?;
 
         // Update fields
         if let Some(name) = input.name {
@@ -542,10 +1689,17 @@ impl Skills {
         }
 
         // Write updated SKILL.md
-        let file_content =
-            Self::generate_skill_file(&skill.name, &skill.description, &skill.content);
+        let frontmatter = SkillFrontmatter {
+            name: skill.name,
+            description: skill.description,
+            version: skill.version,
+            author: skill.author,
+            license: skill.license,
+            tags: skill.tags,
+        };
+        let file_content = Self::generate_skill_file(&frontmatter, &skill.content)?;
         let skill_file = skill_dir.join("SKILL.md");
-        fs::write(&skill_file, &file_content)?;
+        crate::fs_utils::write(&skill_file, &file_content)?;
 
         // Handle enabled toggle
         if let Some(enabled) = input.enabled {
@@ -560,6 +1714,11 @@ impl Skills {
         Ok(())
     }
 
+    /// Ids of skills that are globally disabled
+    pub fn disabled_ids() -> Result<Vec<String>> {
+        Ok(Self::load_config()?.disabled)
+    }
+
     /// Toggle skill enabled state
     pub fn toggle(id: &str, enabled: bool) -> Result<()> {
         let skill_dir = Self::skills_dir()?.join(id);
@@ -582,6 +1741,68 @@ impl Skills {
         Self::save_config(&config)
     }
 
+    /// Ids of skills disabled for `target` specifically (not counting globally
+    /// disabled ones)
+    pub fn disabled_for_target(target: DeployTarget) -> Result<Vec<String>> {
+        let config = Self::load_config()?;
+        let mut ids: Vec<String> = config
+            .disabled_by_target
+            .keys()
+            .filter(|id| config.is_disabled_for(id, target))
+            .cloned()
+            .collect();
+        ids.sort();
+        Ok(ids)
+    }
+
+    /// Whether a skill is enabled for `target`: a globally disabled skill is off
+    /// for every target, whatever its per-target setting
+    pub fn is_enabled_for_target(id: &str, target: DeployTarget) -> Result<bool> {
+        Ok(Self::load_config()?.deploys_to(id, target, true))
+    }
+
+    /// The subset of `ids` an install deploys to `target`, by the same rule as
+    /// [`Self::is_enabled_for_target`]. Without `only_enabled` the global
+    /// setting is ignored, since installs deploy disabled skills by default.
+    pub(crate) fn deployed_to_target(
+        ids: &[String],
+        target: DeployTarget,
+        only_enabled: bool,
+    ) -> Result<Vec<String>> {
+        let config = Self::load_config()?;
+        Ok(ids
+            .iter()
+            .filter(|id| config.deploys_to(id, target, only_enabled))
+            .cloned()
+            .collect())
+    }
+
+    /// Enable or disable a skill for one deploy target only
+    pub fn toggle_for_target(id: &str, target: DeployTarget, enabled: bool) -> Result<()> {
+        let skill_dir = Self::skills_dir()?.join(id);
+
+        if !skill_dir.exists() {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Skill '{}' not found",
+                id
+            )));
+        }
+
+        let mut config = Self::load_config()?;
+        let targets = config.disabled_by_target.entry(id.to_string()).or_default();
+
+        if enabled {
+            targets.retain(|t| t != target.id());
+        } else if !targets.iter().any(|t| t == target.id()) {
+            targets.push(target.id().to_string());
+        }
+        if targets.is_empty() {
+            config.disabled_by_target.remove(id);
+        }
+
+        Self::save_config(&config)
+    }
+
     /// Delete a custom skill
     pub fn delete(id: &str) -> Result<()> {
         let config = Self::load_config()?;
@@ -617,13 +1838,93 @@ impl Skills {
         let mut config = Self::load_config()?;
         config.custom.retain(|s| s != id);
         config.disabled.retain(|s| s != id);
+        config.disabled_by_target.remove(id);
         config.skill_meta.remove(id);
         config.category_map.remove(id);
         Self::save_config(&config)?;
 
+        let original = Self::originals_dir()?.join(format!("{}.md", id));
+        if original.exists() {
+            fs::remove_file(original)?;
+        }
+
         Ok(())
     }
 
+    /// Rename a custom or source-installed skill, carrying over its config entries
+    /// (enabled state, source metadata, category) and updating profiles that use it.
+    pub fn rename(old_id: &str, new_id: &str) -> Result<Skill> {
+        if new_id.is_empty()
+            || !new_id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Invalid skill id '{}': use lowercase letters, digits and dashes",
+                new_id
+            )));
+        }
+
+        let skills_dir = Self::skills_dir()?;
+        let old_dir = skills_dir.join(old_id);
+        let new_dir = skills_dir.join(new_id);
+        if !old_dir.exists() {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Skill '{}' not found",
+                old_id
+            )));
+        }
+        if new_dir.exists() {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Skill '{}' already exists",
+                new_id
+            )));
+        }
+
+        let mut config = Self::load_config()?;
+        let is_custom = config.custom.iter().any(|s| s == old_id);
+        let has_source = config
+            .skill_meta
+            .get(old_id)
+            .is_some_and(|m| m.source_id.is_some());
+        if !is_custom && !has_source {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Cannot rename built-in skill '{}'",
+                old_id
+            )));
+        }
+
+        fs::rename(&old_dir, &new_dir)?;
+
+        for ids in [&mut config.custom, &mut config.disabled] {
+            for id in ids.iter_mut().filter(|id| *id == old_id) {
+                *id = new_id.to_string();
+            }
+        }
+        if let Some(targets) = config.disabled_by_target.remove(old_id) {
+            config
+                .disabled_by_target
+                .insert(new_id.to_string(), targets);
+        }
+        if let Some(meta) = config.skill_meta.remove(old_id) {
+            config.skill_meta.insert(new_id.to_string(), meta);
+        }
+        if let Some(category) = config.category_map.remove(old_id) {
+            config.category_map.insert(new_id.to_string(), category);
+        }
+        Self::save_config(&config)?;
+
+        let originals = Self::originals_dir()?;
+        let original = originals.join(format!("{}.md", old_id));
+        if original.exists() {
+            fs::rename(original, originals.join(format!("{}.md", new_id)))?;
+        }
+
+        Profiles::rename_skill_references(old_id, new_id)?;
+
+        Self::load_from_dir(&new_dir, &config, &BuiltinCategories::load())
+    }
+
     // ============================================
     // Profile-based Skill Queries
     // ============================================
@@ -650,6 +1951,29 @@ impl Skills {
         Profiles::get_profiles_for_skill(skill_id)
     }
 
+    /// Get the auto-invoke rules for a skill, as `(profile_id, rule)` pairs,
+    /// from every profile that includes it
+    pub fn get_invocation_contexts(skill_id: &str) -> Result<Vec<(String, AutoInvokeRule)>> {
+        let profiles = Profiles::get_profiles_for_skill(skill_id)?;
+        Ok(Self::invocation_contexts_from(&profiles, skill_id))
+    }
+
+    fn invocation_contexts_from(
+        profiles: &[Profile],
+        skill_id: &str,
+    ) -> Vec<(String, AutoInvokeRule)> {
+        profiles
+            .iter()
+            .flat_map(|profile| {
+                profile
+                    .auto_invoke_rules
+                    .iter()
+                    .filter(|rule| rule.skill_id == skill_id)
+                    .map(|rule| (profile.id.clone(), rule.clone()))
+            })
+            .collect()
+    }
+
     // ============================================
     // Source Management
     // ============================================
@@ -666,34 +1990,125 @@ impl Skills {
         // Merge default source values with saved config
         // This ensures that default sources have correct fetchable/schema values
         // even if they were saved before those fields were added
-        let default_sources = SkillSource::default_sources();
-        let sources: Vec<SkillSource> = config
-            .sources
+        Ok(Self::normalize_sources(config.sources))
+    }
+
+    /// Check that `.skills-config.json` loads. A missing file is fine.
+    pub fn config_problem() -> Result<Option<SkillsConfigProblem>> {
+        let path = Self::config_path()?;
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path)?;
+        if let Err(e) = serde_json::from_str::<SkillsConfig>(&content) {
+            return Ok(Some(SkillsConfigProblem::Unreadable(e.to_string())));
+        }
+
+        let raw: serde_json::Value = serde_json::from_str(&content)?;
+        let mut unknown: Vec<(String, String)> = raw
+            .get("categoryMap")
+            .and_then(serde_json::Value::as_object)
             .into_iter()
-            .map(|mut s| {
-                // For known default sources, ensure fetchable and schema are set correctly
-                if let Some(default) = default_sources.iter().find(|d| d.id == s.id) {
-                    // Only override if the source hasn't been explicitly set to fetchable
-                    // We check if it's false (the old default) and the default is true
-                    if !s.fetchable && default.fetchable {
-                        s.fetchable = default.fetchable;
-                    }
-                }
-                s
+            .flatten()
+            .filter(|(_, value)| serde_json::from_value::<SkillCategory>((*value).clone()).is_err())
+            .map(|(id, value)| {
+                let value = value.as_str().map_or(value.to_string(), str::to_string);
+                (id.clone(), value)
             })
             .collect();
+        if unknown.is_empty() {
+            return Ok(None);
+        }
+        unknown.sort();
+        Ok(Some(SkillsConfigProblem::UnknownCategories(unknown)))
+    }
 
-        Ok(sources)
+    /// Repair `.skills-config.json`: an unreadable file is backed up and replaced by
+    /// an empty config (returning the backup's path); otherwise the config is
+    /// rewritten, which drops unknown `categoryMap` entries.
+    pub fn repair_config() -> Result<Option<PathBuf>> {
+        match Self::config_problem()? {
+            Some(SkillsConfigProblem::Unreadable(_)) => {
+                let content = serde_json::to_string_pretty(&SkillsConfig::default())?;
+                crate::paths::atomic_write(&Self::config_path()?, content, true)
+            }
+            Some(SkillsConfigProblem::UnknownCategories(_)) => {
+                Self::save_config(&Self::load_config()?)?;
+                Ok(None)
+            }
+            None => Ok(None),
+        }
     }
 
-    /// Add a new skill source
-    pub fn add_source(source: SkillSource) -> Result<()> {
-        let mut config = Self::load_config()?;
+    /// Ids of sources that appear more than once in the saved config
+    pub fn duplicate_source_ids() -> Result<Vec<String>> {
+        let config = Self::load_config()?;
+
+        let mut seen = std::collections::HashSet::new();
+        let mut duplicates = Vec::new();
+        for source in &config.sources {
+            if !seen.insert(source.id.as_str()) && !duplicates.contains(&source.id) {
+                duplicates.push(source.id.clone());
+            }
+        }
+
+        Ok(duplicates)
+    }
+
+    /// Collapse duplicate sources in the saved config and restore the fixed fields
+    /// of default sources. Returns the number of entries removed.
+    pub fn repair_sources() -> Result<usize> {
+        let mut config = Self::load_config()?;
+        let before = config.sources.len();
+
+        let normalized = Self::normalize_sources(config.sources.clone());
+        let removed = before - normalized.len();
+        if normalized != config.sources {
+            config.sources = normalized;
+            Self::save_config(&config)?;
+        }
+
+        Ok(removed)
+    }
+
+    /// Dedup sources by id and re-assert the immutable fields of default sources.
+    ///
+    /// The last entry for an id wins (it holds the newest user edits) but keeps the
+    /// position of the first one. For default ids, `source_type`, `fetchable` and
+    /// `schema` always come from `SkillSource::default_sources()`.
+    fn normalize_sources(sources: Vec<SkillSource>) -> Vec<SkillSource> {
+        let default_sources = SkillSource::default_sources();
+        let mut normalized: Vec<SkillSource> = Vec::with_capacity(sources.len());
+
+        for mut source in sources {
+            if let Some(default) = default_sources.iter().find(|d| d.id == source.id) {
+                source.source_type = default.source_type.clone();
+                source.fetchable = default.fetchable;
+                source.schema = default.schema.clone();
+            }
+
+            match normalized.iter_mut().find(|s| s.id == source.id) {
+                Some(existing) => *existing = source,
+                None => normalized.push(source),
+            }
+        }
+
+        normalized
+    }
+
+    /// Add a new skill source.
+    ///
+    /// With `validate`, a fetchable source is probed first (see [`Self::validate_source`])
+    /// and only added if it answers, with its skill count recorded.
+    pub async fn add_source(mut source: SkillSource, validate: bool) -> Result<()> {
+        let mut config = Self::load_config()?;
 
         // Initialize with defaults if empty
         if config.sources.is_empty() {
             config.sources = SkillSource::default_sources();
         }
+        config.sources = Self::normalize_sources(config.sources);
 
         // Check for duplicate id
         if config.sources.iter().any(|s| s.id == source.id) {
@@ -702,20 +2117,23 @@ impl Skills {
                 source.id
             )));
         }
+        if let Some(var) = &source.auth_token_env {
+            validate_token_env(var)?;
+        }
+        if validate && source.fetchable {
+            source.skill_count = Some(Self::validate_source(&source).await?);
+        }
 
         config.sources.push(source);
         Self::save_config(&config)
     }
 
-    /// Update an existing skill source
-    pub fn update_source(
+    /// Update an existing skill source. With `validate`, the updated source is probed
+    /// like in [`Self::add_source`] and nothing is saved if it can't be reached.
+    pub async fn update_source(
         id: &str,
-        enabled: Option<bool>,
-        name: Option<String>,
-        url: Option<String>,
-        description: Option<String>,
-        fetchable: Option<bool>,
-        schema: Option<SkillSchema>,
+        input: UpdateSkillSourceInput,
+        validate: bool,
     ) -> Result<()> {
         let mut config = Self::load_config()?;
 
@@ -723,6 +2141,7 @@ impl Skills {
         if config.sources.is_empty() {
             config.sources = SkillSource::default_sources();
         }
+        config.sources = Self::normalize_sources(config.sources);
 
         let source = config
             .sources
@@ -730,24 +2149,42 @@ impl Skills {
             .find(|s| s.id == id)
             .ok_or_else(|| RhinolabsError::ConfigError(format!("Source '{}' not found", id)))?;
 
-        if let Some(e) = enabled {
+        if let Some(e) = input.enabled {
             source.enabled = e;
         }
-        if let Some(n) = name {
+        if let Some(n) = input.name {
             source.name = n;
         }
-        if let Some(u) = url {
+        if let Some(u) = input.url {
             source.url = u;
         }
-        if let Some(d) = description {
+        if let Some(d) = input.description {
             source.description = d;
         }
-        if let Some(f) = fetchable {
+        if let Some(f) = input.fetchable {
             source.fetchable = f;
         }
-        if let Some(s) = schema {
+        if let Some(s) = input.schema {
             source.schema = s;
         }
+        if let Some(b) = input.branch {
+            let b = b.trim();
+            source.branch = (!b.is_empty()).then(|| b.to_string());
+        }
+        if let Some(path) = input.skills_path {
+            let path = path.trim();
+            source.skills_path = (path != DEFAULT_SKILLS_PATH).then(|| path.to_string());
+        }
+        if let Some(var) = input.auth_token_env {
+            let var = var.trim();
+            if !var.is_empty() {
+                validate_token_env(var)?;
+            }
+            source.auth_token_env = (!var.is_empty()).then(|| var.to_string());
+        }
+        if validate && source.fetchable {
+            source.skill_count = Some(Self::validate_source(source).await?);
+        }
 
         Self::save_config(&config)
     }
@@ -769,13 +2206,17 @@ impl Skills {
         Self::save_config(&config)
     }
 
-    /// Install a skill from a source (downloads and saves locally)
+    /// Install a skill from a source (downloads and saves locally).
+    /// With `assign_to_profile`, the skill is also appended to that profile;
+    /// the main (user) profile requires `allow_main`.
     pub fn install_from_source(
         skill_id: &str,
         skill_content: &str,
         source_id: &str,
         source_name: &str,
-    ) -> Result<Skill> {
+        assign_to_profile: Option<&str>,
+        allow_main: bool,
+    ) -> Result<SkillInstallResult> {
         let skill_dir = Self::skills_dir()?.join(skill_id);
 
         if skill_dir.exists() {
@@ -785,14 +2226,19 @@ impl Skills {
             )));
         }
 
+        if let Some(profile_id) = assign_to_profile {
+            Profiles::check_skill_assignment(profile_id, allow_main)?;
+        }
+
         // Create skill directory
         fs::create_dir_all(&skill_dir)?;
 
         // Write SKILL.md
         let skill_file = skill_dir.join("SKILL.md");
-        fs::write(&skill_file, skill_content)?;
+        crate::fs_utils::write(&skill_file, skill_content)?;
 
         // Update config with source metadata
+        Self::save_original(skill_id, skill_content)?;
         let mut config = Self::load_config()?;
         let content_hash = Self::hash_content(skill_content);
 
@@ -809,16 +2255,155 @@ impl Skills {
 
         // Return the installed skill
         let config = Self::load_config()?;
-        Self::load_from_dir(&skill_dir, &config)
+        let skill = Self::load_from_dir(&skill_dir, &config, &BuiltinCategories::load())?;
+        Self::assign_installed(skill, assign_to_profile, allow_main)
     }
 
-    /// Install a skill from a remote source, downloading all files
+    /// Install a skill from a remote source, downloading all files from `branch`
+    /// (default `main`, falling back to `master`). The skill is looked up under the
+    /// skills path configured on the source with id `source_id`, if there is one.
+    /// `assign_to_profile` and `allow_main` behave as in `install_from_source`.
     pub async fn install_from_remote(
         source_url: &str,
         skill_id: &str,
         source_id: &str,
         source_name: &str,
-    ) -> Result<Skill> {
+        branch: Option<&str>,
+        assign_to_profile: Option<&str>,
+        allow_main: bool,
+    ) -> Result<SkillInstallResult> {
+        // Get list of files, from the skills directory and with the token the
+        // source is configured with
+        let source = Self::list_sources()?
+            .into_iter()
+            .find(|s| s.id == source_id);
+        let location = RemoteSkillLocation {
+            api_base: "https://api.github.com",
+            repo_url: source_url,
+            skill_id,
+            branch: branch.unwrap_or(DEFAULT_BRANCH),
+            skills_path: source
+                .as_ref()
+                .map_or(DEFAULT_SKILLS_PATH, |s| s.skills_path()),
+            token: match &source {
+                Some(source) => source.auth_token()?,
+                None => Self::github_token(),
+            },
+            token_var: source
+                .as_ref()
+                .map_or(GLOBAL_TOKEN_VARS.to_string(), SkillSource::token_var),
+        };
+        let meta = SkillMeta {
+            source_id: Some(source_id.to_string()),
+            source_name: Some(source_name.to_string()),
+            original_hash: None,
+        };
+
+        Self::install_remote_files(&location, meta, assign_to_profile, allow_main).await
+    }
+
+    /// Install a skill from a GitHub link to its directory or its SKILL.md, e.g.
+    /// `https://github.com/owner/repo/tree/main/skills/foo` or
+    /// `https://github.com/owner/repo/blob/main/skills/foo/SKILL.md`.
+    ///
+    /// When a configured source points at the same repo and skills path, the skill
+    /// is recorded as installed from it (so update checks work) and the source's
+    /// token is used. A branch containing `/` is told apart from the path after it
+    /// by asking GitHub which prefix names a ref.
+    pub async fn install_from_github_url(url: &str) -> Result<SkillInstallResult> {
+        Self::install_from_github_url_at("https://api.github.com", url).await
+    }
+
+    /// `install_from_github_url` against an explicit GitHub API host
+    async fn install_from_github_url_at(api_base: &str, url: &str) -> Result<SkillInstallResult> {
+        let link = GitHubSkillUrl::parse(url)?;
+        let repo_url = format!("https://github.com/{}/{}", link.owner, link.repo);
+
+        let repo_sources: Vec<SkillSource> = Self::list_sources()?
+            .into_iter()
+            .filter(|s| {
+                s.schema == SkillSchema::Standard
+                    && s.url.trim_end_matches('/').eq_ignore_ascii_case(&repo_url)
+            })
+            .collect();
+        // Any source on the repo can see its refs; the skills path is known only after
+        let (token, token_var) = match repo_sources.first() {
+            Some(source) => (source.auth_token()?, source.token_var()),
+            None => (Self::github_token(), GLOBAL_TOKEN_VARS.to_string()),
+        };
+        let parsed =
+            Self::resolve_github_ref(api_base, &link, token.as_deref(), &token_var).await?;
+
+        let source = repo_sources
+            .into_iter()
+            .find(|s| s.skills_path() == parsed.skills_path);
+        let (token, token_var) = match &source {
+            Some(source) => (source.auth_token()?, source.token_var()),
+            None => (token, token_var),
+        };
+        let meta = SkillMeta {
+            source_id: source.as_ref().map(|s| s.id.clone()),
+            source_name: Some(
+                source.map_or_else(|| format!("{}/{}", parsed.owner, parsed.repo), |s| s.name),
+            ),
+            original_hash: None,
+        };
+        let location = RemoteSkillLocation {
+            api_base,
+            repo_url: &repo_url,
+            skill_id: &parsed.skill_id,
+            branch: &parsed.branch,
+            skills_path: &parsed.skills_path,
+            token,
+            token_var,
+        };
+
+        Self::install_remote_files(&location, meta, None, false).await
+    }
+
+    /// Split a link's ref from the skill path after it. Each prefix of the path
+    /// segments is tried as a ref, shortest first; a link whose ref can't be found
+    /// keeps its first segment as the branch so the install reports what is missing.
+    async fn resolve_github_ref(
+        api_base: &str,
+        link: &GitHubSkillUrl,
+        token: Option<&str>,
+        token_var: &str,
+    ) -> Result<GitHubSkillUrl> {
+        if link.ref_path.len() < 2 {
+            return Ok(link.clone());
+        }
+
+        let client = crate::network::client()?;
+        for ref_len in 1..=link.ref_path.len() {
+            let candidate = link.ref_path[..ref_len].join("/");
+            let url = format!(
+                "{}/repos/{}/{}/commits/{}",
+                api_base, link.owner, link.repo, candidate
+            );
+            let response = crate::network::send_with_retry(Self::github_get(&client, &url, token))
+                .await
+                .map_err(crate::network::request_error)?;
+            if response.status().is_success() {
+                return Ok(link.with_ref_len(ref_len));
+            }
+            if let Some(err) = Self::github_status_error(&response, token.map(|_| token_var)) {
+                return Err(err);
+            }
+        }
+
+        Ok(link.clone())
+    }
+
+    /// Download a remote skill into the skills directory and record `meta` for it
+    /// (its `original_hash` is filled in from the downloaded SKILL.md)
+    async fn install_remote_files(
+        location: &RemoteSkillLocation<'_>,
+        mut meta: SkillMeta,
+        assign_to_profile: Option<&str>,
+        allow_main: bool,
+    ) -> Result<SkillInstallResult> {
+        let skill_id = location.skill_id;
         let skill_dir = Self::skills_dir()?.join(skill_id);
 
         if skill_dir.exists() {
@@ -828,24 +2413,69 @@ impl Skills {
             )));
         }
 
-        // Get list of files
-        let files = Self::fetch_remote_skill_files(source_url, skill_id).await?;
+        if let Some(profile_id) = assign_to_profile {
+            Profiles::check_skill_assignment(profile_id, allow_main)?;
+        }
+
+        let (files, used_branch) = Self::fetch_github_skill_files_at(
+            location.api_base,
+            location.repo_url,
+            skill_id,
+            location.branch,
+            location.skills_path,
+            location.token.as_deref(),
+            &location.token_var,
+        )
+        .await?;
+
+        let skill_md_content =
+            Self::download_skill_files(&skill_dir, &files, &location.token, &location.token_var)
+                .await?;
+
+        // Update config with source metadata
+        Self::save_original(skill_id, &skill_md_content)?;
+        let mut config = Self::load_config()?;
+        meta.original_hash = Some(Self::hash_content(&skill_md_content));
+        config.skill_meta.insert(skill_id.to_string(), meta);
 
+        Self::save_config(&config)?;
+
+        // Return the installed skill
+        let config = Self::load_config()?;
+        let skill = Self::load_from_dir(&skill_dir, &config, &BuiltinCategories::load())?;
+        let mut result = Self::assign_installed(skill, assign_to_profile, allow_main)?;
+        result.branch = Some(used_branch);
+        Ok(result)
+    }
+
+    /// Download `files` into `skill_dir`, returning the SKILL.md content
+    async fn download_skill_files(
+        skill_dir: &Path,
+        files: &[RemoteSkillFile],
+        token: &Option<String>,
+        token_var: &str,
+    ) -> Result<String> {
         // Create skill directory
-        fs::create_dir_all(&skill_dir)?;
+        fs::create_dir_all(skill_dir)?;
 
-        let client = reqwest::Client::new();
+        let client = crate::network::client()?;
         let mut skill_md_content = String::new();
 
         // Download and save each file
-        for file in &files {
+        for file in files {
             if file.is_directory {
                 // Create subdirectory
                 let dir_path = skill_dir.join(&file.relative_path);
                 fs::create_dir_all(&dir_path)?;
             } else if let Some(url) = &file.download_url {
                 // Download file content
-                let content = Self::fetch_skill_content(&client, url).await?;
+                let content = Self::fetch_skill_content(
+                    &client,
+                    url,
+                    Self::token_for_url(url, token),
+                    token_var,
+                )
+                .await?;
 
                 // Save to local path
                 let file_path = skill_dir.join(&file.relative_path);
@@ -855,7 +2485,7 @@ impl Skills {
                     fs::create_dir_all(parent)?;
                 }
 
-                fs::write(&file_path, &content)?;
+                crate::fs_utils::write(&file_path, &content)?;
 
                 // Keep track of SKILL.md content for hash
                 if file.name == "SKILL.md" {
@@ -864,24 +2494,151 @@ impl Skills {
             }
         }
 
-        // Update config with source metadata
-        let mut config = Self::load_config()?;
-        let content_hash = Self::hash_content(&skill_md_content);
+        Ok(skill_md_content)
+    }
 
-        config.skill_meta.insert(
-            skill_id.to_string(),
-            SkillMeta {
-                source_id: Some(source_id.to_string()),
-                source_name: Some(source_name.to_string()),
-                original_hash: Some(content_hash),
-            },
-        );
+    /// Re-download a source-installed skill from its source, replacing the local
+    /// copy (and any local modifications) and recording the new original.
+    pub async fn reset_from_source(skill_id: &str) -> Result<Skill> {
+        Self::reset_from_source_at("https://api.github.com", skill_id).await
+    }
 
-        Self::save_config(&config)?;
+    /// `reset_from_source` against an explicit GitHub API host
+    async fn reset_from_source_at(api_base: &str, skill_id: &str) -> Result<Skill> {
+        let skill_dir = Self::skills_dir()?.join(skill_id);
+        if !skill_dir.exists() {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Skill '{}' not found",
+                skill_id
+            )));
+        }
 
-        // Return the installed skill
         let config = Self::load_config()?;
-        Self::load_from_dir(&skill_dir, &config)
+        let Some(source_id) = config
+            .skill_meta
+            .get(skill_id)
+            .and_then(|m| m.source_id.clone())
+        else {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Skill '{}' was not installed from a source and cannot be reset",
+                skill_id
+            )));
+        };
+        let source = Self::list_sources()?
+            .into_iter()
+            .find(|s| s.id == source_id)
+            .ok_or_else(|| {
+                RhinolabsError::ConfigError(format!(
+                    "Source '{}' of skill '{}' no longer exists",
+                    source_id, skill_id
+                ))
+            })?;
+
+        let token = source.auth_token()?;
+        let (files, _) = Self::fetch_github_skill_files_at(
+            api_base,
+            &source.url,
+            skill_id,
+            source.branch(),
+            source.skills_path(),
+            token.as_deref(),
+            &source.token_var(),
+        )
+        .await?;
+
+        // Download next to the skills dir first so a failed fetch leaves the skill intact
+        let plugin_dir = Paths::plugin_dir()?;
+        let staging = tempfile::TempDir::new_in(&plugin_dir)?;
+        let skill_md_content =
+            Self::download_skill_files(staging.path(), &files, &token, &source.token_var()).await?;
+
+        // Move the old copy aside (deleted when `backup` drops) and put it back if the
+        // new one can't take its place
+        let backup = tempfile::TempDir::new_in(&plugin_dir)?;
+        let old = backup.path().join(skill_id);
+        fs::rename(&skill_dir, &old)?;
+        if let Err(e) = fs::rename(staging.path(), &skill_dir) {
+            fs::rename(&old, &skill_dir)?;
+            return Err(e.into());
+        }
+
+        Self::save_original(skill_id, &skill_md_content)?;
+        let mut config = Self::load_config()?;
+        if let Some(meta) = config.skill_meta.get_mut(skill_id) {
+            meta.original_hash = Some(Self::hash_content(&skill_md_content));
+        }
+        Self::save_config(&config)?;
+
+        Self::load_from_dir(&skill_dir, &config, &BuiltinCategories::load())
+    }
+
+    /// Install several remote skills, continuing past failures.
+    /// Skills that are already installed are skipped rather than reported as errors.
+    pub async fn install_many(specs: Vec<InstallSkillInput>) -> Result<BulkSkillInstallResult> {
+        let sources = Self::list_sources()?;
+        let skills_dir = Self::skills_dir()?;
+        let mut result = BulkSkillInstallResult::default();
+
+        for spec in specs {
+            if skills_dir.join(&spec.skill_id).exists() {
+                result.skills_skipped.push(spec.skill_id);
+                continue;
+            }
+
+            let installed = match sources.iter().find(|s| s.id == spec.source_id) {
+                Some(source) if source.schema == SkillSchema::Standard => {
+                    Self::install_from_remote(
+                        &source.url,
+                        &spec.skill_id,
+                        &source.id,
+                        &source.name,
+                        source.branch.as_deref(),
+                        None,
+                        false,
+                    )
+                    .await
+                }
+                Some(source) => Err(RhinolabsError::ConfigError(format!(
+                    "Source '{}' does not support installing skills by id",
+                    source.id
+                ))),
+                None => Err(RhinolabsError::ConfigError(format!(
+                    "Source '{}' not found",
+                    spec.source_id
+                ))),
+            };
+
+            match installed {
+                Ok(_) => result.skills_installed.push(spec.skill_id),
+                Err(e) => result.skills_failed.push(SkillInstallError {
+                    skill_id: spec.skill_id,
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Add a freshly installed skill to `assign_to_profile`, if given
+    fn assign_installed(
+        skill: Skill,
+        assign_to_profile: Option<&str>,
+        allow_main: bool,
+    ) -> Result<SkillInstallResult> {
+        let assigned_profile = match assign_to_profile {
+            Some(profile_id) => {
+                Profiles::add_skill(profile_id, &skill.id, allow_main)?;
+                Some(profile_id.to_string())
+            }
+            None => None,
+        };
+
+        Ok(SkillInstallResult {
+            skill,
+            assigned_profile,
+            branch: None,
+        })
     }
 
     /// Reset a modified skill to its original content
@@ -896,12 +2653,13 @@ impl Skills {
         }
 
         let skill_file = skill_dir.join("SKILL.md");
-        fs::write(&skill_file, original_content)?;
+        crate::fs_utils::write(&skill_file, original_content)?;
 
         // Update hash in meta
         let mut config = Self::load_config()?;
         if let Some(meta) = config.skill_meta.get_mut(id) {
             meta.original_hash = Some(Self::hash_content(original_content));
+            Self::save_original(id, original_content)?;
         }
 
         Self::save_config(&config)
@@ -934,7 +2692,11 @@ impl Skills {
     /// Get the category for a skill
     pub fn get_skill_category(skill_id: &str) -> Result<SkillCategory> {
         let config = Self::load_config()?;
-        Ok(Self::get_category(skill_id, &config))
+        Ok(Self::get_category(
+            skill_id,
+            &config,
+            &BuiltinCategories::load(),
+        ))
     }
 
     /// Get list of installed skill IDs for checking installation status
@@ -960,138 +2722,890 @@ impl Skills {
         Ok(ids)
     }
 
-    /// Fetch skills from a source, choosing the appropriate method based on schema
-    pub async fn fetch_from_source(source: &SkillSource) -> Result<Vec<RemoteSkill>> {
-        match source.schema {
-            SkillSchema::Standard => Self::fetch_from_github(source).await,
-            SkillSchema::SkillsSh => Self::fetch_from_skills_sh(source).await,
-            SkillSchema::Custom => Err(RhinolabsError::ConfigError(
-                "Custom schema sources cannot be fetched automatically".into(),
-            )),
-        }
+    /// GitHub token for skill fetching.
+    /// Priority: 1) `RHINOLABS_GITHUB_TOKEN`, 2) `GITHUB_TOKEN`, 3) `githubToken` in the skills config
+    pub fn github_token() -> Option<String> {
+        ["RHINOLABS_GITHUB_TOKEN", "GITHUB_TOKEN"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|token| !token.trim().is_empty())
+            .or_else(|| Self::load_config().ok()?.github_token)
     }
 
-    /// Fetch skills from a GitHub repository
-    /// Expects URL format: https://github.com/owner/repo
-    pub async fn fetch_from_github(source: &SkillSource) -> Result<Vec<RemoteSkill>> {
-        // Parse GitHub URL to get owner/repo
-        let url = &source.url;
-        let parts: Vec<&str> = url.trim_end_matches('/').split('/').collect();
+    /// Store (or clear, with `None`) the GitHub token in the skills config
+    pub fn set_github_token(token: Option<&str>) -> Result<()> {
+        let mut config = Self::load_config()?;
+        config.github_token = token
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(String::from);
+        Self::save_config(&config)
+    }
 
-        if parts.len() < 2 {
-            return Err(RhinolabsError::ConfigError(
-                "Invalid GitHub URL format".into(),
-            ));
+    /// Only hand the token to GitHub hosts, never to arbitrary download URLs
+    fn token_for_url<'a>(url: &str, token: &'a Option<String>) -> Option<&'a str> {
+        const GITHUB_HOSTS: &[&str] = &[
+            "https://api.github.com/",
+            "https://raw.githubusercontent.com/",
+        ];
+        if GITHUB_HOSTS.iter().any(|host| url.starts_with(host)) {
+            token.as_deref()
+        } else {
+            None
         }
+    }
 
-        let repo = parts[parts.len() - 1];
-        let owner = parts[parts.len() - 2];
+    /// GET request with the rhinolabs-ai User-Agent and, if given, a bearer token
+    fn github_get(
+        client: &reqwest::Client,
+        url: &str,
+        token: Option<&str>,
+    ) -> reqwest::RequestBuilder {
+        let request = client.get(url).header("User-Agent", "rhinolabs-ai");
+        match token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
 
-        // Fetch the skills directory contents from GitHub API
-        let api_url = format!(
-            "https://api.github.com/repos/{}/{}/contents/skills?ref=main",
-            owner, repo
+    /// Whether `branch` exists in `owner/repo`, so a missing file isn't mistaken for a
+    /// missing branch. Rate limits and auth failures are errors.
+    async fn branch_exists(client: &reqwest::Client, github: &GitHubRepo<'_>) -> Result<bool> {
+        let url = format!(
+            "{}/repos/{}/{}/branches/{}",
+            github.api_base, github.owner, github.repo, github.branch
         );
+        let response =
+            crate::network::send_with_retry(Self::github_get(client, &url, github.token))
+                .await
+                .map_err(crate::network::request_error)?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        if let Some(err) =
+            Self::github_status_error(&response, github.token.map(|_| github.token_var))
+        {
+            return Err(err);
+        }
+        Ok(true)
+    }
 
-        let client = reqwest::Client::new();
-        let response = client
-            .get(&api_url)
-            .header("User-Agent", "rhinolabs-ai")
-            .header("Accept", "application/vnd.github.v3+json")
-            .send()
-            .await
-            .map_err(|e| RhinolabsError::NetworkError(e.to_string()))?;
+    /// Explain a failed GitHub response when it is a rate limit or an auth failure.
+    /// `token_var` names where the request's token came from, if it had one.
+    /// Returns `None` for other errors so callers keep their own message.
+    fn github_status_error(
+        response: &reqwest::Response,
+        token_var: Option<&str>,
+    ) -> Option<RhinolabsError> {
+        let authenticated = token_var.is_some();
+        let status = response.status();
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return Err(RhinolabsError::NetworkError(format!(
-                "GitHub API error {}: {}",
-                status, body
+        let rate_limited = (status == reqwest::StatusCode::FORBIDDEN
+            || status == reqwest::StatusCode::TOO_MANY_REQUESTS)
+            && header("x-ratelimit-remaining").as_deref() == Some("0");
+
+        if rate_limited {
+            let resets = header("x-ratelimit-reset")
+                .and_then(|reset| reset.parse::<i64>().ok())
+                .and_then(|reset| chrono::DateTime::from_timestamp(reset, 0))
+                .map(|at| format!(" (resets at {})", at.format("%H:%M UTC")))
+                .unwrap_or_default();
+            let hint = if authenticated {
+                ""
+            } else {
+                ". Set GITHUB_TOKEN or RHINOLABS_GITHUB_TOKEN to raise the limit"
+            };
+            return Some(RhinolabsError::NetworkError(format!(
+                "GitHub API rate limit exceeded{}{}",
+                resets, hint
             )));
         }
 
-        let contents: Vec<GitHubContent> = response.json().await.map_err(|e| {
-            RhinolabsError::NetworkError(format!("Failed to parse GitHub response: {}", e))
-        })?;
+        if status == reqwest::StatusCode::UNAUTHORIZED
+            || (authenticated && status == reqwest::StatusCode::FORBIDDEN)
+        {
+            return Some(RhinolabsError::NetworkError(format!(
+                "GitHub authentication failed ({}). Check {}",
+                status,
+                token_var.unwrap_or(GLOBAL_TOKEN_VARS)
+            )));
+        }
 
-        // Get installed skill IDs
-        let installed = Self::installed_ids().unwrap_or_default();
+        None
+    }
 
-        let mut remote_skills = Vec::new();
+    /// Compare every source-installed skill against its source's current SKILL.md.
+    /// Skills whose source is gone or cannot be fetched are reported in `warnings`.
+    pub async fn check_updates(warnings: &mut Warnings) -> Result<Vec<SkillUpdate>> {
+        Self::check_updates_at(
+            "https://api.github.com",
+            "https://raw.githubusercontent.com",
+            warnings,
+        )
+        .await
+    }
 
-        // For each directory, try to fetch SKILL.md
-        for item in contents {
-            if item.content_type == "dir" {
-                let skill_url = format!(
-                    "https://raw.githubusercontent.com/{}/{}/main/skills/{}/SKILL.md",
-                    owner, repo, item.name
-                );
+    /// `check_updates` against explicit GitHub API and raw-content hosts
+    async fn check_updates_at(
+        api_base: &str,
+        raw_base: &str,
+        warnings: &mut Warnings,
+    ) -> Result<Vec<SkillUpdate>> {
+        let config = Self::load_config()?;
+        let sources = Self::list_sources()?;
+        let skills_dir = Self::skills_dir()?;
+        let client = crate::network::client()?;
 
-                match Self::fetch_skill_content(&client, &skill_url).await {
-                    Ok(skill_content) => match Self::parse_skill_file(&skill_content) {
-                        Ok((frontmatter, _)) => {
-                            remote_skills.push(RemoteSkill {
-                                id: item.name.clone(),
-                                name: frontmatter.name,
-                                description: frontmatter.description,
-                                category: "custom".to_string(),
-                                source_id: source.id.clone(),
-                                source_name: source.name.clone(),
-                                url: skill_url,
-                                stars: None,
-                                installed: installed.contains(&item.name),
-                            });
-                        }
-                        Err(e) => {
-                            eprintln!("[WARN] Failed to parse SKILL.md for '{}': {}", item.name, e);
-                        }
-                    },
-                    Err(e) => {
-                        eprintln!("[WARN] Failed to fetch SKILL.md for '{}': {}", item.name, e);
-                    }
+        let mut tracked: Vec<(&String, &SkillMeta)> = config.skill_meta.iter().collect();
+        tracked.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut updates = Vec::new();
+        for (skill_id, meta) in tracked {
+            let (Some(source_id), Some(original_hash)) = (&meta.source_id, &meta.original_hash)
+            else {
+                continue;
+            };
+            let Ok(local) = fs::read_to_string(skills_dir.join(skill_id).join("SKILL.md")) else {
+                continue;
+            };
+
+            let Some(source) = sources.iter().find(|s| &s.id == source_id) else {
+                warnings.push(format!(
+                    "Cannot check '{}': source '{}' no longer exists",
+                    skill_id, source_id
+                ));
+                continue;
+            };
+
+            let remote = match source.auth_token() {
+                Ok(token) => {
+                    Self::fetch_source_skill_md(
+                        &client,
+                        (api_base, raw_base),
+                        source,
+                        skill_id,
+                        &token,
+                    )
+                    .await
                 }
+                Err(e) => Err(e),
+            };
+            match remote {
+                Ok(remote) => updates.push(SkillUpdate {
+                    skill_id: skill_id.clone(),
+                    has_update: &Self::hash_content(&remote) != original_hash,
+                    is_locally_modified: &Self::hash_content(&local) != original_hash,
+                }),
+                Err(e) => warnings.push(format!("Cannot check '{}': {}", skill_id, e)),
             }
         }
 
-        Ok(remote_skills)
+        Ok(updates)
     }
 
-    /// Fetch skills from skills.sh by scraping the HTML
-    /// The site embeds JSON data in the HTML that we can extract
-    pub async fn fetch_from_skills_sh(source: &SkillSource) -> Result<Vec<RemoteSkill>> {
-        let client = reqwest::Client::new();
-
-        // Fetch the skills.sh page (use /hot for popular skills)
-        let url = if source.url.ends_with('/') {
-            format!("{}hot", source.url)
-        } else if source.url == "https://skills.sh" || source.url == "https://skills.sh/" {
-            "https://skills.sh/hot".to_string()
-        } else {
-            source.url.clone()
+    /// Fetch a skill's current SKILL.md from a GitHub skills source, trying the
+    /// source's branch and then, if that branch does not exist, the fallback branch.
+    /// Takes the GitHub API and raw-content hosts.
+    async fn fetch_source_skill_md(
+        client: &reqwest::Client,
+        (api_base, raw_base): (&str, &str),
+        source: &SkillSource,
+        skill_id: &str,
+        token: &Option<String>,
+    ) -> Result<String> {
+        if source.schema != SkillSchema::Standard {
+            return Err(RhinolabsError::Other(format!(
+                "source '{}' is not a GitHub skills repository",
+                source.name
+            )));
+        }
+        let parts: Vec<&str> = source.url.trim_end_matches('/').split('/').collect();
+        if parts.len() < 2 {
+            return Err(RhinolabsError::Other(format!(
+                "invalid GitHub URL '{}'",
+                source.url
+            )));
+        }
+        let (owner, repo) = (parts[parts.len() - 2], parts[parts.len() - 1]);
+
+        let token_var = source.token_var();
+        let configured = GitHubRepo {
+            api_base,
+            owner,
+            repo,
+            branch: source.branch(),
+            token: Self::token_for_url(&format!("{}/", api_base), token),
+            token_var: &token_var,
         };
+        let mut remote = Err(RhinolabsError::NetworkError(String::new()));
+        for branch in branch_candidates(source.branch()) {
+            if branch != source.branch() && Self::branch_exists(client, &configured).await? {
+                break;
+            }
+            for dir in skill_dir_candidates(source.skills_path(), repo, skill_id) {
+                let url = format!(
+                    "{}/{}/{}/{}/{}",
+                    raw_base,
+                    owner,
+                    repo,
+                    branch,
+                    repo_path(&dir, "SKILL.md")
+                );
+                remote = Self::fetch_skill_content(
+                    client,
+                    &url,
+                    Self::token_for_url(&url, token),
+                    &token_var,
+                )
+                .await;
+                if remote.is_ok() {
+                    return remote;
+                }
+            }
+        }
+        remote
+    }
 
-        let response = client
-            .get(&url)
-            .header("User-Agent", "rhinolabs-ai")
-            .send()
-            .await
-            .map_err(|e| RhinolabsError::NetworkError(e.to_string()))?;
+    /// Unified diff of a source-installed skill's SKILL.md against the content it
+    /// was installed with. Empty when the skill has not been modified.
+    ///
+    /// The original comes from the copy cached at install time. Without it, a skill
+    /// matching its recorded `original_hash` is unmodified; otherwise the skill is
+    /// compared against its source's current SKILL.md, with a warning when that is
+    /// no longer the original.
+    pub async fn diff(skill_id: &str, warnings: &mut Warnings) -> Result<String> {
+        Self::diff_at(
+            "https://api.github.com",
+            "https://raw.githubusercontent.com",
+            skill_id,
+            warnings,
+        )
+        .await
+    }
 
-        if !response.status().is_success() {
-            return Err(RhinolabsError::NetworkError(format!(
-                "Failed to fetch skills.sh: {}",
-                response.status()
+    /// `diff` against explicit GitHub API and raw-content hosts
+    async fn diff_at(
+        api_base: &str,
+        raw_base: &str,
+        skill_id: &str,
+        warnings: &mut Warnings,
+    ) -> Result<String> {
+        let skill_file = Self::skills_dir()?.join(skill_id).join("SKILL.md");
+        if !skill_file.exists() {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Skill '{}' not found",
+                skill_id
             )));
         }
 
-        let html = response
-            .text()
-            .await
-            .map_err(|e| RhinolabsError::NetworkError(e.to_string()))?;
+        let config = Self::load_config()?;
+        let Some((source_id, meta)) = config
+            .skill_meta
+            .get(skill_id)
+            .and_then(|m| m.source_id.as_ref().map(|source_id| (source_id, m)))
+        else {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Skill '{}' was not installed from a source, so there is no original to compare against",
+                skill_id
+            )));
+        };
 
-        // Extract JSON data from HTML - skills.sh embeds data in allTimeSkills array
-        let skills_json = Self::extract_skills_sh_data(&html)?;
+        let local = fs::read_to_string(&skill_file)?;
+        let original_hash = meta.original_hash.as_deref();
+        let original = match Self::cached_original(skill_id, original_hash) {
+            Some(original) => original,
+            None if original_hash == Some(Self::hash_content(&local).as_str()) => {
+                return Ok(String::new());
+            }
+            None => {
+                let sources = Self::list_sources()?;
+                let source = sources.iter().find(|s| &s.id == source_id).ok_or_else(|| {
+                    RhinolabsError::ConfigError(format!(
+                        "Source '{}' of skill '{}' no longer exists, so there is no original to compare against",
+                        source_id, skill_id
+                    ))
+                })?;
+                let client = crate::network::client()?;
+                let upstream = Self::fetch_source_skill_md(
+                    &client,
+                    (api_base, raw_base),
+                    source,
+                    skill_id,
+                    &source.auth_token()?,
+                )
+                .await?;
+                if original_hash != Some(Self::hash_content(&upstream).as_str()) {
+                    warnings.push(format!(
+                        "The original SKILL.md of '{}' is no longer available; comparing against the current version from '{}'",
+                        skill_id, source.name
+                    ));
+                }
+                upstream
+            }
+        };
+
+        Ok(similar::TextDiff::from_lines(&original, &local)
+            .unified_diff()
+            .header(
+                &format!("a/{}/SKILL.md", skill_id),
+                &format!("b/{}/SKILL.md", skill_id),
+            )
+            .to_string())
+    }
+
+    /// Directory holding the SKILL.md each source-installed skill was installed with
+    fn originals_dir() -> Result<PathBuf> {
+        Ok(Paths::plugin_dir()?.join(".skill-originals"))
+    }
+
+    /// Keep a copy of a skill's original SKILL.md for `diff`
+    fn save_original(skill_id: &str, content: &str) -> Result<()> {
+        let dir = Self::originals_dir()?;
+        fs::create_dir_all(&dir)?;
+        crate::fs_utils::write(dir.join(format!("{}.md", skill_id)), content)?;
+        Ok(())
+    }
+
+    /// The cached original of a skill, if it still matches the recorded hash
+    fn cached_original(skill_id: &str, original_hash: Option<&str>) -> Option<String> {
+        let path = Self::originals_dir().ok()?.join(format!("{}.md", skill_id));
+        let content = fs::read_to_string(path).ok()?;
+        (original_hash == Some(Self::hash_content(&content).as_str())).then_some(content)
+    }
+
+    /// Find lines matching the regex `pattern` in every installed skill's SKILL.md and,
+    /// with `include_files`, in its other text files (minus `.skillignore` entries).
+    /// Never touches the network. Matches are ordered by skill, file and line.
+    pub fn search_content(
+        pattern: &str,
+        case_insensitive: bool,
+        include_files: bool,
+    ) -> Result<Vec<SkillContentMatch>> {
+        let re = regex::RegexBuilder::new(pattern)
+            .case_insensitive(case_insensitive)
+            .build()
+            .map_err(|e| RhinolabsError::ConfigError(format!("Invalid pattern: {}", e)))?;
+
+        let mut skill_ids = Self::installed_ids()?;
+        skill_ids.sort();
+
+        let mut matches = Vec::new();
+        for skill_id in skill_ids {
+            let skill_dir = Self::skills_dir()?.join(&skill_id);
+            let mut files = vec![PathBuf::from("SKILL.md")];
+            if include_files {
+                let ignore = SkillIgnore::load(&skill_dir)?;
+                let walker = walkdir::WalkDir::new(&skill_dir)
+                    .min_depth(1)
+                    .sort_by_file_name()
+                    .into_iter()
+                    .filter_entry(|entry| {
+                        let relative = entry
+                            .path()
+                            .strip_prefix(&skill_dir)
+                            .unwrap_or(entry.path());
+                        !ignore.is_ignored(relative, entry.file_type().is_dir())
+                    });
+                files.extend(
+                    walker
+                        .filter_map(|entry| entry.ok())
+                        .filter(|entry| entry.file_type().is_file())
+                        .filter_map(|entry| {
+                            entry
+                                .path()
+                                .strip_prefix(&skill_dir)
+                                .ok()
+                                .map(Path::to_path_buf)
+                        })
+                        .filter(|relative| relative != Path::new("SKILL.md")),
+                );
+            }
+
+            for relative in files {
+                // Binary and unreadable files are skipped
+                let Ok(content) = fs::read_to_string(skill_dir.join(&relative)) else {
+                    continue;
+                };
+                let file = relative.to_string_lossy().replace('\\', "/");
+                for (index, line) in content.lines().enumerate() {
+                    if re.is_match(line) {
+                        matches.push(SkillContentMatch {
+                            skill_id: skill_id.clone(),
+                            file: file.clone(),
+                            line: index + 1,
+                            text: line.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(matches)
+    }
+
+    /// Search enabled, fetchable sources (or only `source_id`) for skills matching `query`.
+    /// A source that cannot be fetched is reported in `warnings` and the search goes on.
+    /// skills.sh sources are searched in the `listing` leaderboard; only the
+    /// default listing is cached.
+    pub async fn search(
+        query: &str,
+        source_id: Option<&str>,
+        listing: &SkillsShQuery,
+        warnings: &mut Warnings,
+    ) -> Result<Vec<RemoteSkill>> {
+        let sources = Self::list_sources()?;
+
+        let sources: Vec<SkillSource> = match source_id {
+            Some(id) => {
+                let source = sources.into_iter().find(|s| s.id == id).ok_or_else(|| {
+                    RhinolabsError::ConfigError(format!("Source '{}' not found", id))
+                })?;
+                if !source.fetchable {
+                    return Err(RhinolabsError::ConfigError(format!(
+                        "Source '{}' is browse-only and cannot be searched",
+                        id
+                    )));
+                }
+                vec![source]
+            }
+            None => sources
+                .into_iter()
+                .filter(|s| s.enabled && s.fetchable)
+                .collect(),
+        };
+
+        let mut results = Vec::new();
+        for source in &sources {
+            let fetched =
+                if source.schema == SkillSchema::SkillsSh && *listing != SkillsShQuery::default() {
+                    Self::fetch_from_skills_sh_with(source, listing).await
+                } else {
+                    Self::fetch_from_source(source, warnings).await
+                };
+            match fetched {
+                Ok(skills) => results.extend(skills.into_iter().filter(|s| s.matches(query))),
+                Err(e) => warnings.push(format!("Could not search '{}': {}", source.name, e)),
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Fetch skills from a source, choosing the appropriate method based on schema
+    /// Skills that could not be read are skipped and reported in `warnings`.
+    /// Listings are cached per source for `cache_ttl()`; use `invalidate_cache` to refetch.
+    pub async fn fetch_from_source(
+        source: &SkillSource,
+        warnings: &mut Warnings,
+    ) -> Result<Vec<RemoteSkill>> {
+        if source.schema == SkillSchema::Custom {
+            return Self::fetch_uncached(source, warnings).await;
+        }
+
+        if let Some(mut skills) = Self::cached_listing(source) {
+            let installed = Self::installed_ids().unwrap_or_default();
+            for skill in &mut skills {
+                skill.installed = installed.contains(&skill.id);
+            }
+            return Ok(skills);
+        }
+
+        let skills = Self::fetch_uncached(source, warnings).await?;
+        // Caching is best-effort; a failed write only costs a refetch next time
+        let _ = Self::store_listing(source, &skills);
+        Ok(skills)
+    }
+
+    async fn fetch_uncached(
+        source: &SkillSource,
+        warnings: &mut Warnings,
+    ) -> Result<Vec<RemoteSkill>> {
+        match source.schema {
+            SkillSchema::Standard => Self::fetch_from_github(source, warnings).await,
+            SkillSchema::SkillsSh => Self::fetch_from_skills_sh(source).await,
+            SkillSchema::Custom => Err(RhinolabsError::ConfigError(
+                "Custom schema sources cannot be fetched automatically".into(),
+            )),
+        }
+    }
+
+    /// Get the remote listing cache file path
+    fn cache_path() -> Result<PathBuf> {
+        Ok(Paths::plugin_dir()?.join(".skill-cache.json"))
+    }
+
+    /// Load the listing cache; a missing or corrupted file is an empty cache
+    fn load_cache() -> std::collections::HashMap<String, CachedListing> {
+        Self::cache_path()
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn save_cache(cache: &std::collections::HashMap<String, CachedListing>) -> Result<()> {
+        let content = serde_json::to_string_pretty(cache)?;
+        crate::fs_utils::write(Self::cache_path()?, content)?;
+        Ok(())
+    }
+
+    /// A source's cached listing, if it was fetched from the same URL and branch within the TTL
+    fn cached_listing(source: &SkillSource) -> Option<Vec<RemoteSkill>> {
+        let entry = Self::load_cache().remove(&source.id)?;
+        let age = chrono::Utc::now().signed_duration_since(entry.fetched_at);
+        let fresh = age.to_std().is_ok_and(|age| age < Self::cache_ttl());
+
+        (fresh && entry.url == source.url && entry.branch == source.branch())
+            .then_some(entry.skills)
+    }
+
+    fn store_listing(source: &SkillSource, skills: &[RemoteSkill]) -> Result<()> {
+        let mut cache = Self::load_cache();
+        cache.insert(
+            source.id.clone(),
+            CachedListing {
+                url: source.url.clone(),
+                branch: source.branch().to_string(),
+                fetched_at: chrono::Utc::now(),
+                skills: skills.to_vec(),
+            },
+        );
+        Self::save_cache(&cache)
+    }
+
+    /// Drop the cached listing for `source_id`, or every cached listing when `None`
+    pub fn invalidate_cache(source_id: Option<&str>) -> Result<()> {
+        let mut cache = Self::load_cache();
+        match source_id {
+            Some(id) => {
+                if cache.remove(id).is_none() {
+                    return Ok(());
+                }
+            }
+            None => cache.clear(),
+        }
+        Self::save_cache(&cache)
+    }
+
+    /// How long remote listings are served from the cache
+    pub fn cache_ttl() -> std::time::Duration {
+        let secs = Self::load_config()
+            .ok()
+            .and_then(|config| config.cache_ttl_secs)
+            .unwrap_or(DEFAULT_CACHE_TTL_SECS);
+        std::time::Duration::from_secs(secs)
+    }
+
+    /// Set the listing cache TTL in seconds (`None` restores the default, `0` disables caching)
+    pub fn set_cache_ttl(secs: Option<u64>) -> Result<()> {
+        let mut config = Self::load_config()?;
+        config.cache_ttl_secs = secs;
+        Self::save_config(&config)
+    }
+
+    /// Fetch skills from a GitHub repository
+    /// Expects URL format: https://github.com/owner/repo
+    pub async fn fetch_from_github(
+        source: &SkillSource,
+        warnings: &mut Warnings,
+    ) -> Result<Vec<RemoteSkill>> {
+        let token = source.auth_token()?;
+        Self::fetch_from_github_at(
+            source,
+            "https://api.github.com",
+            "https://raw.githubusercontent.com",
+            token.as_deref(),
+            warnings,
+        )
+        .await
+    }
+
+    /// `fetch_from_github` against explicit API and raw-content hosts
+    async fn fetch_from_github_at(
+        source: &SkillSource,
+        api_base: &str,
+        raw_base: &str,
+        token: Option<&str>,
+        warnings: &mut Warnings,
+    ) -> Result<Vec<RemoteSkill>> {
+        let client = crate::network::client()?;
+        let GitHubSkillListing {
+            owner,
+            repo,
+            branch,
+            skill_dirs,
+        } = Self::list_github_skill_dirs(&client, source, api_base, token, warnings).await?;
+
+        // Get installed skill IDs
+        let installed = Self::installed_ids().unwrap_or_default();
+
+        // Fetch each directory's SKILL.md concurrently, a few at a time,
+        // then sort so results are in a stable order
+        use futures::StreamExt;
+        let client = &client;
+        let token_var = &source.token_var();
+        let mut fetches: Vec<_> = futures::stream::iter(skill_dirs)
+            .map(|(name, dir)| async move {
+                let skill_url = format!(
+                    "{}/{}/{}/{}/{}",
+                    raw_base,
+                    owner,
+                    repo,
+                    branch,
+                    repo_path(&dir, "SKILL.md")
+                );
+                let content = Self::fetch_skill_content(client, &skill_url, token, token_var).await;
+                (name, skill_url, content)
+            })
+            .buffer_unordered(GITHUB_FETCH_CONCURRENCY)
+            .collect()
+            .await;
+        fetches.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut remote_skills = Vec::new();
+        for (name, skill_url, content) in fetches {
+            match content {
+                Ok(skill_content) => match Self::parse_skill_file(&skill_content) {
+                    Ok((frontmatter, _)) => {
+                        remote_skills.push(RemoteSkill {
+                            installed: installed.contains(&name),
+                            id: name,
+                            name: frontmatter.name,
+                            description: frontmatter.description,
+                            category: "custom".to_string(),
+                            source_id: source.id.clone(),
+                            source_name: source.name.clone(),
+                            url: skill_url,
+                            stars: None,
+                        });
+                    }
+                    Err(e) => {
+                        warnings.push(format!("Failed to parse SKILL.md for '{}': {}", name, e));
+                    }
+                },
+                Err(e) => {
+                    warnings.push(format!("Failed to fetch SKILL.md for '{}': {}", name, e));
+                }
+            }
+        }
+
+        Ok(remote_skills)
+    }
+
+    /// List the skill directories of a GitHub source as `(skill id, repo path)` pairs,
+    /// without fetching any SKILL.md
+    async fn list_github_skill_dirs<'a>(
+        client: &reqwest::Client,
+        source: &'a SkillSource,
+        api_base: &str,
+        token: Option<&str>,
+        warnings: &mut Warnings,
+    ) -> Result<GitHubSkillListing<'a>> {
+        // Parse GitHub URL to get owner/repo
+
+        let url = &source.url;
+        let parts: Vec<&str> = url.trim_end_matches('/').split('/').collect();
+
+        if parts.len() < 2 {
+            return Err(RhinolabsError::ConfigError(
+                "Invalid GitHub URL format".into(),
+            ));
+        }
+
+        let repo = parts[parts.len() - 1];
+        let owner = parts[parts.len() - 2];
+
+        // Fetch the skills directory contents from GitHub API,
+        // falling back to `master` if the configured branch does not exist
+        let skills_path = source.skills_path();
+        let list_skills = |branch: &str| {
+            let api_url = Self::contents_url(api_base, owner, repo, skills_path, branch);
+            crate::network::send_with_retry(
+                Self::github_get(client, &api_url, token)
+                    .header("Accept", "application/vnd.github.v3+json"),
+            )
+        };
+
+        let mut branch = source.branch();
+        let mut response = list_skills(branch)
+            .await
+            .map_err(crate::network::request_error)?;
+
+        let token_var = source.token_var();
+        if response.status() == reqwest::StatusCode::NOT_FOUND
+            && branch != FALLBACK_BRANCH
+            && !Self::branch_exists(
+                client,
+                &GitHubRepo {
+                    api_base,
+                    owner,
+                    repo,
+                    branch,
+                    token,
+                    token_var: &token_var,
+                },
+            )
+            .await?
+        {
+            branch = FALLBACK_BRANCH;
+            response = list_skills(branch)
+                .await
+                .map_err(crate::network::request_error)?;
+            if response.status().is_success() {
+                warnings.push(format!(
+                    "Branch '{}' not found in {}/{}; using '{}'",
+                    source.branch(),
+                    owner,
+                    repo,
+                    branch
+                ));
+            }
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            if status == reqwest::StatusCode::NOT_FOUND {
+                // GitHub answers 404 rather than 401 for private repos it won't show
+                let hint = if token.is_none() && source.auth_token_env.is_none() {
+                    ". If it is private, set the source's authTokenEnv to a variable holding a GitHub token".to_string()
+                } else {
+                    private_repo_hint(token.is_some(), &token_var)
+                };
+                return Err(RhinolabsError::NetworkError(format!(
+                    "'{}' not found in {}/{}{}",
+                    if skills_path.is_empty() {
+                        "/"
+                    } else {
+                        skills_path
+                    },
+                    owner,
+                    repo,
+                    hint
+                )));
+            }
+            if let Some(err) =
+                Self::github_status_error(&response, token.map(|_| token_var.as_str()))
+            {
+                return Err(err);
+            }
+            let body = response.text().await.unwrap_or_default();
+            return Err(RhinolabsError::NetworkError(format!(
+                "GitHub API error {}: {}",
+                status, body
+            )));
+        }
+
+        let contents: Vec<GitHubContent> = response.json().await.map_err(|e| {
+            RhinolabsError::NetworkError(format!("Failed to parse GitHub response: {}", e))
+        })?;
+
+        // A SKILL.md directly in the skills path makes it a single flat skill;
+        // otherwise every subdirectory is a skill
+        let is_flat = contents
+            .iter()
+            .any(|item| item.content_type == "file" && item.name == "SKILL.md");
+        let skill_dirs: Vec<(String, String)> = if is_flat {
+            vec![(
+                flat_skill_id(skills_path, repo).to_string(),
+                skills_path.to_string(),
+            )]
+        } else {
+            contents
+                .into_iter()
+                .filter(|item| item.content_type == "dir")
+                .map(|item| {
+                    let dir = repo_path(skills_path, &item.name);
+                    (item.name, dir)
+                })
+                .collect()
+        };
+
+        Ok(GitHubSkillListing {
+            owner,
+            repo,
+            branch,
+            skill_dirs,
+        })
+    }
+
+    /// Check that skills can be fetched from a source, returning how many it offers.
+    ///
+    /// Standard sources have their skills directory listed, without downloading any
+    /// SKILL.md; skills.sh sources must serve a page whose embedded skill data can be
+    /// extracted. Custom-schema sources are browse-only and can't be checked.
+    pub async fn validate_source(source: &SkillSource) -> Result<u32> {
+        Self::validate_source_at(source, "https://api.github.com").await
+    }
+
+    /// `validate_source` against an explicit GitHub API host
+    async fn validate_source_at(source: &SkillSource, api_base: &str) -> Result<u32> {
+        let count = match source.schema {
+            SkillSchema::Standard => {
+                let token = source.auth_token()?;
+                let client = crate::network::client()?;
+                let mut warnings = Warnings::new();
+                Self::list_github_skill_dirs(
+                    &client,
+                    source,
+                    api_base,
+                    token.as_deref(),
+                    &mut warnings,
+                )
+                .await?
+                .skill_dirs
+                .len()
+            }
+            SkillSchema::SkillsSh => Self::fetch_from_skills_sh(source).await?.len(),
+            SkillSchema::Custom => {
+                return Err(RhinolabsError::ConfigError(format!(
+                    "Source '{}' is browse-only; there is nothing to fetch from it",
+                    source.id
+                )))
+            }
+        };
+        Ok(count as u32)
+    }
+
+    /// Fetch the hot skills from skills.sh (see [`Self::fetch_from_skills_sh_with`])
+    pub async fn fetch_from_skills_sh(source: &SkillSource) -> Result<Vec<RemoteSkill>> {
+        Self::fetch_from_skills_sh_with(source, &SkillsShQuery::default()).await
+    }
+
+    /// Fetch a skills.sh listing by scraping the HTML.
+    /// The site embeds JSON data in the HTML that we can extract
+    pub async fn fetch_from_skills_sh_with(
+        source: &SkillSource,
+        query: &SkillsShQuery,
+    ) -> Result<Vec<RemoteSkill>> {
+        let client = crate::network::client()?;
+        let url = query.url(&source.url);
+
+        let response =
+            crate::network::send_with_retry(client.get(&url).header("User-Agent", "rhinolabs-ai"))
+                .await
+                .map_err(crate::network::request_error)?;
+
+        if !response.status().is_success() {
+            return Err(RhinolabsError::NetworkError(format!(
+                "Failed to fetch skills.sh: {}",
+                response.status()
+            )));
+        }
+
+        let html = response
+            .text()
+            .await
+            .map_err(|e| RhinolabsError::NetworkError(e.to_string()))?;
+
+        // Extract JSON data from HTML - each listing embeds its skills in a named array
+        let skills_json = Self::extract_skills_sh_data(&html, query.sort.data_key())?;
 
         // Get installed skill IDs
         let installed = Self::installed_ids().unwrap_or_default();
@@ -1120,10 +3634,11 @@ impl Skills {
                 continue;
             }
 
-            // Build GitHub URL for this skill
+            // Build GitHub URL for this skill. Each entry is its own repo, so the
+            // source's branch doesn't apply; installing falls back to master if needed
             let skill_url = format!(
-                "https://github.com/{}/tree/main/skills/{}",
-                source_repo, skill_id
+                "https://github.com/{}/tree/{}/skills/{}",
+                source_repo, DEFAULT_BRANCH, skill_id
             );
 
             // Create unique ID combining source repo and skill id
@@ -1146,25 +3661,94 @@ impl Skills {
         Ok(remote_skills)
     }
 
-    /// Extract skills data from skills.sh HTML
+    /// Extract skills data from skills.sh HTML.
+    /// The page holds several arrays; the one named `array` is read, else the
+    /// first skills array on the page, else any skill objects found in it.
     /// Supports both regular JSON and backslash-escaped JSON formats
-    fn extract_skills_sh_data(html: &str) -> Result<Vec<serde_json::Value>> {
+    fn extract_skills_sh_data(html: &str, array: &str) -> Result<Vec<serde_json::Value>> {
         use std::collections::HashSet;
 
-        let mut skills = Vec::new();
+        let candidates = Self::skills_sh_array(html, array)
+            .unwrap_or_else(|| Self::scan_skills_sh_objects(html));
+
         let mut seen = HashSet::new();
+        let skills: Vec<serde_json::Value> = candidates
+            .into_iter()
+            .filter(|skill| {
+                skill
+                    .get("skillId")
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|id| seen.insert(id.to_string()))
+            })
+            .collect();
+
+        if skills.is_empty() {
+            return Err(RhinolabsError::ConfigError(
+                "Could not find skills data in skills.sh HTML".into(),
+            ));
+        }
+
+        Ok(skills)
+    }
 
-        // Try regular JSON format first: {"source":"owner/repo",...}
+    /// Parse the array named `array`, or the first array of skill objects, out of
+    /// the page. Escaped JSON sits inside a script string, which is decoded first.
+    fn skills_sh_array(html: &str, array: &str) -> Option<Vec<serde_json::Value>> {
+        let named = format!("\"{}\":[", array);
+        let named_escaped = format!("{}\\\":[", array);
+        let (start, escaped) = html
+            .find(&named)
+            .map(|i| (i + named.len() - 1, false))
+            .or_else(|| {
+                html.find(&named_escaped)
+                    .map(|i| (i + named_escaped.len() - 1, true))
+            })
+            .or_else(|| html.find(r#"[{"source":""#).map(|i| (i, false)))
+            .or_else(|| html.find(r#"[{\"source\":\""#).map(|i| (i, true)))?;
+
+        let rest = &html[start..];
+        let json = if escaped {
+            // The enclosing string ends at the first unescaped quote
+            let mut in_escape = false;
+            let end = rest
+                .char_indices()
+                .find(|&(_, c)| match (in_escape, c) {
+                    (true, _) => {
+                        in_escape = false;
+                        false
+                    }
+                    (false, '\\') => {
+                        in_escape = true;
+                        false
+                    }
+                    (false, c) => c == '"',
+                })
+                .map_or(rest.len(), |(i, _)| i);
+            serde_json::from_str::<String>(&format!("\"{}\"", &rest[..end])).ok()?
+        } else {
+            rest.to_string()
+        };
+
+        serde_json::Deserializer::from_str(&json)
+            .into_iter::<Vec<serde_json::Value>>()
+            .next()?
+            .ok()
+    }
+
+    /// Skill objects anywhere on the page, for pages whose arrays can't be parsed.
+    /// Regular JSON is tried first, then the escaped form.
+    fn scan_skills_sh_objects(html: &str) -> Vec<serde_json::Value> {
+        let mut skills = Vec::new();
+
+        // Regular JSON format: {"source":"owner/repo",...}
         let mut pos = 0;
         while let Some(start) = html[pos..].find(r#"{"source":""#) {
             let abs_start = pos + start;
             if let Some(end) = html[abs_start..].find('}') {
                 let obj_str = &html[abs_start..abs_start + end + 1];
                 if let Ok(skill) = serde_json::from_str::<serde_json::Value>(obj_str) {
-                    if let Some(skill_id) = skill.get("skillId").and_then(|v| v.as_str()) {
-                        if seen.insert(skill_id.to_string()) {
-                            skills.push(skill);
-                        }
+                    if skill.get("skillId").is_some() {
+                        skills.push(skill);
                     }
                 }
                 pos = abs_start + end + 1;
@@ -1173,7 +3757,7 @@ impl Skills {
             }
         }
 
-        // Fallback: try escaped JSON format: {\"source\":\"owner/repo\",...}
+        // Fallback: escaped JSON format: {\"source\":\"owner/repo\",...}
         if skills.is_empty() {
             pos = 0;
             while let Some(start) = html[pos..].find("{\\\"source\\\":\\\"") {
@@ -1182,10 +3766,8 @@ impl Skills {
                     let obj_str = &html[abs_start..abs_start + end + 1];
                     let unescaped = obj_str.replace("\\\"", "\"");
                     if let Ok(skill) = serde_json::from_str::<serde_json::Value>(&unescaped) {
-                        if let Some(skill_id) = skill.get("skillId").and_then(|v| v.as_str()) {
-                            if seen.insert(skill_id.to_string()) {
-                                skills.push(skill);
-                            }
+                        if skill.get("skillId").is_some() {
+                            skills.push(skill);
                         }
                     }
                     pos = abs_start + end + 1;
@@ -1195,25 +3777,32 @@ impl Skills {
             }
         }
 
-        if skills.is_empty() {
-            return Err(RhinolabsError::ConfigError(
-                "Could not find skills data in skills.sh HTML".into(),
-            ));
-        }
-
-        Ok(skills)
+        skills
     }
 
     /// Helper to fetch skill content from URL
-    async fn fetch_skill_content(client: &reqwest::Client, url: &str) -> Result<String> {
-        let response = client
-            .get(url)
-            .header("User-Agent", "rhinolabs-ai")
-            .send()
+    /// `token_var` names where `token` came from (see `SkillSource::token_var`).
+    async fn fetch_skill_content(
+        client: &reqwest::Client,
+        url: &str,
+        token: Option<&str>,
+        token_var: &str,
+    ) -> Result<String> {
+        let response = crate::network::send_with_retry(Self::github_get(client, url, token))
             .await
-            .map_err(|e| RhinolabsError::NetworkError(e.to_string()))?;
+            .map_err(crate::network::request_error)?;
 
         if !response.status().is_success() {
+            if let Some(err) = Self::github_status_error(&response, token.map(|_| token_var)) {
+                return Err(err);
+            }
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                return Err(RhinolabsError::NetworkError(format!(
+                    "Not found: {}{}",
+                    url,
+                    private_repo_hint(token.is_some(), token_var)
+                )));
+            }
             return Err(RhinolabsError::NetworkError(format!(
                 "Failed to fetch: {}",
                 response.status()
@@ -1228,15 +3817,71 @@ impl Skills {
 
     /// Fetch a single skill's content from its URL
     pub async fn fetch_skill_by_url(url: &str) -> Result<String> {
-        let client = reqwest::Client::new();
-        Self::fetch_skill_content(&client, url).await
+        let client = crate::network::client()?;
+        let token = Self::github_token();
+        Self::fetch_skill_content(
+            &client,
+            url,
+            Self::token_for_url(url, &token),
+            GLOBAL_TOKEN_VARS,
+        )
+        .await
     }
 
-    /// Fetch the file structure of a remote skill from GitHub
+    /// Fetch the file structure of a remote skill from GitHub.
+    /// `branch` defaults to `main`; `master` is tried if it does not exist.
+    /// `skills_path` defaults to `skills` (see [`SkillSource::skills_path`]).
     pub async fn fetch_remote_skill_files(
         source_url: &str,
         skill_id: &str,
+        branch: Option<&str>,
+        skills_path: Option<&str>,
     ) -> Result<Vec<RemoteSkillFile>> {
+        let token = Self::github_token();
+        let (files, _) = Self::fetch_github_skill_files(
+            source_url,
+            skill_id,
+            branch.unwrap_or(DEFAULT_BRANCH),
+            skills_path.unwrap_or(DEFAULT_SKILLS_PATH),
+            token.as_deref(),
+            GLOBAL_TOKEN_VARS,
+        )
+        .await?;
+        Ok(files)
+    }
+
+    /// `fetch_remote_skill_files` with an explicit GitHub token.
+    /// Also returns the branch the files were found on.
+    async fn fetch_github_skill_files(
+        source_url: &str,
+        skill_id: &str,
+        branch: &str,
+        skills_path: &str,
+        token: Option<&str>,
+        token_var: &str,
+    ) -> Result<(Vec<RemoteSkillFile>, String)> {
+        Self::fetch_github_skill_files_at(
+            "https://api.github.com",
+            source_url,
+            skill_id,
+            branch,
+            skills_path,
+            token,
+            token_var,
+        )
+        .await
+    }
+
+    /// `fetch_github_skill_files` against an explicit GitHub API host
+    async fn fetch_github_skill_files_at(
+        api_base: &str,
+        source_url: &str,
+        skill_id: &str,
+        branch: &str,
+        skills_path: &str,
+        token: Option<&str>,
+        token_var: &str,
+    ) -> Result<(Vec<RemoteSkillFile>, String)> {
         // Validate inputs
         if skill_id.is_empty() {
             return Err(RhinolabsError::ConfigError(
@@ -1257,40 +3902,111 @@ impl Skills {
         let repo = parts[parts.len() - 1];
         let owner = parts[parts.len() - 2];
 
-        let client = reqwest::Client::new();
-        let mut files = Vec::new();
+        let client = crate::network::client()?;
+        let mut tried = Vec::new();
+
+        for candidate in branch_candidates(branch) {
+            let github = GitHubRepo {
+                api_base,
+                owner,
+                repo,
+                branch: candidate,
+                token,
+                token_var,
+            };
+            // Only a branch that doesn't exist falls back; a missing skill doesn't
+            if candidate != branch
+                && Self::branch_exists(&client, &GitHubRepo { branch, ..github }).await?
+            {
+                break;
+            }
+            tried.push(candidate);
+
+            // Recursively fetch directory contents
+            for path in skill_dir_candidates(skills_path, repo, skill_id) {
+                let mut files = Vec::new();
+                if Self::fetch_github_directory_contents(&client, &github, &path, "", &mut files)
+                    .await?
+                {
+                    return Ok((files, candidate.to_string()));
+                }
+            }
+        }
 
-        let path = format!("skills/{}", skill_id);
+        // The contents API answers 404 for private repos it won't show too
+        let repo_url = format!("{}/repos/{}/{}", api_base, owner, repo);
+        let repo_response =
+            crate::network::send_with_retry(Self::github_get(&client, &repo_url, token))
+                .await
+                .map_err(crate::network::request_error)?;
+        if repo_response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(RhinolabsError::NetworkError(format!(
+                "Repository {}/{} not found{}",
+                owner,
+                repo,
+                private_repo_hint(token.is_some(), token_var)
+            )));
+        }
+        if let Some(err) = Self::github_status_error(&repo_response, token.map(|_| token_var)) {
+            return Err(err);
+        }
 
-        // Recursively fetch directory contents
-        Self::fetch_github_directory_contents(&client, owner, repo, &path, "", &mut files).await?;
+        Err(RhinolabsError::NetworkError(format!(
+            "Skill '{}' not found in {}/{} (tried branch {})",
+            skill_id,
+            owner,
+            repo,
+            tried.join(", ")
+        )))
+    }
 
-        Ok(files)
+    /// GitHub contents API URL for `path` (the repo root when empty) on `branch`
+    fn contents_url(api_base: &str, owner: &str, repo: &str, path: &str, branch: &str) -> String {
+        let path = if path.is_empty() {
+            String::new()
+        } else {
+            format!("/{}", path)
+        };
+        format!(
+            "{}/repos/{}/{}/contents{}?ref={}",
+            api_base, owner, repo, path, branch
+        )
     }
 
+    /// Collect the files under `path`, recursing into subdirectories.
+    /// Returns `false` if `path` does not exist on the branch.
     async fn fetch_github_directory_contents(
         client: &reqwest::Client,
-        owner: &str,
-        repo: &str,
+        github: &GitHubRepo<'_>,
         path: &str,
         relative_path: &str,
         files: &mut Vec<RemoteSkillFile>,
-    ) -> Result<()> {
-        // Explicitly use main branch to ensure consistency
-        let api_url = format!(
-            "https://api.github.com/repos/{}/{}/contents/{}?ref=main",
-            owner, repo, path
+    ) -> Result<bool> {
+        let api_url = Self::contents_url(
+            github.api_base,
+            github.owner,
+            github.repo,
+            path,
+            github.branch,
         );
 
-        let response = client
-            .get(&api_url)
-            .header("User-Agent", "rhinolabs-ai")
-            .header("Accept", "application/vnd.github.v3+json")
-            .send()
-            .await
-            .map_err(|e| RhinolabsError::NetworkError(e.to_string()))?;
+        let response = crate::network::send_with_retry(
+            Self::github_get(client, &api_url, github.token)
+                .header("Accept", "application/vnd.github.v3+json"),
+        )
+        .await
+        .map_err(crate::network::request_error)?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
 
         if !response.status().is_success() {
+            if let Some(err) =
+                Self::github_status_error(&response, github.token.map(|_| github.token_var))
+            {
+                return Err(err);
+            }
             return Err(RhinolabsError::NetworkError(format!(
                 "Failed to fetch '{}' from GitHub: HTTP {}",
                 path,
@@ -1322,8 +4038,7 @@ impl Skills {
                 // Recursively fetch subdirectory
                 Box::pin(Self::fetch_github_directory_contents(
                     client,
-                    owner,
-                    repo,
+                    github,
                     &format!("{}/{}", path, item.name),
                     &item_relative_path,
                     files,
@@ -1341,7 +4056,7 @@ impl Skills {
             }
         }
 
-        Ok(())
+        Ok(true)
     }
 
     fn detect_language_from_name(name: &str) -> Option<String> {
@@ -1366,7 +4081,112 @@ impl Skills {
     }
 }
 
-/// GitHub API response structure
+/// Where a remote install downloads a skill from
+struct RemoteSkillLocation<'a> {
+    /// GitHub API host the skill's files are listed through
+    api_base: &'a str,
+    /// `https://github.com/owner/repo`
+    repo_url: &'a str,
+    skill_id: &'a str,
+    branch: &'a str,
+    skills_path: &'a str,
+    token: Option<String>,
+    /// Where `token` came from, for error hints (see `SkillSource::token_var`)
+    token_var: String,
+}
+
+/// A skill directory in a GitHub repo, as linked by a tree or blob URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct GitHubSkillUrl {
+    owner: String,
+    repo: String,
+    branch: String,
+    /// Directory holding the skill's directory (see [`SkillSource::skills_path`])
+    skills_path: String,
+    skill_id: String,
+    /// Segments after `tree`/`blob` up to the skill directory: the ref, which
+    /// may span several when the branch contains `/`, then the skill's path
+    ref_path: Vec<String>,
+}
+
+impl GitHubSkillUrl {
+    /// Parse `https://github.com/<owner>/<repo>/(tree|blob)/<branch>[/<path>]`.
+    /// A blob URL names a file in the skill (normally SKILL.md), so its parent
+    /// directory is the skill. A link to the repo root is a flat single-skill repo.
+    /// The branch is taken to be the first segment; see [`Self::with_ref_len`].
+    fn parse(url: &str) -> Result<Self> {
+        let invalid = || {
+            RhinolabsError::ConfigError(format!(
+                "'{}' is not a GitHub skill link; expected https://github.com/<owner>/<repo>/tree/<branch>/skills/<id>",
+                url
+            ))
+        };
+
+        let rest = url
+            .trim()
+            .strip_prefix("https://")
+            .or_else(|| url.trim().strip_prefix("http://"))
+            .ok_or_else(invalid)?;
+        let rest = rest.split(['?', '#']).next().unwrap_or_default();
+        let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+        if !host.eq_ignore_ascii_case("github.com") && !host.eq_ignore_ascii_case("www.github.com")
+        {
+            return Err(RhinolabsError::ConfigError(format!(
+                "Only github.com links are supported, got host '{}'",
+                host
+            )));
+        }
+
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let [owner, repo, kind, ref_path @ ..] = segments.as_slice() else {
+            return Err(invalid());
+        };
+        let ref_path = match *kind {
+            "tree" if !ref_path.is_empty() => ref_path,
+            "blob" if ref_path.len() > 1 => &ref_path[..ref_path.len() - 1],
+            _ => return Err(invalid()),
+        };
+
+        let link = Self {
+            owner: owner.to_string(),
+            repo: repo.trim_end_matches(".git").to_string(),
+            branch: String::new(),
+            skills_path: String::new(),
+            skill_id: String::new(),
+            ref_path: ref_path.iter().map(|s| s.to_string()).collect(),
+        };
+        Ok(link.with_ref_len(1))
+    }
+
+    /// The link read with its first `ref_len` path segments as the branch
+    fn with_ref_len(&self, ref_len: usize) -> Self {
+        let (branch, dirs) = self.ref_path.split_at(ref_len);
+        let (skills_path, skill_id) = match dirs.split_last() {
+            Some((skill_id, parents)) => (parents.join("/"), skill_id.clone()),
+            None => (String::new(), self.repo.clone()),
+        };
+
+        Self {
+            branch: branch.join("/"),
+            skills_path,
+            skill_id,
+            ..self.clone()
+        }
+    }
+}
+
+/// A GitHub repository at a specific branch, as seen through the API at `api_base`
+struct GitHubRepo<'a> {
+    api_base: &'a str,
+    owner: &'a str,
+    repo: &'a str,
+    branch: &'a str,
+    token: Option<&'a str>,
+    /// Where `token` came from, for error hints (see `SkillSource::token_var`)
+    token_var: &'a str,
+}
+
+/// GitHub API response structure
 #[derive(Debug, Deserialize)]
 struct GitHubContent {
     name: String,
@@ -1374,6 +4194,15 @@ struct GitHubContent {
     content_type: String,
 }
 
+/// Skill directories found in a GitHub source, on the branch they were listed from
+struct GitHubSkillListing<'a> {
+    owner: &'a str,
+    repo: &'a str,
+    branch: &'a str,
+    /// `(skill id, repo path)` pairs
+    skill_dirs: Vec<(String, String)>,
+}
+
 /// Extended GitHub API response structure
 #[derive(Debug, Deserialize)]
 struct GitHubContentExtended {
@@ -1399,6 +4228,24 @@ mod tests {
     use super::*;
     use crate::test_utils::{TestEnv as BaseTestEnv, ENV_MUTEX};
 
+    /// A standard-schema source on `acme/skills-repo`
+    pub(super) fn github_source() -> SkillSource {
+        SkillSource {
+            id: "acme".to_string(),
+            name: "Acme".to_string(),
+            source_type: SkillSourceType::Community,
+            url: "https://github.com/acme/skills-repo".to_string(),
+            description: "".to_string(),
+            enabled: true,
+            fetchable: true,
+            schema: SkillSchema::Standard,
+            skill_count: None,
+            branch: None,
+            skills_path: None,
+            auth_token_env: None,
+        }
+    }
+
     /// Extended test environment with skills-specific helpers
     struct TestEnv {
         base: BaseTestEnv,
@@ -1426,7 +4273,9 @@ mod tests {
         fn create_skill(&self, id: &str, name: &str, description: &str, content: &str) {
             let skill_dir = self.skills_dir().join(id);
             fs::create_dir_all(&skill_dir).expect("Failed to create skill dir");
-            let skill_content = Skills::generate_skill_file(name, description, content);
+            let skill_content =
+                Skills::generate_skill_file(&SkillFrontmatter::new(name, description), content)
+                    .unwrap();
             fs::write(skill_dir.join("SKILL.md"), skill_content)
                 .expect("Failed to write skill file");
         }
@@ -1480,39 +4329,88 @@ This is the content.
         let config = SkillsConfig::default();
 
         assert_eq!(
-            Skills::get_category("rhinolabs-standards", &config),
+            Skills::get_category(
+                "rhinolabs-standards",
+                &config,
+                &BuiltinCategories::embedded()
+            ),
             SkillCategory::Corporate
         );
         assert_eq!(
-            Skills::get_category("react-patterns", &config),
+            Skills::get_category("react-patterns", &config, &BuiltinCategories::embedded()),
             SkillCategory::Frontend
         );
         assert_eq!(
-            Skills::get_category("playwright", &config),
+            Skills::get_category("django-drf", &config, &BuiltinCategories::embedded()),
+            SkillCategory::Backend
+        );
+        assert_eq!(
+            Skills::get_category("playwright", &config, &BuiltinCategories::embedded()),
             SkillCategory::Testing
         );
         assert_eq!(
-            Skills::get_category("ai-sdk-core", &config),
+            Skills::get_category("ai-sdk-core", &config, &BuiltinCategories::embedded()),
             SkillCategory::AiSdk
         );
         assert_eq!(
-            Skills::get_category("skill-creator", &config),
+            Skills::get_category("skill-creator", &config, &BuiltinCategories::embedded()),
             SkillCategory::Utilities
         );
         assert_eq!(
-            Skills::get_category("unknown-skill", &config),
+            Skills::get_category("unknown-skill", &config, &BuiltinCategories::embedded()),
             SkillCategory::Custom
         );
     }
 
     #[test]
     fn test_generate_skill_file() {
-        let content = Skills::generate_skill_file("My Skill", "Description", "# Content");
+        let content = Skills::generate_skill_file(
+            &SkillFrontmatter::new("My Skill", "Description"),
+            "# Content",
+        )
+        .unwrap();
 
         assert!(content.starts_with("---"));
         assert!(content.contains("name: My Skill"));
         assert!(content.contains("description: Description"));
         assert!(content.contains("# Content"));
+        assert!(!content.contains("version:"));
+        assert!(!content.contains("tags:"));
+    }
+
+    #[test]
+    fn test_generate_skill_file_quotes_special_characters() {
+        let frontmatter = SkillFrontmatter::new("Fix: things", "Use #hashes and: colons");
+        let content = Skills::generate_skill_file(&frontmatter, "# Content").unwrap();
+
+        let (parsed, body) = Skills::parse_skill_file(&content).unwrap();
+        assert_eq!(parsed.name, "Fix: things");
+        assert_eq!(parsed.description, "Use #hashes and: colons");
+        assert!(body.contains("# Content"));
+    }
+
+    #[test]
+    fn test_frontmatter_metadata_roundtrip() {
+        let (frontmatter, body) = Skills::parse_skill_file(
+            "---\nname: Rich\ndescription: Has metadata\nversion: 1.0\nauthor: Acme\nlicense: MIT\ntags: [react, ui]\n---\n\n# Body",
+        )
+        .unwrap();
+        assert_eq!(frontmatter.version.as_deref(), Some("1.0"));
+        assert_eq!(frontmatter.author.as_deref(), Some("Acme"));
+        assert_eq!(frontmatter.license.as_deref(), Some("MIT"));
+        assert_eq!(frontmatter.tags, vec!["react", "ui"]);
+
+        let regenerated = Skills::generate_skill_file(&frontmatter, &body).unwrap();
+        let (reparsed, _) = Skills::parse_skill_file(&regenerated).unwrap();
+        assert_eq!(reparsed.version.as_deref(), Some("1.0"));
+        assert_eq!(reparsed.tags, vec!["react", "ui"]);
+
+        // Comma-separated tags and name/description-only files also parse
+        let (plain, _) =
+            Skills::parse_skill_file("---\nname: Plain\ndescription: d\ntags: a, b\n---\n")
+                .unwrap();
+        assert_eq!(plain.tags, vec!["a", "b"]);
+        assert!(plain.version.is_none());
     }
 
     #[test]
@@ -1616,6 +4514,9 @@ This is the content.
                 fetchable: false, // Old saved value
                 schema: SkillSchema::Standard,
                 skill_count: None,
+                branch: None,
+                skills_path: None,
+                auth_token_env: None,
             }],
             ..Default::default()
         };
@@ -1634,6 +4535,97 @@ This is the content.
         );
     }
 
+    fn anthropic_source(enabled: bool, fetchable: bool, schema: SkillSchema) -> SkillSource {
+        SkillSource {
+            id: "anthropic-official".to_string(),
+            name: "Anthropic Official".to_string(),
+            source_type: SkillSourceType::Official,
+            url: "https://github.com/anthropics/skills".to_string(),
+            description: "Official skills".to_string(),
+            enabled,
+            fetchable,
+            schema,
+            skill_count: None,
+            branch: None,
+            skills_path: None,
+            auth_token_env: None,
+        }
+    }
+
+    #[test]
+    fn test_repair_sources_collapses_duplicate_defaults() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let custom = SkillSource {
+            id: "my-source".to_string(),
+            name: "My Source".to_string(),
+            source_type: SkillSourceType::Local,
+            url: "https://github.com/me/skills".to_string(),
+            description: "Mine".to_string(),
+            enabled: true,
+            fetchable: true,
+            schema: SkillSchema::Standard,
+            skill_count: None,
+            branch: None,
+            skills_path: None,
+            auth_token_env: None,
+        };
+        let config = SkillsConfig {
+            sources: vec![
+                anthropic_source(true, true, SkillSchema::Standard),
+                custom.clone(),
+                anthropic_source(true, true, SkillSchema::Standard), // exact duplicate
+                // Newest entry: user disabled it, but also drifted fixed fields
+                anthropic_source(false, false, SkillSchema::Custom),
+            ],
+            ..Default::default()
+        };
+        env.create_config(&config);
+
+        assert_eq!(
+            Skills::duplicate_source_ids().unwrap(),
+            vec!["anthropic-official".to_string()]
+        );
+
+        let removed = Skills::repair_sources().expect("Should repair sources");
+        assert_eq!(removed, 2);
+
+        let saved = Skills::load_config().unwrap();
+        assert_eq!(saved.sources.len(), 2);
+        // Collapsed entry keeps the first position and the newest user edits...
+        assert_eq!(saved.sources[0].id, "anthropic-official");
+        assert!(!saved.sources[0].enabled);
+        // ...but the default's fixed fields are restored
+        assert!(saved.sources[0].fetchable);
+        assert_eq!(saved.sources[0].schema, SkillSchema::Standard);
+        assert_eq!(saved.sources[1], custom);
+
+        assert!(Skills::duplicate_source_ids().unwrap().is_empty());
+        assert_eq!(Skills::repair_sources().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_list_sources_dedups_by_id() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let config = SkillsConfig {
+            sources: vec![
+                anthropic_source(true, false, SkillSchema::Standard),
+                anthropic_source(true, false, SkillSchema::Standard),
+            ],
+            ..Default::default()
+        };
+        env.create_config(&config);
+
+        let sources = Skills::list_sources().unwrap();
+        assert_eq!(sources.len(), 1);
+        assert!(sources[0].fetchable);
+    }
+
     #[test]
     fn test_list_sources_preserves_custom_sources() {
         let _lock = ENV_MUTEX.lock().unwrap();
@@ -1651,6 +4643,9 @@ This is the content.
                 fetchable: false,
                 schema: SkillSchema::Custom,
                 skill_count: None,
+                branch: None,
+                skills_path: None,
+                auth_token_env: None,
             }],
             ..Default::default()
         };
@@ -1661,6 +4656,733 @@ This is the content.
         assert_eq!(sources[0].id, "custom-source");
     }
 
+    #[test]
+    fn test_check_updates_reports_upstream_and_local_changes() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let mut skill_meta = std::collections::HashMap::new();
+        for id in ["fresh", "stale", "edited", "orphan"] {
+            env.create_skill(id, "Skill", "Installed", "# v1");
+            let installed = fs::read_to_string(env.skills_dir().join(id).join("SKILL.md")).unwrap();
+            skill_meta.insert(
+                id.to_string(),
+                SkillMeta {
+                    source_id: Some(if id == "orphan" { "gone" } else { "acme" }.to_string()),
+                    source_name: Some("Acme".to_string()),
+                    original_hash: Some(Skills::hash_content(&installed)),
+                },
+            );
+        }
+        let installed = fs::read_to_string(env.skills_dir().join("fresh/SKILL.md")).unwrap();
+        fs::write(env.skills_dir().join("edited/SKILL.md"), "# local edit").unwrap();
+
+        env.create_config(&SkillsConfig {
+            sources: vec![SkillSource {
+                id: "acme".to_string(),
+                name: "Acme".to_string(),
+                source_type: SkillSourceType::Community,
+                url: "https://github.com/acme/skills-repo".to_string(),
+                description: "".to_string(),
+                enabled: true,
+                fetchable: true,
+                schema: SkillSchema::Standard,
+                skill_count: None,
+                branch: None,
+                skills_path: None,
+                auth_token_env: None,
+            }],
+            skill_meta,
+            ..Default::default()
+        });
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let mut warnings = Warnings::new();
+        let updates = rt.block_on(async {
+            let server = MockServer::start().await;
+            for (id, body) in [
+                ("fresh", installed.as_str()),
+                ("stale", "# v2"),
+                ("edited", "# v2"),
+            ] {
+                Mock::given(method("GET"))
+                    .and(path(format!(
+                        "/acme/skills-repo/main/skills/{}/SKILL.md",
+                        id
+                    )))
+                    .respond_with(ResponseTemplate::new(200).set_body_string(body))
+                    .mount(&server)
+                    .await;
+            }
+            Skills::check_updates_at(&server.uri(), &server.uri(), &mut warnings)
+                .await
+                .unwrap()
+        });
+
+        let status = |id: &str| {
+            let u = updates.iter().find(|u| u.skill_id == id).unwrap();
+            (u.has_update, u.is_locally_modified)
+        };
+        assert_eq!(updates.len(), 3);
+        assert_eq!(status("fresh"), (false, false));
+        assert_eq!(status("stale"), (true, false));
+        assert_eq!(status("edited"), (true, true));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings.iter().next().unwrap().contains("'orphan'"));
+    }
+
+    #[test]
+    fn test_search_content() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        env.create_skill(
+            "react-19",
+            "React",
+            "Hooks",
+            "Use useEffect sparingly.\nPrefer server actions.",
+        );
+        env.create_skill("testing", "Testing", "Tests", "Mock fetch, not useEffect.");
+        fs::create_dir_all(env.skills_dir().join("react-19/references")).unwrap();
+        fs::write(
+            env.skills_dir().join("react-19/references/hooks.md"),
+            "# Hooks\nuseEffect runs after paint.\n",
+        )
+        .unwrap();
+
+        let matches = Skills::search_content("useEffect", false, false).unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].skill_id, "react-19");
+        assert_eq!(matches[0].file, "SKILL.md");
+        assert_eq!(matches[0].text, "Use useEffect sparingly.");
+        let skill_md = fs::read_to_string(env.skills_dir().join("react-19/SKILL.md")).unwrap();
+        assert_eq!(
+            skill_md.lines().nth(matches[0].line - 1),
+            Some("Use useEffect sparingly.")
+        );
+        assert_eq!(matches[1].skill_id, "testing");
+
+        assert!(Skills::search_content("USEEFFECT", false, false)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            Skills::search_content("USEEFFECT", true, false)
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let with_files = Skills::search_content("useEffect", false, true).unwrap();
+        assert_eq!(with_files.len(), 3);
+        assert_eq!(with_files[1].file, "references/hooks.md");
+        assert_eq!(with_files[1].line, 2);
+
+        let err = Skills::search_content("use(", false, false).unwrap_err();
+        assert!(err.to_string().contains("Invalid pattern"));
+    }
+
+    #[test]
+    fn test_diff_uses_cached_original() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let original = "---\nname: Tracked\ndescription: d\n---\n\nKeep this.\nOld line.\n";
+        Skills::install_from_source("tracked", original, "acme", "Acme", None, false).unwrap();
+        env.create_skill("custom-only", "Custom", "Mine", "# body");
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        // The cached copy is used, so no request goes out to the (unreachable) host
+        let unchanged = rt
+            .block_on(Skills::diff_at(
+                "http://127.0.0.1:9",
+                "http://127.0.0.1:9",
+                "tracked",
+                &mut Warnings::new(),
+            ))
+            .unwrap();
+        assert!(unchanged.is_empty());
+
+        fs::write(
+            env.skills_dir().join("tracked/SKILL.md"),
+            original.replace("Old line.", "New line."),
+        )
+        .unwrap();
+        let diff = rt
+            .block_on(Skills::diff_at(
+                "http://127.0.0.1:9",
+                "http://127.0.0.1:9",
+                "tracked",
+                &mut Warnings::new(),
+            ))
+            .unwrap();
+        assert!(diff.starts_with("--- a/tracked/SKILL.md\n+++ b/tracked/SKILL.md\n"));
+        assert!(diff.contains("\n-Old line.\n+New line.\n"));
+
+        let err = rt
+            .block_on(Skills::diff_at(
+                "http://127.0.0.1:9",
+                "http://127.0.0.1:9",
+                "custom-only",
+                &mut Warnings::new(),
+            ))
+            .unwrap_err();
+        assert!(err.to_string().contains("no original to compare against"));
+    }
+
+    #[test]
+    fn test_install_from_github_url_resolves_branches_with_slashes() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_config(&SkillsConfig {
+            sources: vec![github_source()],
+            ..Default::default()
+        });
+        let upstream = "---\nname: Foo\ndescription: From a feature branch\n---\n\n# Foo\n";
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let result = rt.block_on(async {
+            let server = MockServer::start().await;
+            // `feature` is not a ref (unmatched requests answer 404); `feature/x` is
+            Mock::given(method("GET"))
+                .and(path("/repos/acme/skills-repo/commits/feature/x"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/repos/acme/skills-repo/contents/skills/foo"))
+                .and(query_param("ref", "feature/x"))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                        "name": "SKILL.md",
+                        "type": "file",
+                        "download_url": format!("{}/raw/SKILL.md", server.uri()),
+                    }])),
+                )
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/raw/SKILL.md"))
+                .respond_with(ResponseTemplate::new(200).set_body_string(upstream))
+                .mount(&server)
+                .await;
+            Skills::install_from_github_url_at(
+                &server.uri(),
+                "https://github.com/acme/skills-repo/blob/feature/x/skills/foo/SKILL.md",
+            )
+            .await
+            .unwrap()
+        });
+
+        assert_eq!(result.skill.id, "foo");
+        assert_eq!(result.branch.as_deref(), Some("feature/x"));
+        assert_eq!(
+            fs::read_to_string(env.skills_dir().join("foo/SKILL.md")).unwrap(),
+            upstream
+        );
+        // The link matched the configured source, so update checks can follow it
+        let meta = Skills::load_config().unwrap().skill_meta["foo"].clone();
+        assert_eq!(meta.source_id.as_deref(), Some("acme"));
+        assert_eq!(meta.original_hash, Some(Skills::hash_content(upstream)));
+    }
+
+    #[test]
+    fn test_reset_from_source_restores_upstream_files() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_config(&SkillsConfig {
+            sources: vec![github_source()],
+            ..Default::default()
+        });
+        let upstream = "---\nname: Tracked\ndescription: d\n---\n\nUpstream.\n";
+        let installed = upstream.replace("Upstream.", "Older upstream.");
+        Skills::install_from_source("tracked", &installed, "acme", "Acme", None, false).unwrap();
+        fs::write(
+            env.skills_dir().join("tracked/SKILL.md"),
+            upstream.replace("Upstream.", "Local edit."),
+        )
+        .unwrap();
+        fs::write(env.skills_dir().join("tracked/notes.md"), "stray").unwrap();
+        env.create_skill("custom-only", "Custom", "Mine", "# body");
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let skill = rt.block_on(async {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/repos/acme/skills-repo/contents/skills/tracked"))
+                .and(query_param("ref", "main"))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                        "name": "SKILL.md",
+                        "type": "file",
+                        "download_url": format!("{}/raw/SKILL.md", server.uri()),
+                    }])),
+                )
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/raw/SKILL.md"))
+                .respond_with(ResponseTemplate::new(200).set_body_string(upstream))
+                .mount(&server)
+                .await;
+            Skills::reset_from_source_at(&server.uri(), "tracked")
+                .await
+                .unwrap()
+        });
+
+        assert!(!skill.is_modified);
+        let dir = env.skills_dir().join("tracked");
+        assert_eq!(fs::read_to_string(dir.join("SKILL.md")).unwrap(), upstream);
+        assert!(!dir.join("notes.md").exists());
+        // Neither the download nor the old copy is left behind
+        assert!(fs::read_dir(env.plugin_dir()).unwrap().all(|entry| !entry
+            .unwrap()
+            .file_name()
+            .to_string_lossy()
+            .starts_with(".tmp")));
+        let diff = rt
+            .block_on(Skills::diff_at(
+                "http://127.0.0.1:9",
+                "http://127.0.0.1:9",
+                "tracked",
+                &mut Warnings::new(),
+            ))
+            .unwrap();
+        assert!(diff.is_empty());
+
+        let err = rt
+            .block_on(Skills::reset_from_source_at(
+                "http://127.0.0.1:9",
+                "custom-only",
+            ))
+            .unwrap_err();
+        assert!(err.to_string().contains("cannot be reset"));
+    }
+
+    #[test]
+    fn test_reset_from_source_keeps_skill_when_download_fails() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_config(&SkillsConfig {
+            sources: vec![github_source()],
+            ..Default::default()
+        });
+        let installed = "---\nname: Tracked\ndescription: d\n---\n\nInstalled.\n";
+        Skills::install_from_source("tracked", installed, "acme", "Acme", None, false).unwrap();
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let result = rt.block_on(async {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/repos/acme/skills-repo/contents/skills/tracked"))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                        "name": "SKILL.md",
+                        "type": "file",
+                        "download_url": format!("{}/raw/SKILL.md", server.uri()),
+                    }])),
+                )
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/raw/SKILL.md"))
+                .respond_with(ResponseTemplate::new(500))
+                .mount(&server)
+                .await;
+            Skills::reset_from_source_at(&server.uri(), "tracked").await
+        });
+
+        assert!(result.is_err());
+        assert_eq!(
+            fs::read_to_string(env.skills_dir().join("tracked/SKILL.md")).unwrap(),
+            installed
+        );
+    }
+
+    #[test]
+    fn test_diff_without_cached_original() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("edited", "Edited", "Installed", "# local edit\n");
+        env.create_skill("pristine", "Pristine", "Installed", "# v1\n");
+        let local = fs::read_to_string(env.skills_dir().join("edited/SKILL.md")).unwrap();
+        let original = local.replace("# local edit", "# v1");
+        let upstream = local.replace("# local edit", "# v2");
+        let pristine = fs::read_to_string(env.skills_dir().join("pristine/SKILL.md")).unwrap();
+        let meta = |hash: &str| SkillMeta {
+            source_id: Some("acme".to_string()),
+            source_name: Some("Acme".to_string()),
+            original_hash: Some(hash.to_string()),
+        };
+        let set_edited_hash = |hash: &str| {
+            env.create_config(&SkillsConfig {
+                sources: vec![github_source()],
+                skill_meta: std::collections::HashMap::from([
+                    ("edited".to_string(), meta(hash)),
+                    (
+                        "pristine".to_string(),
+                        meta(&Skills::hash_content(&pristine)),
+                    ),
+                ]),
+                ..Default::default()
+            });
+        };
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let server = rt.block_on(MockServer::start());
+        rt.block_on(
+            Mock::given(method("GET"))
+                .and(path("/acme/skills-repo/main/skills/edited/SKILL.md"))
+                .respond_with(ResponseTemplate::new(200).set_body_string(&upstream))
+                .mount(&server),
+        );
+
+        // Matching its recorded hash, a skill is unmodified without asking upstream
+        set_edited_hash(&Skills::hash_content(&original));
+        let mut warnings = Warnings::new();
+        let diff = rt
+            .block_on(Skills::diff_at(
+                "http://127.0.0.1:9",
+                "http://127.0.0.1:9",
+                "pristine",
+                &mut warnings,
+            ))
+            .unwrap();
+        assert!(diff.is_empty());
+        assert!(warnings.is_empty());
+
+        // Upstream moved on since the install, so the original is gone
+        let diff = rt
+            .block_on(Skills::diff_at(
+                &server.uri(),
+                &server.uri(),
+                "edited",
+                &mut warnings,
+            ))
+            .unwrap();
+        assert!(diff.contains("\n-# v2\n+# local edit\n"));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings
+            .iter()
+            .next()
+            .unwrap()
+            .contains("original SKILL.md of 'edited' is no longer available"));
+
+        // Upstream still is the original
+        set_edited_hash(&Skills::hash_content(&upstream));
+        let mut warnings = Warnings::new();
+        let diff = rt
+            .block_on(Skills::diff_at(
+                &server.uri(),
+                &server.uri(),
+                "edited",
+                &mut warnings,
+            ))
+            .unwrap();
+        assert!(diff.contains("\n-# v2\n+# local edit\n"));
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_install_many_skips_installed_and_collects_failures() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("react-19", "React 19", "Already here", "# React");
+        let browse_only = SkillSource::default_sources()
+            .into_iter()
+            .find(|s| s.schema != SkillSchema::Standard)
+            .unwrap();
+
+        let spec = |source_id: &str, skill_id: &str| InstallSkillInput {
+            source_id: source_id.to_string(),
+            skill_id: skill_id.to_string(),
+        };
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let result = rt
+            .block_on(Skills::install_many(vec![
+                spec("anthropic-official", "react-19"),
+                spec("missing-source", "zod-4"),
+                spec(&browse_only.id, "tailwind-4"),
+            ]))
+            .unwrap();
+
+        assert!(result.skills_installed.is_empty());
+        assert_eq!(result.skills_skipped, vec!["react-19"]);
+        assert_eq!(result.skills_failed.len(), 2);
+        assert_eq!(result.skills_failed[0].skill_id, "zod-4");
+        assert!(result.skills_failed[0].error.contains("not found"));
+        assert_eq!(result.skills_failed[1].skill_id, "tailwind-4");
+        assert!(!env.skills_dir().join("zod-4").exists());
+    }
+
+    #[test]
+    fn test_remote_skill_matches_id_name_or_description() {
+        let skill = RemoteSkill {
+            id: "react-19".to_string(),
+            name: "React Patterns".to_string(),
+            description: "Hooks and Server Components".to_string(),
+            category: "custom".to_string(),
+            source_id: "acme".to_string(),
+            source_name: "Acme".to_string(),
+            url: "https://example.com".to_string(),
+            stars: None,
+            installed: false,
+        };
+
+        assert!(skill.matches("REACT-19"));
+        assert!(skill.matches("patterns"));
+        assert!(skill.matches("server comp"));
+        assert!(!skill.matches("vue"));
+    }
+
+    #[test]
+    fn test_search_reads_the_requested_skills_sh_listing() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let server = rt.block_on(MockServer::start());
+        rt.block_on(
+            Mock::given(method("GET"))
+                .and(path("/trending/web%20dev"))
+                .respond_with(ResponseTemplate::new(200).set_body_string(
+                    r#"{"trendingSkills":[{"source":"a/b","skillId":"react-hooks","name":"React Hooks","installs":3}],"allTimeSkills":[{"source":"c/d","skillId":"react-classic","name":"React Classic","installs":9}]}"#,
+                ))
+                .mount(&server),
+        );
+        env.create_config(&SkillsConfig {
+            sources: vec![SkillSource {
+                id: "skills-sh".to_string(),
+                name: "skills.sh".to_string(),
+                source_type: SkillSourceType::Community,
+                url: format!("{}/", server.uri()),
+                description: "".to_string(),
+                enabled: true,
+                fetchable: true,
+                schema: SkillSchema::SkillsSh,
+                skill_count: None,
+                branch: None,
+                skills_path: None,
+                auth_token_env: None,
+            }],
+            ..Default::default()
+        });
+
+        let listing = SkillsShQuery {
+            sort: SkillsShSort::Trending,
+            category: Some("web dev".to_string()),
+        };
+        let skills = rt
+            .block_on(Skills::search(
+                "react",
+                Some("skills-sh"),
+                &listing,
+                &mut Warnings::new(),
+            ))
+            .unwrap();
+
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].id, "a/b/react-hooks");
+    }
+
+    #[test]
+    fn test_search_merges_matches_across_sources() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let server = rt.block_on(MockServer::start());
+        let listing = |owner: &str, skill_id: &str| {
+            let skills = format!(
+                r#"[{{"source":"{}/skills","skillId":"{}","name":"{}","installs":1}},{{"source":"{}/skills","skillId":"vue-basics","name":"Vue Basics","installs":1}}]"#,
+                owner, skill_id, skill_id, owner
+            );
+            format!(
+                r#"{{"trendingSkills":{},"allTimeSkills":{}}}"#,
+                skills, skills
+            )
+        };
+        for (route, body) in [
+            ("/team", listing("team", "react-hooks")),
+            ("/community", listing("community", "react-testing")),
+        ] {
+            rt.block_on(
+                Mock::given(method("GET"))
+                    .and(path(route))
+                    .respond_with(ResponseTemplate::new(200).set_body_string(body))
+                    .mount(&server),
+            );
+        }
+        rt.block_on(
+            Mock::given(method("GET"))
+                .and(path("/broken"))
+                .respond_with(ResponseTemplate::new(404))
+                .mount(&server),
+        );
+
+        let source = |id: &str, enabled: bool| SkillSource {
+            id: id.to_string(),
+            name: id.to_string(),
+            source_type: SkillSourceType::Community,
+            url: format!("{}/{}", server.uri(), id),
+            description: "".to_string(),
+            enabled,
+            fetchable: true,
+            schema: SkillSchema::SkillsSh,
+            skill_count: None,
+            branch: None,
+            skills_path: None,
+            auth_token_env: None,
+        };
+        env.create_config(&SkillsConfig {
+            sources: vec![
+                source("team", true),
+                source("community", true),
+                source("broken", true),
+                source("disabled", false),
+            ],
+            ..Default::default()
+        });
+
+        let mut warnings = Warnings::new();
+        let skills = rt
+            .block_on(Skills::search(
+                "REACT",
+                None,
+                &SkillsShQuery::default(),
+                &mut warnings,
+            ))
+            .unwrap();
+
+        let mut ids: Vec<&str> = skills.iter().map(|s| s.id.as_str()).collect();
+        ids.sort();
+        assert_eq!(
+            ids,
+            vec!["community/skills/react-testing", "team/skills/react-hooks"]
+        );
+        // The failing source is a warning; the disabled one isn't searched at all
+        let warnings: Vec<&String> = warnings.iter().collect();
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0].contains("Could not search 'broken'"),
+            "{}",
+            warnings[0]
+        );
+    }
+
+    #[test]
+    fn test_search_rejects_unknown_or_browse_only_source() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        let rt = tokio::runtime::Runtime::new().unwrap();
+
+        let err = rt
+            .block_on(Skills::search(
+                "react",
+                Some("nope"),
+                &SkillsShQuery::default(),
+                &mut Warnings::new(),
+            ))
+            .unwrap_err();
+        assert!(err.to_string().contains("Source 'nope' not found"));
+
+        let browse_only = SkillSource::default_sources()
+            .into_iter()
+            .find(|s| !s.fetchable)
+            .unwrap();
+        let err = rt
+            .block_on(Skills::search(
+                "react",
+                Some(&browse_only.id),
+                &SkillsShQuery::default(),
+                &mut Warnings::new(),
+            ))
+            .unwrap_err();
+        assert!(err.to_string().contains("browse-only"));
+    }
+
+    #[test]
+    fn test_update_source_sets_and_clears_branch() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let source: SkillSource = serde_json::from_value(serde_json::json!({
+            "id": "team-skills",
+            "name": "Team Skills",
+            "sourceType": "community",
+            "url": "https://github.com/acme/team-skills",
+            "description": "",
+            "enabled": true
+        }))
+        .unwrap();
+        assert_eq!(source.branch(), "main");
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(Skills::add_source(source, false)).unwrap();
+
+        let find = || {
+            Skills::list_sources()
+                .unwrap()
+                .into_iter()
+                .find(|s| s.id == "team-skills")
+                .unwrap()
+        };
+
+        rt.block_on(Skills::update_source(
+            "team-skills",
+            UpdateSkillSourceInput {
+                branch: Some("v2".to_string()),
+                ..Default::default()
+            },
+            false,
+        ))
+        .unwrap();
+        assert_eq!(find().branch(), "v2");
+
+        rt.block_on(Skills::update_source(
+            "team-skills",
+            UpdateSkillSourceInput {
+                branch: Some(" ".to_string()),
+                ..Default::default()
+            },
+            false,
+        ))
+        .unwrap();
+        assert_eq!(find().branch, None);
+        assert_eq!(find().branch(), "main");
+    }
+
     // ============================================
     // delete() Tests
     // ============================================
@@ -1800,62 +5522,357 @@ This is the content.
         let env = TestEnv::new();
         env.setup_skills_dir();
 
-        let result = Skills::get_skill_path("nonexistent-skill");
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("not found"));
-    }
+        let result = Skills::get_skill_path("nonexistent-skill");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("not found"));
+    }
+
+    // ============================================
+    // list() Tests
+    // ============================================
+
+    #[test]
+    fn test_list_returns_empty_when_no_skills_dir() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _env = TestEnv::new();
+        // Don't create skills dir
+
+        let skills = Skills::list().expect("Should list skills");
+        assert!(skills.is_empty());
+    }
+
+    #[test]
+    fn test_list_returns_all_skills() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("skill-a", "Skill A", "First skill", "# A");
+        env.create_skill("skill-b", "Skill B", "Second skill", "# B");
+
+        let skills = Skills::list().expect("Should list skills");
+        assert_eq!(skills.len(), 2);
+    }
+
+    #[test]
+    fn test_list_sorts_by_category_then_name() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        // Create skills in different categories (using known built-in IDs)
+        env.create_skill(
+            "rhinolabs-standards",
+            "Rhinolabs Standards",
+            "Corporate",
+            "# Corp",
+        );
+        env.create_skill("react-patterns", "React Patterns", "Frontend", "# Frontend");
+        env.create_skill("custom-skill", "Custom Skill", "Custom", "# Custom");
+
+        let skills = Skills::list().expect("Should list skills");
+        assert_eq!(skills.len(), 3);
+
+        // Corporate should be first
+        assert_eq!(skills[0].id, "rhinolabs-standards");
+        // Frontend second
+        assert_eq!(skills[1].id, "react-patterns");
+        // Custom last
+        assert_eq!(skills[2].id, "custom-skill");
+    }
+
+    #[test]
+    fn test_list_sorts_named_categories_after_known_ones() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("terraform", "Terraform", "IaC", "# IaC");
+        env.create_skill("k8s", "Kubernetes", "Clusters", "# K8s");
+        env.create_skill("custom-skill", "Custom Skill", "Custom", "# Custom");
+        env.create_skill("react-patterns", "React Patterns", "Frontend", "# Frontend");
+
+        Skills::set_category("terraform", SkillCategory::from_label("Platform")).unwrap();
+        Skills::set_category("k8s", SkillCategory::from_label("devops")).unwrap();
+
+        let ids: Vec<String> = Skills::list().unwrap().into_iter().map(|s| s.id).collect();
+        assert_eq!(ids, ["react-patterns", "custom-skill", "k8s", "terraform"]);
+
+        // Labels are stored as-is in the config and read back unchanged
+        let config = Skills::load_config().unwrap();
+        assert_eq!(
+            config.category_map.get("terraform"),
+            Some(&SkillCategory::Named("platform".into()))
+        );
+    }
+
+    #[test]
+    fn test_list_with_warnings_reports_duplicate_names() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("react-a", "React Patterns", "First", "# A");
+        env.create_skill("react-b", "React Patterns", "Second", "# B");
+        env.create_skill("unique", "Unique Skill", "Third", "# C");
+
+        let (skills, warnings) = Skills::list_with_warnings().expect("Should list skills");
+
+        assert_eq!(skills.len(), 3);
+        assert_eq!(
+            warnings.duplicate_names,
+            vec![DuplicateSkillName {
+                name: "React Patterns".to_string(),
+                skill_ids: vec!["react-a".to_string(), "react-b".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_list_with_warnings_empty_when_names_unique() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("skill-a", "Skill A", "First skill", "# A");
+        env.create_skill("skill-b", "Skill B", "Second skill", "# B");
+
+        let (_, warnings) = Skills::list_with_warnings().expect("Should list skills");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validate_strict_accepts_spec_conformant_skill() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill(
+            "pdf-tools",
+            "pdf-tools",
+            "Extract text and tables from PDF files when the user mentions PDFs",
+            "# PDF Tools\n\nUse scripts/extract.py.",
+        );
+        fs::create_dir_all(env.skills_dir().join("pdf-tools/scripts")).unwrap();
+
+        let issues = Skills::validate("pdf-tools", true).unwrap();
+
+        assert!(issues.is_empty(), "{:?}", issues);
+    }
+
+    /// Messages of the `level` issues, joined for `contains` checks
+    fn issue_messages(issues: &[ValidationIssue], level: IssueLevel) -> String {
+        issues
+            .iter()
+            .filter(|issue| issue.level == level)
+            .map(|issue| issue.message.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_validate_strict_reports_spec_violations_as_errors() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("my-skill", "My Skill", "Short", "# My Skill");
+        fs::write(env.skills_dir().join("my-skill/notes.txt"), "scratch").unwrap();
+
+        // Lenient mode only warns
+        let issues = Skills::validate("my-skill", false).unwrap();
+        assert!(issue_messages(&issues, IssueLevel::Error).is_empty());
+        let warnings = issue_messages(&issues, IssueLevel::Warning);
+        assert!(warnings.contains("differs from directory"));
+        assert!(warnings.contains("very short"));
+
+        let issues = Skills::validate("my-skill", true).unwrap();
+        let errors = issue_messages(&issues, IssueLevel::Error);
+        assert!(errors.contains("must match ^[a-z0-9-]+$"));
+        assert!(errors.contains("must match the directory name"));
+        assert!(errors.contains("'notes.txt' is not allowed"));
+        // Soft lints stay warnings
+        assert!(issue_messages(&issues, IssueLevel::Warning).contains("very short"));
+        assert!(!errors.contains("very short"));
+    }
+
+    #[test]
+    fn test_validate_content_levels() {
+        let issues = Skills::validate_content("# No frontmatter");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].level, IssueLevel::Error);
+
+        let issues = Skills::validate_content("---\nname: \"\"\ndescription: d\n---\n");
+        assert!(issues
+            .iter()
+            .any(|i| i.level == IssueLevel::Error && i.message.contains("no `name`")));
+        assert!(issues
+            .iter()
+            .any(|i| i.level == IssueLevel::Warning && i.message.contains("no instructions")));
+
+        let long = format!(
+            "---\nname: long\ndescription: {}\n---\n\n# Body",
+            "x".repeat(1100)
+        );
+        assert_eq!(
+            Skills::validate_content(&long),
+            vec![ValidationIssue::warning(
+                "Description is 1100 characters; the limit for skill discovery is 1024"
+            )]
+        );
+
+        let ok = "---\nname: ok\ndescription: Use when working with PDF files\n---\n\n# Body";
+        assert!(Skills::validate_content(ok).is_empty());
+    }
+
+    #[test]
+    fn test_validate_fails_on_unparseable_frontmatter() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        let dir = env.skills_dir().join("broken");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("SKILL.md"), "---\nname: [unclosed\n---\n# Body").unwrap();
+
+        let issues = Skills::validate("broken", false).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].level, IssueLevel::Error);
+        assert!(issues[0].message.contains("Invalid YAML frontmatter"));
+    }
+
+    #[test]
+    fn test_validate_strict_requires_skill_md() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        fs::create_dir_all(env.skills_dir().join("empty-skill")).unwrap();
+
+        assert_eq!(
+            Skills::validate("empty-skill", true).unwrap(),
+            vec![ValidationIssue::error(
+                "SKILL.md not found at the skill root"
+            )]
+        );
+    }
+
+    // ============================================
+    // Invocation Context Tests
+    // ============================================
+
+    fn profile_with_rules(id: &str, rules: Vec<AutoInvokeRule>) -> Profile {
+        Profile {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            profile_type: crate::ProfileType::Project,
+            skills: vec!["react-patterns".to_string()],
+            auto_invoke_rules: rules,
+            instructions: None,
+            generate_copilot: false,
+            generate_agents: false,
+            generate_gemini: false,
+            generate_opencode: false,
+            tags: Vec::new(),
+            extends: None,
+            output_style: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_invocation_contexts_from_two_profiles_with_different_triggers() {
+        let profiles = vec![
+            profile_with_rules(
+                "web-app",
+                vec![
+                    AutoInvokeRule {
+                        skill_id: "react-patterns".to_string(),
+                        trigger: "Editing .tsx files".to_string(),
+                        description: "React component patterns".to_string(),
+                    },
+                    AutoInvokeRule {
+                        skill_id: "other-skill".to_string(),
+                        trigger: "Never".to_string(),
+                        description: "Unrelated".to_string(),
+                    },
+                ],
+            ),
+            profile_with_rules(
+                "design-system",
+                vec![AutoInvokeRule {
+                    skill_id: "react-patterns".to_string(),
+                    trigger: "Creating UI primitives".to_string(),
+                    description: "Composition patterns".to_string(),
+                }],
+            ),
+            profile_with_rules("no-rules", Vec::new()),
+        ];
+
+        let contexts = Skills::invocation_contexts_from(&profiles, "react-patterns");
+
+        assert_eq!(contexts.len(), 2);
+        assert_eq!(contexts[0].0, "web-app");
+        assert_eq!(contexts[0].1.trigger, "Editing .tsx files");
+        assert_eq!(contexts[1].0, "design-system");
+        assert_eq!(contexts[1].1.trigger, "Creating UI primitives");
+        assert_eq!(contexts[1].1.description, "Composition patterns");
+    }
+
+    // ============================================
+    // history() Tests
+    // ============================================
+
+    fn commit_all(repo: &git2::Repository, message: &str) {
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Test Author", "test@example.com").unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_history_lists_commits_touching_skill() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        let repo = git2::Repository::init(env.plugin_dir()).unwrap();
+
+        env.create_skill("tracked", "Tracked", "Tracked skill", "# v1");
+        commit_all(&repo, "Add tracked skill");
 
-    // ============================================
-    // list() Tests
-    // ============================================
+        env.create_skill("other", "Other", "Unrelated skill", "# other");
+        commit_all(&repo, "Add other skill");
 
-    #[test]
-    fn test_list_returns_empty_when_no_skills_dir() {
-        let _lock = ENV_MUTEX.lock().unwrap();
-        let _env = TestEnv::new();
-        // Don't create skills dir
+        env.create_skill("tracked", "Tracked", "Tracked skill", "# v2");
+        commit_all(&repo, "Update tracked skill");
 
-        let skills = Skills::list().expect("Should list skills");
-        assert!(skills.is_empty());
+        let history = Skills::history("tracked").expect("Should read history");
+        let subjects: Vec<&str> = history.iter().map(|c| c.subject.as_str()).collect();
+        assert_eq!(subjects, vec!["Update tracked skill", "Add tracked skill"]);
+        assert_eq!(history[0].author, "Test Author");
+        assert_eq!(history[0].sha.len(), 40);
+        assert!(!history[0].date.is_empty());
     }
 
     #[test]
-    fn test_list_returns_all_skills() {
+    fn test_history_empty_outside_git() {
         let _lock = ENV_MUTEX.lock().unwrap();
         let env = TestEnv::new();
         env.setup_skills_dir();
-        env.create_skill("skill-a", "Skill A", "First skill", "# A");
-        env.create_skill("skill-b", "Skill B", "Second skill", "# B");
+        env.create_skill("untracked", "Untracked", "No repo", "# content");
 
-        let skills = Skills::list().expect("Should list skills");
-        assert_eq!(skills.len(), 2);
+        // The temp dir lives outside any repository
+        assert!(Skills::history("untracked").unwrap().is_empty());
     }
 
     #[test]
-    fn test_list_sorts_by_category_then_name() {
+    fn test_history_missing_skill_errors() {
         let _lock = ENV_MUTEX.lock().unwrap();
         let env = TestEnv::new();
         env.setup_skills_dir();
 
-        // Create skills in different categories (using known built-in IDs)
-        env.create_skill(
-            "rhinolabs-standards",
-            "Rhinolabs Standards",
-            "Corporate",
-            "# Corp",
-        );
-        env.create_skill("react-patterns", "React Patterns", "Frontend", "# Frontend");
-        env.create_skill("custom-skill", "Custom Skill", "Custom", "# Custom");
-
-        let skills = Skills::list().expect("Should list skills");
-        assert_eq!(skills.len(), 3);
-
-        // Corporate should be first
-        assert_eq!(skills[0].id, "rhinolabs-standards");
-        // Frontend second
-        assert_eq!(skills[1].id, "react-patterns");
-        // Custom last
-        assert_eq!(skills[2].id, "custom-skill");
+        assert!(Skills::history("nope").is_err());
     }
 
     // ============================================
@@ -1889,6 +5906,22 @@ This is the content.
         assert!(skill.is_none());
     }
 
+    #[test]
+    fn test_get_json_includes_content_and_missing_is_null() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("test-skill", "Test Skill", "A test", "# Content here");
+
+        // `skill show --json` / `skill list --json` serialize these directly
+        let json = serde_json::to_value(Skills::get("test-skill").unwrap()).unwrap();
+        assert!(json["content"].as_str().unwrap().contains("# Content here"));
+        assert_eq!(json["isModified"], false);
+
+        let missing = serde_json::to_string(&Skills::get("nonexistent").unwrap()).unwrap();
+        assert_eq!(missing, "null");
+    }
+
     // ============================================
     // create() Tests
     // ============================================
@@ -1905,6 +5938,7 @@ This is the content.
             description: "A brand new skill".to_string(),
             category: SkillCategory::Custom,
             content: "# New Skill Content".to_string(),
+            from_dir: None,
         };
 
         let skill = Skills::create(input).expect("Should create skill");
@@ -1930,6 +5964,7 @@ This is the content.
             description: "Should fail".to_string(),
             category: SkillCategory::Custom,
             content: "# Content".to_string(),
+            from_dir: None,
         };
 
         let result = Skills::create(input);
@@ -1937,6 +5972,31 @@ This is the content.
         assert!(result.unwrap_err().to_string().contains("already exists"));
     }
 
+    #[test]
+    fn test_create_skill_only_rejects_ids_that_are_not_a_single_directory() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let input = |id: &str| CreateSkillInput {
+            id: id.to_string(),
+            name: "Skill".to_string(),
+            description: "A skill".to_string(),
+            category: SkillCategory::Custom,
+            content: "# Content".to_string(),
+            from_dir: None,
+        };
+
+        // Ids the GUI has always accepted still work
+        assert_eq!(Skills::create(input("My_Skill")).unwrap().id, "My_Skill");
+
+        for id in ["", ".", "..", "../escaped", "nested/skill", "nested\\skill"] {
+            let err = Skills::create(input(id)).unwrap_err();
+            assert!(err.to_string().contains("Invalid skill id"), "{}", err);
+        }
+        assert!(!env.plugin_dir().join("escaped").exists());
+    }
+
     // ============================================
     // toggle() Tests
     // ============================================
@@ -1995,6 +6055,50 @@ This is the content.
         assert!(result.unwrap_err().to_string().contains("not found"));
     }
 
+    #[test]
+    fn test_toggle_for_target_is_overridden_by_global_disable() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("toggleable", "Toggleable", "Can be toggled", "# Content");
+
+        Skills::toggle_for_target("toggleable", DeployTarget::Amp, false).unwrap();
+        assert!(!Skills::is_enabled_for_target("toggleable", DeployTarget::Amp).unwrap());
+        assert!(Skills::is_enabled_for_target("toggleable", DeployTarget::ClaudeCode).unwrap());
+        assert_eq!(
+            Skills::disabled_for_target(DeployTarget::Amp).unwrap(),
+            vec!["toggleable"]
+        );
+
+        // Globally off beats per-target on
+        Skills::toggle("toggleable", false).unwrap();
+        Skills::toggle_for_target("toggleable", DeployTarget::Amp, true).unwrap();
+        assert!(!Skills::is_enabled_for_target("toggleable", DeployTarget::Amp).unwrap());
+        assert!(Skills::load_config().unwrap().disabled_by_target.is_empty());
+    }
+
+    #[test]
+    fn test_config_with_unknown_target_id_still_loads() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("toggleable", "Toggleable", "Can be toggled", "# Content");
+        fs::write(
+            env.plugin_dir().join(".skills-config.json"),
+            r#"{
+                "disabled": [],
+                "disabledByTarget": { "toggleable": ["removed-custom-target", "amp"] },
+                "custom": [],
+                "sources": []
+            }"#,
+        )
+        .unwrap();
+
+        assert!(Skills::list().is_ok());
+        assert!(!Skills::is_enabled_for_target("toggleable", DeployTarget::Amp).unwrap());
+        assert!(Skills::is_enabled_for_target("toggleable", DeployTarget::ClaudeCode).unwrap());
+    }
+
     // ============================================
     // Default Sources Tests
     // ============================================
@@ -2035,7 +6139,9 @@ This is the content.
         let env = TestEnv::new();
         env.setup_skills_dir();
 
-        let original_content = Skills::generate_skill_file("Test", "Desc", "# Original");
+        let original_content =
+            Skills::generate_skill_file(&SkillFrontmatter::new("Test", "Desc"), "# Original")
+                .unwrap();
         let original_hash = Skills::hash_content(&original_content);
 
         // Create skill with source metadata
@@ -2063,7 +6169,9 @@ This is the content.
         assert!(!skill.is_modified);
 
         // Modify the file
-        let modified_content = Skills::generate_skill_file("Test", "Desc", "# MODIFIED!");
+        let modified_content =
+            Skills::generate_skill_file(&SkillFrontmatter::new("Test", "Desc"), "# MODIFIED!")
+                .unwrap();
         fs::write(skill_dir.join("SKILL.md"), modified_content).unwrap();
 
         // Now it should be detected as modified
@@ -2088,7 +6196,8 @@ This is the content.
         };
 
         // Should use category_map value, not hardcoded Frontend
-        let category = Skills::get_category("react-patterns", &config);
+        let category =
+            Skills::get_category("react-patterns", &config, &BuiltinCategories::embedded());
         assert_eq!(category, SkillCategory::Corporate);
     }
 
@@ -2097,18 +6206,87 @@ This is the content.
         // Test that hardcoded constants are used when no category_map entry
         let config = SkillsConfig::default();
 
-        let category = Skills::get_category("react-patterns", &config);
+        let category =
+            Skills::get_category("react-patterns", &config, &BuiltinCategories::embedded());
         assert_eq!(category, SkillCategory::Frontend);
 
-        let category = Skills::get_category("rhinolabs-standards", &config);
+        let category = Skills::get_category(
+            "rhinolabs-standards",
+            &config,
+            &BuiltinCategories::embedded(),
+        );
         assert_eq!(category, SkillCategory::Corporate);
     }
 
+    #[test]
+    fn test_builtin_categories_come_from_plugin_file() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        let config = SkillsConfig::default();
+
+        // The embedded default covers every category key
+        let default: BuiltinCategories = serde_json::from_str(DEFAULT_CATEGORIES).unwrap();
+        assert!(!default.corporate.is_empty());
+        assert_eq!(
+            Skills::get_category("tailwind-4", &config, &BuiltinCategories::load()),
+            SkillCategory::Frontend
+        );
+
+        fs::write(
+            env.plugin_dir().join(CATEGORIES_FILE),
+            r#"{"corporate": ["rhinolabs-standards"], "backend": ["rust", "rhinolabs-standards"], "workflow": ["x"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            Skills::get_category("rust", &config, &BuiltinCategories::load()),
+            SkillCategory::Backend
+        );
+        assert_eq!(
+            Skills::get_category("x", &config, &BuiltinCategories::load()),
+            SkillCategory::Named("workflow".into())
+        );
+        assert_eq!(
+            Skills::get_category("rhinolabs-standards", &config, &BuiltinCategories::load()),
+            SkillCategory::Corporate
+        );
+        // The installed file replaces the default instead of adding to it
+        assert_eq!(
+            Skills::get_category("tailwind-4", &config, &BuiltinCategories::load()),
+            SkillCategory::Custom
+        );
+
+        // User overrides still win
+        let config = SkillsConfig {
+            category_map: [("rust".to_string(), SkillCategory::Utilities)].into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            Skills::get_category("rust", &config, &BuiltinCategories::load()),
+            SkillCategory::Utilities
+        );
+
+        // An unreadable file falls back to the default
+        fs::write(env.plugin_dir().join(CATEGORIES_FILE), "{ not json").unwrap();
+        assert_eq!(
+            Skills::get_category(
+                "tailwind-4",
+                &SkillsConfig::default(),
+                &BuiltinCategories::load()
+            ),
+            SkillCategory::Frontend
+        );
+    }
+
     #[test]
     fn test_get_category_returns_custom_for_unknown() {
         let config = SkillsConfig::default();
 
-        let category = Skills::get_category("some-unknown-skill", &config);
+        let category = Skills::get_category(
+            "some-unknown-skill",
+            &config,
+            &BuiltinCategories::embedded(),
+        );
         assert_eq!(category, SkillCategory::Custom);
     }
 
@@ -2145,23 +6323,405 @@ This is the content.
         let config = Skills::load_config().unwrap();
         assert!(config.category_map.contains_key("my-skill"));
 
-        // Set back to Custom
-        Skills::set_category("my-skill", SkillCategory::Custom).unwrap();
+        // Set back to Custom
+        Skills::set_category("my-skill", SkillCategory::Custom).unwrap();
+
+        // Should be removed from category_map
+        let config = Skills::load_config().unwrap();
+        assert!(!config.category_map.contains_key("my-skill"));
+    }
+
+    #[test]
+    fn test_set_category_fails_for_nonexistent_skill() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let result = Skills::set_category("nonexistent-skill", SkillCategory::Frontend);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("not found"));
+    }
+
+    fn from_dir_input(id: &str, dir: &Path) -> CreateSkillInput {
+        CreateSkillInput {
+            id: id.to_string(),
+            name: String::new(),
+            description: String::new(),
+            category: SkillCategory::Custom,
+            content: String::new(),
+            from_dir: Some(dir.to_path_buf()),
+        }
+    }
+
+    #[test]
+    fn test_create_skill_from_dir_imports_supporting_files() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let source = tempfile::tempdir().unwrap();
+        fs::write(
+            source.path().join("SKILL.md"),
+            Skills::generate_skill_file(
+                &SkillFrontmatter::new("Local Tools", "Imported from disk"),
+                "# Local",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        fs::create_dir_all(source.path().join("examples/nested")).unwrap();
+        fs::write(source.path().join("examples/basic.md"), "example").unwrap();
+        fs::write(source.path().join("examples/nested/deep.md"), "deep").unwrap();
+        fs::create_dir_all(source.path().join("node_modules/pkg")).unwrap();
+        fs::write(source.path().join("node_modules/pkg/index.js"), "").unwrap();
+        fs::write(source.path().join("notes.log"), "scratch").unwrap();
+        fs::write(
+            source.path().join(".skillignore"),
+            "# local junk\nnode_modules/\n*.log\n",
+        )
+        .unwrap();
+
+        let skill = Skills::create(from_dir_input("local-tools", source.path())).unwrap();
+
+        assert_eq!(skill.name, "Local Tools");
+        assert!(skill.is_custom);
+        let imported = env.skills_dir().join("local-tools");
+        assert_eq!(
+            fs::read_to_string(imported.join("examples/basic.md")).unwrap(),
+            "example"
+        );
+        assert!(imported.join("examples/nested/deep.md").exists());
+        assert!(!imported.join("node_modules").exists());
+        assert!(!imported.join("notes.log").exists());
+        assert!(!imported.join(".skillignore").exists());
+    }
+
+    #[test]
+    fn test_create_skill_from_dir_requires_skill_md() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let source = tempfile::tempdir().unwrap();
+        fs::create_dir_all(source.path().join("examples")).unwrap();
+        fs::write(source.path().join("README.md"), "# Not a skill").unwrap();
+
+        let result = Skills::create(from_dir_input("not-a-skill", source.path()));
+
+        assert!(result.unwrap_err().to_string().contains("no SKILL.md"));
+        assert!(!env.skills_dir().join("not-a-skill").exists());
+    }
+
+    #[test]
+    fn test_export_writes_files_and_meta() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("my-tools", "My Tools", "Local helpers", "# Tools");
+        let skill_dir = env.skills_dir().join("my-tools");
+        fs::create_dir_all(skill_dir.join("references")).unwrap();
+        fs::write(skill_dir.join("references/guide.md"), "guide").unwrap();
+        fs::write(skill_dir.join("scratch.log"), "junk").unwrap();
+        fs::write(skill_dir.join(".skillignore"), "*.log\n").unwrap();
+        env.create_config(&SkillsConfig {
+            custom: vec!["my-tools".to_string()],
+            category_map: [("my-tools".to_string(), SkillCategory::Utilities)].into(),
+            ..Default::default()
+        });
+
+        let out = tempfile::tempdir().unwrap();
+        let archive_path = Skills::export("my-tools", out.path()).unwrap();
+
+        assert_eq!(archive_path, out.path().join("my-tools.zip"));
+        let mut archive = zip::ZipArchive::new(fs::File::open(&archive_path).unwrap()).unwrap();
+        let mut names: Vec<String> = archive.file_names().map(String::from).collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "SKILL.md",
+                "references/",
+                "references/guide.md",
+                SKILL_ARCHIVE_META_FILE
+            ]
+        );
+
+        let meta: SkillArchiveMeta =
+            serde_json::from_reader(archive.by_name(SKILL_ARCHIVE_META_FILE).unwrap()).unwrap();
+        assert_eq!(meta.id, "my-tools");
+        assert_eq!(meta.category, SkillCategory::Utilities);
+        assert_eq!(meta.source_id, None);
+    }
+
+    #[test]
+    fn test_export_unknown_skill_fails() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let out = tempfile::tempdir().unwrap();
+        let err = Skills::export("missing", out.path()).unwrap_err();
+
+        assert!(err.to_string().contains("not found"));
+        assert!(!out.path().join("missing.zip").exists());
+    }
+
+    #[test]
+    fn test_import_round_trips_exported_archive() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("my-tools", "My Tools", "Local helpers", "# Tools");
+        let skill_dir = env.skills_dir().join("my-tools");
+        fs::create_dir_all(skill_dir.join("references")).unwrap();
+        fs::write(skill_dir.join("references/guide.md"), "guide").unwrap();
+        env.create_config(&SkillsConfig {
+            custom: vec!["my-tools".to_string()],
+            category_map: [("my-tools".to_string(), SkillCategory::Utilities)].into(),
+            skill_meta: [(
+                "my-tools".to_string(),
+                SkillMeta {
+                    source_id: Some("team".to_string()),
+                    source_name: Some("Team Skills".to_string()),
+                    original_hash: None,
+                },
+            )]
+            .into(),
+            ..Default::default()
+        });
+
+        let out = tempfile::tempdir().unwrap();
+        let archive_path = Skills::export("my-tools", out.path()).unwrap();
+        fs::remove_dir_all(&skill_dir).unwrap();
+        env.create_config(&SkillsConfig::default());
+
+        let skill = Skills::import_from_path(&archive_path).unwrap();
+
+        assert_eq!(skill.id, "my-tools");
+        assert!(skill.is_custom);
+        assert_eq!(skill.category, SkillCategory::Utilities);
+        assert_eq!(skill.source_name.as_deref(), Some("Team Skills"));
+        assert_eq!(
+            fs::read_to_string(skill_dir.join("references/guide.md")).unwrap(),
+            "guide"
+        );
+        assert!(!skill_dir.join(SKILL_ARCHIVE_META_FILE).exists());
+    }
+
+    #[test]
+    fn test_import_rejects_archive_with_unsafe_id() {
+        use std::io::Write;
+
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_config(&SkillsConfig::default());
+
+        let out = tempfile::tempdir().unwrap();
+        let archive_path = out.path().join("evil.zip");
+        let mut zip = zip::ZipWriter::new(fs::File::create(&archive_path).unwrap());
+        let options = zip::write::FileOptions::default();
+        zip.start_file("SKILL.md", options).unwrap();
+        zip.write_all(b"---\nname: Evil\ndescription: d\n---\n")
+            .unwrap();
+        zip.start_file(SKILL_ARCHIVE_META_FILE, options).unwrap();
+        zip.write_all(
+            br#"{"id": "../escaped", "category": "custom", "exportedAt": "2024-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+        zip.finish().unwrap();
+
+        let err = Skills::import_from_path(&archive_path).unwrap_err();
+        assert!(err.to_string().contains("Invalid skill id"), "{}", err);
+        assert!(!env.plugin_dir().join("escaped").exists());
+        assert!(Skills::load_config().unwrap().custom.is_empty());
+    }
+
+    #[test]
+    fn test_import_directory_rejects_existing_or_invalid_skill() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("taken", "Taken", "Already here", "# Taken");
+        env.create_config(&SkillsConfig::default());
+
+        let src = tempfile::tempdir().unwrap();
+        let taken = src.path().join("taken");
+        fs::create_dir_all(&taken).unwrap();
+        fs::write(
+            taken.join("SKILL.md"),
+            "---\nname: T\ndescription: d\n---\n",
+        )
+        .unwrap();
+        let err = Skills::import_from_path(&taken).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        let no_frontmatter = src.path().join("broken");
+        fs::create_dir_all(&no_frontmatter).unwrap();
+        fs::write(no_frontmatter.join("SKILL.md"), "# No frontmatter").unwrap();
+        assert!(Skills::import_from_path(&no_frontmatter).is_err());
+        assert!(!env.skills_dir().join("broken").exists());
+
+        let empty = src.path().join("empty");
+        fs::create_dir_all(&empty).unwrap();
+        let err = Skills::import_from_path(&empty).unwrap_err();
+        assert!(err.to_string().contains("no SKILL.md"));
+
+        let fresh = src.path().join("fresh");
+        fs::create_dir_all(&fresh).unwrap();
+        fs::write(
+            fresh.join("SKILL.md"),
+            "---\nname: Fresh\ndescription: d\n---\n",
+        )
+        .unwrap();
+        let skill = Skills::import_from_path(&fresh).unwrap();
+        assert!(skill.is_custom);
+        assert_eq!(skill.category, SkillCategory::Custom);
+    }
+
+    fn cached_source(url: &str) -> SkillSource {
+        SkillSource {
+            id: "team".to_string(),
+            name: "Team".to_string(),
+            source_type: SkillSourceType::Community,
+            url: url.to_string(),
+            description: "".to_string(),
+            enabled: true,
+            fetchable: true,
+            schema: SkillSchema::Standard,
+            skill_count: None,
+            branch: None,
+            skills_path: None,
+            auth_token_env: None,
+        }
+    }
+
+    fn write_cached_listing(env: &TestEnv, url: &str, age: chrono::Duration) {
+        let skill = RemoteSkill {
+            id: "cached-skill".to_string(),
+            name: "Cached".to_string(),
+            description: "From cache".to_string(),
+            category: "custom".to_string(),
+            source_id: "team".to_string(),
+            source_name: "Team".to_string(),
+            url: "https://example.com/SKILL.md".to_string(),
+            stars: None,
+            installed: false,
+        };
+        let cache = std::collections::HashMap::from([(
+            "team".to_string(),
+            CachedListing {
+                url: url.to_string(),
+                branch: DEFAULT_BRANCH.to_string(),
+                fetched_at: chrono::Utc::now() - age,
+                skills: vec![skill],
+            },
+        )]);
+        fs::write(
+            env.plugin_dir().join(".skill-cache.json"),
+            serde_json::to_string(&cache).unwrap(),
+        )
+        .unwrap();
+    }
+
+    // The sources below point at an unparseable URL, so any network attempt errors
+    const UNFETCHABLE_URL: &str = "not-a-valid-github-url";
+
+    #[test]
+    fn test_fetch_from_source_serves_fresh_cache() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        env.create_skill("cached-skill", "Cached", "Installed since", "# Cached");
+        write_cached_listing(&env, UNFETCHABLE_URL, chrono::Duration::minutes(5));
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let skills = rt
+            .block_on(Skills::fetch_from_source(
+                &cached_source(UNFETCHABLE_URL),
+                &mut Warnings::new(),
+            ))
+            .unwrap();
+
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].id, "cached-skill");
+        assert!(skills[0].installed, "installed flag is refreshed on a hit");
+    }
+
+    #[test]
+    fn test_fetch_from_source_skips_stale_or_unusable_cache() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let fetch = |url: &str| {
+            rt.block_on(Skills::fetch_from_source(
+                &cached_source(url),
+                &mut Warnings::new(),
+            ))
+        };
+
+        write_cached_listing(&env, UNFETCHABLE_URL, chrono::Duration::hours(2));
+        assert!(fetch(UNFETCHABLE_URL).is_err(), "expired entry is a miss");
 
-        // Should be removed from category_map
-        let config = Skills::load_config().unwrap();
-        assert!(!config.category_map.contains_key("my-skill"));
+        write_cached_listing(&env, "https://github.com/old/url", chrono::Duration::zero());
+        assert!(
+            fetch(UNFETCHABLE_URL).is_err(),
+            "edited source URL is a miss"
+        );
+
+        fs::write(env.plugin_dir().join(".skill-cache.json"), "{ not json").unwrap();
+        assert!(fetch(UNFETCHABLE_URL).is_err(), "corrupted cache is a miss");
+
+        write_cached_listing(&env, UNFETCHABLE_URL, chrono::Duration::zero());
+        Skills::invalidate_cache(Some("team")).unwrap();
+        assert!(
+            fetch(UNFETCHABLE_URL).is_err(),
+            "invalidated entry is a miss"
+        );
     }
 
     #[test]
-    fn test_set_category_fails_for_nonexistent_skill() {
+    fn test_cache_ttl_is_configurable() {
         let _lock = ENV_MUTEX.lock().unwrap();
         let env = TestEnv::new();
         env.setup_skills_dir();
+        assert_eq!(
+            Skills::cache_ttl(),
+            std::time::Duration::from_secs(DEFAULT_CACHE_TTL_SECS)
+        );
 
-        let result = Skills::set_category("nonexistent-skill", SkillCategory::Frontend);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("not found"));
+        Skills::set_cache_ttl(Some(0)).unwrap();
+        write_cached_listing(&env, UNFETCHABLE_URL, chrono::Duration::zero());
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let result = rt.block_on(Skills::fetch_from_source(
+            &cached_source(UNFETCHABLE_URL),
+            &mut Warnings::new(),
+        ));
+        assert!(result.is_err(), "a zero TTL disables the cache");
+    }
+
+    #[test]
+    fn test_skillignore_patterns() {
+        let ignore = SkillIgnore::parse("build/\n/secret.txt\n**/*.tmp\ndraft?.md\n");
+
+        assert!(ignore.is_ignored(Path::new("build"), true));
+        assert!(!ignore.is_ignored(Path::new("build"), false));
+        assert!(ignore.is_ignored(Path::new("secret.txt"), false));
+        assert!(!ignore.is_ignored(Path::new("docs/secret.txt"), false));
+        assert!(ignore.is_ignored(Path::new("a/b/c.tmp"), false));
+        assert!(ignore.is_ignored(Path::new("docs/draft1.md"), false));
+        assert!(!ignore.is_ignored(Path::new("SKILL.md"), false));
+    }
+
+    #[test]
+    fn test_skillignore_negation_reincludes_paths() {
+        let ignore = SkillIgnore::parse("*.md\n!SKILL.md\n!docs/*.md\n");
+
+        assert!(ignore.is_ignored(Path::new("notes.md"), false));
+        assert!(!ignore.is_ignored(Path::new("SKILL.md"), false));
+        assert!(!ignore.is_ignored(Path::new("docs/usage.md"), false));
+        assert!(ignore.is_ignored(Path::new("drafts/idea.md"), false));
     }
 
     #[test]
@@ -2176,6 +6736,7 @@ This is the content.
             description: "A new skill".to_string(),
             category: SkillCategory::Frontend,
             content: "# Content".to_string(),
+            from_dir: None,
         };
 
         let skill = Skills::create(input).unwrap();
@@ -2201,6 +6762,7 @@ This is the content.
             description: "A custom skill".to_string(),
             category: SkillCategory::Custom, // Default
             content: "# Content".to_string(),
+            from_dir: None,
         };
 
         let skill = Skills::create(input).unwrap();
@@ -2322,15 +6884,33 @@ This is the content.
         }
     }
 
+    #[test]
+    fn test_skill_category_deserialize_named_labels() {
+        let cases = vec![
+            ("\"workflow\"", SkillCategory::Named("workflow".into())),
+            ("\"DevOps\"", SkillCategory::Named("devops".into())),
+            // Built-in names are matched case-insensitively
+            ("\"CORPORATE\"", SkillCategory::Corporate),
+            ("\"Frontend\"", SkillCategory::Frontend),
+            ("\"ai-sdk\"", SkillCategory::AiSdk),
+        ];
+
+        for (json, expected) in cases {
+            let result: SkillCategory = serde_json::from_str(json).unwrap();
+            assert_eq!(result, expected, "Mismatch for '{}'", json);
+        }
+        assert_eq!(
+            serde_json::to_string(&SkillCategory::Named("devops".into())).unwrap(),
+            "\"devops\""
+        );
+    }
+
     #[test]
     fn test_skill_category_deserialize_invalid_value() {
-        // These must ALL fail — especially "workflow" which was the real bug
         let invalid_values = vec![
-            "\"workflow\"",
-            "\"invalid\"",
-            "\"CORPORATE\"", // case-sensitive: "CORPORATE" != "corporate"
-            "\"Frontend\"",  // PascalCase not accepted
-            "\"\"",          // empty string
+            "\"\"",   // empty string
+            "\"  \"", // blank
+            "42",     // not a string
         ];
 
         for json in invalid_values {
@@ -2436,25 +7016,57 @@ This is the content.
         let env = TestEnv::new();
         env.setup_skills_dir();
 
-        // Write config with "workflow" as a category value — THE BUG
+        // "workflow" is a named category; an empty value is not a category at all
         let raw_json = r#"{
             "disabled": [],
             "custom": [],
             "sources": [],
             "skillMeta": {},
             "categoryMap": {
-                "my-skill": "workflow"
+                "my-skill": "workflow",
+                "broken": ""
             }
         }"#;
 
         let config_path = env.plugin_dir().join(".skills-config.json");
         fs::write(&config_path, raw_json).unwrap();
 
-        let result = Skills::load_config();
-        assert!(
-            result.is_err(),
-            "Config with invalid category 'workflow' in categoryMap should fail deserialization"
+        // The invalid category is dropped rather than failing the whole load
+        let config = Skills::load_config().unwrap();
+        assert_eq!(config.category_map.len(), 1);
+        assert_eq!(
+            config.category_map.get("my-skill"),
+            Some(&SkillCategory::Named("workflow".into()))
         );
+        assert_eq!(
+            Skills::config_problem().unwrap(),
+            Some(SkillsConfigProblem::UnknownCategories(vec![(
+                "broken".to_string(),
+                "".to_string()
+            )]))
+        );
+
+        Skills::repair_config().unwrap();
+        assert_eq!(Skills::config_problem().unwrap(), None);
+    }
+
+    #[test]
+    fn test_repair_config_backs_up_and_resets_unreadable_config() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+
+        let config_path = env.plugin_dir().join(".skills-config.json");
+        fs::write(&config_path, "{ \"disabled\": [,] }").unwrap();
+        assert!(matches!(
+            Skills::config_problem().unwrap(),
+            Some(SkillsConfigProblem::Unreadable(e)) if e.contains("line 1")
+        ));
+
+        let backup = Skills::repair_config().unwrap().unwrap();
+        assert_eq!(fs::read_to_string(backup).unwrap(), "{ \"disabled\": [,] }");
+        assert!(Skills::load_config().is_ok());
+        assert_eq!(Skills::config_problem().unwrap(), None);
     }
 
     #[test]
@@ -2515,7 +7127,7 @@ This is the content.
         };
 
         assert_eq!(
-            Skills::get_category("unknown-skill", &config),
+            Skills::get_category("unknown-skill", &config, &BuiltinCategories::embedded()),
             SkillCategory::Testing
         );
     }
@@ -2526,15 +7138,27 @@ This is the content.
         let config = SkillsConfig::default();
 
         assert_eq!(
-            Skills::get_category("rhinolabs-standards", &config),
+            Skills::get_category(
+                "rhinolabs-standards",
+                &config,
+                &BuiltinCategories::embedded()
+            ),
             SkillCategory::Corporate
         );
         assert_eq!(
-            Skills::get_category("rhinolabs-architecture", &config),
+            Skills::get_category(
+                "rhinolabs-architecture",
+                &config,
+                &BuiltinCategories::embedded()
+            ),
             SkillCategory::Corporate
         );
         assert_eq!(
-            Skills::get_category("rhinolabs-security", &config),
+            Skills::get_category(
+                "rhinolabs-security",
+                &config,
+                &BuiltinCategories::embedded()
+            ),
             SkillCategory::Corporate
         );
     }
@@ -2544,7 +7168,11 @@ This is the content.
         let config = SkillsConfig::default();
 
         assert_eq!(
-            Skills::get_category("some-random-skill-nobody-knows", &config),
+            Skills::get_category(
+                "some-random-skill-nobody-knows",
+                &config,
+                &BuiltinCategories::embedded()
+            ),
             SkillCategory::Custom
         );
     }
@@ -2560,23 +7188,92 @@ This is the content.
             ..Default::default()
         };
 
-        // categoryMap takes priority over CORPORATE_SKILLS constant
+        // categoryMap takes priority over the built-in corporate list
         assert_eq!(
-            Skills::get_category("rhinolabs-standards", &config),
+            Skills::get_category(
+                "rhinolabs-standards",
+                &config,
+                &BuiltinCategories::embedded()
+            ),
             SkillCategory::Utilities
         );
     }
+
+    #[test]
+    fn test_github_token_env_overrides_config() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        let saved: Vec<_> = ["RHINOLABS_GITHUB_TOKEN", "GITHUB_TOKEN"]
+            .iter()
+            .map(|var| (*var, std::env::var(var).ok()))
+            .collect();
+        for (var, _) in &saved {
+            std::env::remove_var(var);
+        }
+
+        Skills::set_github_token(Some(" ghp_config ")).unwrap();
+        assert_eq!(Skills::github_token().as_deref(), Some("ghp_config"));
+
+        std::env::set_var("RHINOLABS_GITHUB_TOKEN", "ghp_env");
+        assert_eq!(Skills::github_token().as_deref(), Some("ghp_env"));
+
+        for (var, value) in saved {
+            match value {
+                Some(value) => std::env::set_var(var, value),
+                None => std::env::remove_var(var),
+            }
+        }
+    }
+
+    #[test]
+    fn test_source_auth_token_env_is_stored_by_name_only() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let env = TestEnv::new();
+        env.setup_skills_dir();
+        std::env::set_var("RHINOLABS_TEST_SOURCE_TOKEN", "ghp_private");
+
+        let mut source = SkillSource::default_sources().remove(0);
+        source.id = "internal".to_string();
+        source.auth_token_env = Some("RHINOLABS_TEST_SOURCE_TOKEN".to_string());
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(Skills::add_source(source, false)).unwrap();
+
+        let saved = Skills::list_sources()
+            .unwrap()
+            .into_iter()
+            .find(|s| s.id == "internal")
+            .unwrap();
+        assert_eq!(saved.auth_token().unwrap().as_deref(), Some("ghp_private"));
+        let config = fs::read_to_string(Skills::config_path().unwrap()).unwrap();
+        assert!(config.contains("RHINOLABS_TEST_SOURCE_TOKEN"));
+        assert!(!config.contains("ghp_private"));
+
+        std::env::remove_var("RHINOLABS_TEST_SOURCE_TOKEN");
+        let err = saved.auth_token().unwrap_err().to_string();
+        assert!(err.contains("$RHINOLABS_TEST_SOURCE_TOKEN"), "{}", err);
+
+        // A pasted token is rejected rather than saved
+        let pasted = UpdateSkillSourceInput {
+            auth_token_env: Some("ghp_abc123".to_string()),
+            ..Default::default()
+        };
+        assert!(rt
+            .block_on(Skills::update_source("internal", pasted, false))
+            .is_err());
+    }
 }
 
 #[cfg(test)]
 mod skills_sh_tests {
+    use super::tests::github_source;
     use super::*;
 
     #[test]
     fn test_extract_skills_sh_data_regular_json() {
         let html = r#"some prefix allTimeSkills":[{"source":"vercel-labs/skills","skillId":"find-skills","name":"find-skills","installs":98546},{"source":"test/repo","skillId":"test-skill","name":"Test Skill","installs":100}] more suffix"#;
 
-        let result = Skills::extract_skills_sh_data(html);
+        let result = Skills::extract_skills_sh_data(html, "allTimeSkills");
         assert!(result.is_ok(), "Should parse regular JSON: {:?}", result);
 
         let skills = result.unwrap();
@@ -2600,7 +7297,7 @@ mod skills_sh_tests {
     fn test_extract_skills_sh_data_escaped_json() {
         let html = r#"some prefix allTimeSkills\":[{\"source\":\"vercel-labs/skills\",\"skillId\":\"find-skills\",\"name\":\"find-skills\",\"installs\":98546},{\"source\":\"test/repo\",\"skillId\":\"test-skill\",\"name\":\"Test Skill\",\"installs\":100}] more suffix"#;
 
-        let result = Skills::extract_skills_sh_data(html);
+        let result = Skills::extract_skills_sh_data(html, "allTimeSkills");
         assert!(result.is_ok(), "Should parse escaped JSON: {:?}", result);
 
         let skills = result.unwrap();
@@ -2616,7 +7313,7 @@ mod skills_sh_tests {
     fn test_extract_skills_sh_data_deduplicates() {
         let html = r#"first [{"source":"a/b","skillId":"skill-1","name":"S1","installs":10}] second [{"source":"a/b","skillId":"skill-1","name":"S1","installs":10}]"#;
 
-        let result = Skills::extract_skills_sh_data(html);
+        let result = Skills::extract_skills_sh_data(html, "allTimeSkills");
         assert!(result.is_ok());
 
         let skills = result.unwrap();
@@ -2626,7 +7323,7 @@ mod skills_sh_tests {
     #[test]
     fn test_extract_skills_sh_data_no_data() {
         let html = "<html><body>No skills here</body></html>";
-        let result = Skills::extract_skills_sh_data(html);
+        let result = Skills::extract_skills_sh_data(html, "allTimeSkills");
         assert!(result.is_err(), "Should error on empty data");
     }
 
@@ -2634,7 +7331,7 @@ mod skills_sh_tests {
     fn test_extract_skills_sh_data_skips_missing_fields() {
         // Objects missing skillId should be skipped
         let html = r#"[{"source":"a/b","name":"no-id","installs":1},{"source":"c/d","skillId":"valid","name":"Valid","installs":2}]"#;
-        let result = Skills::extract_skills_sh_data(html);
+        let result = Skills::extract_skills_sh_data(html, "allTimeSkills");
         assert!(result.is_ok());
         let skills = result.unwrap();
         assert_eq!(skills.len(), 1);
@@ -2649,7 +7346,7 @@ mod skills_sh_tests {
         // First object has truncated value (no closing quote on source), second is valid
         let html =
             r#"{"source":"bad} {"source":"a/b","skillId":"good","name":"Good","installs":5}"#;
-        let result = Skills::extract_skills_sh_data(html);
+        let result = Skills::extract_skills_sh_data(html, "allTimeSkills");
         assert!(result.is_ok());
         let skills = result.unwrap();
         assert_eq!(skills.len(), 1);
@@ -2672,17 +7369,100 @@ mod skills_sh_tests {
             ));
             html.push(',');
         }
-        let result = Skills::extract_skills_sh_data(&html);
+        let result = Skills::extract_skills_sh_data(&html, "allTimeSkills");
         assert!(result.is_ok());
         let skills = result.unwrap();
         assert_eq!(skills.len(), 10, "Should deduplicate to 10 unique skills");
     }
 
+    #[test]
+    fn test_extract_skills_sh_data_reads_only_the_named_array() {
+        let html = r#"{"hotSkills":[{"source":"a/b","skillId":"hot-one","name":"Hot","installs":1}],"allTimeSkills":[{"source":"c/d","skillId":"classic","name":"Classic","installs":9}]}"#;
+
+        let hot = Skills::extract_skills_sh_data(html, "hotSkills").unwrap();
+        assert_eq!(hot.len(), 1);
+        assert_eq!(hot[0]["skillId"], "hot-one");
+
+        let all_time = Skills::extract_skills_sh_data(html, "allTimeSkills").unwrap();
+        assert_eq!(all_time.len(), 1);
+        assert_eq!(all_time[0]["skillId"], "classic");
+
+        let escaped = html.replace('"', "\\\"");
+        let all_time = Skills::extract_skills_sh_data(&escaped, "allTimeSkills").unwrap();
+        assert_eq!(all_time[0]["skillId"], "classic");
+    }
+
+    #[test]
+    fn test_extract_skills_sh_data_parses_brackets_inside_skills() {
+        let html = r#"{"hotSkills":[{"source":"a/b","skillId":"beta","name":"Beta [preview]","tags":["x","y"],"installs":1},{"source":"a/b","skillId":"second","name":"Second","installs":2}],"allTimeSkills":[{"source":"c/d","skillId":"classic","name":"Classic","installs":9}]}"#;
+
+        let hot = Skills::extract_skills_sh_data(html, "hotSkills").unwrap();
+        let ids: Vec<_> = hot.iter().map(|s| s["skillId"].as_str().unwrap()).collect();
+        assert_eq!(ids, vec!["beta", "second"]);
+
+        let escaped = format!("self.__next_f.push([1,\"{}\"])", html.replace('"', "\\\""));
+        let hot = Skills::extract_skills_sh_data(&escaped, "hotSkills").unwrap();
+        assert_eq!(hot.len(), 2);
+        assert_eq!(hot[0]["name"], "Beta [preview]");
+    }
+
+    #[test]
+    fn test_extract_skills_sh_data_falls_back_to_the_first_array() {
+        let html = r#"{"featured":[{"source":"a/b","skillId":"first","name":"First","installs":1}],"other":[{"source":"c/d","skillId":"later","name":"Later","installs":2}]}"#;
+
+        let skills = Skills::extract_skills_sh_data(html, "trendingSkills").unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0]["skillId"], "first");
+    }
+
+    #[test]
+    fn test_skills_sh_sort_from_str() {
+        assert_eq!(
+            "Trending".parse::<SkillsShSort>().unwrap(),
+            SkillsShSort::Trending
+        );
+        assert_eq!(
+            "all-time".parse::<SkillsShSort>().unwrap(),
+            SkillsShSort::AllTime
+        );
+        assert!("popular".parse::<SkillsShSort>().is_err());
+    }
+
+    #[test]
+    fn test_skills_sh_query_url() {
+        let query = |sort, category: Option<&str>| SkillsShQuery {
+            sort,
+            category: category.map(String::from),
+        };
+
+        assert_eq!(
+            query(SkillsShSort::Hot, None).url("https://skills.sh"),
+            "https://skills.sh/hot"
+        );
+        assert_eq!(
+            query(SkillsShSort::Trending, Some("react")).url("https://skills.sh/"),
+            "https://skills.sh/trending/react"
+        );
+        assert_eq!(
+            query(SkillsShSort::AllTime, None).url("http://localhost:9000/"),
+            "http://localhost:9000/all-time"
+        );
+        assert_eq!(
+            query(SkillsShSort::Hot, Some("web dev/ui")).url("https://skills.sh"),
+            "https://skills.sh/hot/web%20dev%2Fui"
+        );
+        // A source pointing at a specific page is left alone
+        assert_eq!(
+            query(SkillsShSort::New, None).url("https://skills.sh/custom-page"),
+            "https://skills.sh/custom-page"
+        );
+    }
+
     #[test]
     fn test_extract_prefers_regular_over_escaped() {
         // If regular JSON is found, escaped fallback should NOT run
         let html = r#"[{"source":"a/b","skillId":"regular","name":"Regular","installs":1}] also {\"source\":\"c/d\",\"skillId\":\"escaped\",\"name\":\"Escaped\",\"installs\":2}"#;
-        let result = Skills::extract_skills_sh_data(html);
+        let result = Skills::extract_skills_sh_data(html, "allTimeSkills");
         assert!(result.is_ok());
         let skills = result.unwrap();
         // Regular format found first, so escaped fallback is skipped
@@ -2704,9 +7484,12 @@ mod skills_sh_tests {
             fetchable: true,
             schema: SkillSchema::Standard,
             skill_count: None,
+            branch: None,
+            skills_path: None,
+            auth_token_env: None,
         };
         let rt = tokio::runtime::Runtime::new().unwrap();
-        let result = rt.block_on(Skills::fetch_from_source(&source));
+        let result = rt.block_on(Skills::fetch_from_source(&source, &mut Warnings::new()));
         // Should fail because URL is invalid GitHub, NOT because of skills.sh parsing
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
@@ -2729,15 +7512,733 @@ mod skills_sh_tests {
             fetchable: true,
             schema: SkillSchema::Custom,
             skill_count: None,
+            branch: None,
+            skills_path: None,
+            auth_token_env: None,
         };
         let rt = tokio::runtime::Runtime::new().unwrap();
-        let result = rt.block_on(Skills::fetch_from_source(&source));
+        let result = rt.block_on(Skills::fetch_from_source(&source, &mut Warnings::new()));
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
             .to_string()
             .contains("cannot be fetched automatically"));
     }
+
+    #[tokio::test]
+    async fn test_fetch_from_github_reports_parse_failure_as_warning() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "name": "good-skill", "type": "dir" },
+                { "name": "broken-skill", "type": "dir" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/acme/skills-repo/main/skills/good-skill/SKILL.md"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("---\nname: Good Skill\ndescription: Works\n---\n\n# Good"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/acme/skills-repo/main/skills/broken-skill/SKILL.md"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("# No frontmatter"))
+            .mount(&server)
+            .await;
+
+        let source = SkillSource {
+            id: "acme".to_string(),
+            name: "Acme".to_string(),
+            source_type: SkillSourceType::Community,
+            url: "https://github.com/acme/skills-repo".to_string(),
+            description: "".to_string(),
+            enabled: true,
+            fetchable: true,
+            schema: SkillSchema::Standard,
+            skill_count: None,
+            branch: None,
+            skills_path: None,
+            auth_token_env: None,
+        };
+
+        let mut warnings = Warnings::new();
+        let skills = Skills::fetch_from_github_at(
+            &source,
+            &server.uri(),
+            &server.uri(),
+            None,
+            &mut warnings,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].id, "good-skill");
+        assert_eq!(warnings.len(), 1);
+        let warning = warnings.iter().next().unwrap();
+        assert!(warning.contains("Failed to parse SKILL.md for 'broken-skill'"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_github_sorts_concurrent_results_by_id() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let ids = ["zeta", "alpha", "mid"];
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(
+                    ids.iter()
+                        .map(|id| serde_json::json!({ "name": id, "type": "dir" }))
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .mount(&server)
+            .await;
+        for (i, id) in ids.iter().enumerate() {
+            // Earlier listings answer slower, so completion order differs from both orders
+            Mock::given(method("GET"))
+                .and(path(format!(
+                    "/acme/skills-repo/main/skills/{}/SKILL.md",
+                    id
+                )))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_string(format!("---\nname: {}\ndescription: d\n---\n", id))
+                        .set_delay(std::time::Duration::from_millis(60 - 20 * i as u64)),
+                )
+                .mount(&server)
+                .await;
+        }
+
+        let mut warnings = Warnings::new();
+        let skills = Skills::fetch_from_github_at(
+            &github_source(),
+            &server.uri(),
+            &server.uri(),
+            None,
+            &mut warnings,
+        )
+        .await
+        .unwrap();
+
+        let fetched: Vec<&str> = skills.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(fetched, vec!["alpha", "mid", "zeta"]);
+        assert!(warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_github_sends_bearer_token() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .and(header("Authorization", "Bearer ghp_test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "name": "good-skill", "type": "dir" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/acme/skills-repo/main/skills/good-skill/SKILL.md"))
+            .and(header("Authorization", "Bearer ghp_test"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("---\nname: Good Skill\ndescription: Works\n---\n\n# Good"),
+            )
+            .mount(&server)
+            .await;
+
+        let mut warnings = Warnings::new();
+        let skills = Skills::fetch_from_github_at(
+            &github_source(),
+            &server.uri(),
+            &server.uri(),
+            Some("ghp_test"),
+            &mut warnings,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(skills.len(), 1);
+        assert!(warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_github_explains_missing_repo_by_token() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let mut source = github_source();
+        let err = Skills::fetch_from_github_at(
+            &source,
+            &server.uri(),
+            &server.uri(),
+            None,
+            &mut Warnings::new(),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("not found in acme/skills-repo"), "{}", err);
+        assert!(err.contains("If it is private"), "{}", err);
+
+        source.auth_token_env = Some("ACME_TOKEN".to_string());
+        let err = Skills::fetch_from_github_at(
+            &source,
+            &server.uri(),
+            &server.uri(),
+            Some("ghp_test"),
+            &mut Warnings::new(),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("$ACME_TOKEN cannot access it"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_github_distinguishes_rate_limit_from_auth_failure() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let rate_limited = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .insert_header("X-RateLimit-Remaining", "0")
+                    .insert_header("X-RateLimit-Reset", "1700000000"),
+            )
+            .mount(&rate_limited)
+            .await;
+
+        let err = Skills::fetch_from_github_at(
+            &github_source(),
+            &rate_limited.uri(),
+            &rate_limited.uri(),
+            None,
+            &mut Warnings::new(),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("rate limit exceeded"), "{}", err);
+        assert!(err.contains("GITHUB_TOKEN"), "{}", err);
+
+        let forbidden = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .respond_with(ResponseTemplate::new(403).insert_header("X-RateLimit-Remaining", "4999"))
+            .mount(&forbidden)
+            .await;
+
+        let err = Skills::fetch_from_github_at(
+            &github_source(),
+            &forbidden.uri(),
+            &forbidden.uri(),
+            Some("ghp_revoked"),
+            &mut Warnings::new(),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("authentication failed"), "{}", err);
+        assert!(!err.contains("rate limit"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_github_uses_configured_branch() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .and(query_param("ref", "v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "name": "good-skill", "type": "dir" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/acme/skills-repo/v2/skills/good-skill/SKILL.md"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("---\nname: Good Skill\ndescription: Works\n---\n\n# Good"),
+            )
+            .mount(&server)
+            .await;
+
+        let mut source = github_source();
+        source.branch = Some("v2".to_string());
+        let mut warnings = Warnings::new();
+        let skills = Skills::fetch_from_github_at(
+            &source,
+            &server.uri(),
+            &server.uri(),
+            None,
+            &mut warnings,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(skills.len(), 1);
+        assert!(skills[0].url.contains("/v2/skills/good-skill/"));
+        assert!(warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_github_reads_configured_skills_path() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/packages/agents"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "name": "good-skill", "type": "dir" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/acme/skills-repo/main/packages/agents/good-skill/SKILL.md",
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("---\nname: Good Skill\ndescription: Works\n---\n\n# Good"),
+            )
+            .mount(&server)
+            .await;
+
+        let mut source = github_source();
+        source.skills_path = Some("/packages/agents/".to_string());
+        let mut warnings = Warnings::new();
+        let skills = Skills::fetch_from_github_at(
+            &source,
+            &server.uri(),
+            &server.uri(),
+            None,
+            &mut warnings,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].id, "good-skill");
+        assert!(warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_github_treats_root_skill_md_as_single_skill() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "name": "SKILL.md", "type": "file" },
+                { "name": "scripts", "type": "dir" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/acme/skills-repo/main/SKILL.md"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("---\nname: Solo\ndescription: One skill\n---\n\n# Solo"),
+            )
+            .mount(&server)
+            .await;
+
+        let mut source = github_source();
+        source.skills_path = Some(String::new());
+        let mut warnings = Warnings::new();
+        let skills = Skills::fetch_from_github_at(
+            &source,
+            &server.uri(),
+            &server.uri(),
+            None,
+            &mut warnings,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].id, "skills-repo");
+        assert_eq!(skills[0].name, "Solo");
+        assert!(warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_validate_source_counts_skill_dirs_without_fetching_them() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "name": "one", "type": "dir" },
+                { "name": "two", "type": "dir" },
+                { "name": "README.md", "type": "file" }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let source = github_source();
+        let count = Skills::validate_source_at(&source, &server.uri())
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+
+        // A typo'd repo fails up front with the missing path in the message
+        let mut typo = github_source();
+        typo.url = "https://github.com/acme/skils-repo".to_string();
+        let err = Skills::validate_source_at(&typo, &server.uri())
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("acme/skils-repo"), "{}", err);
+
+        typo.schema = SkillSchema::Custom;
+        let err = Skills::validate_source_at(&typo, &server.uri())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("browse-only"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_github_falls_back_to_master() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .and(query_param("ref", "main"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/branches/main"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .and(query_param("ref", "master"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "name": "good-skill", "type": "dir" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/acme/skills-repo/master/skills/good-skill/SKILL.md"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("---\nname: Good Skill\ndescription: Works\n---\n\n# Good"),
+            )
+            .mount(&server)
+            .await;
+
+        let mut warnings = Warnings::new();
+        let skills = Skills::fetch_from_github_at(
+            &github_source(),
+            &server.uri(),
+            &server.uri(),
+            None,
+            &mut warnings,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(skills.len(), 1);
+        assert!(skills[0].url.contains("/master/skills/good-skill/"));
+        let warning = warnings.iter().next().unwrap();
+        assert!(warning.contains("Branch 'main' not found"), "{}", warning);
+        assert!(warning.contains("using 'master'"), "{}", warning);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_github_keeps_an_existing_branch() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .and(query_param("ref", "main"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/branches/main"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "name": "main"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills"))
+            .and(query_param("ref", "master"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "name": "stale-skill", "type": "dir" }
+            ])))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        // The branch is there, only the skills path is missing: no fallback
+        let mut warnings = Warnings::new();
+        let err = Skills::fetch_from_github_at(
+            &github_source(),
+            &server.uri(),
+            &server.uri(),
+            None,
+            &mut warnings,
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("'skills' not found"), "{}", err);
+        assert!(warnings.is_empty());
+
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+        let err = Skills::fetch_github_skill_files_at(
+            &server.uri(),
+            "https://github.com/acme/skills-repo",
+            "missing-skill",
+            "main",
+            "skills",
+            None,
+            GLOBAL_TOKEN_VARS,
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("(tried branch main)"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_github_directory_contents_reports_missing_branch() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo/contents/skills/good-skill"))
+            .and(query_param("ref", "main"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        let github = GitHubRepo {
+            api_base: &uri,
+            owner: "acme",
+            repo: "skills-repo",
+            branch: "main",
+            token: None,
+            token_var: GLOBAL_TOKEN_VARS,
+        };
+        let mut files = Vec::new();
+        let found = Skills::fetch_github_directory_contents(
+            &reqwest::Client::new(),
+            &github,
+            "skills/good-skill",
+            "",
+            &mut files,
+        )
+        .await
+        .unwrap();
+
+        assert!(!found);
+        assert!(files.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_github_directory_contents_sends_authorization_header() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/repos/acme/private-repo/contents/skills/secret-skill",
+            ))
+            .and(header("authorization", "Bearer ghp_test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        let github = GitHubRepo {
+            api_base: &uri,
+            owner: "acme",
+            repo: "private-repo",
+            branch: "main",
+            token: Some("ghp_test"),
+            token_var: "$ACME_TOKEN",
+        };
+        let found = Skills::fetch_github_directory_contents(
+            &reqwest::Client::new(),
+            &github,
+            "skills/secret-skill",
+            "",
+            &mut Vec::new(),
+        )
+        .await
+        .unwrap();
+        assert!(found);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_github_skill_files_tells_private_repo_from_missing_skill() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Every contents request 404s; only the public repo itself is visible
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/skills-repo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+
+        let fetch = |repo: &'static str, token: Option<&'static str>, token_var: &'static str| {
+            let uri = server.uri();
+            async move {
+                Skills::fetch_github_skill_files_at(
+                    &uri,
+                    &format!("https://github.com/acme/{}", repo),
+                    "missing-skill",
+                    "main",
+                    "skills",
+                    token,
+                    token_var,
+                )
+                .await
+                .unwrap_err()
+                .to_string()
+            }
+        };
+
+        let err = fetch("skills-repo", None, GLOBAL_TOKEN_VARS).await;
+        assert!(
+            err.contains("Skill 'missing-skill' not found in acme/skills-repo"),
+            "{}",
+            err
+        );
+
+        let err = fetch("private-repo", None, GLOBAL_TOKEN_VARS).await;
+        assert!(
+            err.contains("Repository acme/private-repo not found"),
+            "{}",
+            err
+        );
+        assert!(
+            err.contains("set GITHUB_TOKEN or RHINOLABS_GITHUB_TOKEN"),
+            "{}",
+            err
+        );
+
+        // The hint names the source's own token variable when the token came from it
+        let err = fetch("private-repo", Some("ghp_test"), "$ACME_TOKEN").await;
+        assert!(
+            err.contains("or the token in $ACME_TOKEN cannot access it"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_parse_github_skill_url() {
+        let parsed =
+            GitHubSkillUrl::parse("https://github.com/acme/skills-repo/tree/main/skills/foo")
+                .unwrap();
+        assert_eq!(
+            parsed,
+            GitHubSkillUrl {
+                owner: "acme".into(),
+                repo: "skills-repo".into(),
+                branch: "main".into(),
+                skills_path: "skills".into(),
+                skill_id: "foo".into(),
+                ref_path: vec!["main".into(), "skills".into(), "foo".into()],
+            }
+        );
+
+        let nested = parsed.with_ref_len(2);
+        assert_eq!(
+            (nested.branch.as_str(), nested.skills_path.as_str()),
+            ("main/skills", "")
+        );
+        assert_eq!(nested.skill_id, "foo");
+
+        let blob = GitHubSkillUrl::parse(
+            "https://github.com/acme/skills-repo/blob/v2/skills/foo/SKILL.md?plain=1",
+        )
+        .unwrap();
+        assert_eq!(blob.branch, "v2");
+        assert_eq!(
+            (blob.skills_path.as_str(), blob.skill_id.as_str()),
+            ("skills", "foo")
+        );
+
+        // A link to the repo root is a flat single-skill repo
+        let root = GitHubSkillUrl::parse("https://github.com/acme/solo/tree/main").unwrap();
+        assert_eq!(
+            (root.skills_path.as_str(), root.skill_id.as_str()),
+            ("", "solo")
+        );
+
+        assert!(GitHubSkillUrl::parse("https://github.com/acme/skills-repo").is_err());
+        assert!(GitHubSkillUrl::parse("https://github.com/acme/repo/pulls/1/x").is_err());
+        let err = GitHubSkillUrl::parse("https://gitlab.com/acme/repo/tree/main/skills/foo")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("gitlab.com"), "{}", err);
+    }
+
+    #[test]
+    fn test_token_only_sent_to_github_hosts() {
+        let token = Some("ghp_test".to_string());
+        assert_eq!(
+            Skills::token_for_url("https://raw.githubusercontent.com/a/b/main/x", &token),
+            Some("ghp_test")
+        );
+        assert_eq!(
+            Skills::token_for_url("https://example.com/SKILL.md", &token),
+            None
+        );
+    }
 }
 
 #[cfg(test)]
@@ -2756,9 +8257,12 @@ mod skills_sh_integration_tests {
             fetchable: true,
             schema: SkillSchema::SkillsSh,
             skill_count: None,
+            branch: None,
+            skills_path: None,
+            auth_token_env: None,
         };
 
-        let result = Skills::fetch_from_source(&source).await;
+        let result = Skills::fetch_from_source(&source, &mut Warnings::new()).await;
         println!("Result: {:?}", result);
 
         assert!(result.is_ok(), "Should fetch skills: {:?}", result);
@@ -1,7 +1,7 @@
 use std::fs;
 use std::path::Path;
 
-use crate::{fs_utils, McpConfig, McpConfigManager, Paths, Result};
+use crate::{fs_utils, McpConfig, McpConfigManager, Paths, Result, Warnings};
 
 use super::{
     DeployTarget, InstructionsDeployer, McpDeployer, SkillDeployer, TargetDetector, TargetPaths,
@@ -75,7 +75,7 @@ impl InstructionsDeployer for ClaudeCodeDeployer {
             fs::create_dir_all(parent)?;
         }
 
-        fs::write(&path, content)?;
+        crate::fs_utils::write(&path, content)?;
         Ok(())
     }
 
@@ -86,7 +86,7 @@ impl InstructionsDeployer for ClaudeCodeDeployer {
             fs::create_dir_all(parent)?;
         }
 
-        fs::write(&path, content)?;
+        crate::fs_utils::write(&path, content)?;
         Ok(())
     }
 
@@ -119,8 +119,12 @@ impl McpDeployer for ClaudeCodeDeployer {
         DeployTarget::ClaudeCode
     }
 
-    fn deploy_mcp(&self, config: &McpConfig) -> Result<()> {
-        McpConfigManager::update(config)
+    /// Writes `config` with its `${VAR}` placeholders intact (Claude Code expands
+    /// them at load time); under `strict_env` unset variables are rejected first.
+    fn deploy_mcp(&self, config: &McpConfig) -> Result<Warnings> {
+        McpConfigManager::resolve_env(config)?;
+        McpConfigManager::update(config)?;
+        Ok(Warnings::new())
     }
 
     fn read_mcp(&self) -> Result<Option<McpConfig>> {
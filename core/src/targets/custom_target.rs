@@ -0,0 +1,292 @@
+use std::path::{Component, Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{DeployTarget, Paths, Result, RhinolabsError};
+
+/// A user-defined deploy target, configured in `~/.config/rhinolabs-ai/targets.json`.
+///
+/// Custom targets follow the same layout as the built-ins: skills are deployed to
+/// `{project}/{skills_prefix}/` (or `~/{skills_prefix}/` for user installs) and the
+/// instructions file is written next to them at the project root.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomTarget {
+    pub id: String,
+    #[serde(alias = "display_name")]
+    pub display_name: String,
+    /// Skills directory relative to the project root, e.g. `.mytool/skills`
+    #[serde(alias = "skills_prefix")]
+    pub skills_prefix: String,
+    #[serde(alias = "instructions_filename")]
+    pub instructions_filename: String,
+}
+
+/// The configured custom targets, read from `targets.json` once per process so
+/// `DeployTarget` can hold a `&'static` reference and stay `Copy`. A config that
+/// failed to load keeps its error.
+static REGISTRY: OnceLock<std::result::Result<Vec<CustomTarget>, String>> = OnceLock::new();
+
+/// Ids (and aliases) reserved by the built-in targets and the `all` keyword
+const RESERVED_IDS: &[&str] = &[
+    "claude-code",
+    "claude",
+    "amp",
+    "antigravity",
+    "gemini",
+    "open-code",
+    "opencode",
+    "all",
+];
+
+impl CustomTarget {
+    /// Path to the custom targets file
+    pub fn config_path() -> Result<PathBuf> {
+        Ok(Paths::rhinolabs_config_dir()?.join("targets.json"))
+    }
+
+    /// The configured custom targets (none if the file does not exist). The file is
+    /// read the first time this is called; later edits apply to the next process.
+    pub fn load_all() -> Result<&'static [CustomTarget]> {
+        REGISTRY
+            .get_or_init(|| {
+                Self::config_path()
+                    .and_then(|path| Self::read_config(&path))
+                    .map_err(|e| e.to_string())
+            })
+            .as_deref()
+            .map_err(|e| RhinolabsError::ConfigError(e.clone()))
+    }
+
+    /// Find a configured custom target by id
+    pub fn find(id: &str) -> Result<Option<&'static CustomTarget>> {
+        Ok(Self::load_all()?.iter().find(|t| t.id == id))
+    }
+
+    /// Read and validate the targets in `path`. A target may not reuse the id, skills
+    /// directory or instructions file of a built-in target or of another custom target.
+    fn read_config(path: &Path) -> Result<Vec<CustomTarget>> {
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = std::fs::read_to_string(path)?;
+        let targets: Vec<CustomTarget> = serde_json::from_str(&content).map_err(|e| {
+            RhinolabsError::ConfigError(format!("Invalid {}: {}", path.display(), e))
+        })?;
+
+        for (index, target) in targets.iter().enumerate() {
+            target.validate()?;
+            if targets[..index].iter().any(|t| t.id == target.id) {
+                return Err(RhinolabsError::ConfigError(format!(
+                    "Custom target '{}' is defined more than once",
+                    target.id
+                )));
+            }
+
+            let taken = DeployTarget::all()
+                .iter()
+                .map(|t| {
+                    (
+                        t.display_name(),
+                        t.project_skills_prefix(),
+                        t.instructions_filename(),
+                    )
+                })
+                .chain(targets[..index].iter().map(|t| {
+                    (
+                        t.display_name.as_str(),
+                        t.skills_prefix.trim_end_matches('/'),
+                        t.instructions_filename.as_str(),
+                    )
+                }));
+            for (owner, prefix, filename) in taken {
+                if prefixes_overlap(&target.skills_prefix, prefix) {
+                    return Err(RhinolabsError::ConfigError(format!(
+                        "Custom target '{}': skills_prefix '{}' overlaps {}'s skills directory '{}'",
+                        target.id, target.skills_prefix, owner, prefix
+                    )));
+                }
+                if target.instructions_filename.eq_ignore_ascii_case(filename) {
+                    return Err(RhinolabsError::ConfigError(format!(
+                        "Custom target '{}': instructions_filename '{}' is already written by {}",
+                        target.id, target.instructions_filename, owner
+                    )));
+                }
+            }
+        }
+
+        Ok(targets)
+    }
+
+    /// The target's config directory name: the first component of `skills_prefix`
+    pub fn config_dir_name(&self) -> &str {
+        self.skills_prefix
+            .split('/')
+            .find(|part| !part.is_empty())
+            .unwrap_or(&self.skills_prefix)
+    }
+
+    fn validate(&self) -> Result<()> {
+        let invalid = |reason: &str| {
+            Err(RhinolabsError::ConfigError(format!(
+                "Custom target '{}': {}",
+                self.id, reason
+            )))
+        };
+
+        if self.id.is_empty()
+            || !self
+                .id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return invalid("id must be lowercase letters, digits and dashes");
+        }
+        if RESERVED_IDS.contains(&self.id.as_str()) {
+            return invalid("id is reserved by a built-in target");
+        }
+
+        let prefix = Path::new(&self.skills_prefix);
+        if self.skills_prefix.is_empty()
+            || !prefix
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)))
+        {
+            return invalid("skills_prefix must be a relative path inside the project");
+        }
+
+        if matches!(self.instructions_filename.as_str(), "" | "." | "..")
+            || self.instructions_filename.contains(['/', '\\'])
+        {
+            return invalid("instructions_filename must be a plain file name");
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether two skills directories are the same or one contains the other
+fn prefixes_overlap(a: &str, b: &str) -> bool {
+    let a = Path::new(a.trim_end_matches('/'));
+    let b = Path::new(b.trim_end_matches('/'));
+    a.starts_with(b) || b.starts_with(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(id: &str, prefix: &str, filename: &str) -> CustomTarget {
+        CustomTarget {
+            id: id.to_string(),
+            display_name: id.to_string(),
+            skills_prefix: prefix.to_string(),
+            instructions_filename: filename.to_string(),
+        }
+    }
+
+    fn write_targets(json: &str) -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("targets.json");
+        std::fs::write(&path, json).unwrap();
+        (dir, path)
+    }
+
+    fn entry(id: &str, prefix: &str, filename: &str) -> String {
+        format!(
+            r#"{{"id": "{}", "displayName": "{}", "skillsPrefix": "{}", "instructionsFilename": "{}"}}"#,
+            id, id, prefix, filename
+        )
+    }
+
+    #[test]
+    fn test_read_config_without_file_is_empty() {
+        let dir = tempfile::TempDir::new().unwrap();
+
+        let targets = CustomTarget::read_config(&dir.path().join("targets.json")).unwrap();
+        assert!(targets.is_empty());
+    }
+
+    #[test]
+    fn test_read_config_accepts_snake_case_fields() {
+        let (_dir, path) = write_targets(
+            r#"[{
+                "id": "acme-ai",
+                "display_name": "Acme AI",
+                "skills_prefix": ".acme/skills",
+                "instructions_filename": "ACME.md"
+            }]"#,
+        );
+
+        let targets = CustomTarget::read_config(&path).unwrap();
+
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].display_name, "Acme AI");
+        assert_eq!(targets[0].config_dir_name(), ".acme");
+    }
+
+    #[test]
+    fn test_validate_rejects_unsafe_definitions() {
+        assert!(target("acme", ".acme/skills", "ACME.md").validate().is_ok());
+        assert!(target("amp", ".amp/skills", "AMP.md").validate().is_err());
+        assert!(target("Acme", ".acme/skills", "ACME.md")
+            .validate()
+            .is_err());
+        assert!(target("acme", "../outside", "ACME.md").validate().is_err());
+        assert!(target("acme", "/etc/skills", "ACME.md").validate().is_err());
+        assert!(target("acme", ".acme/skills", "docs/ACME.md")
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_read_config_rejects_duplicate_ids() {
+        let entry = entry("acme", ".acme/skills", "ACME.md");
+        let (_dir, path) = write_targets(&format!("[{}, {}]", entry, entry));
+
+        let err = CustomTarget::read_config(&path).unwrap_err();
+        assert!(err.to_string().contains("more than once"));
+    }
+
+    #[test]
+    fn test_read_config_rejects_collisions_with_built_in_targets() {
+        for (prefix, filename, expected) in [
+            (
+                ".claude/skills",
+                "ACME.md",
+                "Claude Code's skills directory",
+            ),
+            (
+                ".claude/skills/acme/",
+                "ACME.md",
+                "Claude Code's skills directory",
+            ),
+            (".agent", "ACME.md", "Antigravity's skills directory"),
+            (".acme/skills", "AGENTS.md", "already written by Amp"),
+            (
+                ".acme/skills",
+                "claude.md",
+                "already written by Claude Code",
+            ),
+        ] {
+            let (_dir, path) = write_targets(&format!("[{}]", entry("acme", prefix, filename)));
+
+            let err = CustomTarget::read_config(&path).unwrap_err().to_string();
+            assert!(err.contains(expected), "{}: {}", prefix, err);
+        }
+    }
+
+    #[test]
+    fn test_read_config_rejects_collisions_between_custom_targets() {
+        let (_dir, path) = write_targets(&format!(
+            "[{}, {}]",
+            entry("acme", ".acme/skills", "ACME.md"),
+            entry("zeta", ".zeta/skills", "ACME.md")
+        ));
+
+        let err = CustomTarget::read_config(&path).unwrap_err().to_string();
+        assert!(err.contains("already written by acme"), "{}", err);
+    }
+}
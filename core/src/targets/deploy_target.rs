@@ -1,21 +1,25 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::str::FromStr;
 
-use crate::Paths;
+use crate::{Paths, Result};
+
+use super::CustomTarget;
 
 /// Represents a supported AI coding agent target for deployment.
 ///
 /// Each variant corresponds to a different AI coding assistant that
 /// rhinolabs-ai can deploy skills, instructions, and MCP config to.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
+/// `Custom` targets come from `targets.json` (see [`CustomTarget`]).
+/// Targets serialize as their kebab-case id, e.g. `"claude-code"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum DeployTarget {
     #[default]
     ClaudeCode,
     Amp,
     Antigravity,
     OpenCode,
+    Custom(&'static CustomTarget),
 }
 
 impl DeployTarget {
@@ -29,6 +33,42 @@ impl DeployTarget {
         ]
     }
 
+    /// Returns the built-in targets followed by any configured custom targets.
+    pub fn all_with_custom() -> Result<Vec<DeployTarget>> {
+        let mut targets = Self::all().to_vec();
+        targets.extend(CustomTarget::load_all()?.iter().map(DeployTarget::Custom));
+        Ok(targets)
+    }
+
+    /// Parses `s` against the built-in targets and the given custom targets.
+    fn parse_with_custom(
+        s: &str,
+        custom: &'static [CustomTarget],
+    ) -> std::result::Result<Self, String> {
+        if let Some(target) = custom.iter().find(|t| t.id == s) {
+            return Ok(DeployTarget::Custom(target));
+        }
+
+        let mut valid = vec!["claude-code", "amp", "antigravity", "open-code"];
+        valid.extend(custom.iter().map(|t| t.id.as_str()));
+        Err(format!(
+            "Unknown target '{}'. Valid: {}",
+            s,
+            valid.join(", ")
+        ))
+    }
+
+    /// Returns the identifier used on the command line and in serialized form.
+    pub fn id(&self) -> &'static str {
+        match self {
+            DeployTarget::ClaudeCode => "claude-code",
+            DeployTarget::Amp => "amp",
+            DeployTarget::Antigravity => "antigravity",
+            DeployTarget::OpenCode => "open-code",
+            DeployTarget::Custom(custom) => &custom.id,
+        }
+    }
+
     /// Returns the human-readable display name for this target.
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -36,6 +76,7 @@ impl DeployTarget {
             DeployTarget::Amp => "Amp",
             DeployTarget::Antigravity => "Antigravity",
             DeployTarget::OpenCode => "OpenCode",
+            DeployTarget::Custom(custom) => &custom.display_name,
         }
     }
 
@@ -46,19 +87,28 @@ impl DeployTarget {
             DeployTarget::Amp => "AGENTS.md",
             DeployTarget::Antigravity => "GEMINI.md",
             DeployTarget::OpenCode => "opencode.json",
+            DeployTarget::Custom(custom) => &custom.instructions_filename,
         }
     }
 
     /// Returns the MCP configuration filename used by this target.
+    /// Custom targets fall back to `.mcp.json`.
     pub fn mcp_config_filename(&self) -> &'static str {
         match self {
             DeployTarget::ClaudeCode => ".mcp.json",
             DeployTarget::Amp => "settings.json",
             DeployTarget::Antigravity => "config.json",
             DeployTarget::OpenCode => "opencode.json",
+            DeployTarget::Custom(_) => ".mcp.json",
         }
     }
 
+    /// Whether MCP servers can be deployed to this target. Custom targets have no
+    /// known MCP config format.
+    pub fn supports_mcp(&self) -> bool {
+        !matches!(self, DeployTarget::Custom(_))
+    }
+
     /// Returns the project-level skills path prefix for use in instructions content.
     ///
     /// Used when generating instructions files (CLAUDE.md, AGENTS.md, etc.)
@@ -69,6 +119,7 @@ impl DeployTarget {
             DeployTarget::Amp => ".agents/skills",
             DeployTarget::Antigravity => ".agent/skills",
             DeployTarget::OpenCode => ".opencode/skills",
+            DeployTarget::Custom(custom) => custom.skills_prefix.trim_end_matches('/'),
         }
     }
 
@@ -92,17 +143,31 @@ impl fmt::Display for DeployTarget {
 impl FromStr for DeployTarget {
     type Err = String;
 
+    /// Parses a built-in target name, then falls back to the configured custom targets.
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         match s {
-            "claude-code" | "claude" => Ok(DeployTarget::ClaudeCode),
-            "amp" => Ok(DeployTarget::Amp),
-            "antigravity" | "gemini" => Ok(DeployTarget::Antigravity),
-            "open-code" | "opencode" => Ok(DeployTarget::OpenCode),
-            _ => Err(format!(
-                "Unknown target '{}'. Valid: claude-code, amp, antigravity, open-code",
-                s
-            )),
+            "claude-code" | "claude" => return Ok(DeployTarget::ClaudeCode),
+            "amp" => return Ok(DeployTarget::Amp),
+            "antigravity" | "gemini" => return Ok(DeployTarget::Antigravity),
+            "open-code" | "opencode" => return Ok(DeployTarget::OpenCode),
+            _ => {}
         }
+
+        let custom = CustomTarget::load_all().map_err(|e| e.to_string())?;
+        Self::parse_with_custom(s, custom)
+    }
+}
+
+impl Serialize for DeployTarget {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for DeployTarget {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        id.parse().map_err(serde::de::Error::custom)
     }
 }
 
@@ -454,4 +519,26 @@ mod tests {
         assert_eq!(first[2], DeployTarget::Antigravity);
         assert_eq!(first[3], DeployTarget::OpenCode);
     }
+
+    #[test]
+    fn test_custom_target_parses_and_roundtrips() {
+        let custom = &crate::test_utils::CUSTOM_TARGETS;
+
+        let target = DeployTarget::parse_with_custom("acme", custom).unwrap();
+        assert_eq!(target, crate::test_utils::acme_target());
+        assert_eq!(target.display_name(), "Acme AI");
+        assert_eq!(target.instructions_filename(), "ACME.md");
+        assert_eq!(target.project_skills_prefix(), ".acme/skills");
+
+        let json = serde_json::to_string(&target).unwrap();
+        assert_eq!(json, "\"acme\"");
+        let id: String = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            DeployTarget::parse_with_custom(&id, custom).unwrap(),
+            target
+        );
+
+        let err = DeployTarget::parse_with_custom("vim", custom).unwrap_err();
+        assert!(err.contains("acme"));
+    }
 }
@@ -1,9 +1,13 @@
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use crate::{fs_utils, Result};
+use crate::{fs_utils, McpConfig, McpConfigManager, Paths, Result, RhinolabsError, Warnings};
 
-use super::{DeployTarget, InstructionsDeployer, SkillDeployer, TargetPaths};
+use super::{
+    mcp_format, ClaudeCodeDeployer, DeployTarget, InstructionsDeployer, McpDeployer, SkillDeployer,
+    TargetDetector, TargetPaths,
+};
 
 /// Generic deployer that works for ANY deploy target.
 ///
@@ -85,7 +89,7 @@ impl InstructionsDeployer for GenericDeployer {
             fs::create_dir_all(parent)?;
         }
 
-        fs::write(&path, content)?;
+        crate::fs_utils::write(&path, content)?;
         Ok(())
     }
 
@@ -96,7 +100,7 @@ impl InstructionsDeployer for GenericDeployer {
             fs::create_dir_all(parent)?;
         }
 
-        fs::write(&path, content)?;
+        crate::fs_utils::write(&path, content)?;
         Ok(())
     }
 
@@ -124,9 +128,196 @@ impl InstructionsDeployer for GenericDeployer {
     }
 }
 
+impl GenericDeployer {
+    /// The target's user-level MCP config file and the key its servers live under
+    fn mcp_location(&self) -> Result<(std::path::PathBuf, &'static str)> {
+        let key = mcp_format::servers_key(self.target).ok_or_else(|| {
+            RhinolabsError::TargetNotSupported(self.target.display_name().to_string())
+        })?;
+        let config_dir = TargetPaths::user_config_dir(self.target)?;
+        Ok((TargetPaths::mcp_config_path(self.target, &config_dir), key))
+    }
+
+    /// Where the names of the servers deployed to each target are recorded
+    fn deployed_servers_path() -> Result<PathBuf> {
+        Ok(Paths::rhinolabs_config_dir()?.join("mcp-deployed.json"))
+    }
+
+    /// Target id -> names of the servers rhinolabs-ai wrote into its MCP config.
+    /// An unreadable record is treated as empty: nothing is known to be managed.
+    fn load_deployed_servers() -> Result<BTreeMap<String, Vec<String>>> {
+        let path = Self::deployed_servers_path()?;
+        if !path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(&path)?).unwrap_or_default())
+    }
+
+    /// The target's config file as a JSON object; other settings in it are kept
+    fn read_mcp_document(path: &Path) -> Result<serde_json::Map<String, serde_json::Value>> {
+        if !path.exists() {
+            return Ok(serde_json::Map::new());
+        }
+        match serde_json::from_str(&fs::read_to_string(path)?) {
+            Ok(serde_json::Value::Object(document)) => Ok(document),
+            _ => Err(RhinolabsError::ConfigError(format!(
+                "{} is not a JSON object; not overwriting it",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// Claude Code reads the managed config directly; every other target gets the
+/// enabled servers in its own format.
+///
+/// Only servers rhinolabs-ai deployed before (recorded per target) are replaced or
+/// removed; servers the user added to the target's config themselves are kept, and
+/// the previous file is backed up. `${VAR}` placeholders become the target's own
+/// variable references where it has them, and are resolved otherwise, with a
+/// warning that the values were written in plain text.
+impl McpDeployer for GenericDeployer {
+    fn target(&self) -> DeployTarget {
+        self.target
+    }
+
+    fn deploy_mcp(&self, config: &McpConfig) -> Result<Warnings> {
+        if self.target == DeployTarget::ClaudeCode {
+            return ClaudeCodeDeployer.deploy_mcp(config);
+        }
+
+        let (path, key) = self.mcp_location()?;
+        let mut warnings = Warnings::new();
+        let target = self.target;
+        let resolved = if mcp_format::env_reference(target, "VAR").is_some() {
+            McpConfigManager::resolve_env(&McpConfigManager::rewrite_env_references(
+                config,
+                |name| mcp_format::env_reference(target, name).unwrap_or_default(),
+            ))?
+        } else {
+            let mut names: Vec<&String> = config
+                .mcp_servers
+                .iter()
+                .filter(|(_, server)| server.enabled && server.has_env_placeholders())
+                .map(|(name, _)| name)
+                .collect();
+            names.sort();
+            for name in names {
+                warnings.push(format!(
+                    "MCP server '{}': environment variables were resolved and written in plain text to {}, since {} can't reference them",
+                    name,
+                    path.display(),
+                    target.display_name()
+                ));
+            }
+            McpConfigManager::resolve_env(config)?
+        };
+
+        let mut deployed = Self::load_deployed_servers()?;
+        let previous = deployed.remove(target.id()).unwrap_or_default();
+
+        let mut document = Self::read_mcp_document(&path)?;
+        let mut servers = match document.remove(key) {
+            Some(serde_json::Value::Object(servers)) => servers,
+            _ => serde_json::Map::new(),
+        };
+        servers.retain(|name, _| !previous.contains(name));
+
+        let mut managed: Vec<String> = Vec::new();
+        for (name, server) in resolved.mcp_servers.iter().filter(|(_, s)| s.enabled) {
+            servers.insert(name.clone(), mcp_format::to_target(target, server));
+            managed.push(name.clone());
+        }
+        managed.sort();
+
+        document.insert(key.to_string(), serde_json::Value::Object(servers));
+        crate::paths::atomic_write(&path, serde_json::to_string_pretty(&document)?, true)?;
+
+        if !managed.is_empty() {
+            deployed.insert(target.id().to_string(), managed);
+        }
+        crate::paths::atomic_write(
+            &Self::deployed_servers_path()?,
+            serde_json::to_string_pretty(&deployed)?,
+            false,
+        )?;
+        Ok(warnings)
+    }
+
+    fn read_mcp(&self) -> Result<Option<McpConfig>> {
+        if self.target == DeployTarget::ClaudeCode {
+            return ClaudeCodeDeployer.read_mcp();
+        }
+
+        let (path, key) = self.mcp_location()?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        let document = Self::read_mcp_document(&path)?;
+        let Some(servers) = document.get(key).and_then(|v| v.as_object()) else {
+            return Ok(None);
+        };
+
+        let mut config = McpConfig {
+            _note: None,
+            ..McpConfig::default()
+        };
+        for (name, entry) in servers {
+            if let Some(server) = mcp_format::from_target(self.target, entry) {
+                config.mcp_servers.insert(name.clone(), server);
+            }
+        }
+        Ok(Some(config))
+    }
+}
+
+impl GenericDeployer {
+    /// Executables and home-relative directories that only exist once the tool
+    /// has been installed or run. The user config dirs from `TargetPaths` are not
+    /// used: rhinolabs-ai creates those itself when deploying.
+    fn install_markers(&self) -> (&'static [&'static str], &'static [&'static str]) {
+        match self.target {
+            DeployTarget::Amp => (&["amp"], &[".config/amp", ".local/share/amp"]),
+            DeployTarget::Antigravity => (&["antigravity"], &[".antigravity"]),
+            DeployTarget::OpenCode => (&["opencode"], &[".opencode", ".local/share/opencode"]),
+            DeployTarget::ClaudeCode | DeployTarget::Custom(_) => (&[], &[]),
+        }
+    }
+}
+
+/// A best-effort check: a target can be reported missing while still working
+/// (e.g. installed somewhere unusual), so callers should warn rather than refuse.
+impl TargetDetector for GenericDeployer {
+    fn target(&self) -> DeployTarget {
+        self.target
+    }
+
+    fn is_installed(&self) -> bool {
+        match self.target {
+            DeployTarget::ClaudeCode => ClaudeCodeDeployer.is_installed(),
+            // Nothing is known about a custom tool; trust that it exists
+            DeployTarget::Custom(_) => true,
+            _ => {
+                let (binaries, dirs) = self.install_markers();
+                let home = dirs::home_dir();
+                binaries.iter().any(|binary| which::which(binary).is_ok())
+                    || (self.target == DeployTarget::Antigravity
+                        && cfg!(target_os = "macos")
+                        && Path::new("/Applications/Antigravity.app").exists())
+                    || home.is_some_and(|home| dirs.iter().any(|dir| home.join(dir).is_dir()))
+            }
+        }
+    }
+
+    fn display_name(&self) -> &str {
+        self.target.display_name()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::acme_target;
 
     #[test]
     fn test_generic_deployer_target() {
@@ -221,6 +412,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_deploy_project_custom_target() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skill-src");
+        fs::create_dir_all(&source).unwrap();
+        fs::write(source.join("SKILL.md"), "# Acme Skill").unwrap();
+
+        let project = temp.path().join("project");
+        fs::create_dir_all(&project).unwrap();
+
+        let deployer = GenericDeployer::new(acme_target());
+        deployer
+            .deploy_skill_project("my-skill", &source, &project)
+            .unwrap();
+        deployer
+            .deploy_instructions_project("# Acme instructions", &project)
+            .unwrap();
+
+        assert!(project
+            .join(".acme")
+            .join("skills")
+            .join("my-skill")
+            .join("SKILL.md")
+            .exists());
+        assert_eq!(
+            fs::read_to_string(project.join("ACME.md")).unwrap(),
+            "# Acme instructions"
+        );
+    }
+
     #[test]
     fn test_deploy_skill_project_antigravity() {
         let temp = tempfile::TempDir::new().unwrap();
@@ -308,4 +529,163 @@ mod tests {
         assert_eq!(deployer.target(), DeployTarget::OpenCode);
         assert_eq!(deployer.instructions_filename(), "opencode.json");
     }
+
+    fn mcp_config() -> McpConfig {
+        let mut config = McpConfig::default();
+        config.mcp_servers.insert(
+            "git".into(),
+            crate::McpServer::stdio("npx".into(), vec!["git-mcp".into()]),
+        );
+        let mut docs = crate::McpServer::http("https://docs.example.com/mcp".into());
+        docs.enabled = false;
+        config.mcp_servers.insert("docs".into(), docs);
+        config
+    }
+
+    #[test]
+    fn test_deploy_mcp_writes_each_target_config() {
+        let _lock = crate::test_utils::ENV_MUTEX.lock().unwrap();
+        let home = crate::test_utils::HomeEnv::new();
+        let root = home.dir.path();
+
+        let expected = [
+            (
+                DeployTarget::Amp,
+                root.join(".config/agents/settings.json"),
+                "amp.mcpServers",
+            ),
+            (
+                DeployTarget::Antigravity,
+                root.join(".gemini/antigravity/config.json"),
+                "mcpServers",
+            ),
+            (
+                DeployTarget::OpenCode,
+                root.join(".config/opencode/opencode.json"),
+                "mcp",
+            ),
+        ];
+
+        for (target, path, key) in &expected {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, r#"{ "theme": "dark" }"#).unwrap();
+
+            let deployer = GenericDeployer::new(*target);
+            deployer.deploy_mcp(&mcp_config()).unwrap();
+
+            let written: serde_json::Value =
+                serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+            assert_eq!(written["theme"], "dark", "{:?} kept other settings", target);
+            assert!(
+                fs::read_dir(path.parent().unwrap())
+                    .unwrap()
+                    .flatten()
+                    .any(|e| e.file_name().to_string_lossy().contains(".backup.")),
+                "{:?} backed up the previous config",
+                target
+            );
+            assert!(
+                written[key]["git"].is_object(),
+                "{:?} wrote under {}",
+                target,
+                key
+            );
+            assert!(
+                written[key].get("docs").is_none(),
+                "{:?} skipped disabled",
+                target
+            );
+
+            let read = deployer.read_mcp().unwrap().unwrap();
+            assert_eq!(
+                read.mcp_servers["git"].command.as_deref(),
+                Some("npx"),
+                "{:?}",
+                target
+            );
+        }
+    }
+
+    #[test]
+    fn test_deploy_mcp_keeps_user_servers_and_env_references() {
+        let _lock = crate::test_utils::ENV_MUTEX.lock().unwrap();
+        let home = crate::test_utils::HomeEnv::new();
+        let path = home.dir.path().join(".config/opencode/opencode.json");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(
+            &path,
+            r#"{ "mcp": { "mine": { "type": "remote", "url": "https://mine.example.com" } } }"#,
+        )
+        .unwrap();
+
+        let mut config = mcp_config();
+        let mut api = crate::McpServer::stdio("api-mcp".into(), vec![]);
+        api.env = Some([("TOKEN".to_string(), "${API_TOKEN}".to_string())].into());
+        config.mcp_servers.insert("api".into(), api);
+
+        let opencode = GenericDeployer::new(DeployTarget::OpenCode);
+        assert!(opencode.deploy_mcp(&config).unwrap().is_empty());
+        let read = |path: &Path| -> serde_json::Value {
+            serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+        };
+        let written = read(&path);
+        assert_eq!(written["mcp"]["mine"]["url"], "https://mine.example.com");
+        assert_eq!(
+            written["mcp"]["api"]["environment"]["TOKEN"],
+            "{env:API_TOKEN}"
+        );
+
+        // A server dropped from the managed config goes; the user's stays
+        config.mcp_servers.remove("git");
+        opencode.deploy_mcp(&config).unwrap();
+        let written = read(&path);
+        assert!(written["mcp"].get("git").is_none());
+        assert!(written["mcp"]["mine"].is_object());
+
+        // Amp has no variable references: the value is resolved, with a warning
+        let warnings = GenericDeployer::new(DeployTarget::Amp)
+            .deploy_mcp(&config)
+            .unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings.iter().next().unwrap().contains("'api'"));
+    }
+
+    #[test]
+    fn test_deploy_mcp_custom_target_not_supported() {
+        let deployer = GenericDeployer::new(acme_target());
+        assert!(matches!(
+            deployer.deploy_mcp(&mcp_config()),
+            Err(RhinolabsError::TargetNotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_installed_checks_tool_markers_not_deploy_dirs() {
+        let _lock = crate::test_utils::ENV_MUTEX.lock().unwrap();
+        let home = crate::test_utils::HomeEnv::new();
+        let bin = crate::test_utils::PathEnv::new();
+
+        let amp = GenericDeployer::new(DeployTarget::Amp);
+        let opencode = GenericDeployer::new(DeployTarget::OpenCode);
+        assert!(!amp.is_installed());
+        assert!(!opencode.is_installed());
+
+        // Directories rhinolabs-ai deploys to do not count as an installation
+        fs::create_dir_all(TargetPaths::user_skills_dir(DeployTarget::OpenCode).unwrap()).unwrap();
+        assert!(!opencode.is_installed());
+
+        fs::create_dir_all(home.dir.path().join(".config/amp")).unwrap();
+        assert!(amp.is_installed());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let binary = bin.dir.path().join("opencode");
+            fs::write(&binary, "#!/bin/sh\n").unwrap();
+            fs::set_permissions(&binary, fs::Permissions::from_mode(0o755)).unwrap();
+            assert!(opencode.is_installed());
+        }
+
+        assert!(GenericDeployer::new(acme_target()).is_installed());
+    }
 }
@@ -0,0 +1,193 @@
+//! How each target spells its MCP server entries.
+//!
+//! The managed config uses the Claude Code shape (`command`/`args`/`env` or
+//! `url`/`headers` under `mcpServers`); other targets keep servers under their
+//! own key in a config file they share with other settings.
+
+use std::collections::HashMap;
+
+use serde_json::{json, Map, Value};
+
+use crate::McpServer;
+
+use super::DeployTarget;
+
+/// Key holding the server map in the target's MCP config file, or `None` when
+/// the target has no known MCP support
+pub(super) fn servers_key(target: DeployTarget) -> Option<&'static str> {
+    match target {
+        DeployTarget::ClaudeCode | DeployTarget::Antigravity => Some("mcpServers"),
+        DeployTarget::Amp => Some("amp.mcpServers"),
+        DeployTarget::OpenCode => Some("mcp"),
+        DeployTarget::Custom(_) => None,
+    }
+}
+
+/// How the target's config spells a reference to environment variable `name`, or
+/// `None` when the target can't expand variables (values are then resolved first)
+pub(super) fn env_reference(target: DeployTarget, name: &str) -> Option<String> {
+    match target {
+        DeployTarget::ClaudeCode => Some(format!("${{{}}}", name)),
+        DeployTarget::OpenCode => Some(format!("{{env:{}}}", name)),
+        DeployTarget::Amp | DeployTarget::Antigravity | DeployTarget::Custom(_) => None,
+    }
+}
+
+/// One server in the target's format
+pub(super) fn to_target(target: DeployTarget, server: &McpServer) -> Value {
+    let mut entry = Map::new();
+    match target {
+        DeployTarget::OpenCode => {
+            if let Some(command) = &server.command {
+                let mut command_line = vec![command.clone()];
+                command_line.extend(server.args.iter().cloned());
+                entry.insert("type".into(), json!("local"));
+                entry.insert("command".into(), json!(command_line));
+                insert_map(&mut entry, "environment", &server.env);
+            } else {
+                entry.insert("type".into(), json!("remote"));
+                entry.insert("url".into(), json!(server.url));
+                insert_map(&mut entry, "headers", &server.headers);
+            }
+            entry.insert("enabled".into(), json!(true));
+        }
+        _ => {
+            if let Some(command) = &server.command {
+                entry.insert("command".into(), json!(command));
+                if !server.args.is_empty() {
+                    entry.insert("args".into(), json!(server.args));
+                }
+                insert_map(&mut entry, "env", &server.env);
+            } else {
+                // Antigravity calls the endpoint `serverUrl`
+                let url_key = if target == DeployTarget::Antigravity {
+                    "serverUrl"
+                } else {
+                    "url"
+                };
+                entry.insert(url_key.into(), json!(server.url));
+                insert_map(&mut entry, "headers", &server.headers);
+            }
+        }
+    }
+    Value::Object(entry)
+}
+
+/// Read one server entry written in the target's format
+pub(super) fn from_target(target: DeployTarget, entry: &Value) -> Option<McpServer> {
+    let string_map = |key: &str| -> Option<HashMap<String, String>> {
+        serde_json::from_value(entry.get(key)?.clone()).ok()
+    };
+
+    let mut server = match target {
+        DeployTarget::OpenCode => {
+            let mut server = match entry.get("command").and_then(Value::as_array) {
+                Some(command_line) => {
+                    let mut parts = command_line.iter().filter_map(Value::as_str);
+                    let mut server = McpServer::stdio(parts.next()?.to_string(), vec![]);
+                    server.args = parts.map(str::to_string).collect();
+                    server.env = string_map("environment");
+                    server
+                }
+                None => McpServer::http(entry.get("url")?.as_str()?.to_string()),
+            };
+            server.enabled = entry.get("enabled").and_then(Value::as_bool) != Some(false);
+            server
+        }
+        _ => {
+            let url = entry
+                .get("url")
+                .or_else(|| entry.get("serverUrl"))
+                .and_then(Value::as_str);
+            match (entry.get("command").and_then(Value::as_str), url) {
+                (Some(command), _) => {
+                    let mut server = McpServer::stdio(command.to_string(), vec![]);
+                    server.args = entry
+                        .get("args")
+                        .and_then(|args| serde_json::from_value(args.clone()).ok())
+                        .unwrap_or_default();
+                    server.env = string_map("env");
+                    server
+                }
+                (None, Some(url)) => McpServer::http(url.to_string()),
+                (None, None) => return None,
+            }
+        }
+    };
+
+    if server.is_http() {
+        server.headers = string_map("headers");
+    }
+    Some(server)
+}
+
+fn insert_map(entry: &mut Map<String, Value>, key: &str, values: &Option<HashMap<String, String>>) {
+    if let Some(values) = values.as_ref().filter(|v| !v.is_empty()) {
+        entry.insert(key.into(), json!(values));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stdio_server() -> McpServer {
+        let mut server = McpServer::stdio("npx".into(), vec!["-y".into(), "git-mcp".into()]);
+        server.env = Some(HashMap::from([("LOG".to_string(), "debug".to_string())]));
+        server
+    }
+
+    #[test]
+    fn test_opencode_format() {
+        let entry = to_target(DeployTarget::OpenCode, &stdio_server());
+        assert_eq!(
+            entry,
+            json!({
+                "type": "local",
+                "command": ["npx", "-y", "git-mcp"],
+                "environment": { "LOG": "debug" },
+                "enabled": true,
+            })
+        );
+
+        let entry = to_target(
+            DeployTarget::OpenCode,
+            &McpServer::http("https://docs.example.com/mcp".into()),
+        );
+        assert_eq!(
+            entry,
+            json!({ "type": "remote", "url": "https://docs.example.com/mcp", "enabled": true })
+        );
+    }
+
+    #[test]
+    fn test_antigravity_uses_server_url() {
+        let entry = to_target(
+            DeployTarget::Antigravity,
+            &McpServer::http("https://docs.example.com/mcp".into()),
+        );
+        assert_eq!(
+            entry,
+            json!({ "serverUrl": "https://docs.example.com/mcp" })
+        );
+    }
+
+    #[test]
+    fn test_round_trip_every_target() {
+        let http = McpServer::http("https://docs.example.com/mcp".into());
+        for target in [
+            DeployTarget::Amp,
+            DeployTarget::Antigravity,
+            DeployTarget::OpenCode,
+        ] {
+            let stdio = stdio_server();
+            let read = from_target(target, &to_target(target, &stdio)).unwrap();
+            assert_eq!(read.command, stdio.command, "{:?}", target);
+            assert_eq!(read.args, stdio.args, "{:?}", target);
+            assert_eq!(read.env, stdio.env, "{:?}", target);
+
+            let read = from_target(target, &to_target(target, &http)).unwrap();
+            assert_eq!(read.url, http.url, "{:?}", target);
+        }
+    }
+}
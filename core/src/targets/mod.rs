@@ -1,11 +1,16 @@
 mod claude_code;
+mod custom_target;
 mod deploy_target;
 mod generic;
+mod mcp_format;
 mod target_paths;
+mod target_status;
 mod traits;
 
 pub use claude_code::ClaudeCodeDeployer;
+pub use custom_target::CustomTarget;
 pub use deploy_target::DeployTarget;
 pub use generic::GenericDeployer;
-pub use target_paths::TargetPaths;
+pub use target_paths::{TargetPaths, OPENCODE_INSTRUCTIONS_FILE};
+pub use target_status::TargetStatus;
 pub use traits::{InstructionsDeployer, McpDeployer, SkillDeployer, TargetDetector};
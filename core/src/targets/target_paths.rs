@@ -4,6 +4,11 @@ use crate::{Result, RhinolabsError};
 
 use super::DeployTarget;
 
+/// Generated OpenCode instructions markdown, next to the `opencode.json` that lists it.
+/// It lives at the project root rather than in `.opencode/`, which installs replace
+/// wholesale when they stage skills.
+pub const OPENCODE_INSTRUCTIONS_FILE: &str = "OPENCODE.md";
+
 /// Static utility for resolving filesystem paths per deploy target.
 ///
 /// Each target stores skills, instructions, and MCP config in different
@@ -17,6 +22,7 @@ impl TargetPaths {
     /// - Amp: `~/.config/agents/skills/`
     /// - Antigravity: `~/.gemini/antigravity/skills/`
     /// - OpenCode: `~/.config/opencode/skills/`
+    /// - Custom: `~/{skills_prefix}/`
     pub fn user_skills_dir(target: DeployTarget) -> Result<PathBuf> {
         let home = dirs::home_dir()
             .ok_or_else(|| RhinolabsError::Other("Could not find home directory".into()))?;
@@ -36,6 +42,7 @@ impl TargetPaths {
                 })?;
                 config.join("opencode").join("skills")
             }
+            DeployTarget::Custom(custom) => home.join(&custom.skills_prefix),
         };
 
         Ok(path)
@@ -47,6 +54,7 @@ impl TargetPaths {
     /// - Amp: `~/.config/agents/`
     /// - Antigravity: `~/.gemini/antigravity/`
     /// - OpenCode: `~/.config/opencode/`
+    /// - Custom: `~/{first component of skills_prefix}/`
     pub fn user_config_dir(target: DeployTarget) -> Result<PathBuf> {
         let home = dirs::home_dir()
             .ok_or_else(|| RhinolabsError::Other("Could not find home directory".into()))?;
@@ -66,6 +74,7 @@ impl TargetPaths {
                 })?;
                 config.join("opencode")
             }
+            DeployTarget::Custom(custom) => home.join(custom.config_dir_name()),
         };
 
         Ok(path)
@@ -77,12 +86,14 @@ impl TargetPaths {
     /// - Amp: `{project}/.agents/`
     /// - Antigravity: `{project}/.agent/`
     /// - OpenCode: `{project}/.opencode/`
+    /// - Custom: `{project}/{first component of skills_prefix}/`
     pub fn project_config_dir(target: DeployTarget, project_path: &Path) -> PathBuf {
         match target {
             DeployTarget::ClaudeCode => project_path.join(".claude"),
             DeployTarget::Amp => project_path.join(".agents"),
             DeployTarget::Antigravity => project_path.join(".agent"),
             DeployTarget::OpenCode => project_path.join(".opencode"),
+            DeployTarget::Custom(custom) => project_path.join(custom.config_dir_name()),
         }
     }
 
@@ -92,12 +103,14 @@ impl TargetPaths {
     /// - Amp: `{project}/.agents/skills/`
     /// - Antigravity: `{project}/.agent/skills/`
     /// - OpenCode: `{project}/.opencode/skills/`
+    /// - Custom: `{project}/{skills_prefix}/`
     pub fn project_skills_dir(target: DeployTarget, project_path: &Path) -> PathBuf {
         match target {
             DeployTarget::ClaudeCode => project_path.join(".claude").join("skills"),
             DeployTarget::Amp => project_path.join(".agents").join("skills"),
             DeployTarget::Antigravity => project_path.join(".agent").join("skills"),
             DeployTarget::OpenCode => project_path.join(".opencode").join("skills"),
+            DeployTarget::Custom(custom) => project_path.join(&custom.skills_prefix),
         }
     }
 
@@ -106,6 +119,14 @@ impl TargetPaths {
         base_dir.join(target.instructions_filename())
     }
 
+    /// Returns the path of the markdown file a project's `opencode.json` points at.
+    ///
+    /// OpenCode's config is JSON, so the generated instructions live in this file
+    /// and `opencode.json` lists it under `instructions`.
+    pub fn opencode_instructions_path(base_dir: &Path) -> PathBuf {
+        base_dir.join(OPENCODE_INSTRUCTIONS_FILE)
+    }
+
     /// Returns the path to the MCP config file for the given target and base directory.
     pub fn mcp_config_path(target: DeployTarget, base_dir: &Path) -> PathBuf {
         base_dir.join(target.mcp_config_filename())
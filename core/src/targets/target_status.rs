@@ -0,0 +1,112 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+
+use super::{DeployTarget, GenericDeployer, TargetDetector, TargetPaths};
+
+/// What is currently deployed at user level for one target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetStatus {
+    pub target: DeployTarget,
+    /// Whether the tool itself appears to be installed (see [`TargetDetector`])
+    pub installed: bool,
+    /// The target's user config directory, e.g. `~/.claude`
+    pub config_dir: PathBuf,
+    pub config_dir_exists: bool,
+    pub skills_dir: PathBuf,
+    /// Skill directories (or links to them) in `skills_dir`
+    pub skill_count: usize,
+    pub instructions_path: PathBuf,
+    pub instructions_exist: bool,
+}
+
+impl TargetStatus {
+    /// Inspect the user-level skills and instructions of `target`
+    pub fn user(target: DeployTarget) -> Result<Self> {
+        let config_dir = TargetPaths::user_config_dir(target)?;
+        let skills_dir = TargetPaths::user_skills_dir(target)?;
+        let instructions_path = TargetPaths::instructions_path(target, &config_dir);
+
+        Ok(Self {
+            target,
+            installed: GenericDeployer::new(target).is_installed(),
+            config_dir_exists: config_dir.is_dir(),
+            skill_count: Self::count_skills(&skills_dir),
+            instructions_exist: instructions_path.is_file(),
+            config_dir,
+            skills_dir,
+            instructions_path,
+        })
+    }
+
+    /// [`Self::user`] for every built-in target
+    pub fn all_user() -> Result<Vec<Self>> {
+        DeployTarget::all()
+            .iter()
+            .map(|target| Self::user(*target))
+            .collect()
+    }
+
+    /// Whether anything (skills or instructions) is deployed for the target
+    pub fn is_deployed(&self) -> bool {
+        self.skill_count > 0 || self.instructions_exist
+    }
+
+    /// Visible directories in `dir`, following links; broken links are not counted
+    fn count_skills(dir: &Path) -> usize {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return 0;
+        };
+        entries
+            .flatten()
+            .filter(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+            .filter(|entry| entry.path().is_dir())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{HomeEnv, ENV_MUTEX};
+
+    #[test]
+    fn test_user_status_counts_deployed_skills_and_instructions() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let _home = HomeEnv::new();
+
+        let skills_dir = TargetPaths::user_skills_dir(DeployTarget::Amp).unwrap();
+        fs::create_dir_all(skills_dir.join("react-19")).unwrap();
+        fs::create_dir_all(skills_dir.join(".staging")).unwrap();
+        fs::write(skills_dir.join("notes.txt"), "not a skill").unwrap();
+
+        let statuses = TargetStatus::all_user().unwrap();
+        assert_eq!(statuses.len(), DeployTarget::all().len());
+
+        let amp = statuses
+            .iter()
+            .find(|s| s.target == DeployTarget::Amp)
+            .unwrap();
+        assert!(amp.config_dir_exists);
+        assert_eq!(amp.skill_count, 1);
+        assert!(!amp.instructions_exist);
+        assert!(amp.is_deployed());
+
+        let claude = statuses
+            .iter()
+            .find(|s| s.target == DeployTarget::ClaudeCode)
+            .unwrap();
+        assert!(!claude.is_deployed());
+
+        fs::create_dir_all(&claude.config_dir).unwrap();
+        fs::write(&claude.instructions_path, "# Instructions").unwrap();
+        let claude = TargetStatus::user(DeployTarget::ClaudeCode).unwrap();
+        assert!(claude.instructions_exist);
+        assert_eq!(claude.skill_count, 0);
+        assert!(claude.is_deployed());
+    }
+}
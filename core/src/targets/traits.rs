@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use crate::{McpConfig, Result};
+use crate::{McpConfig, Result, Warnings};
 
 use super::DeployTarget;
 
@@ -59,8 +59,9 @@ pub trait McpDeployer {
     /// Which target this deployer serves.
     fn target(&self) -> DeployTarget;
 
-    /// Write the MCP config to the target's expected location.
-    fn deploy_mcp(&self, config: &McpConfig) -> Result<()>;
+    /// Write the MCP config to the target's expected location, returning
+    /// anything the user should know about what was written.
+    fn deploy_mcp(&self, config: &McpConfig) -> Result<Warnings>;
 
     /// Read the MCP config from the target's expected location.
     fn read_mcp(&self) -> Result<Option<McpConfig>>;
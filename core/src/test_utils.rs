@@ -7,10 +7,13 @@
 #[cfg(test)]
 use std::path::PathBuf;
 #[cfg(test)]
-use std::sync::Mutex;
+use std::sync::{LazyLock, Mutex};
 #[cfg(test)]
 use tempfile::TempDir;
 
+#[cfg(test)]
+use crate::{CustomTarget, DeployTarget};
+
 /// Global mutex to serialize tests that modify the RHINOLABS_DEV_PATH env var.
 /// This prevents race conditions when multiple test modules modify the same env var.
 #[cfg(test)]
@@ -53,3 +56,87 @@ impl Drop for TestEnv {
         }
     }
 }
+
+/// Points HOME and XDG_CONFIG_HOME at a temp dir for user-level installs.
+/// Caller must hold ENV_MUTEX.
+#[cfg(test)]
+pub struct HomeEnv {
+    pub dir: TempDir,
+    original_home: Option<String>,
+    original_xdg: Option<String>,
+}
+
+#[cfg(test)]
+impl HomeEnv {
+    pub fn new() -> Self {
+        let dir = TempDir::new().expect("Failed to create temp home");
+        let original_home = std::env::var("HOME").ok();
+        let original_xdg = std::env::var("XDG_CONFIG_HOME").ok();
+        std::env::set_var("HOME", dir.path());
+        std::env::set_var("XDG_CONFIG_HOME", dir.path().join(".config"));
+        HomeEnv {
+            dir,
+            original_home,
+            original_xdg,
+        }
+    }
+}
+
+#[cfg(test)]
+impl Drop for HomeEnv {
+    fn drop(&mut self) {
+        match &self.original_home {
+            Some(val) => std::env::set_var("HOME", val),
+            None => std::env::remove_var("HOME"),
+        }
+        match &self.original_xdg {
+            Some(val) => std::env::set_var("XDG_CONFIG_HOME", val),
+            None => std::env::remove_var("XDG_CONFIG_HOME"),
+        }
+    }
+}
+
+/// Points PATH at an empty temp dir so binary lookups only see what the test puts
+/// there, and restores it on drop. Caller must hold ENV_MUTEX.
+#[cfg(test)]
+pub struct PathEnv {
+    pub dir: TempDir,
+    original_path: Option<std::ffi::OsString>,
+}
+
+#[cfg(test)]
+impl PathEnv {
+    pub fn new() -> Self {
+        let dir = TempDir::new().expect("Failed to create temp bin dir");
+        let original_path = std::env::var_os("PATH");
+        std::env::set_var("PATH", dir.path());
+        PathEnv { dir, original_path }
+    }
+}
+
+#[cfg(test)]
+impl Drop for PathEnv {
+    fn drop(&mut self) {
+        match &self.original_path {
+            Some(val) => std::env::set_var("PATH", val),
+            None => std::env::remove_var("PATH"),
+        }
+    }
+}
+
+/// Custom targets for tests, standing in for a loaded `targets.json`
+#[cfg(test)]
+pub static CUSTOM_TARGETS: LazyLock<Vec<CustomTarget>> = LazyLock::new(|| {
+    vec![CustomTarget {
+        id: "acme".to_string(),
+        display_name: "Acme AI".to_string(),
+        skills_prefix: ".acme/skills".to_string(),
+        instructions_filename: "ACME.md".to_string(),
+    }]
+});
+
+/// The `acme` custom target from [`CUSTOM_TARGETS`]
+#[cfg(test)]
+pub fn acme_target() -> DeployTarget {
+    DeployTarget::Custom(&CUSTOM_TARGETS[0])
+}
@@ -1,12 +1,167 @@
-use crate::{Installer, Paths, Profiles, Result, RhinolabsError, Version};
+use crate::{
+    InstallOptions, Installer, Paths, Profiles, Project, ReleaseInfo, Result, RhinolabsError,
+    Version,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Preview of what an update would change, computed without downloading or writing anything
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePlan {
+    pub current_version: String,
+    pub latest_version: String,
+    pub update_available: bool,
+    /// Release notes (body) of the latest release, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_notes: Option<String>,
+    /// Files in the installed plugin directory that the update would replace
+    pub files_replaced: Vec<String>,
+}
+
+/// How the running executable was replaced by a binary update
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BinaryReplace {
+    /// The new binary is in place (Unix: atomic rename over the old one)
+    Replaced,
+    /// The running binary was moved aside and the new one takes over on next launch (Windows)
+    RestartRequired,
+}
+
+/// Release asset name of the CLI binary for the current platform (see release.yml)
+pub fn platform_asset_name() -> Option<&'static str> {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("linux", "x86_64") => Some("rhinolabs-ai-linux-x64"),
+        ("linux", "aarch64") => Some("rhinolabs-ai-linux-arm64"),
+        ("macos", "x86_64") => Some("rhinolabs-ai-darwin-x64"),
+        ("macos", "aarch64") => Some("rhinolabs-ai-darwin-arm64"),
+        ("windows", "x86_64") => Some("rhinolabs-ai-windows-x64.exe"),
+        _ => None,
+    }
+}
+
+/// A downloaded binary written next to the one it will replace, verified before use.
+/// Dropping it without applying removes the staged file.
+#[derive(Debug)]
+pub struct StagedBinary {
+    path: PathBuf,
+    applied: bool,
+}
+
+impl StagedBinary {
+    /// Write `bytes` to `<target>.new` and check them against the expected size
+    /// and SHA-256 (hex). Nothing is staged if verification fails.
+    pub fn stage(
+        bytes: &[u8],
+        target: &Path,
+        expected_size: Option<u64>,
+        expected_sha256: Option<&str>,
+    ) -> Result<Self> {
+        if let Some(size) = expected_size {
+            if bytes.len() as u64 != size {
+                return Err(RhinolabsError::UpdateFailed(format!(
+                    "Downloaded binary is {} bytes, expected {}",
+                    bytes.len(),
+                    size
+                )));
+            }
+        }
+        if let Some(expected) = expected_sha256 {
+            let actual = format!("{:x}", Sha256::digest(bytes));
+            if !actual.eq_ignore_ascii_case(expected) {
+                return Err(RhinolabsError::UpdateFailed(format!(
+                    "Checksum mismatch: got sha256 {}, expected {}",
+                    actual, expected
+                )));
+            }
+        }
+
+        let path = crate::fs_utils::sibling_with_suffix(target, "new");
+        crate::fs_utils::write(&path, bytes)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+        }
+
+        Ok(Self {
+            path,
+            applied: false,
+        })
+    }
+
+    /// Path of the staged binary
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Put the staged binary in place of `target`.
+    ///
+    /// On Unix the rename is atomic and works while `target` is running.
+    /// Windows locks a running executable, but it can still be renamed: the old
+    /// binary is moved to `<target>.old` (removed on next launch by
+    /// [`cleanup_replaced_binary`]) and the new one takes its place.
+    pub fn apply(mut self, target: &Path) -> Result<BinaryReplace> {
+        let outcome = if cfg!(windows) {
+            let old = crate::fs_utils::sibling_with_suffix(target, "old");
+            if old.exists() {
+                fs::remove_file(&old)?;
+            }
+            fs::rename(target, &old)?;
+            if let Err(e) = fs::rename(&self.path, target) {
+                let _ = fs::rename(&old, target);
+                return Err(e.into());
+            }
+            BinaryReplace::RestartRequired
+        } else {
+            fs::rename(&self.path, target)?;
+            BinaryReplace::Replaced
+        };
+
+        self.applied = true;
+        Ok(outcome)
+    }
+}
+
+impl Drop for StagedBinary {
+    fn drop(&mut self) {
+        if !self.applied {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Remove the `<target>.old` binary left behind by a Windows update.
+/// Returns whether a leftover was removed.
+pub fn cleanup_replaced_binary(target: &Path) -> bool {
+    let old = crate::fs_utils::sibling_with_suffix(target, "old");
+    old.exists() && fs::remove_file(&old).is_ok()
+}
+
+/// Parse a GitHub asset `digest` field ("sha256:<hex>") into the hex hash
+fn sha256_from_digest(digest: Option<&str>) -> Option<&str> {
+    digest.and_then(|d| d.strip_prefix("sha256:"))
+}
 
 pub struct Updater {
     dry_run: bool,
+    verify: bool,
+    version: Option<String>,
+    allow_downgrade: bool,
 }
 
 impl Updater {
     pub fn new() -> Self {
-        Self { dry_run: false }
+        Self {
+            dry_run: false,
+            verify: true,
+            version: None,
+            allow_downgrade: false,
+        }
     }
 
     pub fn dry_run(mut self, enabled: bool) -> Self {
@@ -14,35 +169,59 @@ impl Updater {
         self
     }
 
-    /// Update plugin to latest version
-    pub async fn update(&self) -> Result<()> {
+    /// Verify the downloaded plugin or binary against the release's checksums (see [`Installer::verify`])
+    pub fn verify(mut self, enabled: bool) -> Self {
+        self.verify = enabled;
+        self
+    }
+
+    /// Move to the release tagged `version` instead of the latest one
+    pub fn version(mut self, version: Option<String>) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Let a pinned [`Self::version`] be older than the installed plugin
+    pub fn allow_downgrade(mut self, enabled: bool) -> Self {
+        self.allow_downgrade = enabled;
+        self
+    }
+
+    /// Update plugin to latest version. Returns the changelog of the releases
+    /// between the previously installed version and the new one (newest first);
+    /// it is empty if the release notes couldn't be fetched.
+    pub async fn update(&self) -> Result<Vec<ReleaseInfo>> {
         // Check if plugin is installed
         if !Paths::is_plugin_installed() {
             return Err(RhinolabsError::PluginNotInstalled);
         }
 
         // Check for updates
-        let latest_version = Version::check_update().await?;
+        let target_version = match &self.version {
+            Some(tag) => Some(self.check_pinned(tag).await?),
+            None => Version::check_update().await?,
+        };
 
-        if latest_version.is_none() {
+        let Some(target_version) = target_version else {
             return Err(RhinolabsError::UpdateFailed(
                 "Already on latest version".into(),
             ));
-        }
+        };
 
         if self.dry_run {
-            println!(
-                "[DRY RUN] Would update to version: {}",
-                latest_version.unwrap()
-            );
-            return Ok(());
+            println!("[DRY RUN] Would update to version: {}", target_version);
+            return Ok(Vec::new());
         }
 
+        let previous_version = Version::installed_or_current()?;
+
         // Backup current installation
         self.backup_current()?;
 
         // Uninstall current version
-        let installer = Installer::new();
+        let installer = Installer::new()
+            .verify(self.verify)
+            .version(self.version.clone());
         installer.uninstall()?;
 
         // Install latest version (downloads new plugin with updated profiles.json)
@@ -53,11 +232,264 @@ impl Updater {
         if !synced.is_empty() {
             // Re-deploy main profile skills if it was updated
             if synced.iter().any(|id| id == "main") {
-                let _ = Profiles::install("main", None, None);
+                let _ = Profiles::install("main", None, None, InstallOptions::default());
             }
         }
 
-        Ok(())
+        // The update already succeeded, so missing release notes are not an error
+        let releases = Project::fetch_releases().await.unwrap_or_default();
+        Ok(ReleaseInfo::changelog_between(
+            &releases,
+            &previous_version,
+            &target_version,
+        ))
+    }
+
+    /// Release notes between the installed version and the latest release,
+    /// newest first. Nothing is installed.
+    pub async fn changelog(&self) -> Result<Vec<ReleaseInfo>> {
+        let installed = Version::installed_or_current()?;
+        let releases = Project::fetch_releases().await?;
+        let Some(latest) = Project::fetch_latest_release().await? else {
+            return Ok(Vec::new());
+        };
+        Ok(ReleaseInfo::changelog_between(
+            &releases,
+            &installed,
+            &latest.version,
+        ))
+    }
+
+    /// Make sure the release tagged `tag` exists and is not older than the installed
+    /// plugin (unless downgrades are allowed). Returns its version.
+    async fn check_pinned(&self, tag: &str) -> Result<String> {
+        let version = Version::plugin_download(Some(tag)).await?.version;
+        let installed = Version::installed_or_current()?;
+
+        if !self.pinned_is_update(&installed, &version)? {
+            return Err(RhinolabsError::UpdateFailed(format!(
+                "Version {} is already installed",
+                version
+            )));
+        }
+        Ok(version)
+    }
+
+    /// Whether moving from `installed` to the pinned `version` changes anything.
+    /// A downgrade is an error unless downgrades are allowed.
+    fn pinned_is_update(&self, installed: &str, version: &str) -> Result<bool> {
+        if installed == version {
+            return Ok(false);
+        }
+        if !self.allow_downgrade && Version::is_downgrade(installed, version)? {
+            return Err(RhinolabsError::UpdateFailed(format!(
+                "Version {} is older than the installed {}; pass --allow-downgrade to install it anyway",
+                version, installed
+            )));
+        }
+        Ok(true)
+    }
+
+    /// Download the latest CLI binary for this platform and replace `exe` with it.
+    ///
+    /// The download is verified against the release asset's size and SHA-256 digest,
+    /// or the release's `SHA256SUMS` when the asset has no digest, before anything is
+    /// replaced. On Windows the new binary takes effect on next launch
+    /// (`BinaryReplace::RestartRequired`).
+    pub async fn update_binary(&self, exe: &Path) -> Result<BinaryReplace> {
+        let url = Version::releases_api_url()?;
+        self.update_binary_from_url(&url, exe).await
+    }
+
+    async fn update_binary_from_url(&self, url: &str, exe: &Path) -> Result<BinaryReplace> {
+        let asset_name = platform_asset_name().ok_or_else(|| {
+            RhinolabsError::UpdateFailed(format!(
+                "No prebuilt binary for {}-{}",
+                std::env::consts::OS,
+                std::env::consts::ARCH
+            ))
+        })?;
+
+        let client = crate::network::client()?;
+        let release: serde_json::Value =
+            crate::network::send(client.get(url).header("User-Agent", "rhinolabs-cli"))
+                .await
+                .map_err(crate::network::request_error)?
+                .error_for_status()
+                .map_err(|e| RhinolabsError::NetworkError(e.to_string()))?
+                .json()
+                .await?;
+
+        let asset = release["assets"]
+            .as_array()
+            .and_then(|assets| assets.iter().find(|a| a["name"] == asset_name))
+            .ok_or_else(|| {
+                RhinolabsError::DownloadFailed(format!("Asset {} not found in release", asset_name))
+            })?;
+        let download_url = asset["browser_download_url"]
+            .as_str()
+            .ok_or_else(|| RhinolabsError::DownloadFailed("Asset has no download URL".into()))?;
+
+        let response = crate::network::send(
+            client
+                .get(download_url)
+                .header("User-Agent", "rhinolabs-cli"),
+        )
+        .await
+        .map_err(crate::network::request_error)?;
+        if !response.status().is_success() {
+            return Err(RhinolabsError::DownloadFailed(format!(
+                "HTTP {}",
+                response.status()
+            )));
+        }
+        let bytes = response.bytes().await?;
+
+        let checksums;
+        let expected_sha256 = match sha256_from_digest(asset["digest"].as_str()) {
+            Some(hash) => Some(hash),
+            None if self.verify => {
+                checksums = Self::release_checksums(&client, &release, asset_name).await?;
+                Some(
+                    crate::installer::expected_sha256(&checksums, asset_name).ok_or_else(|| {
+                        RhinolabsError::DownloadFailed(format!(
+                            "SHA256SUMS has no entry for {}",
+                            asset_name
+                        ))
+                    })?,
+                )
+            }
+            None => None,
+        };
+
+        let staged = StagedBinary::stage(&bytes, exe, asset["size"].as_u64(), expected_sha256)?;
+        staged.apply(exe)
+    }
+
+    /// Download the release's `SHA256SUMS`, for assets published without a digest
+    async fn release_checksums(
+        client: &reqwest::Client,
+        release: &serde_json::Value,
+        asset_name: &str,
+    ) -> Result<String> {
+        let checksums_url = release["assets"]
+            .as_array()
+            .and_then(|assets| {
+                assets
+                    .iter()
+                    .find(|a| matches!(a["name"].as_str(), Some("SHA256SUMS" | "SHA256SUMS.txt")))
+            })
+            .and_then(|a| a["browser_download_url"].as_str())
+            .ok_or_else(|| {
+                RhinolabsError::DownloadFailed(format!(
+                    "The release has no checksum to verify {} against (use --no-verify to update anyway)",
+                    asset_name
+                ))
+            })?;
+
+        let response = crate::network::send(
+            client
+                .get(checksums_url)
+                .header("User-Agent", "rhinolabs-cli"),
+        )
+        .await
+        .map_err(crate::network::request_error)?;
+        if !response.status().is_success() {
+            return Err(RhinolabsError::DownloadFailed(format!(
+                "HTTP {}",
+                response.status()
+            )));
+        }
+        Ok(response.text().await?)
+    }
+
+    /// Build an update preview: version delta, release notes and the files
+    /// that would be replaced. Never downloads the asset or touches disk.
+    pub async fn plan(&self) -> Result<UpdatePlan> {
+        let url = Version::release_api_url(self.version.as_deref())?;
+        let installed = match self.version {
+            Some(_) => Version::installed_or_current()?,
+            None => Version::current(),
+        };
+        self.plan_from_url(&url, &Paths::plugin_dir()?, &installed)
+            .await
+    }
+
+    /// With a pinned [`Self::version`] the plan moves from the `installed` plugin
+    /// to exactly that release, under the same rules as [`Self::update`]
+    async fn plan_from_url(
+        &self,
+        url: &str,
+        plugin_dir: &Path,
+        installed: &str,
+    ) -> Result<UpdatePlan> {
+        let client = crate::network::client()?;
+        let response = crate::network::send(client.get(url).header("User-Agent", "rhinolabs-cli"))
+            .await
+            .map_err(crate::network::request_error)?;
+
+        if !response.status().is_success() {
+            return Err(RhinolabsError::NetworkError(format!(
+                "Failed to fetch release info: HTTP {}",
+                response.status()
+            )));
+        }
+
+        let release: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| RhinolabsError::NetworkError(e.to_string()))?;
+        let latest_tag = release["tag_name"]
+            .as_str()
+            .ok_or_else(|| RhinolabsError::Other("Invalid release response".into()))?;
+
+        let update_available = if self.version.is_some() {
+            self.pinned_is_update(installed, latest_tag.trim_start_matches('v'))?
+        } else {
+            Version::newer_than_current(latest_tag)?.is_some()
+        };
+
+        let release_notes = release["body"]
+            .as_str()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+
+        let files_replaced = if update_available {
+            Self::list_plugin_files(plugin_dir)
+        } else {
+            Vec::new()
+        };
+
+        Ok(UpdatePlan {
+            current_version: installed.to_string(),
+            latest_version: latest_tag.trim_start_matches('v').to_string(),
+            update_available,
+            release_notes,
+            files_replaced,
+        })
+    }
+
+    /// List files (relative paths) under the plugin directory, skipping `.git/`
+    fn list_plugin_files(plugin_dir: &Path) -> Vec<String> {
+        if !plugin_dir.exists() {
+            return Vec::new();
+        }
+
+        let mut files: Vec<String> = walkdir::WalkDir::new(plugin_dir)
+            .into_iter()
+            .filter_entry(|e| e.file_name() != ".git")
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| {
+                e.path()
+                    .strip_prefix(plugin_dir)
+                    .ok()
+                    .map(|p| p.to_string_lossy().replace('\\', "/"))
+            })
+            .collect();
+
+        files.sort();
+        files
     }
 
     /// Backup current installation
@@ -108,6 +540,146 @@ mod tests {
         assert!(!updater.dry_run);
     }
 
+    #[tokio::test]
+    async fn test_plan_reports_delta_without_touching_files() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let plugin_dir = temp_dir.path().to_path_buf();
+        std::fs::create_dir_all(plugin_dir.join("skills").join("demo")).unwrap();
+        std::fs::write(plugin_dir.join("settings.json"), "{}").unwrap();
+        std::fs::write(plugin_dir.join("skills").join("demo").join("SKILL.md"), "x").unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/releases/latest"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "tag_name": "v99.0.0",
+                "body": "- New skills\n- Bug fixes",
+                "assets": [{
+                    "name": "rhinolabs-claude.zip",
+                    "browser_download_url": format!("{}/download/rhinolabs-claude.zip", server.uri())
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let before = Updater::list_plugin_files(&plugin_dir);
+        let plan = Updater::new()
+            .plan_from_url(
+                &format!("{}/releases/latest", server.uri()),
+                &plugin_dir,
+                &Version::current(),
+            )
+            .await
+            .unwrap();
+
+        assert!(plan.update_available);
+        assert_eq!(plan.current_version, Version::current());
+        assert_eq!(plan.latest_version, "99.0.0");
+        assert_eq!(
+            plan.release_notes.as_deref(),
+            Some("- New skills\n- Bug fixes")
+        );
+        assert_eq!(
+            plan.files_replaced,
+            vec![
+                "settings.json".to_string(),
+                "skills/demo/SKILL.md".to_string()
+            ]
+        );
+
+        // Nothing was downloaded or written
+        assert_eq!(Updater::list_plugin_files(&plugin_dir), before);
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url.path(), "/releases/latest");
+    }
+
+    #[tokio::test]
+    async fn test_plan_when_already_current() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "tag_name": format!("v{}", Version::current()),
+                "assets": []
+            })))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(temp_dir.path().join("settings.json"), "{}").unwrap();
+
+        let plan = Updater::new()
+            .plan_from_url(&server.uri(), temp_dir.path(), &Version::current())
+            .await
+            .unwrap();
+        assert!(!plan.update_available);
+        assert!(plan.files_replaced.is_empty());
+        assert!(plan.release_notes.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_plan_pinned_compares_against_installed_plugin() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "tag_name": "v1.2.0",
+                "assets": []
+            })))
+            .mount(&server)
+            .await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let pinned = Updater::new().version(Some("v1.2.0".into()));
+
+        let plan = pinned
+            .plan_from_url(&server.uri(), temp_dir.path(), "1.0.0")
+            .await
+            .unwrap();
+        assert!(plan.update_available);
+        assert_eq!(plan.current_version, "1.0.0");
+
+        let plan = pinned
+            .plan_from_url(&server.uri(), temp_dir.path(), "1.2.0")
+            .await
+            .unwrap();
+        assert!(!plan.update_available);
+
+        let err = pinned
+            .plan_from_url(&server.uri(), temp_dir.path(), "2.0.0")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("--allow-downgrade"));
+
+        let plan = pinned
+            .allow_downgrade(true)
+            .plan_from_url(&server.uri(), temp_dir.path(), "2.0.0")
+            .await
+            .unwrap();
+        assert!(plan.update_available);
+    }
+
+    #[tokio::test]
+    async fn test_plan_offline_returns_network_error() {
+        // Nothing listens on port 9 (discard) in the test environment
+        let temp_dir = tempfile::tempdir().unwrap();
+        let result = Updater::new()
+            .plan_from_url(
+                "http://127.0.0.1:9/releases/latest",
+                temp_dir.path(),
+                &Version::current(),
+            )
+            .await;
+        assert!(matches!(result, Err(RhinolabsError::NetworkError(_))));
+    }
+
     #[test]
     fn test_backup_naming_format() {
         // Test that backup naming follows pattern: rhinolabs-claude.backup.YYYYMMDD_HHMMSS
@@ -147,4 +719,213 @@ mod tests {
         let content = std::fs::read_to_string(backup_dir.join("test.txt")).unwrap();
         assert_eq!(content, "content");
     }
+
+    fn sha256_hex(bytes: &[u8]) -> String {
+        format!("{:x}", Sha256::digest(bytes))
+    }
+
+    #[test]
+    fn test_stage_binary_verifies_size_and_hash() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let target = temp_dir.path().join("rhinolabs-ai");
+        let bytes = b"new binary";
+
+        let staged = StagedBinary::stage(
+            bytes,
+            &target,
+            Some(bytes.len() as u64),
+            Some(&sha256_hex(bytes).to_uppercase()),
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(staged.path()).unwrap(), bytes);
+
+        // Dropping without applying cleans up
+        let staged_path = staged.path().to_path_buf();
+        drop(staged);
+        assert!(!staged_path.exists());
+    }
+
+    #[test]
+    fn test_stage_binary_rejects_bad_download() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let target = temp_dir.path().join("rhinolabs-ai");
+
+        let wrong_size = StagedBinary::stage(b"truncated", &target, Some(100), None);
+        assert!(matches!(wrong_size, Err(RhinolabsError::UpdateFailed(_))));
+
+        let wrong_hash = StagedBinary::stage(b"tampered", &target, None, Some(&sha256_hex(b"x")));
+        assert!(wrong_hash.unwrap_err().to_string().contains("Checksum"));
+
+        // Nothing was left next to the target
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_sha256_from_digest() {
+        assert_eq!(sha256_from_digest(Some("sha256:abc")), Some("abc"));
+        assert_eq!(sha256_from_digest(Some("md5:abc")), None);
+        assert_eq!(sha256_from_digest(None), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_apply_binary_replaces_atomically_on_unix() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let target = temp_dir.path().join("rhinolabs-ai");
+        std::fs::write(&target, "old").unwrap();
+
+        let staged = StagedBinary::stage(b"new", &target, None, None).unwrap();
+        assert_eq!(staged.apply(&target).unwrap(), BinaryReplace::Replaced);
+
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "new");
+        let mode = std::fs::metadata(&target).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+        assert!(!temp_dir.path().join("rhinolabs-ai.new").exists());
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_apply_binary_requires_restart_on_windows() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let target = temp_dir.path().join("rhinolabs-ai.exe");
+        std::fs::write(&target, "old").unwrap();
+
+        let staged = StagedBinary::stage(b"new", &target, None, None).unwrap();
+        assert_eq!(
+            staged.apply(&target).unwrap(),
+            BinaryReplace::RestartRequired
+        );
+
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "new");
+        assert!(cleanup_replaced_binary(&target));
+        assert!(!temp_dir.path().join("rhinolabs-ai.exe.old").exists());
+    }
+
+    #[tokio::test]
+    async fn test_update_binary_downloads_and_verifies_platform_asset() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let Some(asset_name) = platform_asset_name() else {
+            return;
+        };
+        let bytes = b"fresh binary".to_vec();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/releases/latest"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "tag_name": "v99.0.0",
+                "assets": [{
+                    "name": asset_name,
+                    "size": bytes.len(),
+                    "digest": format!("sha256:{}", sha256_hex(&bytes)),
+                    "browser_download_url": format!("{}/download/{}", server.uri(), asset_name)
+                }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/download/{}", asset_name)))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(bytes.clone()))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let exe = temp_dir.path().join(asset_name);
+        std::fs::write(&exe, "old").unwrap();
+
+        let outcome = Updater::new()
+            .update_binary_from_url(&format!("{}/releases/latest", server.uri()), &exe)
+            .await
+            .unwrap();
+
+        let expected = if cfg!(windows) {
+            BinaryReplace::RestartRequired
+        } else {
+            BinaryReplace::Replaced
+        };
+        assert_eq!(outcome, expected);
+        assert_eq!(std::fs::read(&exe).unwrap(), bytes);
+    }
+
+    #[tokio::test]
+    async fn test_update_binary_falls_back_to_release_checksums() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let Some(asset_name) = platform_asset_name() else {
+            return;
+        };
+        let bytes = b"fresh binary".to_vec();
+
+        let server = MockServer::start().await;
+        let release = |with_checksums: bool| {
+            let mut assets = vec![serde_json::json!({
+                "name": asset_name,
+                "browser_download_url": format!("{}/download/{}", server.uri(), asset_name)
+            })];
+            if with_checksums {
+                assets.push(serde_json::json!({
+                    "name": "SHA256SUMS.txt",
+                    "browser_download_url": format!("{}/download/SHA256SUMS.txt", server.uri())
+                }));
+            }
+            serde_json::json!({ "tag_name": "v99.0.0", "assets": assets })
+        };
+        Mock::given(method("GET"))
+            .and(path("/releases/latest"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(release(true)))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/releases/unverified"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(release(false)))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/download/{}", asset_name)))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(bytes.clone()))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/download/SHA256SUMS.txt"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+                "{}  {}\n",
+                sha256_hex(b"tampered"),
+                asset_name
+            )))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let exe = temp_dir.path().join(asset_name);
+        std::fs::write(&exe, "old").unwrap();
+
+        // The asset has no digest, so its SHA256SUMS entry is checked
+        let err = Updater::new()
+            .update_binary_from_url(&format!("{}/releases/latest", server.uri()), &exe)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Checksum mismatch"), "{}", err);
+        assert_eq!(std::fs::read_to_string(&exe).unwrap(), "old");
+
+        // With neither a digest nor SHA256SUMS only --no-verify goes ahead
+        let url = format!("{}/releases/unverified", server.uri());
+        let err = Updater::new()
+            .update_binary_from_url(&url, &exe)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("--no-verify"), "{}", err);
+        assert_eq!(std::fs::read_to_string(&exe).unwrap(), "old");
+
+        Updater::new()
+            .verify(false)
+            .update_binary_from_url(&url, &exe)
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(&exe).unwrap(), bytes);
+    }
 }
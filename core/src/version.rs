@@ -5,6 +5,42 @@ use std::fs;
 
 const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The plugin zip of a release and the checksums published next to it
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PluginDownload {
+    /// Release version, without the `v` prefix
+    pub version: String,
+    pub name: String,
+    pub url: String,
+    /// Download URL of the release's `SHA256SUMS` asset, if it has one
+    pub checksums_url: Option<String>,
+}
+
+/// Which component of a semantic version to increment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BumpKind {
+    Major,
+    Minor,
+    Patch,
+}
+
+impl std::str::FromStr for BumpKind {
+    type Err = RhinolabsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "major" => Ok(Self::Major),
+            "minor" => Ok(Self::Minor),
+            "patch" => Ok(Self::Patch),
+            _ => Err(RhinolabsError::ConfigError(format!(
+                "Invalid bump type: {}. Use major, minor, or patch",
+                s
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Version {
     pub version: String,
@@ -30,16 +66,29 @@ impl Version {
         Ok(Some(version))
     }
 
+    /// Installed plugin version, or this build's version when none is recorded
+    pub(crate) fn installed_or_current() -> Result<String> {
+        Ok(Self::installed()?
+            .map(|v| v.version)
+            .unwrap_or_else(Self::current))
+    }
+
     /// Save version info
     pub fn save(&self) -> Result<()> {
         let version_file = crate::Paths::version_file_path()?;
         let content = serde_json::to_string_pretty(self)?;
-        fs::write(version_file, content)?;
+        crate::fs_utils::write(version_file, content)?;
         Ok(())
     }
 
     /// Build GitHub API releases URL from project config
-    fn releases_api_url() -> Result<String> {
+    pub(crate) fn releases_api_url() -> Result<String> {
+        Self::release_api_url(None)
+    }
+
+    /// GitHub API URL of the release tagged `tag`, or of the latest release.
+    /// A bare version (`1.2.0`) is looked up as the `v1.2.0` tag.
+    pub(crate) fn release_api_url(tag: Option<&str>) -> Result<String> {
         let config = Project::get_config()?;
 
         if config.github.owner.is_empty() || config.github.repo.is_empty() {
@@ -48,22 +97,65 @@ impl Version {
             ));
         }
 
+        let release = match tag {
+            Some(tag) => format!("tags/{}", Self::normalize_tag(tag)),
+            None => "latest".to_string(),
+        };
         Ok(format!(
-            "https://api.github.com/repos/{}/{}/releases/latest",
-            config.github.owner, config.github.repo
+            "https://api.github.com/repos/{}/{}/releases/{}",
+            config.github.owner, config.github.repo, release
         ))
     }
 
+    /// Release tags are `v`-prefixed (see release.yml)
+    fn normalize_tag(tag: &str) -> String {
+        if tag.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("v{}", tag)
+        } else {
+            tag.to_string()
+        }
+    }
+
+    /// Increment one component of `current` and reset the lower ones, e.g.
+    /// `1.2.3` → `1.3.0` for [`BumpKind::Minor`]. Pre-release and build labels
+    /// are dropped.
+    pub fn bump(current: &str, kind: BumpKind) -> Result<String> {
+        let version = SemVersion::parse(current).map_err(|e| {
+            RhinolabsError::InvalidVersion(format!(
+                "'{}' is not a semantic version like 1.2.3 ({})",
+                current, e
+            ))
+        })?;
+
+        let bumped = match kind {
+            BumpKind::Major => SemVersion::new(version.major + 1, 0, 0),
+            BumpKind::Minor => SemVersion::new(version.major, version.minor + 1, 0),
+            BumpKind::Patch => SemVersion::new(version.major, version.minor, version.patch + 1),
+        };
+        Ok(bumped.to_string())
+    }
+
+    /// Whether `version` is a semantic version such as `1.2.0` (no `v` prefix)
+    pub(crate) fn is_semver(version: &str) -> bool {
+        SemVersion::parse(version).is_ok()
+    }
+
+    /// Whether moving from `from` to `to` (tags or versions) goes back in version
+    pub(crate) fn is_downgrade(from: &str, to: &str) -> Result<bool> {
+        let parse = |v: &str| {
+            SemVersion::parse(v.trim_start_matches('v'))
+                .map_err(|e| RhinolabsError::InvalidVersion(format!("{}: {}", v, e)))
+        };
+        Ok(parse(to)? < parse(from)?)
+    }
+
     /// Check if update is available
     pub async fn check_update() -> Result<Option<String>> {
         let url = Self::releases_api_url()?;
 
-        let client = reqwest::Client::new();
-        let response = client
-            .get(&url)
-            .header("User-Agent", "rhinolabs-cli")
-            .send()
-            .await?;
+        let client = crate::network::client()?;
+        let response =
+            crate::network::send(client.get(&url).header("User-Agent", "rhinolabs-cli")).await?;
 
         if !response.status().is_success() {
             return Err(RhinolabsError::Other("Failed to check for updates".into()));
@@ -74,6 +166,12 @@ impl Version {
             .as_str()
             .ok_or_else(|| RhinolabsError::Other("Invalid release response".into()))?;
 
+        Self::newer_than_current(latest_tag)
+    }
+
+    /// Compare a release tag against the current version.
+    /// Returns the tag's version (without 'v' prefix) if it is newer.
+    pub(crate) fn newer_than_current(latest_tag: &str) -> Result<Option<String>> {
         // Remove 'v' prefix if present
         let latest_version = latest_tag.trim_start_matches('v');
         let current_version = Self::current();
@@ -92,15 +190,25 @@ impl Version {
 
     /// Get download URL for latest release
     pub async fn get_latest_download_url() -> Result<String> {
-        let url = Self::releases_api_url()?;
+        Ok(Self::plugin_download(None).await?.url)
+    }
+
+    /// Plugin zip and checksums asset of the release tagged `tag`, or of the latest release
+    pub(crate) async fn plugin_download(tag: Option<&str>) -> Result<PluginDownload> {
+        Self::plugin_download_from_url(&Self::release_api_url(tag)?, tag).await
+    }
 
-        let client = reqwest::Client::new();
-        let response = client
-            .get(&url)
-            .header("User-Agent", "rhinolabs-cli")
-            .send()
-            .await?;
+    async fn plugin_download_from_url(url: &str, tag: Option<&str>) -> Result<PluginDownload> {
+        let client = crate::network::client()?;
+        let response =
+            crate::network::send(client.get(url).header("User-Agent", "rhinolabs-cli")).await?;
 
+        if let (Some(tag), reqwest::StatusCode::NOT_FOUND) = (tag, response.status()) {
+            return Err(RhinolabsError::DownloadFailed(format!(
+                "Release {} not found",
+                Self::normalize_tag(tag)
+            )));
+        }
         if !response.status().is_success() {
             return Err(RhinolabsError::DownloadFailed(
                 "Could not fetch release info".into(),
@@ -108,16 +216,38 @@ impl Version {
         }
 
         let release: serde_json::Value = response.json().await?;
+        Self::plugin_download_from_release(&release)
+    }
+
+    fn plugin_download_from_release(release: &serde_json::Value) -> Result<PluginDownload> {
+        let version = release["tag_name"]
+            .as_str()
+            .ok_or_else(|| RhinolabsError::Other("Invalid release response".into()))?
+            .trim_start_matches('v')
+            .to_string();
         let assets = release["assets"]
             .as_array()
             .ok_or_else(|| RhinolabsError::DownloadFailed("No assets found".into()))?;
+        let download_url =
+            |asset: &serde_json::Value| asset["browser_download_url"].as_str().map(str::to_string);
+
+        // SHA256SUMS.txt in releases built by release.yml
+        let checksums_url = assets
+            .iter()
+            .find(|a| matches!(a["name"].as_str(), Some("SHA256SUMS" | "SHA256SUMS.txt")))
+            .and_then(download_url);
 
         // Find rhinolabs-claude.zip asset
         for asset in assets {
             if let Some(name) = asset["name"].as_str() {
                 if name.starts_with("rhinolabs-claude") && name.ends_with(".zip") {
-                    if let Some(download_url) = asset["browser_download_url"].as_str() {
-                        return Ok(download_url.to_string());
+                    if let Some(url) = download_url(asset) {
+                        return Ok(PluginDownload {
+                            version,
+                            name: name.to_string(),
+                            url,
+                            checksums_url,
+                        });
                     }
                 }
             }
@@ -133,6 +263,40 @@ impl Version {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_bump_increments_and_resets_lower_components() {
+        assert_eq!(Version::bump("1.2.3", BumpKind::Patch).unwrap(), "1.2.4");
+        assert_eq!(Version::bump("1.2.3", BumpKind::Minor).unwrap(), "1.3.0");
+        assert_eq!(Version::bump("1.2.3", BumpKind::Major).unwrap(), "2.0.0");
+        assert_eq!(Version::bump("0.9.9", BumpKind::Minor).unwrap(), "0.10.0");
+        assert_eq!(
+            Version::bump("1.2.3-beta.1", BumpKind::Patch).unwrap(),
+            "1.2.4"
+        );
+    }
+
+    #[test]
+    fn test_bump_rejects_non_semver() {
+        for current in ["1.2", "v1.2.3", "latest", ""] {
+            let err = Version::bump(current, BumpKind::Patch).unwrap_err();
+            assert!(
+                matches!(err, RhinolabsError::InvalidVersion(_)),
+                "{}",
+                current
+            );
+        }
+    }
+
+    #[test]
+    fn test_bump_kind_from_str() {
+        assert_eq!("Minor".parse::<BumpKind>().unwrap(), BumpKind::Minor);
+        assert!("huge".parse::<BumpKind>().is_err());
+        assert_eq!(
+            serde_json::from_str::<BumpKind>("\"major\"").unwrap(),
+            BumpKind::Major
+        );
+    }
+
     #[test]
     fn test_current_version() {
         let version = Version::current();
@@ -302,4 +466,67 @@ mod tests {
         assert!(stable > alpha);
         assert!(beta > alpha);
     }
+
+    #[test]
+    fn test_plugin_download_finds_checksums_asset() {
+        let release = serde_json::json!({
+            "tag_name": "v1.2.3",
+            "assets": [
+                { "name": "rhinolabs-claude-1.2.3.zip", "browser_download_url": "https://example.com/plugin.zip" },
+                { "name": "SHA256SUMS.txt", "browser_download_url": "https://example.com/SHA256SUMS.txt" }
+            ]
+        });
+        let download = Version::plugin_download_from_release(&release).unwrap();
+        assert_eq!(download.version, "1.2.3");
+        assert_eq!(download.name, "rhinolabs-claude-1.2.3.zip");
+        assert_eq!(download.url, "https://example.com/plugin.zip");
+        assert_eq!(
+            download.checksums_url.as_deref(),
+            Some("https://example.com/SHA256SUMS.txt")
+        );
+
+        let release = serde_json::json!({
+            "tag_name": "v1.2.3",
+            "assets": [{ "name": "rhinolabs-claude.zip", "browser_download_url": "https://example.com/plugin.zip" }]
+        });
+        let download = Version::plugin_download_from_release(&release).unwrap();
+        assert_eq!(download.checksums_url, None);
+    }
+
+    #[test]
+    fn test_release_api_url_for_tag() {
+        let url = Version::release_api_url(Some("1.2.0")).unwrap();
+        assert!(url.ends_with("/releases/tags/v1.2.0"));
+        let url = Version::release_api_url(Some("v1.2.0")).unwrap();
+        assert!(url.ends_with("/releases/tags/v1.2.0"));
+        assert!(Version::release_api_url(None)
+            .unwrap()
+            .ends_with("/releases/latest"));
+    }
+
+    #[test]
+    fn test_is_downgrade() {
+        assert!(Version::is_downgrade("1.3.0", "v1.2.9").unwrap());
+        assert!(!Version::is_downgrade("v1.2.0", "1.2.0").unwrap());
+        assert!(!Version::is_downgrade("1.2.0", "1.10.0").unwrap());
+        assert!(Version::is_downgrade("1.2.0", "main").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_plugin_download_missing_tag() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let url = format!("{}/releases/tags/v9.9.9", server.uri());
+        let err = Version::plugin_download_from_url(&url, Some("9.9.9"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Download failed: Release v9.9.9 not found");
+    }
 }
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+/// Non-fatal problems collected during an operation that otherwise succeeded.
+///
+/// Operations push human-readable messages here instead of printing to stderr,
+/// so callers decide how to surface them (CLI output, `--json`, GUI toasts).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Warnings(Vec<String>);
+
+impl Warnings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a warning; also logged (`-v` in the CLI) so it shows up next to the
+    /// requests that led to it
+    pub fn push(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        tracing::warn!("{}", message);
+        self.0.push(message);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, String> {
+        self.0.iter()
+    }
+
+    pub fn into_vec(self) -> Vec<String> {
+        self.0
+    }
+}
+
+impl<'a> IntoIterator for &'a Warnings {
+    type Item = &'a String;
+    type IntoIter = std::slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl From<Vec<String>> for Warnings {
+    fn from(messages: Vec<String>) -> Self {
+        Self(messages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serializes_as_plain_array() {
+        let mut warnings = Warnings::new();
+        warnings.push("first");
+        warnings.push(String::from("second"));
+
+        let json = serde_json::to_value(&warnings).unwrap();
+        assert_eq!(json, serde_json::json!(["first", "second"]));
+    }
+}
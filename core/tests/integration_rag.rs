@@ -3,7 +3,7 @@
 //! Tests local configuration management for RAG.
 //! All actual RAG operations are handled by the MCP Worker.
 
-use rhinolabs_core::{Rag, RagConfig, RagSettings};
+use rhinolabs_core::{Rag, RagConfig, RagIndexSettings, RagSettings};
 use std::fs;
 use std::sync::Mutex;
 use tempfile::TempDir;
@@ -13,7 +13,6 @@ static ENV_MUTEX: Mutex<()> = Mutex::new(());
 
 /// Helper to set up test environment with isolated config
 struct TestEnv {
-    #[allow(dead_code)]
     temp_dir: TempDir,
     project_dir: TempDir,
     _lock: std::sync::MutexGuard<'static, ()>,
@@ -106,6 +105,7 @@ fn test_rag_load_config_returns_saved_config() {
         project_id: "test-project".to_string(),
         api_key: "rl_abc123".to_string(),
         mcp_url: Some("https://custom.workers.dev".to_string()),
+        settings: RagIndexSettings::default(),
     };
 
     let content = serde_json::to_string_pretty(&config).unwrap();
@@ -170,6 +170,7 @@ fn test_get_mcp_url_returns_default() {
         project_id: "test".to_string(),
         api_key: "rl_xxx".to_string(),
         mcp_url: None,
+        settings: RagIndexSettings::default(),
     };
 
     let url = Rag::get_mcp_url(&config);
@@ -183,6 +184,7 @@ fn test_get_mcp_url_returns_custom_url() {
         project_id: "test".to_string(),
         api_key: "rl_xxx".to_string(),
         mcp_url: Some("https://custom-mcp.example.com".to_string()),
+        settings: RagIndexSettings::default(),
     };
 
     let url = Rag::get_mcp_url(&config);
@@ -231,6 +233,40 @@ fn test_rag_settings_persistence() {
     assert_eq!(loaded.admin_key, Some("my_admin_key".to_string()));
 }
 
+#[test]
+fn test_rag_set_setting_persists_and_validates() {
+    let env = TestEnv::new();
+
+    // Settings belong to an initialized project
+    assert!(Rag::set_setting(env.project_path(), "chunk-size", "800").is_err());
+    Rag::init(env.project_path(), "my-project", "rl_test123").unwrap();
+
+    Rag::set_setting(env.project_path(), "chunk-size", "800").unwrap();
+    Rag::set_setting(
+        env.project_path(),
+        "embedding-model",
+        "@cf/baai/bge-small-en-v1.5",
+    )
+    .unwrap();
+
+    // Overlap must be smaller than chunk size; invalid values are not saved
+    assert!(Rag::set_setting(env.project_path(), "chunk-overlap", "800").is_err());
+
+    let loaded = Rag::load_config(env.project_path()).unwrap().unwrap();
+    assert_eq!(loaded.settings.chunk_size, 800);
+    assert_eq!(
+        loaded.settings.chunk_overlap,
+        RagIndexSettings::default().chunk_overlap
+    );
+    assert_eq!(
+        loaded.settings.embedding_model,
+        "@cf/baai/bge-small-en-v1.5"
+    );
+
+    // Nothing is written to the global settings file
+    assert!(!env.temp_dir.path().join("rag-settings.json").exists());
+}
+
 // =============================================================================
 // Serialization Tests
 // =============================================================================
@@ -241,6 +277,7 @@ fn test_rag_config_serialization_camel_case() {
         project_id: "test-project".to_string(),
         api_key: "rl_abc123".to_string(),
         mcp_url: Some("https://example.com".to_string()),
+        settings: RagIndexSettings::default(),
     };
 
     let json = serde_json::to_string(&config).unwrap();
@@ -280,6 +317,7 @@ fn test_rag_config_skips_none_mcp_url() {
         project_id: "test".to_string(),
         api_key: "rl_xxx".to_string(),
         mcp_url: None,
+        settings: RagIndexSettings::default(),
     };
 
     let json = serde_json::to_string(&config).unwrap();
@@ -332,6 +370,7 @@ fn test_api_key_format() {
         project_id: "test".to_string(),
         api_key: "rl_abc123def456".to_string(),
         mcp_url: None,
+        settings: RagIndexSettings::default(),
     };
 
     assert!(config.api_key.starts_with("rl_"));
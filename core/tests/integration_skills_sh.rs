@@ -1,4 +1,7 @@
-use rhinolabs_core::skills::{SkillSchema, SkillSource, SkillSourceType, Skills};
+use rhinolabs_core::skills::{
+    SkillSchema, SkillSource, SkillSourceType, Skills, SkillsShQuery, SkillsShSort,
+};
+use rhinolabs_core::Warnings;
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -56,6 +59,9 @@ fn make_source(url: &str) -> SkillSource {
         fetchable: true,
         schema: SkillSchema::SkillsSh,
         skill_count: None,
+        branch: None,
+        skills_path: None,
+        auth_token_env: None,
     }
 }
 
@@ -229,7 +235,7 @@ async fn test_fetch_from_source_dispatches_skills_sh() {
 
     let source = make_source(&server.uri());
     // Use fetch_from_source (dispatcher) instead of fetch_from_skills_sh directly
-    let result = Skills::fetch_from_source(&source).await;
+    let result = Skills::fetch_from_source(&source, &mut Warnings::new()).await;
 
     assert!(
         result.is_ok(),
@@ -238,3 +244,26 @@ async fn test_fetch_from_source_dispatches_skills_sh() {
     );
     assert_eq!(result.unwrap().len(), 3);
 }
+
+#[tokio::test]
+async fn test_fetch_skills_sh_query_selects_listing_and_array() {
+    let server = MockServer::start().await;
+
+    let html = r#"<html><script>self.__next_f.push([1,"a:{"trendingSkills":[{"source":"a/b","skillId":"rising","name":"Rising","installs":10}],"allTimeSkills":[{"source":"c/d","skillId":"classic","name":"Classic","installs":900}]}"])</script></html>"#;
+    Mock::given(method("GET"))
+        .and(path("/trending/react"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(html))
+        .mount(&server)
+        .await;
+
+    let query = SkillsShQuery {
+        sort: SkillsShSort::Trending,
+        category: Some("react".to_string()),
+    };
+    let skills = Skills::fetch_from_skills_sh_with(&make_source(&server.uri()), &query)
+        .await
+        .unwrap();
+
+    assert_eq!(skills.len(), 1);
+    assert_eq!(skills[0].id, "a/b/rising");
+}
@@ -1,12 +1,14 @@
 use rhinolabs_core::diagnostics::DiagnosticReport;
 use rhinolabs_core::{
-    AutoInvokeRule, ConfigManifest, CreateProfileInput, CreateSkillInput, Deploy, DeployResult,
-    Doctor, Installer, Instructions, InstructionsManager, Manifest, McpConfig, McpConfigManager,
+    AutoInvokeRule, BulkSkillInstallResult, BumpKind, ConfigManifest, CreateProfileInput,
+    CreateSkillInput, Deploy, DeployResult, Doctor, InstallOptions, InstallSkillInput, Installer,
+    Instructions, InstructionsManager, InstructionsSection, Manifest, McpConfig, McpConfigManager,
     McpServer, McpSettings, McpSync, OutputStyle, OutputStyles, Paths, PermissionConfig,
     PluginManifest, PluginSettings, Profile, ProfileInstallResult, Profiles, Project,
-    ProjectConfig, ProjectStatus, RemoteSkill, RemoteSkillFile, Settings, Skill, SkillCategory,
-    SkillSchema, SkillSource, SkillSourceType, Skills, StatusLineConfig, SyncResult,
-    UpdateProfileInput, UpdateSkillInput, Updater, Version,
+    ProjectConfig, ProjectStatus, RemoteSkillFetch, RemoteSkillFile, Settings, Skill,
+    SkillCategory, SkillDiff, SkillInstallResult, SkillSchema, SkillSource, SkillSourceType,
+    SkillUpdateCheck, Skills, StatusLineConfig, SyncResult, UpdateProfileInput, UpdateSkillInput,
+    UpdateSkillSourceInput, Updater, Version, Warnings, DEFAULT_SKILLS_PATH,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -67,7 +69,11 @@ pub async fn install_plugin(local_path: Option<String>) -> Result<(), String> {
 #[tauri::command]
 pub async fn update_plugin() -> Result<(), String> {
     let updater = Updater::new();
-    updater.update().await.map_err(|e| e.to_string())
+    updater
+        .update()
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -82,7 +88,7 @@ pub fn uninstall_plugin() -> Result<(), String> {
 
 #[tauri::command]
 pub async fn run_diagnostics() -> Result<DiagnosticReport, String> {
-    Doctor::run().await.map_err(|e| e.to_string())
+    Doctor::run(false).await.map_err(|e| e.to_string())
 }
 
 // ============================================
@@ -197,6 +203,11 @@ pub fn remove_mcp_server(name: String) -> Result<(), String> {
     McpConfigManager::remove_server(&name).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub fn set_mcp_server_enabled(name: String, enabled: bool) -> Result<(), String> {
+    McpConfigManager::set_enabled(&name, enabled).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub fn get_mcp_settings() -> Result<McpSettings, String> {
     McpConfigManager::get_settings().map_err(|e| e.to_string())
@@ -215,7 +226,7 @@ pub async fn sync_mcp_config(url: Option<String>, file_path: Option<String>) ->
         _ => return Err("Must specify either url or file_path".into()),
     };
 
-    sync.sync().await.map_err(|e| e.to_string())
+    sync.sync().await.map(|_| ()).map_err(|e| e.to_string())
 }
 
 // ============================================
@@ -293,8 +304,12 @@ pub fn delete_output_style(id: String) -> Result<(), String> {
 // ============================================
 
 #[tauri::command]
-pub fn list_skills() -> Result<Vec<Skill>, String> {
-    Skills::list().map_err(|e| e.to_string())
+pub fn list_skills(include_profiles: Option<bool>) -> Result<Vec<Skill>, String> {
+    if include_profiles.unwrap_or(false) {
+        Skills::list_with_profiles().map_err(|e| e.to_string())
+    } else {
+        Skills::list().map_err(|e| e.to_string())
+    }
 }
 
 #[tauri::command]
@@ -307,6 +322,11 @@ pub fn create_skill(input: CreateSkillInput) -> Result<Skill, String> {
     Skills::create(input).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub fn import_skill(path: String) -> Result<Skill, String> {
+    Skills::import_from_path(Path::new(&path)).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub fn update_skill(id: String, input: UpdateSkillInput) -> Result<(), String> {
     Skills::update(&id, input).map_err(|e| e.to_string())
@@ -317,23 +337,25 @@ pub fn toggle_skill(id: String, enabled: bool) -> Result<(), String> {
     Skills::toggle(&id, enabled).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub fn toggle_skill_for_target(id: String, target: String, enabled: bool) -> Result<(), String> {
+    let target: rhinolabs_core::DeployTarget = target.parse()?;
+    Skills::toggle_for_target(&id, target, enabled).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub fn delete_skill(id: String) -> Result<(), String> {
     Skills::delete(&id).map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-pub fn set_skill_category(skill_id: String, category: String) -> Result<(), String> {
-    let category_enum = match category.to_lowercase().as_str() {
-        "corporate" => SkillCategory::Corporate,
-        "frontend" => SkillCategory::Frontend,
-        "testing" => SkillCategory::Testing,
-        "aisdk" | "ai-sdk" => SkillCategory::AiSdk,
-        "utilities" => SkillCategory::Utilities,
-        _ => SkillCategory::Custom,
-    };
+pub fn rename_skill(old_id: String, new_id: String) -> Result<Skill, String> {
+    Skills::rename(&old_id, &new_id).map_err(|e| e.to_string())
+}
 
-    Skills::set_category(&skill_id, category_enum).map_err(|e| e.to_string())
+#[tauri::command]
+pub fn set_skill_category(skill_id: String, category: String) -> Result<(), String> {
+    Skills::set_category(&skill_id, SkillCategory::from_label(&category)).map_err(|e| e.to_string())
 }
 
 // ============================================
@@ -357,10 +379,19 @@ pub struct AddSkillSourceInput {
     fetchable: bool,
     #[serde(default)]
     schema: String,
+    #[serde(default)]
+    branch: Option<String>,
+    #[serde(default)]
+    skills_path: Option<String>,
+    #[serde(default)]
+    auth_token_env: Option<String>,
+    /// Probe the source before adding it
+    #[serde(default)]
+    validate: bool,
 }
 
 #[tauri::command]
-pub fn add_skill_source(input: AddSkillSourceInput) -> Result<(), String> {
+pub async fn add_skill_source(input: AddSkillSourceInput) -> Result<(), String> {
     let source_type = match input.source_type.as_str() {
         "official" => SkillSourceType::Official,
         "marketplace" => SkillSourceType::Marketplace,
@@ -384,13 +415,27 @@ pub fn add_skill_source(input: AddSkillSourceInput) -> Result<(), String> {
         fetchable: input.fetchable,
         schema,
         skill_count: None,
+        branch: input
+            .branch
+            .map(|b| b.trim().to_string())
+            .filter(|b| !b.is_empty()),
+        skills_path: input
+            .skills_path
+            .map(|p| p.trim().to_string())
+            .filter(|p| p != DEFAULT_SKILLS_PATH),
+        auth_token_env: input
+            .auth_token_env
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty()),
     };
 
-    Skills::add_source(source).map_err(|e| e.to_string())
+    Skills::add_source(source, input.validate)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-pub fn update_skill_source(
+pub async fn update_skill_source(
     id: String,
     enabled: Option<bool>,
     name: Option<String>,
@@ -398,13 +443,29 @@ pub fn update_skill_source(
     description: Option<String>,
     fetchable: Option<bool>,
     schema: Option<String>,
+    branch: Option<String>,
+    skills_path: Option<String>,
+    auth_token_env: Option<String>,
+    validate: Option<bool>,
 ) -> Result<(), String> {
     let schema = schema.map(|s| match s.as_str() {
         "standard" => SkillSchema::Standard,
         "skills-sh" => SkillSchema::SkillsSh,
         _ => SkillSchema::Custom,
     });
-    Skills::update_source(&id, enabled, name, url, description, fetchable, schema)
+    let input = UpdateSkillSourceInput {
+        enabled,
+        name,
+        url,
+        description,
+        fetchable,
+        schema,
+        branch,
+        skills_path,
+        auth_token_env,
+    };
+    Skills::update_source(&id, input, validate.unwrap_or(false))
+        .await
         .map_err(|e| e.to_string())
 }
 
@@ -420,15 +481,23 @@ pub struct InstallSkillFromSourceInput {
     skill_content: String,
     source_id: String,
     source_name: String,
+    #[serde(default)]
+    assign_to_profile: Option<String>,
+    #[serde(default)]
+    allow_main: bool,
 }
 
 #[tauri::command]
-pub fn install_skill_from_source(input: InstallSkillFromSourceInput) -> Result<Skill, String> {
+pub fn install_skill_from_source(
+    input: InstallSkillFromSourceInput,
+) -> Result<SkillInstallResult, String> {
     Skills::install_from_source(
         &input.skill_id,
         &input.skill_content,
         &input.source_id,
         &input.source_name,
+        input.assign_to_profile.as_deref(),
+        input.allow_main,
     )
     .map_err(|e| e.to_string())
 }
@@ -440,29 +509,52 @@ pub struct InstallSkillFromRemoteInput {
     skill_id: String,
     source_id: String,
     source_name: String,
+    #[serde(default)]
+    branch: Option<String>,
+    #[serde(default)]
+    assign_to_profile: Option<String>,
+    #[serde(default)]
+    allow_main: bool,
 }
 
 #[tauri::command]
 pub async fn install_skill_from_remote(
     input: InstallSkillFromRemoteInput,
-) -> Result<Skill, String> {
+) -> Result<SkillInstallResult, String> {
     Skills::install_from_remote(
         &input.source_url,
         &input.skill_id,
         &input.source_id,
         &input.source_name,
+        input.branch.as_deref(),
+        input.assign_to_profile.as_deref(),
+        input.allow_main,
     )
     .await
     .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn install_skills_from_remote(
+    specs: Vec<InstallSkillInput>,
+) -> Result<BulkSkillInstallResult, String> {
+    Skills::install_many(specs).await.map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub fn get_installed_skill_ids() -> Result<Vec<String>, String> {
     Skills::installed_ids().map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-pub async fn fetch_remote_skills(source_id: String) -> Result<Vec<RemoteSkill>, String> {
+pub async fn fetch_remote_skills(
+    source_id: String,
+    force_refresh: Option<bool>,
+) -> Result<RemoteSkillFetch, String> {
+    if force_refresh.unwrap_or(false) {
+        Skills::invalidate_cache(Some(&source_id)).map_err(|e| e.to_string())?;
+    }
+
     let sources = Skills::list_sources().map_err(|e| e.to_string())?;
 
     let source = sources
@@ -470,9 +562,36 @@ pub async fn fetch_remote_skills(source_id: String) -> Result<Vec<RemoteSkill>,
         .find(|s| s.id == source_id)
         .ok_or_else(|| format!("Source '{}' not found", source_id))?;
 
-    Skills::fetch_from_source(&source)
+    let mut warnings = Warnings::new();
+    let skills = Skills::fetch_from_source(&source, &mut warnings)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+
+    Ok(RemoteSkillFetch { skills, warnings })
+}
+
+#[tauri::command]
+pub async fn check_skill_updates() -> Result<SkillUpdateCheck, String> {
+    let mut warnings = Warnings::new();
+    let updates = Skills::check_updates(&mut warnings)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(SkillUpdateCheck { updates, warnings })
+}
+
+#[tauri::command]
+pub async fn diff_skill(id: String) -> Result<SkillDiff, String> {
+    let mut warnings = Warnings::new();
+    let diff = Skills::diff(&id, &mut warnings)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(SkillDiff {
+        skill_id: id,
+        diff,
+        warnings,
+    })
 }
 
 #[tauri::command]
@@ -487,15 +606,24 @@ pub async fn fetch_skill_content(url: String) -> Result<String, String> {
 pub struct FetchRemoteSkillFilesInput {
     source_url: String,
     skill_id: String,
+    #[serde(default)]
+    branch: Option<String>,
+    #[serde(default)]
+    skills_path: Option<String>,
 }
 
 #[tauri::command]
 pub async fn fetch_remote_skill_files(
     input: FetchRemoteSkillFilesInput,
 ) -> Result<Vec<RemoteSkillFile>, String> {
-    Skills::fetch_remote_skill_files(&input.source_url, &input.skill_id)
-        .await
-        .map_err(|e| e.to_string())
+    Skills::fetch_remote_skill_files(
+        &input.source_url,
+        &input.skill_id,
+        input.branch.as_deref(),
+        input.skills_path.as_deref(),
+    )
+    .await
+    .map_err(|e| e.to_string())
 }
 
 // ============================================
@@ -512,6 +640,16 @@ pub fn update_instructions(content: String) -> Result<(), String> {
     InstructionsManager::update(&content).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub fn get_instructions_sections() -> Result<Vec<InstructionsSection>, String> {
+    InstructionsManager::sections().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn update_instructions_section(name: String, content: String) -> Result<(), String> {
+    InstructionsManager::update_section(&name, &content).map_err(|e| e.to_string())
+}
+
 // ============================================
 // Project Commands
 // ============================================
@@ -535,12 +673,18 @@ pub fn get_project_status() -> Result<ProjectStatus, String> {
 pub async fn fetch_latest_release() -> Result<Option<String>, String> {
     Project::fetch_latest_release()
         .await
+        .map(|release| release.map(|r| r.version))
         .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-pub fn bump_version(bump_type: String) -> Result<String, String> {
-    Project::bump_version(&bump_type).map_err(|e| e.to_string())
+pub fn bump_version(bump_type: BumpKind) -> Result<String, String> {
+    Project::bump_version(bump_type).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_default_changelog() -> Result<String, String> {
+    Project::default_changelog().map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -763,6 +907,15 @@ pub fn create_profile(input: CreateProfileInput) -> Result<Profile, String> {
     Profiles::create(input).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub fn clone_profile(
+    source_id: String,
+    new_id: String,
+    new_name: String,
+) -> Result<Profile, String> {
+    Profiles::clone(&source_id, &new_id, &new_name).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub fn update_profile(id: String, input: UpdateProfileInput) -> Result<Profile, String> {
     Profiles::update(&id, input).map_err(|e| e.to_string())
@@ -811,7 +964,8 @@ pub fn install_profile(
     let deploy_targets: Option<Vec<rhinolabs_core::DeployTarget>> =
         targets.map(|t| t.iter().filter_map(|s| s.parse().ok()).collect());
     let targets_ref = deploy_targets.as_deref();
-    Profiles::install(&profile_id, path, targets_ref).map_err(|e| e.to_string())
+    Profiles::install(&profile_id, path, targets_ref, InstallOptions::default())
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -824,15 +978,25 @@ pub fn update_installed_profile(
     let deploy_targets: Option<Vec<rhinolabs_core::DeployTarget>> =
         targets.map(|t| t.iter().filter_map(|s| s.parse().ok()).collect());
     let targets_ref = deploy_targets.as_deref();
-    Profiles::update_installed(&profile_id, path, targets_ref).map_err(|e| e.to_string())
+    Profiles::update_installed(&profile_id, path, targets_ref, InstallOptions::default())
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-pub fn uninstall_profile(target_path: String, targets: Option<Vec<String>>) -> Result<(), String> {
+pub fn uninstall_profile(
+    target_path: String,
+    targets: Option<Vec<String>>,
+    keep_instructions: Option<bool>,
+) -> Result<(), String> {
     let deploy_targets: Option<Vec<rhinolabs_core::DeployTarget>> =
         targets.map(|t| t.iter().filter_map(|s| s.parse().ok()).collect());
     let targets_ref = deploy_targets.as_deref();
-    Profiles::uninstall(std::path::Path::new(&target_path), targets_ref).map_err(|e| e.to_string())
+    Profiles::uninstall(
+        std::path::Path::new(&target_path),
+        targets_ref,
+        keep_instructions.unwrap_or(false),
+    )
+    .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
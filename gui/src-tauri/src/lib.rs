@@ -70,6 +70,7 @@ pub fn run() {
             add_mcp_server,
             update_mcp_server,
             remove_mcp_server,
+            set_mcp_server_enabled,
             get_mcp_settings,
             update_mcp_settings,
             sync_mcp_config,
@@ -85,9 +86,12 @@ pub fn run() {
             list_skills,
             get_skill,
             create_skill,
+            import_skill,
             update_skill,
             toggle_skill,
+            toggle_skill_for_target,
             delete_skill,
+            rename_skill,
             set_skill_category,
             // Skill Sources
             list_skill_sources,
@@ -96,19 +100,25 @@ pub fn run() {
             remove_skill_source,
             install_skill_from_source,
             install_skill_from_remote,
+            install_skills_from_remote,
             get_installed_skill_ids,
             fetch_remote_skills,
+            check_skill_updates,
+            diff_skill,
             fetch_skill_content,
             fetch_remote_skill_files,
             // Instructions
             get_instructions,
             update_instructions,
+            get_instructions_sections,
+            update_instructions_section,
             // Project & Release
             get_project_config,
             update_project_config,
             get_project_status,
             fetch_latest_release,
             bump_version,
+            get_default_changelog,
             create_release,
             // IDE & File Operations
             list_available_ides,
@@ -124,6 +134,7 @@ pub fn run() {
             list_profiles,
             get_profile,
             create_profile,
+            clone_profile,
             update_profile,
             delete_profile,
             assign_skills_to_profile,
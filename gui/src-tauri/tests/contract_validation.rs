@@ -73,6 +73,11 @@ fn test_skill_has_frontend_fields() {
         source_id: None,
         source_name: None,
         is_modified: false,
+        version: None,
+        author: None,
+        license: None,
+        tags: Vec::new(),
+        profile_ids: None,
     };
 
     let json = serde_json::to_value(&skill).expect("Skill should serialize");
@@ -133,6 +138,11 @@ fn test_profile_has_frontend_fields() {
         instructions: Some("# Custom instructions".to_string()),
         generate_copilot: true,
         generate_agents: false,
+        generate_gemini: false,
+        generate_opencode: false,
+        tags: Vec::new(),
+        extends: None,
+        output_style: None,
         created_at: "2026-01-20T10:00:00Z".to_string(),
         updated_at: "2026-01-20T10:00:00Z".to_string(),
     };
@@ -195,6 +205,7 @@ fn test_diagnostic_report_has_frontend_fields() {
         passed: 1,
         failed: 1,
         warnings: 1,
+        success: false,
     };
 
     let json = serde_json::to_value(&report).expect("DiagnosticReport should serialize");
@@ -204,6 +215,7 @@ fn test_diagnostic_report_has_frontend_fields() {
     assert_has_field(&json, "passed", context);
     assert_has_field(&json, "failed", context);
     assert_has_field(&json, "warnings", context);
+    assert_has_field(&json, "success", context);
 
     // Each check should have name, status, message
     let checks = json["checks"].as_array().unwrap();
@@ -295,6 +307,9 @@ fn test_skill_source_has_frontend_fields() {
         fetchable: true,
         schema: SkillSchema::Standard,
         skill_count: Some(42),
+        branch: Some("master".to_string()),
+        skills_path: Some("packages".to_string()),
+        auth_token_env: None,
     };
 
     let json = serde_json::to_value(&source).expect("SkillSource should serialize");
@@ -308,6 +323,8 @@ fn test_skill_source_has_frontend_fields() {
     assert_has_field(&json, "enabled", context);
     assert_has_field(&json, "fetchable", context);
     assert_has_field(&json, "schema", context);
+    assert_has_field(&json, "branch", context);
+    assert_has_field(&json, "skillsPath", context);
 
     // sourceType should be one of the valid values
     let st = json["sourceType"].as_str().unwrap();
@@ -407,6 +424,7 @@ fn test_profile_sync_result_has_expected_fields() {
         removed: vec!["old-skill".to_string()],
         unchanged: vec!["react-patterns".to_string()],
         profile_id: Some("react-stack".to_string()),
+        warnings: rhinolabs_core::Warnings::new(),
     };
 
     let json = serde_json::to_value(&result).expect("ProfileSyncResult should serialize");
@@ -417,6 +435,7 @@ fn test_profile_sync_result_has_expected_fields() {
     assert_has_field(&json, "removed", context);
     assert_has_field(&json, "unchanged", context);
     assert_has_field(&json, "profileId", context);
+    assert_has_field(&json, "warnings", context);
 
     // Verify types
     assert!(json["status"].is_string());
@@ -424,6 +443,7 @@ fn test_profile_sync_result_has_expected_fields() {
     assert!(json["removed"].is_array());
     assert!(json["unchanged"].is_array());
     assert!(json["profileId"].is_string());
+    assert!(json["warnings"].is_array());
 
     // Verify status is one of the valid values
     let status = json["status"].as_str().unwrap();
@@ -444,6 +464,7 @@ fn test_profile_sync_result_no_profile_omits_profile_id() {
         removed: Vec::new(),
         unchanged: Vec::new(),
         profile_id: None,
+        warnings: rhinolabs_core::Warnings::new(),
     };
 
     let json = serde_json::to_value(&result).expect("ProfileSyncResult should serialize");
@@ -468,6 +489,7 @@ fn test_profile_install_result_has_expected_fields() {
         profile_name: "React Stack".to_string(),
         target_path: "/project".to_string(),
         skills_installed: vec!["skill-a".to_string()],
+        skills_unchanged: Vec::new(),
         skills_failed: vec![SkillInstallError {
             skill_id: "bad-skill".to_string(),
             error: "Not found".to_string(),
@@ -475,7 +497,11 @@ fn test_profile_install_result_has_expected_fields() {
         instructions_installed: None,
         settings_installed: None,
         output_style_installed: None,
+        plugin_manifest_installed: None,
+        skills_skipped_disabled: vec![],
         targets_installed: vec![rhinolabs_core::DeployTarget::ClaudeCode],
+        targets_failed: vec![],
+        duration_ms: 0,
     };
 
     let json = serde_json::to_value(&result).expect("ProfileInstallResult should serialize");
@@ -487,6 +513,8 @@ fn test_profile_install_result_has_expected_fields() {
     assert_has_field(&json, "skillsInstalled", context);
     assert_has_field(&json, "skillsFailed", context);
     assert_has_field(&json, "targetsInstalled", context);
+    assert_has_field(&json, "durationMs", context);
+    assert_has_field(&json, "targetsFailed", context);
 
     // skillsFailed items should have skillId and error
     let failed = json["skillsFailed"].as_array().unwrap();
@@ -543,23 +571,31 @@ fn test_profile_uninstall_result_json_shape() {
         "success": true,
         "profileId": "react-stack",
         "profileName": "React Stack",
-        "targetPath": "/home/user/project"
+        "targetPath": "/home/user/project",
+        "instructionsKept": false
     });
 
     assert_has_field(&json_with_profile, "success", "ProfileUninstallResult");
     assert_has_field(&json_with_profile, "profileId", "ProfileUninstallResult");
     assert_has_field(&json_with_profile, "profileName", "ProfileUninstallResult");
     assert_has_field(&json_with_profile, "targetPath", "ProfileUninstallResult");
+    assert_has_field(
+        &json_with_profile,
+        "instructionsKept",
+        "ProfileUninstallResult",
+    );
 
     assert!(json_with_profile["success"].is_boolean());
     assert!(json_with_profile["profileId"].is_string());
     assert!(json_with_profile["profileName"].is_string());
     assert!(json_with_profile["targetPath"].is_string());
+    assert!(json_with_profile["instructionsKept"].is_boolean());
 
     // When no profile was detected, profileId and profileName should be absent
     let json_no_profile = serde_json::json!({
         "success": true,
-        "targetPath": "/tmp/empty"
+        "targetPath": "/tmp/empty",
+        "instructionsKept": false
     });
 
     assert_has_field(
@@ -15,6 +15,7 @@ import type {
   SkillSourceType,
   SkillSchema,
   Instructions,
+  InstructionsSection,
   DiagnosticReport,
   PermissionConfig,
   StatusLineConfig,
@@ -158,6 +159,10 @@ export const api = {
     return invoke('remove_mcp_server', { name });
   },
 
+  setMcpServerEnabled(name: string, enabled: boolean): Promise<void> {
+    return invoke('set_mcp_server_enabled', { name, enabled });
+  },
+
   // MCP Settings
   getMcpSettings(): Promise<McpSettings> {
     return invoke('get_mcp_settings');
@@ -211,8 +216,8 @@ export const api = {
   // Skills
   // ============================================
 
-  listSkills(): Promise<Skill[]> {
-    return invoke('list_skills');
+  listSkills(includeProfiles?: boolean): Promise<Skill[]> {
+    return invoke('list_skills', { includeProfiles });
   },
 
   getSkill(id: string): Promise<Skill | null> {
@@ -223,6 +228,10 @@ export const api = {
     return invoke('create_skill', { input });
   },
 
+  importSkill(path: string): Promise<Skill> {
+    return invoke('import_skill', { path });
+  },
+
   updateSkill(id: string, input: UpdateSkillInput): Promise<void> {
     return invoke('update_skill', { id, input });
   },
@@ -231,10 +240,19 @@ export const api = {
     return invoke('toggle_skill', { id, enabled });
   },
 
+  /** Enable or disable a skill for one deploy target; a global disable still wins */
+  toggleSkillForTarget(id: string, target: string, enabled: boolean): Promise<void> {
+    return invoke('toggle_skill_for_target', { id, target, enabled });
+  },
+
   deleteSkill(id: string): Promise<void> {
     return invoke('delete_skill', { id });
   },
 
+  renameSkill(oldId: string, newId: string): Promise<Skill> {
+    return invoke('rename_skill', { oldId, newId });
+  },
+
   setSkillCategory(skillId: string, category: string): Promise<void> {
     return invoke('set_skill_category', { skillId, category });
   },
@@ -255,6 +273,10 @@ export const api = {
     description: string;
     fetchable: boolean;
     schema: string;
+    branch?: string;
+    skillsPath?: string;
+    authTokenEnv?: string;
+    validate?: boolean;
   }): Promise<void> {
     return invoke('add_skill_source', { input: source });
   },
@@ -268,6 +290,10 @@ export const api = {
       description?: string;
       fetchable?: boolean;
       schema?: SkillSchema;
+      branch?: string;
+      skillsPath?: string;
+      authTokenEnv?: string;
+      validate?: boolean;
     }
   ): Promise<void> {
     return invoke('update_skill_source', { id, ...updates });
@@ -282,7 +308,9 @@ export const api = {
     skillContent: string;
     sourceId: string;
     sourceName: string;
-  }): Promise<Skill> {
+    assignToProfile?: string;
+    allowMain?: boolean;
+  }): Promise<import('./types').SkillInstallResult> {
     return invoke('install_skill_from_source', { input });
   },
 
@@ -291,24 +319,52 @@ export const api = {
     skillId: string;
     sourceId: string;
     sourceName: string;
-  }): Promise<Skill> {
+    branch?: string;
+    assignToProfile?: string;
+    allowMain?: boolean;
+  }): Promise<import('./types').SkillInstallResult> {
     return invoke('install_skill_from_remote', { input });
   },
 
+  installSkillsFromRemote(
+    specs: import('./types').InstallSkillInput[]
+  ): Promise<import('./types').BulkSkillInstallResult> {
+    return invoke('install_skills_from_remote', { specs });
+  },
+
   getInstalledSkillIds(): Promise<string[]> {
     return invoke('get_installed_skill_ids');
   },
 
-  fetchRemoteSkills(sourceId: string): Promise<import('./types').RemoteSkill[]> {
-    return invoke('fetch_remote_skills', { sourceId });
+  fetchRemoteSkills(
+    sourceId: string,
+    forceRefresh = false
+  ): Promise<import('./types').RemoteSkillFetch> {
+    return invoke('fetch_remote_skills', { sourceId, forceRefresh });
+  },
+
+  checkSkillUpdates(): Promise<import('./types').SkillUpdateCheck> {
+    return invoke('check_skill_updates');
+  },
+
+  /** Unified diff of a source-installed skill against its original (empty if unmodified) */
+  diffSkill(id: string): Promise<import('./types').SkillDiff> {
+    return invoke('diff_skill', { id });
   },
 
   fetchSkillContent(url: string): Promise<string> {
     return invoke('fetch_skill_content', { url });
   },
 
-  fetchRemoteSkillFiles(sourceUrl: string, skillId: string): Promise<RemoteSkillFile[]> {
-    return invoke('fetch_remote_skill_files', { input: { sourceUrl, skillId } });
+  fetchRemoteSkillFiles(
+    sourceUrl: string,
+    skillId: string,
+    branch?: string,
+    skillsPath?: string
+  ): Promise<RemoteSkillFile[]> {
+    return invoke('fetch_remote_skill_files', {
+      input: { sourceUrl, skillId, branch, skillsPath },
+    });
   },
 
   // ============================================
@@ -323,6 +379,15 @@ export const api = {
     return invoke('update_instructions', { content });
   },
 
+  getInstructionsSections(): Promise<InstructionsSection[]> {
+    return invoke('get_instructions_sections');
+  },
+
+  /** Rewrite one managed section, keeping the rest of CLAUDE.md as is */
+  updateInstructionsSection(name: string, content: string): Promise<void> {
+    return invoke('update_instructions_section', { name, content });
+  },
+
   // ============================================
   // Project & Release
   // ============================================
@@ -347,6 +412,10 @@ export const api = {
     return invoke('bump_version', { bumpType });
   },
 
+  getDefaultChangelog(): Promise<string> {
+    return invoke('get_default_changelog');
+  },
+
   createRelease(version: string, changelog: string, prerelease: boolean): Promise<string> {
     return invoke('create_release', { version, changelog, prerelease });
   },
@@ -391,6 +460,10 @@ export const api = {
     return invoke('create_profile', { input });
   },
 
+  cloneProfile(sourceId: string, newId: string, newName: string): Promise<Profile> {
+    return invoke('clone_profile', { sourceId, newId, newName });
+  },
+
   updateProfile(id: string, input: UpdateProfileInput): Promise<Profile> {
     return invoke('update_profile', { id, input });
   },
@@ -427,8 +500,8 @@ export const api = {
     return invoke('update_installed_profile', { profileId, targetPath: targetPath ?? null });
   },
 
-  uninstallProfile(targetPath: string): Promise<void> {
-    return invoke('uninstall_profile', { targetPath });
+  uninstallProfile(targetPath: string, keepInstructions = false): Promise<void> {
+    return invoke('uninstall_profile', { targetPath, keepInstructions });
   },
 
   // Auto-invoke Rules
@@ -65,6 +65,7 @@ export default function Mcp() {
         command: formData.command,
         args: formData.args.split('\n').filter((a) => a.trim()),
         env: formData.env ? JSON.parse(formData.env) : undefined,
+        enabled: servers[editing]?.enabled,
       };
       await api.updateMcpServer(editing, server);
       toast.success('MCP server updated');
@@ -87,6 +88,16 @@ export default function Mcp() {
     }
   }
 
+  async function handleToggleEnabled(name: string, enabled: boolean) {
+    try {
+      await api.setMcpServerEnabled(name, enabled);
+      toast.success(`MCP server ${enabled ? 'enabled' : 'disabled'}`);
+      loadData();
+    } catch (err) {
+      toast.error(`Failed to ${enabled ? 'enable' : 'disable'} MCP server`);
+    }
+  }
+
   async function handleUpdateSettings(updates: Partial<McpSettings>) {
     if (!settings) return;
     const updated = { ...settings, ...updates };
@@ -288,12 +299,23 @@ export default function Mcp() {
                       HTTP
                     </span>
                   )}
+                  {server.enabled === false && (
+                    <span className="badge badge-secondary" style={{ marginLeft: '0.5rem', fontSize: '0.7rem' }}>
+                      Disabled
+                    </span>
+                  )}
                 </h4>
                 <p>
                   <code>{getServerDisplay(server)}</code>
                 </p>
               </div>
               <div className="item-actions">
+                <button
+                  className="btn btn-sm btn-secondary"
+                  onClick={() => handleToggleEnabled(name, server.enabled === false)}
+                >
+                  {server.enabled === false ? 'Enable' : 'Disable'}
+                </button>
                 {!isHttpServer(server) && (
                   <button className="btn btn-sm btn-secondary" onClick={() => startEdit(name, server)}>
                     Edit
@@ -38,6 +38,12 @@ export default function Release() {
         branch: projectConfig.github.branch,
       });
 
+      // Start from the commits since the last release; the notes stay editable
+      if (projectConfig.autoChangelog) {
+        const generated = await api.getDefaultChangelog().catch(() => '');
+        setChangelog((current) => current || generated);
+      }
+
       // Fetch latest release if configured
       if (projectConfig.github.owner && projectConfig.github.repo) {
         const latest = await api.fetchLatestRelease();
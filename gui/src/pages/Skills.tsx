@@ -1,6 +1,6 @@
 import { useEffect, useRef, useState } from 'react';
 import { api } from '../api';
-import type { Skill, SkillCategory, CreateSkillInput, SkillSource, SkillSourceType, SkillSchema, RemoteSkill, IdeInfo, SkillFile, RemoteSkillFile } from '../types';
+import type { Skill, SkillCategory, CreateSkillInput, SkillSource, SkillSourceType, SkillSchema, RemoteSkill, IdeInfo, SkillFile, RemoteSkillFile, Profile } from '../types';
 import toast from 'react-hot-toast';
 import { Prism as SyntaxHighlighter } from 'react-syntax-highlighter';
 import { vscDarkPlus } from 'react-syntax-highlighter/dist/esm/styles/prism';
@@ -52,6 +52,7 @@ export default function Skills() {
     description: '',
     fetchable: false,
     schema: 'standard' as SkillSchema,
+    branch: '',
   });
 
   // Browse state
@@ -74,6 +75,8 @@ export default function Skills() {
     remote?: RemoteSkill;
   } | null>(null);
   const [selectedCategory, setSelectedCategory] = useState<SkillCategory>('custom');
+  const [projectProfiles, setProjectProfiles] = useState<Profile[]>([]);
+  const [assignToProfile, setAssignToProfile] = useState<string>('');
 
 
   // IDE and skill files state
@@ -111,12 +114,14 @@ export default function Skills() {
 
   async function loadData() {
     try {
-      const [skillList, sourceList] = await Promise.all([
+      const [skillList, sourceList, profileList] = await Promise.all([
         api.listSkills(),
         api.listSkillSources(),
+        api.listProfiles(),
       ]);
       setSkills(skillList);
       setSources(sourceList);
+      setProjectProfiles(profileList.filter((p) => p.profileType === 'project'));
     } catch (err) {
       toast.error('Failed to load skills data');
     } finally {
@@ -196,18 +201,24 @@ export default function Skills() {
         const source = sources.find((s) => s.id === categoryPopup.remote!.sourceId);
         if (!source) throw new Error('Source not found');
 
-        await api.installSkillFromRemote({
+        const installed = await api.installSkillFromRemote({
           sourceUrl: source.url,
           skillId: categoryPopup.remote.id,
           sourceId: categoryPopup.remote.sourceId,
           sourceName: categoryPopup.remote.sourceName,
+          branch: source.branch,
+          assignToProfile: assignToProfile || undefined,
         });
 
         if (selectedCategory !== 'custom') {
           await api.setSkillCategory(categoryPopup.id, selectedCategory);
         }
 
-        toast.success(`Added "${categoryPopup.name}"`);
+        toast.success(
+          installed.assignedProfile
+            ? `Added "${categoryPopup.name}" to profile "${installed.assignedProfile}"`
+            : `Added "${categoryPopup.name}"`
+        );
 
         if (availableIdes.length > 0) {
           await api.openSkillInIde(categoryPopup.id, availableIdes[0].command);
@@ -280,6 +291,7 @@ export default function Skills() {
       description: '',
       fetchable: false,
       schema: 'standard',
+      branch: '',
     });
   }
 
@@ -292,6 +304,7 @@ export default function Skills() {
       description: source.description,
       fetchable: source.fetchable,
       schema: source.schema,
+      branch: source.branch ?? '',
     });
   }
 
@@ -326,6 +339,7 @@ export default function Skills() {
         description: sourceForm.description,
         fetchable: sourceForm.fetchable,
         schema: sourceForm.schema,
+        branch: sourceForm.branch,
       });
       toast.success('Source updated');
       setEditingSource(null);
@@ -377,9 +391,10 @@ export default function Skills() {
     setRemoteSkills([]);
 
     try {
-      const skills = await api.fetchRemoteSkills(sourceId);
+      const { skills, warnings } = await api.fetchRemoteSkills(sourceId, forceRefresh);
       remoteSkillsCache.current[sourceId] = skills;
       setRemoteSkills(skills);
+      warnings.forEach((warning) => toast(warning, { icon: '⚠️' }));
     } catch (err: unknown) {
       const message = err instanceof Error ? err.message : 'Failed to fetch skills';
       toast.error(message);
@@ -390,6 +405,7 @@ export default function Skills() {
 
   function handleAddFromSource(remote: RemoteSkill) {
     setSelectedCategory('custom');
+    setAssignToProfile('');
     setCategoryPopup({
       mode: 'install',
       id: remote.id,
@@ -419,18 +435,22 @@ export default function Skills() {
       // We need: https://github.com/owner/repo and skill-id
       let repoUrl = source.url;
       let skillId = remote.id;
+      let branch = source.branch;
+      let skillsPath = source.skillsPath;
 
       if (source.schema === 'skills-sh' && remote.url.includes('github.com')) {
-        // Extract repo URL and skill ID from the remote.url
-        const match = remote.url.match(/https:\/\/github\.com\/([^/]+\/[^/]+)\/tree\/main\/skills\/(.+)/);
+        // Extract repo URL, branch and skill ID from the remote.url
+        const match = remote.url.match(/https:\/\/github\.com\/([^/]+\/[^/]+)\/tree\/([^/]+)\/skills\/(.+)/);
         if (match) {
           repoUrl = `https://github.com/${match[1]}`;
-          skillId = match[2];
+          branch = match[2];
+          skillId = match[3];
+          skillsPath = undefined;
         }
       }
 
       // Fetch the file structure
-      const files = await api.fetchRemoteSkillFiles(repoUrl, skillId);
+      const files = await api.fetchRemoteSkillFiles(repoUrl, skillId, branch, skillsPath);
 
       // Only update state if this is still the skill we're previewing
       setPreviewFiles(files);
@@ -470,6 +490,7 @@ export default function Skills() {
   function handleAddFromPreview() {
     if (!previewingRemote) return;
     setSelectedCategory('custom');
+    setAssignToProfile('');
     setCategoryPopup({
       mode: 'install',
       id: previewingRemote.id,
@@ -928,6 +949,21 @@ export default function Skills() {
             </div>
           )}
 
+          {sourceForm.fetchable && sourceForm.schema === 'standard' && (
+            <div className="form-group">
+              <label>Branch</label>
+              <input
+                type="text"
+                value={sourceForm.branch}
+                onChange={(e) => setSourceForm({ ...sourceForm, branch: e.target.value })}
+                placeholder="main"
+              />
+              <p style={{ color: 'var(--text-secondary)', fontSize: '0.75rem', marginTop: '0.25rem' }}>
+                Leave empty for <code>main</code>. If the branch does not exist, <code>master</code> is tried.
+              </p>
+            </div>
+          )}
+
           <div style={{ display: 'flex', gap: '0.75rem' }}>
             <button className="btn btn-primary" onClick={isEditing ? handleSaveSource : handleAddSource}>
               {isEditing ? 'Save Changes' : 'Add Source'}
@@ -1354,6 +1390,16 @@ export default function Skills() {
                   </option>
                 ))}
               </select>
+              {selectedSource && enabledSources.find((s) => s.id === selectedSource)?.fetchable && (
+                <button
+                  className="btn btn-secondary"
+                  onClick={() => handleSelectSource(selectedSource, true)}
+                  disabled={browseLoading}
+                  style={{ marginLeft: '0.5rem' }}
+                >
+                  Refresh
+                </button>
+              )}
             </div>
             {enabledSources.length === 0 && (
               <p style={{ color: 'var(--text-secondary)', fontStyle: 'italic' }}>
@@ -1624,6 +1670,23 @@ export default function Skills() {
                 ))}
               </select>
             </div>
+            {categoryPopup.mode === 'install' && projectProfiles.length > 0 && (
+              <div className="form-group">
+                <label>Add to profile</label>
+                <select
+                  value={assignToProfile}
+                  onChange={(e) => setAssignToProfile(e.target.value)}
+                  style={{ width: '100%' }}
+                >
+                  <option value="">None</option>
+                  {projectProfiles.map((profile) => (
+                    <option key={profile.id} value={profile.id}>
+                      {profile.name}
+                    </option>
+                  ))}
+                </select>
+              </div>
+            )}
             <div style={{ display: 'flex', gap: '0.75rem', marginTop: '1rem' }}>
               <button className="btn btn-primary" onClick={handleCategoryPopupConfirm}>
                 Save
@@ -8,6 +8,10 @@ export interface PluginManifest {
   author: {
     name: string;
   };
+  /** Extra command files or directories, relative to the plugin root */
+  commands?: string | string[];
+  /** Extra skill directories, relative to the plugin root */
+  skills?: string | string[];
 }
 
 // ============================================
@@ -57,12 +61,18 @@ export interface McpServer {
   url?: string;
   transport?: 'http';
   headers?: Record<string, string>;
+  // Omitted when enabled; disabled servers are kept but not loaded by Claude Code
+  enabled?: boolean;
 }
 
 export interface McpSettings {
   defaultTimeout: number;
   retryAttempts: number;
   logLevel: 'debug' | 'info' | 'warn' | 'error';
+  /** Explicit secret flags per env/header key (true = always mask, false = never mask) */
+  secretKeys?: Record<string, boolean>;
+  /** Fail on unset `${VAR}` placeholders instead of leaving them as-is */
+  strictEnv?: boolean;
 }
 
 // ============================================
@@ -96,6 +106,12 @@ export interface SkillSource {
   /** The schema/structure used by this source */
   schema: SkillSchema;
   skillCount?: number;
+  /** Git branch skills are read from (defaults to main) */
+  branch?: string;
+  /** Directory holding the skills (defaults to skills; empty for the repo root) */
+  skillsPath?: string;
+  /** Env var holding a GitHub token for a private repo (the token itself is never stored) */
+  authTokenEnv?: string;
 }
 
 export interface RemoteSkill {
@@ -110,6 +126,35 @@ export interface RemoteSkill {
   installed: boolean;
 }
 
+export interface SkillInstallResult extends Skill {
+  assignedProfile?: string;
+  /** Remote installs only: the branch the files came from */
+  branch?: string;
+}
+
+export interface RemoteSkillFetch {
+  skills: RemoteSkill[];
+  warnings: string[];
+}
+
+export interface SkillUpdate {
+  skillId: string;
+  hasUpdate: boolean;
+  /** Local edits that an update would overwrite */
+  isLocallyModified: boolean;
+}
+
+export interface SkillUpdateCheck {
+  updates: SkillUpdate[];
+  warnings: string[];
+}
+
+export interface SkillDiff {
+  skillId: string;
+  diff: string;
+  warnings: string[];
+}
+
 // ============================================
 // Skills (skills/**/SKILL.md)
 // ============================================
@@ -126,6 +171,13 @@ export interface Skill {
   sourceId?: string;
   sourceName?: string;
   isModified: boolean;
+  /** Optional SKILL.md frontmatter metadata */
+  version?: string;
+  author?: string;
+  license?: string;
+  tags?: string[];
+  /** Profiles that include this skill (only when listed with includeProfiles) */
+  profileIds?: string[];
 }
 
 export type SkillCategory =
@@ -135,7 +187,9 @@ export type SkillCategory =
   | 'testing'
   | 'ai-sdk'
   | 'utilities'
-  | 'custom';
+  | 'custom'
+  // Team-defined category label
+  | (string & {});
 
 export interface CreateSkillInput {
   id: string;
@@ -158,6 +212,13 @@ export interface InstallSkillInput {
   skillId: string;
 }
 
+export interface BulkSkillInstallResult {
+  skillsInstalled: string[];
+  /** Already installed; left untouched */
+  skillsSkipped: string[];
+  skillsFailed: SkillInstallError[];
+}
+
 // ============================================
 // Instructions (CLAUDE.md)
 // ============================================
@@ -166,6 +227,12 @@ export interface Instructions {
   lastModified: string;
 }
 
+/** A named block of CLAUDE.md between rhinolabs:section markers */
+export interface InstructionsSection {
+  name: string;
+  content: string;
+}
+
 // ============================================
 // Diagnostics
 // ============================================
@@ -182,6 +249,8 @@ export interface DiagnosticReport {
   passed: number;
   failed: number;
   warnings: number;
+  /** True when no check failed */
+  success: boolean;
 }
 
 // ============================================
@@ -294,6 +363,15 @@ export interface Profile {
   generateCopilot: boolean;
   /** Generate AGENTS.md as master file */
   generateAgents: boolean;
+  /** Generate GEMINI.md even when Antigravity is not a deploy target */
+  generateGemini: boolean;
+  /** Generate opencode.json even when OpenCode is not a deploy target */
+  generateOpencode: boolean;
+  tags: string[];
+  /** Parent profile whose skills and instructions are inherited at install */
+  extends?: string;
+  /** Output style installed with this user profile instead of the globally active one */
+  outputStyle?: string;
   createdAt: string;
   updatedAt: string;
 }
@@ -307,6 +385,9 @@ export interface CreateProfileInput {
   instructions?: string;
   generateCopilot?: boolean;
   generateAgents?: boolean;
+  generateGemini?: boolean;
+  generateOpencode?: boolean;
+  tags?: string[];
 }
 
 export interface UpdateProfileInput {
@@ -316,6 +397,9 @@ export interface UpdateProfileInput {
   instructions?: string;
   generateCopilot?: boolean;
   generateAgents?: boolean;
+  generateGemini?: boolean;
+  generateOpencode?: boolean;
+  tags?: string[];
 }
 
 export interface ProfileInstallResult {
@@ -323,6 +407,10 @@ export interface ProfileInstallResult {
   profileName: string;
   targetPath: string;
   skillsInstalled: string[];
+  /** Installed skills left as is on every target because their source did not change */
+  skillsUnchanged?: string[];
+  /** Skills actually redeployed, per installed target */
+  skillsRefreshed?: TargetSkills[];
   skillsFailed: SkillInstallError[];
   /** For Main-Profile: indicates if instructions were installed */
   instructionsInstalled?: boolean;
@@ -330,6 +418,13 @@ export interface ProfileInstallResult {
   settingsInstalled?: boolean;
   /** For Main-Profile: name of the output style installed */
   outputStyleInstalled?: string;
+  /** Project profiles on Claude Code: whether .claude-plugin/plugin.json was written */
+  pluginManifestInstalled?: boolean;
+  /** Skills left out because they are globally disabled (--only-enabled) */
+  skillsSkippedDisabled?: string[];
+  /** Targets that failed to install; the others were still deployed */
+  targetsFailed?: TargetInstallError[];
+  durationMs: number;
 }
 
 export interface SkillInstallError {
@@ -337,6 +432,16 @@ export interface SkillInstallError {
   error: string;
 }
 
+export interface TargetSkills {
+  target: string;
+  skills: string[];
+}
+
+export interface TargetInstallError {
+  target: string;
+  error: string;
+}
+
 // ============================================
 // Deploy & Sync
 // ============================================
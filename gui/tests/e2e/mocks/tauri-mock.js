@@ -227,6 +227,9 @@
       case 'fetch_latest_release':
         return '1.0.0';
 
+      case 'get_default_changelog':
+        return "## What's Changed\n\n### Features\n\n- add profile diff (abc1234)\n";
+
       case 'install_plugin': {
         state.status.isInstalled = true;
         state.status.version = '1.0.0';
@@ -539,6 +542,30 @@
         return null;
       }
 
+      case 'get_instructions_sections': {
+        const pattern = /<!-- rhinolabs:section:([a-z0-9-]+):start -->\n?([\s\S]*?)\n?<!-- rhinolabs:section:\1:end -->/g;
+        return [...state.instructions.content.matchAll(pattern)].map(([, name, content]) => ({
+          name,
+          content,
+        }));
+      }
+
+      case 'update_instructions_section': {
+        const { name, content } = args;
+        const start = `<!-- rhinolabs:section:${name}:start -->`;
+        const end = `<!-- rhinolabs:section:${name}:end -->`;
+        const block = `${start}\n${content}\n${end}`;
+        const existing = state.instructions.content;
+        const from = existing.indexOf(start);
+        const to = from === -1 ? -1 : existing.indexOf(end, from);
+        state.instructions.content =
+          to === -1
+            ? `${existing.trimEnd()}\n\n${block}\n`
+            : existing.slice(0, from) + block + existing.slice(to + end.length);
+        state.instructions.lastModified = new Date().toISOString();
+        return null;
+      }
+
       // ----------------------------------------
       // IDE Commands
       // ----------------------------------------
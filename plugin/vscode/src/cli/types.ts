@@ -29,6 +29,10 @@ export interface Profile {
   instructions?: string;
   generateCopilot: boolean;
   generateAgents: boolean;
+  generateGemini: boolean;
+  generateOpencode: boolean;
+  tags: string[];
+  extends?: string;
   createdAt: string;
   updatedAt: string;
 }
@@ -42,15 +46,25 @@ export interface SkillInstallError {
   error: string;
 }
 
+export interface TargetInstallError {
+  target: string;
+  error: string;
+}
+
 export interface ProfileInstallResult {
   profileId: string;
   profileName: string;
   targetPath: string;
   skillsInstalled: string[];
+  skillsUnchanged?: string[];
   skillsFailed: SkillInstallError[];
   instructionsInstalled?: boolean;
   settingsInstalled?: boolean;
   outputStyleInstalled?: string;
+  pluginManifestInstalled?: boolean;
+  skillsSkippedDisabled?: string[];
+  targetsFailed?: TargetInstallError[];
+  durationMs: number;
 }
 
 // ============================================
@@ -62,6 +76,7 @@ export interface ProfileUninstallResult {
   profileId?: string;
   profileName?: string;
   targetPath: string;
+  instructionsKept: boolean;
 }
 
 // ============================================
@@ -76,6 +91,7 @@ export interface ProfileSyncResult {
   removed: string[];
   unchanged: string[];
   profileId?: string;
+  warnings: string[];
 }
 
 // ============================================
@@ -104,6 +120,11 @@ export interface Skill {
   sourceId?: string;
   sourceName?: string;
   isModified: boolean;
+  version?: string;
+  author?: string;
+  license?: string;
+  tags?: string[];
+  profileIds?: string[];
 }
 
 // ============================================
@@ -123,6 +144,7 @@ export interface DiagnosticReport {
   passed: number;
   failed: number;
   warnings: number;
+  success: boolean;
 }
 
 // ============================================
@@ -178,6 +178,9 @@ export const mockProfiles: Profile[] = [
     autoInvokeRules: [],
     generateCopilot: true,
     generateAgents: false,
+    generateGemini: false,
+    generateOpencode: false,
+    tags: [],
     createdAt: '2026-01-20T10:00:00Z',
     updatedAt: '2026-01-20T10:00:00Z',
   },
@@ -190,6 +193,9 @@ export const mockProfiles: Profile[] = [
     autoInvokeRules: [],
     generateCopilot: true,
     generateAgents: false,
+    generateGemini: false,
+    generateOpencode: false,
+    tags: [],
     createdAt: '2026-01-20T10:00:00Z',
     updatedAt: '2026-01-20T10:00:00Z',
   },
@@ -206,6 +212,7 @@ export const mockDiagnostics: DiagnosticReport = {
   passed: 4,
   failed: 0,
   warnings: 1,
+  success: true,
 };
 
 export const mockProjectStatus: ProjectStatus = {
@@ -58,6 +58,7 @@ export interface McpSettings {
   defaultTimeout: number;
   retryAttempts: number;
   logLevel: 'debug' | 'info' | 'warn' | 'error';
+  strictEnv?: boolean;
 }
 
 // ============================================
@@ -87,6 +88,12 @@ export interface SkillSource {
   fetchable: boolean;
   schema: SkillSchema;
   skillCount?: number;
+  /** Git branch skills are read from (defaults to main) */
+  branch?: string;
+  /** Directory holding the skills (defaults to skills; empty for the repo root) */
+  skillsPath?: string;
+  /** Env var holding a GitHub token for a private repo (the token itself is never stored) */
+  authTokenEnv?: string;
 }
 
 export interface RemoteSkill {
@@ -117,6 +124,11 @@ export interface Skill {
   sourceId?: string;
   sourceName?: string;
   isModified: boolean;
+  version?: string;
+  author?: string;
+  license?: string;
+  tags?: string[];
+  profileIds?: string[];
 }
 
 export type SkillCategory =
@@ -173,6 +185,7 @@ export interface DiagnosticReport {
   passed: number;
   failed: number;
   warnings: number;
+  success: boolean;
 }
 
 // ============================================
@@ -275,6 +288,10 @@ export interface Profile {
   instructions?: string;
   generateCopilot: boolean;
   generateAgents: boolean;
+  generateGemini: boolean;
+  generateOpencode: boolean;
+  tags: string[];
+  extends?: string;
   createdAt: string;
   updatedAt: string;
 }
@@ -288,6 +305,9 @@ export interface CreateProfileInput {
   instructions?: string;
   generateCopilot?: boolean;
   generateAgents?: boolean;
+  generateGemini?: boolean;
+  generateOpencode?: boolean;
+  tags?: string[];
 }
 
 export interface UpdateProfileInput {
@@ -297,6 +317,9 @@ export interface UpdateProfileInput {
   instructions?: string;
   generateCopilot?: boolean;
   generateAgents?: boolean;
+  generateGemini?: boolean;
+  generateOpencode?: boolean;
+  tags?: string[];
 }
 
 export interface ProfileInstallResult {
@@ -304,10 +327,15 @@ export interface ProfileInstallResult {
   profileName: string;
   targetPath: string;
   skillsInstalled: string[];
+  skillsUnchanged?: string[];
   skillsFailed: SkillInstallError[];
   instructionsInstalled?: boolean;
   settingsInstalled?: boolean;
   outputStyleInstalled?: string;
+  pluginManifestInstalled?: boolean;
+  skillsSkippedDisabled?: string[];
+  targetsFailed?: TargetInstallError[];
+  durationMs: number;
 }
 
 export interface SkillInstallError {
@@ -315,6 +343,11 @@ export interface SkillInstallError {
   error: string;
 }
 
+export interface TargetInstallError {
+  target: string;
+  error: string;
+}
+
 // ============================================
 // Deploy & Sync
 // ============================================